@@ -0,0 +1,474 @@
+use zeroize::Zeroize;
+
+use crate::aead::Aead;
+use crate::cipher::aes::aes_round;
+use crate::error::SymcError;
+use crate::mac::constant_time_eq;
+
+pub const AEGIS_TAG_SIZE: usize = 16;
+
+/// Both the AD and the message are limited to 2^61 - 1 bytes.
+const MAX_LEN: u64 = (1 << 61) - 1;
+
+/// Fibonacci sequence mod 256
+const C0: [u8; 16] = [
+    0x00, 0x01, 0x01, 0x02, 0x03, 0x05, 0x08, 0x0d, 0x15, 0x22, 0x37, 0x59, 0x90, 0xe9, 0x79, 0x62
+];
+const C1: [u8; 16] = [
+    0xdb, 0x3d, 0x18, 0x55, 0x6d, 0xc2, 0x2f, 0xf1, 0x20, 0x11, 0x31, 0x42, 0x73, 0xb5, 0x28, 0xdd
+];
+
+#[inline]
+fn is_valid_tag_size(tag_size: usize) -> bool {
+    matches!(tag_size, 16 | 32)
+}
+
+#[inline]
+fn xor(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    core::array::from_fn(|i| a[i] ^ b[i])
+}
+
+#[inline]
+fn and(a: &[u8; 16], b: &[u8; 16]) -> [u8; 16] {
+    core::array::from_fn(|i| a[i] & b[i])
+}
+
+/// S'_i = AESRound(S_{i-1}, S_i) across the whole state, message words are XORed in by the caller.
+#[inline]
+fn rounds<const N: usize>(s: &mut [[u8; 16]; N]) {
+    let last = s[N - 1];
+    for i in (1..N).rev() {
+        let mut word = s[i - 1];
+        aes_round(&mut word, &s[i]);
+        s[i] = word;
+    }
+    let mut word = last;
+    aes_round(&mut word, &s[0]);
+    s[0] = word;
+}
+
+/// LE64(ad_len_bits) || LE64(msg_len_bits)
+#[inline]
+fn length_block(aad_len: u64, text_len: u64) -> [u8; 16] {
+    let mut block = [0u8; 16];
+    block[..8].copy_from_slice(&(aad_len * 8).to_le_bytes());
+    block[8..].copy_from_slice(&(text_len * 8).to_le_bytes());
+    block
+}
+
+/// Eight word AEGIS-128L state, absorbing 32 bytes per update.
+#[cfg(feature = "aegis128l")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct State128L {
+    s: [[u8; 16]; 8]
+}
+
+#[cfg(feature = "aegis128l")]
+impl State128L {
+    fn new(key: &[u8; 16], nonce: &[u8; 16]) -> Self {
+        let key_nonce = xor(key, nonce);
+        let mut state = Self {
+            s: [key_nonce, C1, C0, C1, key_nonce, xor(key, &C0), xor(key, &C1), xor(key, &C0)]
+        };
+
+        let mut block = [0u8; 32];
+        block[..16].copy_from_slice(nonce);
+        block[16..].copy_from_slice(key);
+        for _ in 0..10 {
+            state.update(&block);
+        }
+        block.zeroize();
+        state
+    }
+
+    #[inline]
+    fn update(&mut self, m: &[u8; 32]) {
+        rounds(&mut self.s);
+        self.s[0] = xor(&self.s[0], m[..16].try_into().unwrap());
+        self.s[4] = xor(&self.s[4], m[16..].try_into().unwrap());
+    }
+
+    #[inline]
+    fn keystream(&self) -> [u8; 32] {
+        let s = &self.s;
+        let z0 = xor(&xor(&s[6], &s[1]), &and(&s[2], &s[3]));
+        let z1 = xor(&xor(&s[2], &s[5]), &and(&s[6], &s[7]));
+        let mut z = [0u8; 32];
+        z[..16].copy_from_slice(&z0);
+        z[16..].copy_from_slice(&z1);
+        z
+    }
+
+    fn finalize(&mut self, aad_len: u64, text_len: u64, tag: &mut [u8]) {
+        let t = xor(&self.s[2], &length_block(aad_len, text_len));
+        let mut block = [0u8; 32];
+        block[..16].copy_from_slice(&t);
+        block[16..].copy_from_slice(&t);
+        for _ in 0..7 {
+            self.update(&block);
+        }
+
+        let s = &self.s;
+        if tag.len() == 16 {
+            let mut t = s[0];
+            s[1..7].iter().for_each(|word| t = xor(&t, word));
+            tag.copy_from_slice(&t);
+        } else {
+            tag[..16].copy_from_slice(&xor(&xor(&s[0], &s[1]), &xor(&s[2], &s[3])));
+            tag[16..].copy_from_slice(&xor(&xor(&s[4], &s[5]), &xor(&s[6], &s[7])));
+        }
+    }
+}
+
+/// Six word AEGIS-256 state, absorbing 16 bytes per update.
+#[cfg(feature = "aegis256")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct State256 {
+    s: [[u8; 16]; 6]
+}
+
+#[cfg(feature = "aegis256")]
+impl State256 {
+    fn new(key: &[u8; 32], nonce: &[u8; 32]) -> Self {
+        let k0: &[u8; 16] = key[..16].try_into().unwrap();
+        let k1: &[u8; 16] = key[16..].try_into().unwrap();
+        let k0_n0 = xor(k0, nonce[..16].try_into().unwrap());
+        let k1_n1 = xor(k1, nonce[16..].try_into().unwrap());
+        let mut state = Self {
+            s: [k0_n0, k1_n1, C1, C0, xor(k0, &C0), xor(k1, &C1)]
+        };
+
+        for _ in 0..4 {
+            state.update(k0);
+            state.update(k1);
+            state.update(&k0_n0);
+            state.update(&k1_n1);
+        }
+        state
+    }
+
+    #[inline]
+    fn update(&mut self, m: &[u8; 16]) {
+        rounds(&mut self.s);
+        self.s[0] = xor(&self.s[0], m);
+    }
+
+    #[inline]
+    fn keystream(&self) -> [u8; 16] {
+        let s = &self.s;
+        xor(&xor(&s[1], &s[4]), &xor(&s[5], &and(&s[2], &s[3])))
+    }
+
+    fn finalize(&mut self, aad_len: u64, text_len: u64, tag: &mut [u8]) {
+        let t = xor(&self.s[3], &length_block(aad_len, text_len));
+        for _ in 0..7 {
+            self.update(&t);
+        }
+
+        let s = &self.s;
+        if tag.len() == 16 {
+            let mut t = s[0];
+            s[1..].iter().for_each(|word| t = xor(&t, word));
+            tag.copy_from_slice(&t);
+        } else {
+            tag[..16].copy_from_slice(&xor(&xor(&s[0], &s[1]), &s[2]));
+            tag[16..].copy_from_slice(&xor(&xor(&s[3], &s[4]), &s[5]));
+        }
+    }
+}
+
+macro_rules! aegis_impl {
+    ($name:ident, $key:ident, $encryptor:ident, $state:ident, $key_size:literal, $nonce_size:literal, $rate:literal, $doc:literal) => {
+        #[derive(Clone, Default, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $key([u8; $key_size]);
+        impl AsRef<[u8]> for $key {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $key {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $key_size]> for $key {
+            fn from(array: [u8; $key_size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$key> for [u8; $key_size] {
+            fn from(key: $key) -> Self {
+                key.0
+            }
+        }
+
+        impl $state {
+            /// Enc/Dec for a whole block and the zero padded last one, `decrypt` picks which side
+            /// of the XOR is fed back into the state.
+            #[inline]
+            fn process(&mut self, input: &[u8], output: &mut [u8], decrypt: bool) {
+                let z = self.keystream();
+                let mut block = [0u8; $rate];
+                block[..input.len()].copy_from_slice(input);
+                let mut out = [0u8; $rate];
+                out.iter_mut()
+                    .zip(block.iter().zip(z.iter()))
+                    .for_each(|(o, (b, k))| *o = b ^ k);
+                output.copy_from_slice(&out[..input.len()]);
+
+                if decrypt {
+                    // ZeroPad(Truncate(out, |cn|))
+                    block = [0u8; $rate];
+                    block[..input.len()].copy_from_slice(&out[..input.len()]);
+                }
+                self.update(&block);
+                block.zeroize();
+                out.zeroize();
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $name {
+            key: $key,
+            tag_size: usize
+        }
+
+        impl $name {
+            /// Creates an instance producing `tag_size` byte tags, 16 or 32.
+            pub fn with_tag_size(key: &$key, tag_size: usize) -> Result<Self, SymcError> {
+                if !is_valid_tag_size(tag_size) {
+                    return Err(SymcError::InvalidLength);
+                }
+
+                let mut aegis = <Self as Aead>::new(key);
+                aegis.tag_size = tag_size;
+                Ok(aegis)
+            }
+
+            /// Starts a streaming encryption with the given nonce.
+            pub fn encryptor(&self, nonce: &[u8]) -> Result<$encryptor, SymcError> {
+                let nonce: [u8; $nonce_size] = nonce.try_into().map_err(|_| SymcError::InvalidLength)?;
+                Ok($encryptor {
+                    state: $state::new(&self.key.0, &nonce),
+                    aad_len: 0,
+                    text_len: 0,
+                    text_started: false,
+                    buffer: [0u8; $rate],
+                    buffer_len: 0
+                })
+            }
+        }
+
+        impl Aead for $name {
+            const KEY_SIZE: usize = $key_size;
+            const TAG_SIZE: usize = AEGIS_TAG_SIZE;
+            type Key = $key;
+
+            fn new(key: &Self::Key) -> Self {
+                Self { key: key.clone(), tag_size: AEGIS_TAG_SIZE }
+            }
+
+            fn tag_size(&self) -> usize {
+                self.tag_size
+            }
+
+            fn encrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+                if tag.len() != self.tag_size {
+                    return Err(SymcError::InvalidLength);
+                }
+                if output.len() < input.len() {
+                    return Err(SymcError::BufferTooSmall);
+                }
+
+                let mut encryptor = self.encryptor(nonce)?;
+                encryptor.update_aad(aad)?;
+                let mut written = encryptor.update(input, output)?;
+                written += encryptor.finalize(&mut output[written..], tag)?;
+                Ok(written)
+            }
+
+            fn decrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+                // the instance fixes the tag size, a 128-bit tag must not pass on a 256-bit instance
+                if tag.len() != self.tag_size {
+                    return Err(SymcError::InvalidLength);
+                }
+                if input.len() as u64 > MAX_LEN || aad.len() as u64 > MAX_LEN {
+                    return Err(SymcError::InvalidInputLength);
+                }
+                if output.len() < input.len() {
+                    return Err(SymcError::BufferTooSmall);
+                }
+                let nonce: [u8; $nonce_size] = nonce.try_into().map_err(|_| SymcError::InvalidLength)?;
+
+                // the tag is only known once the whole message went through the state,
+                // so the plaintext is wiped again on a mismatch
+                let mut state = $state::new(&self.key.0, &nonce);
+                for chunk in aad.chunks($rate) {
+                    let mut block = [0u8; $rate];
+                    block[..chunk.len()].copy_from_slice(chunk);
+                    state.update(&block);
+                }
+                let plaintext = &mut output[..input.len()];
+                for (in_chunk, out_chunk) in input.chunks($rate).zip(plaintext.chunks_mut($rate)) {
+                    state.process(in_chunk, out_chunk, true);
+                }
+
+                let mut expected_tag = [0u8; 32];
+                state.finalize(aad.len() as u64, input.len() as u64, &mut expected_tag[..tag.len()]);
+                let verified = constant_time_eq(&expected_tag[..tag.len()], tag);
+                expected_tag.zeroize();
+                if !verified {
+                    plaintext.zeroize();
+                    return Err(SymcError::AuthenticationFailed);
+                }
+
+                Ok(input.len())
+            }
+        }
+
+        /// Streaming encryption, AAD must be supplied before the payload.
+        ///
+        /// Decryption is one-shot, the tag is only known after the state has absorbed the
+        /// plaintext, so it is decrypted into `output` and wiped again on a mismatch.
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $encryptor {
+            state: $state,
+            aad_len: u64,
+            text_len: u64,
+            text_started: bool,
+            buffer: [u8; $rate],
+            buffer_len: usize
+        }
+
+        impl $encryptor {
+            pub fn new(key: &$key, nonce: &[u8]) -> Result<Self, SymcError> {
+                <$name as Aead>::new(key).encryptor(nonce)
+            }
+
+            pub fn update_aad(&mut self, aad: &[u8]) -> Result<(), SymcError> {
+                if self.text_started {
+                    return Err(SymcError::InvalidInputLength);
+                }
+
+                self.aad_len = self.aad_len.checked_add(aad.len() as u64)
+                    .filter(|len| *len <= MAX_LEN)
+                    .ok_or(SymcError::InvalidInputLength)?;
+
+                let mut input = aad;
+                if self.buffer_len > 0 {
+                    let take = ($rate - self.buffer_len).min(input.len());
+                    self.buffer[self.buffer_len..(self.buffer_len + take)].copy_from_slice(&input[..take]);
+                    self.buffer_len += take;
+                    input = &input[take..];
+                    if self.buffer_len < $rate {
+                        return Ok(());
+                    }
+
+                    let block = self.buffer;
+                    self.state.update(&block);
+                    self.buffer_len = 0;
+                }
+
+                let mut chunks = input.chunks_exact($rate);
+                for chunk in &mut chunks {
+                    self.state.update(chunk.try_into().unwrap());
+                }
+
+                let remainder = chunks.remainder();
+                self.buffer[..remainder.len()].copy_from_slice(remainder);
+                self.buffer_len = remainder.len();
+                Ok(())
+            }
+
+            pub fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+                let mut written = 0;
+
+                self.start_text();
+                if output.len() < (self.buffer_len + input.len()) / $rate * $rate {
+                    return Err(SymcError::BufferTooSmall);
+                }
+
+                let total_len = self.text_len + (self.buffer_len + input.len()) as u64;
+                if total_len > MAX_LEN {
+                    return Err(SymcError::InvalidInputLength);
+                }
+
+                let remaining = $rate - self.buffer_len;
+                if remaining > input.len() {
+                    self.buffer[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(input);
+                    self.buffer_len += input.len();
+                    return Ok(0);
+                }
+
+                self.buffer[self.buffer_len..].copy_from_slice(&input[..remaining]);
+                let block = self.buffer;
+                self.state.process(&block, &mut output[..$rate], false);
+                written += $rate;
+                self.buffer_len = 0;
+
+                let mut chunks = input[remaining..].chunks_exact($rate);
+                for chunk in &mut chunks {
+                    self.state.process(chunk, &mut output[written..(written + $rate)], false);
+                    written += $rate;
+                }
+
+                let remainder = chunks.remainder();
+                self.buffer[..remainder.len()].copy_from_slice(remainder);
+                self.buffer_len = remainder.len();
+
+                self.text_len += written as u64;
+                Ok(written)
+            }
+
+            /// Writes the remaining ciphertext into `output` and the tag into `tag`, whose length selects the tag size.
+            pub fn finalize(mut self, output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+                if !is_valid_tag_size(tag.len()) {
+                    return Err(SymcError::InvalidLength);
+                }
+
+                self.start_text();
+                if output.len() < self.buffer_len {
+                    return Err(SymcError::BufferTooSmall);
+                }
+
+                let buffer_len = self.buffer_len;
+                if buffer_len > 0 {
+                    let block = self.buffer;
+                    self.state.process(&block[..buffer_len], &mut output[..buffer_len], false);
+                    self.text_len += buffer_len as u64;
+                }
+
+                self.state.finalize(self.aad_len, self.text_len, tag);
+                Ok(buffer_len)
+            }
+
+            /// Absorbs the zero padded tail of the AAD before the first payload byte.
+            fn start_text(&mut self) {
+                if self.text_started {
+                    return;
+                }
+
+                if self.buffer_len > 0 {
+                    self.buffer[self.buffer_len..].fill(0);
+                    let block = self.buffer;
+                    self.state.update(&block);
+                    self.buffer_len = 0;
+                }
+                self.text_started = true;
+            }
+        }
+    };
+}
+
+#[cfg(feature = "aegis128l")]
+aegis_impl!(Aegis128L, Aegis128LKey, Aegis128LEncryptor, State128L, 16, 16, 32,
+    "AEGIS-128L (draft-irtf-cfrg-aegis-aead) with a 16 byte nonce and a 16 or 32 byte tag.");
+#[cfg(feature = "aegis256")]
+aegis_impl!(Aegis256, Aegis256Key, Aegis256Encryptor, State256, 32, 32, 16,
+    "AEGIS-256 (draft-irtf-cfrg-aegis-aead) with a 32 byte nonce and a 16 or 32 byte tag.");
@@ -0,0 +1,247 @@
+use zeroize::Zeroize;
+
+use crate::aead::Aead;
+use crate::error::SymcError;
+use crate::hash::ascon::{ascon_p, pad};
+use crate::mac::constant_time_eq;
+
+pub const ASCON_AEAD128_KEY_SIZE: usize = 16;
+pub const ASCON_AEAD128_NONCE_SIZE: usize = 16;
+pub const ASCON_AEAD128_TAG_SIZE: usize = 16;
+
+const AEAD128_IV: u64 = 0x00001000808c0001;
+const RATE: usize = 16;
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct AsconAead128Key([u8; ASCON_AEAD128_KEY_SIZE]);
+impl AsRef<[u8]> for AsconAead128Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for AsconAead128Key {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; ASCON_AEAD128_KEY_SIZE]> for AsconAead128Key {
+    fn from(array: [u8; ASCON_AEAD128_KEY_SIZE]) -> Self {
+        Self(array)
+    }
+}
+impl From<AsconAead128Key> for [u8; ASCON_AEAD128_KEY_SIZE] {
+    fn from(key: AsconAead128Key) -> Self {
+        key.0
+    }
+}
+
+/// Ascon-AEAD128 (SP 800-232, 4) with a 16 byte nonce and a 16 byte tag.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct AsconAead128 {
+    k: [u64; 2]
+}
+
+impl AsconAead128 {
+    /// Starts a streaming encryption with the given nonce.
+    pub fn encryptor(&self, nonce: &[u8]) -> Result<AsconAead128Encryptor, SymcError> {
+        Ok(AsconAead128Encryptor { duplex: Duplex::new(self.k, nonce)? })
+    }
+}
+
+impl Aead for AsconAead128 {
+    const KEY_SIZE: usize = ASCON_AEAD128_KEY_SIZE;
+    const TAG_SIZE: usize = ASCON_AEAD128_TAG_SIZE;
+    type Key = AsconAead128Key;
+
+    fn new(key: &Self::Key) -> Self {
+        Self {
+            k: [
+                u64::from_le_bytes(key.0[..8].try_into().unwrap()),
+                u64::from_le_bytes(key.0[8..].try_into().unwrap())
+            ]
+        }
+    }
+
+    fn encrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        let mut encryptor = self.encryptor(nonce)?;
+        encryptor.update_aad(aad)?;
+        let written = encryptor.update(input, output)?;
+        encryptor.finalize(tag)?;
+        Ok(written)
+    }
+
+    fn decrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        if tag.len() != ASCON_AEAD128_TAG_SIZE {
+            return Err(SymcError::InvalidLength);
+        }
+        if output.len() < input.len() {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        // the duplex only yields the tag after decrypting, so the plaintext is wiped again on a mismatch
+        let mut duplex = Duplex::new(self.k, nonce)?;
+        duplex.absorb_aad(aad);
+        let plaintext = &mut output[..input.len()];
+        duplex.decrypt(input, plaintext);
+
+        let mut expected_tag = [0u8; ASCON_AEAD128_TAG_SIZE];
+        duplex.finalize(&mut expected_tag);
+        let verified = constant_time_eq(&expected_tag, tag);
+        expected_tag.zeroize();
+        if !verified {
+            plaintext.zeroize();
+            return Err(SymcError::AuthenticationFailed);
+        }
+
+        Ok(input.len())
+    }
+}
+
+/// Duplex state over a 16 byte rate in S0 and S1, `pos` is the offset into the current block.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct Duplex {
+    state: [u64; 5],
+    k: [u64; 2],
+    pos: usize,
+    aad_len: u64,
+    aad_done: bool
+}
+
+impl Duplex {
+    /// S = p[12](IV || K || N) ^ (0^192 || K)
+    fn new(k: [u64; 2], nonce: &[u8]) -> Result<Self, SymcError> {
+        let nonce: [u8; ASCON_AEAD128_NONCE_SIZE] = nonce.try_into().map_err(|_| SymcError::InvalidLength)?;
+
+        let mut state = [
+            AEAD128_IV,
+            k[0],
+            k[1],
+            u64::from_le_bytes(nonce[..8].try_into().unwrap()),
+            u64::from_le_bytes(nonce[8..].try_into().unwrap())
+        ];
+        ascon_p(&mut state, 12);
+        state[3] ^= k[0];
+        state[4] ^= k[1];
+
+        Ok(Self { state, k, pos: 0, aad_len: 0, aad_done: false })
+    }
+
+    #[inline]
+    fn byte(&self, index: usize) -> u8 {
+        (self.state[index / 8] >> (8 * (index % 8))) as u8
+    }
+
+    #[inline]
+    fn xor_byte(&mut self, index: usize, byte: u8) {
+        self.state[index / 8] ^= (byte as u64) << (8 * (index % 8));
+    }
+
+    #[inline]
+    fn set_byte(&mut self, index: usize, byte: u8) {
+        let shift = 8 * (index % 8);
+        self.state[index / 8] = (self.state[index / 8] & !(0xff << shift)) | ((byte as u64) << shift);
+    }
+
+    #[inline]
+    fn advance(&mut self) {
+        self.pos += 1;
+        if self.pos == RATE {
+            ascon_p(&mut self.state, 8);
+            self.pos = 0;
+        }
+    }
+
+    fn absorb_aad(&mut self, aad: &[u8]) {
+        for &byte in aad {
+            self.xor_byte(self.pos, byte);
+            self.advance();
+        }
+        self.aad_len += aad.len() as u64;
+    }
+
+    /// Pads the associated data, if there was any, and applies the domain separation bit.
+    fn finish_aad(&mut self) {
+        if self.aad_done {
+            return;
+        }
+        if self.aad_len > 0 {
+            self.state[self.pos / 8] ^= pad(self.pos % 8);
+            ascon_p(&mut self.state, 8);
+            self.pos = 0;
+        }
+        self.state[4] ^= 1 << 63;
+        self.aad_done = true;
+    }
+
+    fn encrypt(&mut self, input: &[u8], output: &mut [u8]) {
+        self.finish_aad();
+        for (&p, c) in input.iter().zip(output.iter_mut()) {
+            self.xor_byte(self.pos, p);
+            *c = self.byte(self.pos);
+            self.advance();
+        }
+    }
+
+    fn decrypt(&mut self, input: &[u8], output: &mut [u8]) {
+        self.finish_aad();
+        for (&c, p) in input.iter().zip(output.iter_mut()) {
+            *p = self.byte(self.pos) ^ c;
+            self.set_byte(self.pos, c);
+            self.advance();
+        }
+    }
+
+    /// Pads the last text block, then T = (p[12](S ^ (0^128 || K || 0^64)) ^ (0^192 || K))[192..320]
+    fn finalize(&mut self, tag: &mut [u8; ASCON_AEAD128_TAG_SIZE]) {
+        self.finish_aad();
+        self.state[self.pos / 8] ^= pad(self.pos % 8);
+        self.state[2] ^= self.k[0];
+        self.state[3] ^= self.k[1];
+        ascon_p(&mut self.state, 12);
+        tag[..8].copy_from_slice(&(self.state[3] ^ self.k[0]).to_le_bytes());
+        tag[8..].copy_from_slice(&(self.state[4] ^ self.k[1]).to_le_bytes());
+    }
+}
+
+/// Streaming Ascon-AEAD128 encryption, AAD must be supplied before the payload.
+///
+/// Ciphertext is produced byte for byte, so nothing is held back until `finalize`. There is
+/// no streaming decryptor: the duplex only yields the tag after decrypting, so
+/// `AsconAead128` decrypts into `output` and wipes it again if the tag does not match.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct AsconAead128Encryptor {
+    duplex: Duplex
+}
+
+impl AsconAead128Encryptor {
+    pub fn new(key: &AsconAead128Key, nonce: &[u8]) -> Result<Self, SymcError> {
+        <AsconAead128 as Aead>::new(key).encryptor(nonce)
+    }
+
+    pub fn update_aad(&mut self, aad: &[u8]) -> Result<(), SymcError> {
+        if self.duplex.aad_done {
+            return Err(SymcError::InvalidInputLength);
+        }
+        self.duplex.absorb_aad(aad);
+        Ok(())
+    }
+
+    /// Encrypts `input` into the first `input.len()` bytes of `output`.
+    pub fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        if output.len() < input.len() {
+            return Err(SymcError::BufferTooSmall);
+        }
+        self.duplex.encrypt(input, &mut output[..input.len()]);
+        Ok(input.len())
+    }
+
+    pub fn finalize(mut self, tag: &mut [u8]) -> Result<(), SymcError> {
+        let tag: &mut [u8; ASCON_AEAD128_TAG_SIZE] = tag.try_into().map_err(|_| SymcError::InvalidLength)?;
+        self.duplex.finalize(tag);
+        Ok(())
+    }
+}
@@ -0,0 +1,151 @@
+use zeroize::Zeroize;
+
+use crate::aead::Aead;
+use crate::cipher::StreamCipher;
+use crate::cipher::chacha20::ChaCha20Key;
+#[cfg(feature = "chacha20poly1305")]
+use crate::cipher::chacha20::{ChaCha20, ChaCha20Nonce};
+#[cfg(feature = "xchacha20poly1305")]
+use crate::cipher::chacha20::{XChaCha20, XChaCha20Nonce};
+use crate::error::SymcError;
+use crate::mac::{Mac, constant_time_eq};
+use crate::mac::prelude::{Poly1305, Poly1305Key, Poly1305Output};
+
+pub const CHACHA20POLY1305_TAG_SIZE: usize = 16;
+
+/// Poly1305 key generation (RFC 8439, 2.6), the first 32 bytes of keystream block 0.
+/// Leaves the cipher positioned at block 1 for the payload.
+fn poly1305_key_gen<S: StreamCipher>(cipher: &mut S) -> Result<Poly1305Key, SymcError> {
+    let mut block = [0u8; 64];
+    cipher.apply_keystream(&[0u8; 64], &mut block)?;
+
+    let mut key = Poly1305Key::default();
+    key.as_mut().copy_from_slice(&block[..32]);
+    block.zeroize();
+    Ok(key)
+}
+
+/// Poly1305 over AAD || pad16(AAD) || C || pad16(C) || le64(len(AAD)) || le64(len(C))
+fn compute_tag(key: &Poly1305Key, aad: &[u8], ciphertext: &[u8]) -> Poly1305Output {
+    const ZERO_PAD: [u8; 16] = [0u8; 16];
+
+    let mut mac = Poly1305::new(key);
+    mac.update(aad);
+    mac.update(&ZERO_PAD[..((16 - aad.len() % 16) % 16)]);
+    mac.update(ciphertext);
+    mac.update(&ZERO_PAD[..((16 - ciphertext.len() % 16) % 16)]);
+    mac.update(&(aad.len() as u64).to_le_bytes());
+    mac.update(&(ciphertext.len() as u64).to_le_bytes());
+    mac.finalize()
+}
+
+fn seal<S: StreamCipher>(mut cipher: S, aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+    if tag.len() != CHACHA20POLY1305_TAG_SIZE {
+        return Err(SymcError::InvalidLength);
+    }
+    if output.len() < input.len() {
+        return Err(SymcError::BufferTooSmall);
+    }
+
+    let poly_key = poly1305_key_gen(&mut cipher)?;
+    let ciphertext = &mut output[..input.len()];
+    cipher.apply_keystream(input, ciphertext)?;
+    tag.copy_from_slice(compute_tag(&poly_key, aad, ciphertext).as_ref());
+    Ok(input.len())
+}
+
+fn open<S: StreamCipher>(mut cipher: S, aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+    if tag.len() != CHACHA20POLY1305_TAG_SIZE {
+        return Err(SymcError::InvalidLength);
+    }
+    if output.len() < input.len() {
+        return Err(SymcError::BufferTooSmall);
+    }
+
+    // the tag covers the ciphertext, so it is checked before anything is decrypted
+    let poly_key = poly1305_key_gen(&mut cipher)?;
+    let expected_tag = compute_tag(&poly_key, aad, input);
+    if !constant_time_eq(expected_tag.as_ref(), tag) {
+        return Err(SymcError::AuthenticationFailed);
+    }
+
+    cipher.apply_keystream(input, &mut output[..input.len()])
+}
+
+/// ChaCha20-Poly1305 AEAD (RFC 8439, 2.8) with a 12 byte nonce.
+#[cfg(feature = "chacha20poly1305")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct ChaCha20Poly1305 {
+    key: ChaCha20Key
+}
+
+#[cfg(feature = "chacha20poly1305")]
+impl Aead for ChaCha20Poly1305 {
+    const KEY_SIZE: usize = 32;
+    const TAG_SIZE: usize = CHACHA20POLY1305_TAG_SIZE;
+    type Key = ChaCha20Key;
+
+    fn new(key: &Self::Key) -> Self {
+        Self { key: key.clone() }
+    }
+
+    fn encrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        let nonce: [u8; 12] = nonce.try_into().map_err(|_| SymcError::InvalidLength)?;
+        seal(ChaCha20::new(&self.key, &ChaCha20Nonce::from(nonce)), aad, input, output, tag)
+    }
+
+    fn decrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let nonce: [u8; 12] = nonce.try_into().map_err(|_| SymcError::InvalidLength)?;
+        open(ChaCha20::new(&self.key, &ChaCha20Nonce::from(nonce)), aad, input, tag, output)
+    }
+}
+
+/// XChaCha20-Poly1305 AEAD (draft-irtf-cfrg-xchacha) with a 24 byte nonce, safe to pick at random.
+#[cfg(feature = "xchacha20poly1305")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct XChaCha20Poly1305 {
+    key: ChaCha20Key
+}
+
+#[cfg(feature = "xchacha20poly1305")]
+impl Aead for XChaCha20Poly1305 {
+    const KEY_SIZE: usize = 32;
+    const TAG_SIZE: usize = CHACHA20POLY1305_TAG_SIZE;
+    type Key = ChaCha20Key;
+
+    fn new(key: &Self::Key) -> Self {
+        Self { key: key.clone() }
+    }
+
+    fn encrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        let nonce: [u8; 24] = nonce.try_into().map_err(|_| SymcError::InvalidLength)?;
+        seal(XChaCha20::new(&self.key, &XChaCha20Nonce::from(nonce)), aad, input, output, tag)
+    }
+
+    fn decrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let nonce: [u8; 24] = nonce.try_into().map_err(|_| SymcError::InvalidLength)?;
+        open(XChaCha20::new(&self.key, &XChaCha20Nonce::from(nonce)), aad, input, tag, output)
+    }
+}
+
+#[cfg(all(test, feature = "chacha20poly1305"))]
+mod tests {
+    use super::*;
+
+    /// Test vector from RFC 8439, 2.6.2
+    #[test]
+    fn poly1305_key_generation() {
+        let key: [u8; 32] = core::array::from_fn(|i| 0x80 + i as u8);
+        let nonce: [u8; 12] = [0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+        let expected: [u8; 32] = [
+            0x8a, 0xd5, 0xa0, 0x8b, 0x90, 0x5f, 0x81, 0xcc, 0x81, 0x50, 0x40, 0x27, 0x4a, 0xb2, 0x94, 0x71,
+            0xa8, 0x33, 0xb6, 0x37, 0xe3, 0xfd, 0x0d, 0xa5, 0x08, 0xdb, 0xb8, 0xe2, 0xfd, 0xd1, 0xa6, 0x46,
+        ];
+
+        let mut chacha = ChaCha20::new(&key.into(), &nonce.into());
+        let poly_key = poly1305_key_gen(&mut chacha).unwrap();
+        assert_eq!(poly_key.as_ref(), &expected);
+    }
+}
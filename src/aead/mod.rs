@@ -0,0 +1,61 @@
+use crate::error::SymcError;
+use zeroize::Zeroize;
+
+#[cfg(any(feature = "chacha20poly1305", feature = "xchacha20poly1305"))]
+pub mod chacha20poly1305;
+
+#[cfg(feature = "ascon-aead128")]
+pub mod ascon;
+
+#[cfg(any(feature = "aegis128l", feature = "aegis256"))]
+pub mod aegis;
+
+#[cfg(feature = "secretbox")]
+pub mod secretbox;
+
+/// Authenticated encryption with associated data.
+///
+/// Decryption is one-shot only, plaintext leaves `decrypt`/`decrypt_detached` only after the
+/// whole message has been authenticated.
+pub trait Aead: Clone + Zeroize {
+    const KEY_SIZE: usize;
+    const TAG_SIZE: usize;
+    type Key: AsRef<[u8]> + Default + Clone + Zeroize;
+
+    fn new(key: &Self::Key) -> Self;
+
+    /// Encrypts `input` into `output` and writes the authentication tag into `tag`.
+    /// `tag` must be exactly `tag_size()` bytes, otherwise this fails with `SymcError::InvalidLength`.
+    fn encrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError>;
+
+    /// Verifies `tag` and decrypts `input` into `output`, `tag` must be exactly `tag_size()` bytes.
+    /// On `SymcError::AuthenticationFailed` no plaintext is left in `output`.
+    fn decrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError>;
+
+    fn tag_size(&self) -> usize {
+        Self::TAG_SIZE
+    }
+
+    /// Encrypts `input` and appends a `tag_size()` byte tag, `output` must hold `input.len() + tag_size()` bytes.
+    fn encrypt(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let tag_size = self.tag_size();
+        if output.len() < input.len() + tag_size {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        let (ciphertext, tag) = output.split_at_mut(input.len());
+        let written = self.encrypt_detached(nonce, aad, input, ciphertext, &mut tag[..tag_size])?;
+        Ok(written + tag_size)
+    }
+
+    /// Decrypts `input` laid out as `ciphertext || tag`.
+    fn decrypt(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let tag_size = self.tag_size();
+        if input.len() < tag_size {
+            return Err(SymcError::InvalidInputLength);
+        }
+
+        let (ciphertext, tag) = input.split_at(input.len() - tag_size);
+        self.decrypt_detached(nonce, aad, ciphertext, tag, output)
+    }
+}
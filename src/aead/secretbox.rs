@@ -0,0 +1,110 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use zeroize::Zeroize;
+
+use crate::cipher::StreamCipher;
+use crate::cipher::salsa20::{Salsa20Key, XSalsa20, XSalsa20Nonce};
+use crate::error::SymcError;
+use crate::mac::{Mac, constant_time_eq};
+use crate::mac::prelude::{Poly1305, Poly1305Key};
+
+pub const SECRETBOX_KEY_SIZE: usize = 32;
+pub const SECRETBOX_NONCE_SIZE: usize = 24;
+pub const SECRETBOX_TAG_SIZE: usize = 16;
+
+/// NaCl `crypto_secretbox` (XSalsa20-Poly1305), in libsodium's combined layout `tag || ciphertext`.
+///
+/// The Poly1305 key is the first 32 bytes of keystream block 0 and the payload is encrypted
+/// with the keystream that follows it, so boxes interoperate with `crypto_secretbox_easy`.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct SecretBox {
+    key: Salsa20Key
+}
+
+impl SecretBox {
+    pub fn new(key: &Salsa20Key) -> Self {
+        Self { key: key.clone() }
+    }
+
+    /// Returns the cipher positioned at byte 32 of block 0 and the one-time Poly1305 key.
+    fn start(&self, nonce: &[u8]) -> Result<(XSalsa20, Poly1305Key), SymcError> {
+        let nonce: [u8; SECRETBOX_NONCE_SIZE] = nonce.try_into().map_err(|_| SymcError::InvalidLength)?;
+        let mut cipher = XSalsa20::new(&self.key, &XSalsa20Nonce::from(nonce));
+
+        let mut poly_key = Poly1305Key::default();
+        cipher.apply_keystream(&[0u8; 32], poly_key.as_mut())?;
+        Ok((cipher, poly_key))
+    }
+
+    /// Encrypts `input` into `output` and writes the authentication tag into `tag`.
+    pub fn seal_detached(&self, nonce: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        if tag.len() != SECRETBOX_TAG_SIZE {
+            return Err(SymcError::InvalidLength);
+        }
+        if output.len() < input.len() {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        let (mut cipher, poly_key) = self.start(nonce)?;
+        let ciphertext = &mut output[..input.len()];
+        cipher.apply_keystream(input, ciphertext)?;
+        tag.copy_from_slice(Poly1305::compute(&poly_key, ciphertext).as_ref());
+        Ok(input.len())
+    }
+
+    /// Verifies `tag` and decrypts `input` into `output`.
+    /// On `SymcError::AuthenticationFailed` nothing is written to `output`.
+    pub fn open_detached(&self, nonce: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        if tag.len() != SECRETBOX_TAG_SIZE {
+            return Err(SymcError::InvalidLength);
+        }
+        if output.len() < input.len() {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        // the tag covers the ciphertext, so it is checked before anything is decrypted
+        let (mut cipher, poly_key) = self.start(nonce)?;
+        if !constant_time_eq(Poly1305::compute(&poly_key, input).as_ref(), tag) {
+            return Err(SymcError::AuthenticationFailed);
+        }
+
+        cipher.apply_keystream(input, &mut output[..input.len()])
+    }
+
+    /// Seals `input` as `tag || ciphertext`, `output` must hold `input.len() + SECRETBOX_TAG_SIZE` bytes.
+    pub fn seal(&self, nonce: &[u8], input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        if output.len() < input.len() + SECRETBOX_TAG_SIZE {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        let (tag, ciphertext) = output.split_at_mut(SECRETBOX_TAG_SIZE);
+        let written = self.seal_detached(nonce, input, ciphertext, tag)?;
+        Ok(written + SECRETBOX_TAG_SIZE)
+    }
+
+    /// Opens a box laid out as `tag || ciphertext`.
+    pub fn open(&self, nonce: &[u8], input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        if input.len() < SECRETBOX_TAG_SIZE {
+            return Err(SymcError::InvalidInputLength);
+        }
+
+        let (tag, ciphertext) = input.split_at(SECRETBOX_TAG_SIZE);
+        self.open_detached(nonce, ciphertext, tag, output)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn seal_vec(&self, nonce: &[u8], input: &[u8]) -> Result<Vec<u8>, SymcError> {
+        let mut output = alloc::vec![0u8; input.len() + SECRETBOX_TAG_SIZE];
+        self.seal(nonce, input, &mut output)?;
+        Ok(output)
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn open_vec(&self, nonce: &[u8], input: &[u8]) -> Result<Vec<u8>, SymcError> {
+        let mut output = alloc::vec![0u8; input.len().saturating_sub(SECRETBOX_TAG_SIZE)];
+        let written = self.open(nonce, input, &mut output)?;
+        output.truncate(written);
+        Ok(output)
+    }
+}
@@ -91,6 +91,19 @@ pub(super) fn mix_columns(state: &mut [u8; 16]) {
     }
 }
 
+/// One full AES encryption round, MixColumns(ShiftRows(SubBytes(state))) ^ round_key,
+/// the building block of AEGIS.
+#[cfg(any(feature = "aegis128l", feature = "aegis256"))]
+#[inline]
+pub(crate) fn aes_round(state: &mut [u8; 16], round_key: &[u8; 16]) {
+    sub_bytes(state);
+    shift_rows(state);
+    mix_columns(state);
+    for (byte, key) in state.iter_mut().zip(round_key.iter()) {
+        *byte ^= key;
+    }
+}
+
 #[inline]
 pub(super) fn inv_sub_bytes(state: &mut [u8; 16]) {
     state[0] = INV_S_BOX[state[0] as usize];
@@ -301,4 +314,26 @@ mod tests {
         
         assert_eq!(state, expected_state);
     }
+
+    /// FIPS-197 Appendix B, the start of round 1 through to the start of round 2.
+    #[cfg(any(feature = "aegis128l", feature = "aegis256"))]
+    #[test]
+    fn aes_fips_197_round() {
+        let mut state: [u8; 16] = [
+            0x19, 0x3d, 0xe3, 0xbe, 0xa0, 0xf4, 0xe2, 0x2b,
+            0x9a, 0xc6, 0x8d, 0x2a, 0xe9, 0xf8, 0x48, 0x08,
+        ];
+        let round_key: [u8; 16] = [
+            0xa0, 0xfa, 0xfe, 0x17, 0x88, 0x54, 0x2c, 0xb1,
+            0x23, 0xa3, 0x39, 0x39, 0x2a, 0x6c, 0x76, 0x05,
+        ];
+        let expected_state: [u8; 16] = [
+            0xa4, 0x9c, 0x7f, 0xf2, 0x68, 0x9f, 0x35, 0x2b,
+            0x6b, 0x5b, 0xea, 0x43, 0x02, 0x6a, 0x50, 0x49,
+        ];
+
+        aes_round(&mut state, &round_key);
+
+        assert_eq!(state, expected_state);
+    }
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
-mod consts;
+pub(crate) mod consts;
 
 mod internal;
+#[cfg(any(feature = "aegis128l", feature = "aegis256"))]
+pub(crate) use internal::aes_round;
 
 mod aes128;
 pub use aes128::Aes128;
@@ -0,0 +1,261 @@
+use zeroize::Zeroize;
+use super::BlockCipher;
+use super::aes::consts::{S_BOX as SB1, INV_S_BOX as SB3};
+
+const SB2: [u8; 256] = [
+    0xe2, 0x4e, 0x54, 0xfc, 0x94, 0xc2, 0x4a, 0xcc, 0x62, 0x0d, 0x6a, 0x46, 0x3c, 0x4d, 0x8b, 0xd1,
+    0x5e, 0xfa, 0x64, 0xcb, 0xb4, 0x97, 0xbe, 0x2b, 0xbc, 0x77, 0x2e, 0x03, 0xd3, 0x19, 0x59, 0xc1,
+    0x1d, 0x06, 0x41, 0x6b, 0x55, 0xf0, 0x99, 0x69, 0xea, 0x9c, 0x18, 0xae, 0x63, 0xdf, 0xe7, 0xbb,
+    0x00, 0x73, 0x66, 0xfb, 0x96, 0x4c, 0x85, 0xe4, 0x3a, 0x09, 0x45, 0xaa, 0x0f, 0xee, 0x10, 0xeb,
+    0x2d, 0x7f, 0xf4, 0x29, 0xac, 0xcf, 0xad, 0x91, 0x8d, 0x78, 0xc8, 0x95, 0xf9, 0x2f, 0xce, 0xcd,
+    0x08, 0x7a, 0x88, 0x38, 0x5c, 0x83, 0x2a, 0x28, 0x47, 0xdb, 0xb8, 0xc7, 0x93, 0xa4, 0x12, 0x53,
+    0xff, 0x87, 0x0e, 0x31, 0x36, 0x21, 0x58, 0x48, 0x01, 0x8e, 0x37, 0x74, 0x32, 0xca, 0xe9, 0xb1,
+    0xb7, 0xab, 0x0c, 0xd7, 0xc4, 0x56, 0x42, 0x26, 0x07, 0x98, 0x60, 0xd9, 0xb6, 0xb9, 0x11, 0x40,
+    0xec, 0x20, 0x8c, 0xbd, 0xa0, 0xc9, 0x84, 0x04, 0x49, 0x23, 0xf1, 0x4f, 0x50, 0x1f, 0x13, 0xdc,
+    0xd8, 0xc0, 0x9e, 0x57, 0xe3, 0xc3, 0x7b, 0x65, 0x3b, 0x02, 0x8f, 0x3e, 0xe8, 0x25, 0x92, 0xe5,
+    0x15, 0xdd, 0xfd, 0x17, 0xa9, 0xbf, 0xd4, 0x9a, 0x7e, 0xc5, 0x39, 0x67, 0xfe, 0x76, 0x9d, 0x43,
+    0xa7, 0xe1, 0xd0, 0xf5, 0x68, 0xf2, 0x1b, 0x34, 0x70, 0x05, 0xa3, 0x8a, 0xd5, 0x79, 0x86, 0xa8,
+    0x30, 0xc6, 0x51, 0x4b, 0x1e, 0xa6, 0x27, 0xf6, 0x35, 0xd2, 0x6e, 0x24, 0x16, 0x82, 0x5f, 0xda,
+    0xe6, 0x75, 0xa2, 0xef, 0x2c, 0xb2, 0x1c, 0x9f, 0x5d, 0x6f, 0x80, 0x0a, 0x72, 0x44, 0x9b, 0x6c,
+    0x90, 0x0b, 0x5b, 0x33, 0x7d, 0x5a, 0x52, 0xf3, 0x61, 0xa1, 0xf7, 0xb0, 0xd6, 0x3f, 0x7c, 0x6d,
+    0xed, 0x14, 0xe0, 0xa5, 0x3d, 0x22, 0xb3, 0xf8, 0x89, 0xde, 0x71, 0x1a, 0xaf, 0xba, 0xb5, 0x81
+];
+
+const SB4: [u8; 256] = {
+    let mut inv = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        inv[SB2[i] as usize] = i as u8;
+        i += 1;
+    }
+    inv
+};
+
+const C: [u128; 3] = [
+    0x517cc1b727220a94fe13abe8fa9a6ee0,
+    0x6db14acc9e21c820ff28b1d5ef5de2b0,
+    0xdb92371d2126e9700324977504e8c90e
+];
+
+/// substitution layer of type 1, used in odd rounds
+#[inline]
+fn sl1(x: u128) -> u128 {
+    let mut b = x.to_be_bytes();
+    for i in (0..16).step_by(4) {
+        b[i] = SB1[b[i] as usize];
+        b[i + 1] = SB2[b[i + 1] as usize];
+        b[i + 2] = SB3[b[i + 2] as usize];
+        b[i + 3] = SB4[b[i + 3] as usize];
+    }
+    u128::from_be_bytes(b)
+}
+
+/// substitution layer of type 2, the inverse of type 1, used in even rounds
+#[inline]
+fn sl2(x: u128) -> u128 {
+    let mut b = x.to_be_bytes();
+    for i in (0..16).step_by(4) {
+        b[i] = SB3[b[i] as usize];
+        b[i + 1] = SB4[b[i + 1] as usize];
+        b[i + 2] = SB1[b[i + 2] as usize];
+        b[i + 3] = SB2[b[i + 3] as usize];
+    }
+    u128::from_be_bytes(b)
+}
+
+/// diffusion layer A, an involution
+#[inline]
+fn a(x: u128) -> u128 {
+    let x = x.to_be_bytes();
+    u128::from_be_bytes([
+        x[3] ^ x[4] ^ x[6] ^ x[8] ^ x[9] ^ x[13] ^ x[14],
+        x[2] ^ x[5] ^ x[7] ^ x[8] ^ x[9] ^ x[12] ^ x[15],
+        x[1] ^ x[4] ^ x[6] ^ x[10] ^ x[11] ^ x[12] ^ x[15],
+        x[0] ^ x[5] ^ x[7] ^ x[10] ^ x[11] ^ x[13] ^ x[14],
+        x[0] ^ x[2] ^ x[5] ^ x[8] ^ x[11] ^ x[14] ^ x[15],
+        x[1] ^ x[3] ^ x[4] ^ x[9] ^ x[10] ^ x[14] ^ x[15],
+        x[0] ^ x[2] ^ x[7] ^ x[9] ^ x[10] ^ x[12] ^ x[13],
+        x[1] ^ x[3] ^ x[6] ^ x[8] ^ x[11] ^ x[12] ^ x[13],
+        x[0] ^ x[1] ^ x[4] ^ x[7] ^ x[10] ^ x[13] ^ x[15],
+        x[0] ^ x[1] ^ x[5] ^ x[6] ^ x[11] ^ x[12] ^ x[14],
+        x[2] ^ x[3] ^ x[5] ^ x[6] ^ x[8] ^ x[13] ^ x[15],
+        x[2] ^ x[3] ^ x[4] ^ x[7] ^ x[9] ^ x[12] ^ x[14],
+        x[1] ^ x[2] ^ x[6] ^ x[7] ^ x[9] ^ x[11] ^ x[12],
+        x[0] ^ x[3] ^ x[6] ^ x[7] ^ x[8] ^ x[10] ^ x[13],
+        x[0] ^ x[3] ^ x[4] ^ x[5] ^ x[9] ^ x[11] ^ x[14],
+        x[1] ^ x[2] ^ x[4] ^ x[5] ^ x[8] ^ x[10] ^ x[15]
+    ])
+}
+
+/// odd round function
+#[inline]
+fn fo(d: u128, rk: u128) -> u128 {
+    a(sl1(d ^ rk))
+}
+
+/// even round function
+#[inline]
+fn fe(d: u128, rk: u128) -> u128 {
+    a(sl2(d ^ rk))
+}
+
+/// Round keys shared by all key sizes, 12, 14 or 16 rounds for 128, 192 or 256-bit keys.
+#[derive(Zeroize, Clone)]
+#[zeroize(drop)]
+struct AriaCore {
+    ek: [u128; 17],
+    dk: [u128; 17],
+    rounds: usize
+}
+
+impl AriaCore {
+    /// `kl` and `kr` are the left 128 bits of the key and the rest, zero padded.
+    fn new(kl: u128, kr: u128, rounds: usize) -> Self {
+        // the constants are rotated according to the key size
+        let offset = (rounds - 12) / 2;
+        let ck = |i: usize| C[(i + offset) % 3];
+
+        let w0 = kl;
+        let w1 = fo(w0, ck(0)) ^ kr;
+        let w2 = fe(w1, ck(1)) ^ w0;
+        let w3 = fo(w2, ck(2)) ^ w1;
+
+        let mut ek = [0u128; 17];
+        let w = [w0, w1, w2, w3];
+        // W_j xor W_(j+1) rotated right by 19 and 31, then left by 61, 31 and 19
+        for (i, rotation) in [128 - 19, 128 - 31, 61, 31, 19].into_iter().enumerate() {
+            for j in 0..4 {
+                if 4 * i + j > rounds {
+                    break;
+                }
+                ek[4 * i + j] = w[j] ^ w[(j + 1) % 4].rotate_left(rotation);
+            }
+        }
+
+        let mut dk = [0u128; 17];
+        dk[0] = ek[rounds];
+        for i in 1..rounds {
+            dk[i] = a(ek[rounds - i]);
+        }
+        dk[rounds] = ek[0];
+
+        AriaCore { ek, dk, rounds }
+    }
+
+    fn crypt(block: &mut [u8; 16], round_keys: &[u128; 17], rounds: usize) {
+        let mut p = u128::from_be_bytes(*block);
+        for (i, rk) in round_keys[..rounds - 1].iter().enumerate() {
+            p = if i % 2 == 0 { fo(p, *rk) } else { fe(p, *rk) };
+        }
+        p = sl2(p ^ round_keys[rounds - 1]) ^ round_keys[rounds];
+        *block = p.to_be_bytes();
+    }
+}
+
+macro_rules! aria_impl {
+    ($name:ident, $key:ident, $size:literal, $rounds:literal, $doc:literal) => {
+        #[derive(Clone, Default, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $key([u8; $size]);
+        impl AsRef<[u8]> for $key {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $key {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $size]> for $key {
+            fn from(array: [u8; $size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$key> for [u8; $size] {
+            fn from(output: $key) -> [u8; $size] {
+                output.0
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Zeroize, Clone)]
+        #[zeroize(drop)]
+        pub struct $name {
+            core: AriaCore
+        }
+
+        impl BlockCipher for $name {
+            const BLOCK_SIZE: usize = 16;
+            const KEY_SIZE: usize = $size;
+            type Block = [u8; 16];
+            type Key = $key;
+
+            fn new(key: &Self::Key) -> Self {
+                let mut right = [0u8; 16];
+                right[..$size - 16].copy_from_slice(&key.0[16..]);
+                let kl = u128::from_be_bytes(key.0[..16].try_into().unwrap());
+                let kr = u128::from_be_bytes(right);
+                right.zeroize();
+                Self { core: AriaCore::new(kl, kr, $rounds) }
+            }
+
+            fn encrypt_block(&self, block: &mut Self::Block) {
+                AriaCore::crypt(block, &self.core.ek, self.core.rounds);
+            }
+
+            fn decrypt_block(&self, block: &mut Self::Block) {
+                AriaCore::crypt(block, &self.core.dk, self.core.rounds);
+            }
+        }
+    };
+}
+
+aria_impl!(Aria128, Aria128Key, 16, 12, "ARIA (RFC 5794) with a 128-bit key.");
+aria_impl!(Aria192, Aria192Key, 24, 14, "ARIA (RFC 5794) with a 192-bit key.");
+aria_impl!(Aria256, Aria256Key, 32, 16, "ARIA (RFC 5794) with a 256-bit key.");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PLAINTEXT: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff
+    ];
+    const KEY: [u8; 32] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f
+    ];
+
+    #[test]
+    fn diffusion_is_involution() {
+        let x = 0x0123456789abcdeffedcba9876543210;
+        assert_eq!(a(a(x)), x);
+        assert_eq!(sl2(sl1(x)), x);
+    }
+
+    /// RFC 5794, Appendix A
+    #[test]
+    fn rfc5794_appendix_a() {
+        let cipher = Aria128::new(&Aria128Key::from(<[u8; 16]>::try_from(&KEY[..16]).unwrap()));
+        let mut block = PLAINTEXT;
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, [0xd7, 0x18, 0xfb, 0xd6, 0xab, 0x64, 0x4c, 0x73, 0x9d, 0xa9, 0x5f, 0x3b, 0xe6, 0x45, 0x17, 0x78]);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, PLAINTEXT);
+
+        let cipher = Aria192::new(&Aria192Key::from(<[u8; 24]>::try_from(&KEY[..24]).unwrap()));
+        let mut block = PLAINTEXT;
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, [0x26, 0x44, 0x9c, 0x18, 0x05, 0xdb, 0xe7, 0xaa, 0x25, 0xa4, 0x68, 0xce, 0x26, 0x3a, 0x9e, 0x79]);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, PLAINTEXT);
+
+        let cipher = Aria256::new(&Aria256Key::from(KEY));
+        let mut block = PLAINTEXT;
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, [0xf9, 0x2b, 0xd7, 0xc7, 0x9f, 0xb7, 0x2e, 0x2f, 0x2b, 0x8f, 0x80, 0xc1, 0x97, 0x2d, 0x24, 0xfc]);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, PLAINTEXT);
+    }
+}
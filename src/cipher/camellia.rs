@@ -0,0 +1,317 @@
+use zeroize::Zeroize;
+use super::BlockCipher;
+
+const SBOX1: [u8; 256] = [
+    0x70, 0x82, 0x2c, 0xec, 0xb3, 0x27, 0xc0, 0xe5, 0xe4, 0x85, 0x57, 0x35, 0xea, 0x0c, 0xae, 0x41,
+    0x23, 0xef, 0x6b, 0x93, 0x45, 0x19, 0xa5, 0x21, 0xed, 0x0e, 0x4f, 0x4e, 0x1d, 0x65, 0x92, 0xbd,
+    0x86, 0xb8, 0xaf, 0x8f, 0x7c, 0xeb, 0x1f, 0xce, 0x3e, 0x30, 0xdc, 0x5f, 0x5e, 0xc5, 0x0b, 0x1a,
+    0xa6, 0xe1, 0x39, 0xca, 0xd5, 0x47, 0x5d, 0x3d, 0xd9, 0x01, 0x5a, 0xd6, 0x51, 0x56, 0x6c, 0x4d,
+    0x8b, 0x0d, 0x9a, 0x66, 0xfb, 0xcc, 0xb0, 0x2d, 0x74, 0x12, 0x2b, 0x20, 0xf0, 0xb1, 0x84, 0x99,
+    0xdf, 0x4c, 0xcb, 0xc2, 0x34, 0x7e, 0x76, 0x05, 0x6d, 0xb7, 0xa9, 0x31, 0xd1, 0x17, 0x04, 0xd7,
+    0x14, 0x58, 0x3a, 0x61, 0xde, 0x1b, 0x11, 0x1c, 0x32, 0x0f, 0x9c, 0x16, 0x53, 0x18, 0xf2, 0x22,
+    0xfe, 0x44, 0xcf, 0xb2, 0xc3, 0xb5, 0x7a, 0x91, 0x24, 0x08, 0xe8, 0xa8, 0x60, 0xfc, 0x69, 0x50,
+    0xaa, 0xd0, 0xa0, 0x7d, 0xa1, 0x89, 0x62, 0x97, 0x54, 0x5b, 0x1e, 0x95, 0xe0, 0xff, 0x64, 0xd2,
+    0x10, 0xc4, 0x00, 0x48, 0xa3, 0xf7, 0x75, 0xdb, 0x8a, 0x03, 0xe6, 0xda, 0x09, 0x3f, 0xdd, 0x94,
+    0x87, 0x5c, 0x83, 0x02, 0xcd, 0x4a, 0x90, 0x33, 0x73, 0x67, 0xf6, 0xf3, 0x9d, 0x7f, 0xbf, 0xe2,
+    0x52, 0x9b, 0xd8, 0x26, 0xc8, 0x37, 0xc6, 0x3b, 0x81, 0x96, 0x6f, 0x4b, 0x13, 0xbe, 0x63, 0x2e,
+    0xe9, 0x79, 0xa7, 0x8c, 0x9f, 0x6e, 0xbc, 0x8e, 0x29, 0xf5, 0xf9, 0xb6, 0x2f, 0xfd, 0xb4, 0x59,
+    0x78, 0x98, 0x06, 0x6a, 0xe7, 0x46, 0x71, 0xba, 0xd4, 0x25, 0xab, 0x42, 0x88, 0xa2, 0x8d, 0xfa,
+    0x72, 0x07, 0xb9, 0x55, 0xf8, 0xee, 0xac, 0x0a, 0x36, 0x49, 0x2a, 0x68, 0x3c, 0x38, 0xf1, 0xa4,
+    0x40, 0x28, 0xd3, 0x7b, 0xbb, 0xc9, 0x43, 0xc1, 0x15, 0xe3, 0xad, 0xf4, 0x77, 0xc7, 0x80, 0x9e
+];
+
+const SIGMA: [u64; 6] = [
+    0xa09e667f3bcc908b, 0xb67ae8584caa73b2, 0xc6ef372fe94f82be,
+    0x54ff53a5f1d36f1c, 0x10e527fade682d1d, 0xb05688c2b3e6c1fd
+];
+
+#[inline]
+fn sbox2(x: u8) -> u8 {
+    SBOX1[x as usize].rotate_left(1)
+}
+
+#[inline]
+fn sbox3(x: u8) -> u8 {
+    SBOX1[x as usize].rotate_left(7)
+}
+
+#[inline]
+fn sbox4(x: u8) -> u8 {
+    SBOX1[x.rotate_left(1) as usize]
+}
+
+/// F-function, S-function followed by the P-function
+#[inline]
+fn f(input: u64, ke: u64) -> u64 {
+    let x = (input ^ ke).to_be_bytes();
+    let t1 = SBOX1[x[0] as usize];
+    let t2 = sbox2(x[1]);
+    let t3 = sbox3(x[2]);
+    let t4 = sbox4(x[3]);
+    let t5 = sbox2(x[4]);
+    let t6 = sbox3(x[5]);
+    let t7 = sbox4(x[6]);
+    let t8 = SBOX1[x[7] as usize];
+    u64::from_be_bytes([
+        t1 ^ t3 ^ t4 ^ t6 ^ t7 ^ t8,
+        t1 ^ t2 ^ t4 ^ t5 ^ t7 ^ t8,
+        t1 ^ t2 ^ t3 ^ t5 ^ t6 ^ t8,
+        t2 ^ t3 ^ t4 ^ t5 ^ t6 ^ t7,
+        t1 ^ t2 ^ t6 ^ t7 ^ t8,
+        t2 ^ t3 ^ t5 ^ t7 ^ t8,
+        t3 ^ t4 ^ t5 ^ t6 ^ t8,
+        t1 ^ t4 ^ t5 ^ t6 ^ t7
+    ])
+}
+
+#[inline]
+fn fl(input: u64, ke: u64) -> u64 {
+    let (mut x1, mut x2) = ((input >> 32) as u32, input as u32);
+    let (k1, k2) = ((ke >> 32) as u32, ke as u32);
+    x2 ^= (x1 & k1).rotate_left(1);
+    x1 ^= x2 | k2;
+    ((x1 as u64) << 32) | x2 as u64
+}
+
+#[inline]
+fn fl_inv(input: u64, ke: u64) -> u64 {
+    let (mut y1, mut y2) = ((input >> 32) as u32, input as u32);
+    let (k1, k2) = ((ke >> 32) as u32, ke as u32);
+    y1 ^= y2 | k2;
+    y2 ^= (y1 & k1).rotate_left(1);
+    ((y1 as u64) << 32) | y2 as u64
+}
+
+#[inline]
+fn halves(x: u128, rotation: u32) -> (u64, u64) {
+    let x = x.rotate_left(rotation);
+    ((x >> 64) as u64, x as u64)
+}
+
+/// KR for the bytes after the first 128 bits of the key.
+fn right_half(rest: &[u8]) -> u128 {
+    match rest.len() {
+        0 => 0,
+        // 192-bit keys complete KR with the complement of their last 64 bits
+        8 => {
+            let right = u64::from_be_bytes(rest.try_into().unwrap());
+            ((right as u128) << 64) | !right as u128
+        }
+        _ => u128::from_be_bytes(rest.try_into().unwrap())
+    }
+}
+
+/// Subkeys shared by all key sizes, 128-bit keys use 18 rounds and two FL layers,
+/// 192 and 256-bit keys use 24 rounds and three.
+#[derive(Zeroize, Clone)]
+#[zeroize(drop)]
+struct CamelliaCore {
+    kw: [u64; 4],
+    k: [u64; 24],
+    ke: [u64; 6],
+    rounds: usize
+}
+
+impl CamelliaCore {
+    /// `kl` and `kr` are the left and right 128 bits of the key, `kr` is zero for 128-bit keys.
+    fn new(kl: u128, kr: u128, rounds: usize) -> Self {
+        let mut d1 = ((kl ^ kr) >> 64) as u64;
+        let mut d2 = (kl ^ kr) as u64;
+        d2 ^= f(d1, SIGMA[0]);
+        d1 ^= f(d2, SIGMA[1]);
+        d1 ^= (kl >> 64) as u64;
+        d2 ^= kl as u64;
+        d2 ^= f(d1, SIGMA[2]);
+        d1 ^= f(d2, SIGMA[3]);
+        let ka = ((d1 as u128) << 64) | d2 as u128;
+
+        let mut core = CamelliaCore { kw: [0; 4], k: [0; 24], ke: [0; 6], rounds };
+
+        if rounds == 18 {
+            (core.kw[0], core.kw[1]) = halves(kl, 0);
+            (core.k[0], core.k[1]) = halves(ka, 0);
+            (core.k[2], core.k[3]) = halves(kl, 15);
+            (core.k[4], core.k[5]) = halves(ka, 15);
+            (core.ke[0], core.ke[1]) = halves(ka, 30);
+            (core.k[6], core.k[7]) = halves(kl, 45);
+            (core.k[8], _) = halves(ka, 45);
+            (_, core.k[9]) = halves(kl, 60);
+            (core.k[10], core.k[11]) = halves(ka, 60);
+            (core.ke[2], core.ke[3]) = halves(kl, 77);
+            (core.k[12], core.k[13]) = halves(kl, 94);
+            (core.k[14], core.k[15]) = halves(ka, 94);
+            (core.k[16], core.k[17]) = halves(kl, 111);
+            (core.kw[2], core.kw[3]) = halves(ka, 111);
+        } else {
+            d1 = ((ka ^ kr) >> 64) as u64;
+            d2 = (ka ^ kr) as u64;
+            d2 ^= f(d1, SIGMA[4]);
+            d1 ^= f(d2, SIGMA[5]);
+            let kb = ((d1 as u128) << 64) | d2 as u128;
+
+            (core.kw[0], core.kw[1]) = halves(kl, 0);
+            (core.k[0], core.k[1]) = halves(kb, 0);
+            (core.k[2], core.k[3]) = halves(kr, 15);
+            (core.k[4], core.k[5]) = halves(ka, 15);
+            (core.ke[0], core.ke[1]) = halves(kr, 30);
+            (core.k[6], core.k[7]) = halves(kb, 30);
+            (core.k[8], core.k[9]) = halves(kl, 45);
+            (core.k[10], core.k[11]) = halves(ka, 45);
+            (core.ke[2], core.ke[3]) = halves(kl, 60);
+            (core.k[12], core.k[13]) = halves(kr, 60);
+            (core.k[14], core.k[15]) = halves(kb, 60);
+            (core.k[16], core.k[17]) = halves(kl, 77);
+            (core.ke[4], core.ke[5]) = halves(ka, 77);
+            (core.k[18], core.k[19]) = halves(kr, 94);
+            (core.k[20], core.k[21]) = halves(ka, 94);
+            (core.k[22], core.k[23]) = halves(kl, 111);
+            (core.kw[2], core.kw[3]) = halves(kb, 111);
+        }
+        d1.zeroize();
+        d2.zeroize();
+        core
+    }
+
+    fn encrypt_block(&self, block: &mut [u8; 16]) {
+        let mut d1 = u64::from_be_bytes(block[..8].try_into().unwrap()) ^ self.kw[0];
+        let mut d2 = u64::from_be_bytes(block[8..].try_into().unwrap()) ^ self.kw[1];
+
+        for i in 0..self.rounds / 6 {
+            if i > 0 {
+                d1 = fl(d1, self.ke[2 * i - 2]);
+                d2 = fl_inv(d2, self.ke[2 * i - 1]);
+            }
+            for j in (6 * i..6 * i + 6).step_by(2) {
+                d2 ^= f(d1, self.k[j]);
+                d1 ^= f(d2, self.k[j + 1]);
+            }
+        }
+
+        d2 ^= self.kw[2];
+        d1 ^= self.kw[3];
+        block[..8].copy_from_slice(&d2.to_be_bytes());
+        block[8..].copy_from_slice(&d1.to_be_bytes());
+    }
+
+    /// Same network with kw swapped and k, ke taken in reverse order.
+    fn decrypt_block(&self, block: &mut [u8; 16]) {
+        let mut d1 = u64::from_be_bytes(block[..8].try_into().unwrap()) ^ self.kw[2];
+        let mut d2 = u64::from_be_bytes(block[8..].try_into().unwrap()) ^ self.kw[3];
+
+        let layers = self.rounds / 6;
+        for i in 0..layers {
+            if i > 0 {
+                d1 = fl(d1, self.ke[2 * (layers - i) - 1]);
+                d2 = fl_inv(d2, self.ke[2 * (layers - i) - 2]);
+            }
+            for j in (6 * i..6 * i + 6).step_by(2) {
+                d2 ^= f(d1, self.k[self.rounds - 1 - j]);
+                d1 ^= f(d2, self.k[self.rounds - 2 - j]);
+            }
+        }
+
+        d2 ^= self.kw[0];
+        d1 ^= self.kw[1];
+        block[..8].copy_from_slice(&d2.to_be_bytes());
+        block[8..].copy_from_slice(&d1.to_be_bytes());
+    }
+}
+
+macro_rules! camellia_impl {
+    ($name:ident, $key:ident, $size:literal, $doc:literal) => {
+        #[derive(Clone, Default, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $key([u8; $size]);
+        impl AsRef<[u8]> for $key {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $key {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $size]> for $key {
+            fn from(array: [u8; $size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$key> for [u8; $size] {
+            fn from(output: $key) -> [u8; $size] {
+                output.0
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Zeroize, Clone)]
+        #[zeroize(drop)]
+        pub struct $name {
+            core: CamelliaCore
+        }
+
+        impl BlockCipher for $name {
+            const BLOCK_SIZE: usize = 16;
+            const KEY_SIZE: usize = $size;
+            type Block = [u8; 16];
+            type Key = $key;
+
+            fn new(key: &Self::Key) -> Self {
+                let kl = u128::from_be_bytes(key.0[..16].try_into().unwrap());
+                let kr = right_half(&key.0[16..]);
+                Self { core: CamelliaCore::new(kl, kr, if $size == 16 { 18 } else { 24 }) }
+            }
+
+            fn encrypt_block(&self, block: &mut Self::Block) {
+                self.core.encrypt_block(block);
+            }
+
+            fn decrypt_block(&self, block: &mut Self::Block) {
+                self.core.decrypt_block(block);
+            }
+        }
+    };
+}
+
+camellia_impl!(Camellia128, Camellia128Key, 16, "Camellia (RFC 3713) with a 128-bit key.");
+camellia_impl!(Camellia192, Camellia192Key, 24, "Camellia (RFC 3713) with a 192-bit key.");
+camellia_impl!(Camellia256, Camellia256Key, 32, "Camellia (RFC 3713) with a 256-bit key.");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PLAINTEXT: [u8; 16] = [
+        0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10
+    ];
+
+    /// RFC 3713, Appendix A
+    #[test]
+    fn rfc3713_appendix_a() {
+        let key: [u8; 32] = [
+            0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10,
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff
+        ];
+
+        let cipher = Camellia128::new(&Camellia128Key::from(<[u8; 16]>::try_from(&key[..16]).unwrap()));
+        let mut block = PLAINTEXT;
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, [0x67, 0x67, 0x31, 0x38, 0x54, 0x96, 0x69, 0x73, 0x08, 0x57, 0x06, 0x56, 0x48, 0xea, 0xbe, 0x43]);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, PLAINTEXT);
+
+        let cipher = Camellia192::new(&Camellia192Key::from(<[u8; 24]>::try_from(&key[..24]).unwrap()));
+        let mut block = PLAINTEXT;
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, [0xb4, 0x99, 0x34, 0x01, 0xb3, 0xe9, 0x96, 0xf8, 0x4e, 0xe5, 0xce, 0xe7, 0xd7, 0x9b, 0x09, 0xb9]);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, PLAINTEXT);
+
+        let cipher = Camellia256::new(&Camellia256Key::from(key));
+        let mut block = PLAINTEXT;
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, [0x9a, 0xcc, 0x23, 0x7d, 0xff, 0x16, 0xd7, 0x6c, 0x20, 0xef, 0x7c, 0x91, 0x9e, 0x3a, 0x75, 0x09]);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, PLAINTEXT);
+    }
+}
@@ -0,0 +1,321 @@
+use zeroize::Zeroize;
+
+use crate::error::SymcError;
+use super::StreamCipher;
+
+/// "expand 32-byte k"
+const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+const CHACHA20_BLOCK_SIZE: usize = 64;
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct ChaCha20Key([u8; 32]);
+impl AsRef<[u8]> for ChaCha20Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for ChaCha20Key {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 32]> for ChaCha20Key {
+    fn from(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+}
+impl From<ChaCha20Key> for [u8; 32] {
+    fn from(output: ChaCha20Key) -> [u8; 32] {
+        output.0
+    }
+}
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct ChaCha20Nonce([u8; 12]);
+impl AsRef<[u8]> for ChaCha20Nonce {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for ChaCha20Nonce {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 12]> for ChaCha20Nonce {
+    fn from(array: [u8; 12]) -> Self {
+        Self(array)
+    }
+}
+impl From<ChaCha20Nonce> for [u8; 12] {
+    fn from(output: ChaCha20Nonce) -> [u8; 12] {
+        output.0
+    }
+}
+
+#[inline(always)]
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[a] = state[a].wrapping_add(state[b]); state[d] ^= state[a]; state[d] = state[d].rotate_left(16);
+    state[c] = state[c].wrapping_add(state[d]); state[b] ^= state[c]; state[b] = state[b].rotate_left(12);
+    state[a] = state[a].wrapping_add(state[b]); state[d] ^= state[a]; state[d] = state[d].rotate_left(8);
+    state[c] = state[c].wrapping_add(state[d]); state[b] ^= state[c]; state[b] = state[b].rotate_left(7);
+}
+
+/// 20 rounds, as 10 iterations of a column round followed by a diagonal round
+#[inline]
+fn chacha20_rounds(state: &mut [u32; 16]) {
+    for _ in 0..10 {
+        // column rounds
+        quarter_round(state, 0, 4, 8, 12);
+        quarter_round(state, 1, 5, 9, 13);
+        quarter_round(state, 2, 6, 10, 14);
+        quarter_round(state, 3, 7, 11, 15);
+        // diagonal rounds
+        quarter_round(state, 0, 5, 10, 15);
+        quarter_round(state, 1, 6, 11, 12);
+        quarter_round(state, 2, 7, 8, 13);
+        quarter_round(state, 3, 4, 9, 14);
+    }
+}
+
+/// ChaCha20 block function (RFC 8439, 2.3)
+fn chacha20_block(input: &[u32; 16], output: &mut [u8; CHACHA20_BLOCK_SIZE]) {
+    let mut working_state = *input;
+    chacha20_rounds(&mut working_state);
+
+    for (i, word) in working_state.iter().enumerate() {
+        let bytes = word.wrapping_add(input[i]).to_le_bytes();
+        output[i * 4..(i + 1) * 4].copy_from_slice(&bytes);
+    }
+    working_state.zeroize();
+}
+
+/// HChaCha20 (draft-irtf-cfrg-xchacha, 2.2), derives a subkey from a key and a 128-bit nonce.
+#[cfg(feature = "xchacha20")]
+pub fn hchacha20(key: &ChaCha20Key, nonce: &[u8; 16]) -> ChaCha20Key {
+    let mut state = [0u32; 16];
+    state[..4].copy_from_slice(&SIGMA);
+    for (word, chunk) in state[4..12].iter_mut().zip(key.as_ref().chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    for (word, chunk) in state[12..].iter_mut().zip(nonce.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    chacha20_rounds(&mut state);
+
+    // first and last rows of the state, without the feed-forward addition
+    let mut subkey = ChaCha20Key::default();
+    for (chunk, word) in subkey.as_mut().chunks_exact_mut(4).zip(state[..4].iter().chain(state[12..].iter())) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+    state.zeroize();
+    subkey
+}
+
+/// ChaCha20 with the IETF 96-bit nonce and 32-bit block counter (RFC 8439).
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct ChaCha20 {
+    state: [u32; 16],
+    // the next block counter, a u64 so running past 2^32 blocks can be detected
+    counter: u64,
+    keystream: [u8; CHACHA20_BLOCK_SIZE],
+    keystream_pos: usize
+}
+
+impl ChaCha20 {
+    /// Starts the keystream at block `counter`, RFC 8439 encryption examples start at 1.
+    pub fn with_counter(key: &ChaCha20Key, nonce: &ChaCha20Nonce, counter: u32) -> Self {
+        let mut state = [0u32; 16];
+        state[..4].copy_from_slice(&SIGMA);
+        for (word, chunk) in state[4..12].iter_mut().zip(key.as_ref().chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+        for (word, chunk) in state[13..].iter_mut().zip(nonce.as_ref().chunks_exact(4)) {
+            *word = u32::from_le_bytes(chunk.try_into().unwrap());
+        }
+
+        Self {
+            state,
+            counter: counter as u64,
+            keystream: [0u8; CHACHA20_BLOCK_SIZE],
+            keystream_pos: CHACHA20_BLOCK_SIZE
+        }
+    }
+
+    fn next_keystream_block(&mut self) -> Result<(), SymcError> {
+        if self.counter > u32::MAX as u64 {
+            return Err(SymcError::InvalidInputLength);
+        }
+
+        self.state[12] = self.counter as u32;
+        chacha20_block(&self.state, &mut self.keystream);
+        self.counter += 1;
+        self.keystream_pos = 0;
+        Ok(())
+    }
+}
+
+impl StreamCipher for ChaCha20 {
+    const KEY_SIZE: usize = 32;
+    const NONCE_SIZE: usize = 12;
+    type Key = ChaCha20Key;
+    type Nonce = ChaCha20Nonce;
+
+    fn new(key: &Self::Key, nonce: &Self::Nonce) -> Self {
+        Self::with_counter(key, nonce, 0)
+    }
+
+    fn apply_keystream(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        if output.len() < input.len() {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        // make sure the whole input fits in the remaining counter space before writing anything
+        let buffered = (CHACHA20_BLOCK_SIZE - self.keystream_pos) as u64;
+        let blocks_needed = (input.len() as u64).saturating_sub(buffered).div_ceil(CHACHA20_BLOCK_SIZE as u64);
+        if blocks_needed > (1u64 << 32) - self.counter {
+            return Err(SymcError::InvalidInputLength);
+        }
+
+        for (o, i) in output[..input.len()].iter_mut().zip(input.iter()) {
+            if self.keystream_pos == CHACHA20_BLOCK_SIZE {
+                self.next_keystream_block()?;
+            }
+            *o = *i ^ self.keystream[self.keystream_pos];
+            self.keystream_pos += 1;
+        }
+
+        Ok(input.len())
+    }
+}
+
+#[cfg(feature = "xchacha20")]
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct XChaCha20Nonce([u8; 24]);
+#[cfg(feature = "xchacha20")]
+impl AsRef<[u8]> for XChaCha20Nonce {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+#[cfg(feature = "xchacha20")]
+impl AsMut<[u8]> for XChaCha20Nonce {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+#[cfg(feature = "xchacha20")]
+impl From<[u8; 24]> for XChaCha20Nonce {
+    fn from(array: [u8; 24]) -> Self {
+        Self(array)
+    }
+}
+#[cfg(feature = "xchacha20")]
+impl From<XChaCha20Nonce> for [u8; 24] {
+    fn from(output: XChaCha20Nonce) -> [u8; 24] {
+        output.0
+    }
+}
+
+/// XChaCha20, ChaCha20 keyed with HChaCha20(key, nonce[..16]) and the nonce 0^32 || nonce[16..].
+#[cfg(feature = "xchacha20")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct XChaCha20 {
+    inner: ChaCha20
+}
+
+#[cfg(feature = "xchacha20")]
+impl XChaCha20 {
+    pub fn with_counter(key: &ChaCha20Key, nonce: &XChaCha20Nonce, counter: u32) -> Self {
+        let subkey = hchacha20(key, nonce.0[..16].try_into().unwrap());
+        let mut chacha_nonce = ChaCha20Nonce::default();
+        chacha_nonce.0[4..].copy_from_slice(&nonce.0[16..]);
+
+        Self { inner: ChaCha20::with_counter(&subkey, &chacha_nonce, counter) }
+    }
+}
+
+#[cfg(feature = "xchacha20")]
+impl StreamCipher for XChaCha20 {
+    const KEY_SIZE: usize = 32;
+    const NONCE_SIZE: usize = 24;
+    type Key = ChaCha20Key;
+    type Nonce = XChaCha20Nonce;
+
+    fn new(key: &Self::Key, nonce: &Self::Nonce) -> Self {
+        Self::with_counter(key, nonce, 0)
+    }
+
+    fn apply_keystream(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        self.inner.apply_keystream(input, output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test vector from RFC 8439, 2.1.1
+    #[test]
+    fn chacha20_quarter_round() {
+        let mut state = [0u32; 16];
+        state[0] = 0x11111111;
+        state[1] = 0x01020304;
+        state[2] = 0x9b8d6f43;
+        state[3] = 0x01234567;
+        quarter_round(&mut state, 0, 1, 2, 3);
+        assert_eq!(&state[..4], &[0xea2a92f4, 0xcb1cf8ce, 0x4581472e, 0x5881c4bb]);
+    }
+
+    /// Test vector from RFC 8439, 2.3.2
+    #[test]
+    fn chacha20_block_function() {
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let nonce: [u8; 12] = [0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00];
+        let expected: [u8; 64] = [
+            0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20, 0x71, 0xc4,
+            0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a, 0xc3, 0xd4, 0x6c, 0x4e,
+            0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2, 0xd7, 0x05, 0xd9, 0x8b, 0x02, 0xa2,
+            0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9, 0xcb, 0xd0, 0x83, 0xe8, 0xa2, 0x50, 0x3c, 0x4e,
+        ];
+
+        let mut chacha = ChaCha20::with_counter(&key.into(), &nonce.into(), 1);
+        let mut keystream = [0u8; 64];
+        chacha.apply_keystream(&[0u8; 64], &mut keystream).unwrap();
+        assert_eq!(keystream, expected);
+        assert_eq!(chacha.counter, 2);
+    }
+
+    #[test]
+    fn chacha20_counter_exhaustion() {
+        let mut chacha = ChaCha20::with_counter(&ChaCha20Key::default(), &ChaCha20Nonce::default(), u32::MAX);
+        let mut output = [0u8; 128];
+        assert_eq!(chacha.apply_keystream(&[0u8; 65], &mut output), Err(SymcError::InvalidInputLength));
+        assert_eq!(chacha.apply_keystream(&[0u8; 64], &mut output), Ok(64));
+        assert_eq!(chacha.apply_keystream(&[0u8; 1], &mut output), Err(SymcError::InvalidInputLength));
+    }
+
+    /// Test vector from draft-irtf-cfrg-xchacha-03, 2.2.1
+    #[cfg(feature = "xchacha20")]
+    #[test]
+    fn hchacha20_test_vector() {
+        let key: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let nonce: [u8; 16] = [
+            0x00, 0x00, 0x00, 0x09, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00, 0x31, 0x41, 0x59, 0x27,
+        ];
+        let expected: [u8; 32] = [
+            0x82, 0x41, 0x3b, 0x42, 0x27, 0xb2, 0x7b, 0xfe, 0xd3, 0x0e, 0x42, 0x50, 0x8a, 0x87, 0x7d, 0x73,
+            0xa0, 0xf9, 0xe4, 0xd5, 0x8a, 0x74, 0xa8, 0x53, 0xc1, 0x2e, 0xc4, 0x13, 0x26, 0xd3, 0xec, 0xdc,
+        ];
+
+        let subkey = hchacha20(&key.into(), &nonce);
+        assert_eq!(subkey.as_ref(), &expected);
+    }
+}
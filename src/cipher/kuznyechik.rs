@@ -0,0 +1,259 @@
+use zeroize::Zeroize;
+use super::BlockCipher;
+
+const PI: [u8; 256] = [
+    0xfc, 0xee, 0xdd, 0x11, 0xcf, 0x6e, 0x31, 0x16, 0xfb, 0xc4, 0xfa, 0xda, 0x23, 0xc5, 0x04, 0x4d,
+    0xe9, 0x77, 0xf0, 0xdb, 0x93, 0x2e, 0x99, 0xba, 0x17, 0x36, 0xf1, 0xbb, 0x14, 0xcd, 0x5f, 0xc1,
+    0xf9, 0x18, 0x65, 0x5a, 0xe2, 0x5c, 0xef, 0x21, 0x81, 0x1c, 0x3c, 0x42, 0x8b, 0x01, 0x8e, 0x4f,
+    0x05, 0x84, 0x02, 0xae, 0xe3, 0x6a, 0x8f, 0xa0, 0x06, 0x0b, 0xed, 0x98, 0x7f, 0xd4, 0xd3, 0x1f,
+    0xeb, 0x34, 0x2c, 0x51, 0xea, 0xc8, 0x48, 0xab, 0xf2, 0x2a, 0x68, 0xa2, 0xfd, 0x3a, 0xce, 0xcc,
+    0xb5, 0x70, 0x0e, 0x56, 0x08, 0x0c, 0x76, 0x12, 0xbf, 0x72, 0x13, 0x47, 0x9c, 0xb7, 0x5d, 0x87,
+    0x15, 0xa1, 0x96, 0x29, 0x10, 0x7b, 0x9a, 0xc7, 0xf3, 0x91, 0x78, 0x6f, 0x9d, 0x9e, 0xb2, 0xb1,
+    0x32, 0x75, 0x19, 0x3d, 0xff, 0x35, 0x8a, 0x7e, 0x6d, 0x54, 0xc6, 0x80, 0xc3, 0xbd, 0x0d, 0x57,
+    0xdf, 0xf5, 0x24, 0xa9, 0x3e, 0xa8, 0x43, 0xc9, 0xd7, 0x79, 0xd6, 0xf6, 0x7c, 0x22, 0xb9, 0x03,
+    0xe0, 0x0f, 0xec, 0xde, 0x7a, 0x94, 0xb0, 0xbc, 0xdc, 0xe8, 0x28, 0x50, 0x4e, 0x33, 0x0a, 0x4a,
+    0xa7, 0x97, 0x60, 0x73, 0x1e, 0x00, 0x62, 0x44, 0x1a, 0xb8, 0x38, 0x82, 0x64, 0x9f, 0x26, 0x41,
+    0xad, 0x45, 0x46, 0x92, 0x27, 0x5e, 0x55, 0x2f, 0x8c, 0xa3, 0xa5, 0x7d, 0x69, 0xd5, 0x95, 0x3b,
+    0x07, 0x58, 0xb3, 0x40, 0x86, 0xac, 0x1d, 0xf7, 0x30, 0x37, 0x6b, 0xe4, 0x88, 0xd9, 0xe7, 0x89,
+    0xe1, 0x1b, 0x83, 0x49, 0x4c, 0x3f, 0xf8, 0xfe, 0x8d, 0x53, 0xaa, 0x90, 0xca, 0xd8, 0x85, 0x61,
+    0x20, 0x71, 0x67, 0xa4, 0x2d, 0x2b, 0x09, 0x5b, 0xcb, 0x9b, 0x25, 0xd0, 0xbe, 0xe5, 0x6c, 0x52,
+    0x59, 0xa6, 0x74, 0xd2, 0xe6, 0xf4, 0xb4, 0xc0, 0xd1, 0x66, 0xaf, 0xc2, 0x39, 0x4b, 0x63, 0xb6
+];
+
+const PI_INV: [u8; 256] = {
+    let mut inv = [0u8; 256];
+    let mut i = 0;
+    while i < 256 {
+        inv[PI[i] as usize] = i as u8;
+        i += 1;
+    }
+    inv
+};
+
+// coefficients of the linear function l, most significant byte first
+const L_COEF: [u8; 16] = [148, 32, 133, 16, 194, 192, 1, 251, 1, 192, 194, 16, 133, 32, 148, 1];
+
+// iteration constants C_i = L(i), 1 <= i <= 32
+const C: [[u8; 16]; 32] = {
+    let mut c = [[0u8; 16]; 32];
+    let mut i = 0;
+    while i < 32 {
+        c[i][15] = (i + 1) as u8;
+        let mut j = 0;
+        while j < 16 {
+            c[i] = r(c[i]);
+            j += 1;
+        }
+        i += 1;
+    }
+    c
+};
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct KuznyechikKey([u8; 32]);
+impl AsRef<[u8]> for KuznyechikKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for KuznyechikKey {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 32]> for KuznyechikKey {
+    fn from(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+}
+impl From<KuznyechikKey> for [u8; 32] {
+    fn from(output: KuznyechikKey) -> [u8; 32] {
+        output.0
+    }
+}
+
+/// multiplication in GF(2^8) modulo x^8 + x^7 + x^6 + x + 1, without branches on the operands
+#[inline]
+const fn gmul(mut a: u8, mut b: u8) -> u8 {
+    let mut p = 0u8;
+    let mut i = 0;
+    while i < 8 {
+        p ^= a & (b & 1).wrapping_neg();
+        let carry = (a >> 7).wrapping_neg();
+        a = (a << 1) ^ (0xc3 & carry);
+        b >>= 1;
+        i += 1;
+    }
+    p
+}
+
+#[inline]
+const fn l_func(a: &[u8; 16]) -> u8 {
+    let mut x = 0u8;
+    let mut i = 0;
+    while i < 16 {
+        x ^= gmul(a[i], L_COEF[i]);
+        i += 1;
+    }
+    x
+}
+
+/// linear transformation R, one step of the LFSR
+#[inline]
+const fn r(a: [u8; 16]) -> [u8; 16] {
+    let x = l_func(&a);
+    let mut out = [0u8; 16];
+    out[0] = x;
+    let mut i = 1;
+    while i < 16 {
+        out[i] = a[i - 1];
+        i += 1;
+    }
+    out
+}
+
+#[inline]
+fn r_inv(a: &[u8; 16]) -> [u8; 16] {
+    let mut shifted = [0u8; 16];
+    shifted[..15].copy_from_slice(&a[1..]);
+    shifted[15] = a[0];
+    let x = l_func(&shifted);
+    shifted[15] = x;
+    shifted
+}
+
+#[inline]
+fn l(a: &mut [u8; 16]) {
+    for _ in 0..16 {
+        *a = r(*a);
+    }
+}
+
+#[inline]
+fn l_inv(a: &mut [u8; 16]) {
+    for _ in 0..16 {
+        *a = r_inv(a);
+    }
+}
+
+#[inline]
+fn xor(a: &mut [u8; 16], b: &[u8; 16]) {
+    for (x, y) in a.iter_mut().zip(b) {
+        *x ^= y;
+    }
+}
+
+/// round transformation LSX[k]
+#[inline]
+fn lsx(a: &mut [u8; 16], k: &[u8; 16]) {
+    xor(a, k);
+    for byte in a.iter_mut() {
+        *byte = PI[*byte as usize];
+    }
+    l(a);
+}
+
+/// Kuznyechik (GOST R 34.12-2015, RFC 7801), 128-bit block and 256-bit key.
+#[derive(Zeroize, Clone)]
+#[zeroize(drop)]
+pub struct Kuznyechik {
+    round_keys: [[u8; 16]; 10]
+}
+
+impl Kuznyechik {
+    fn key_expansion(key: &KuznyechikKey) -> [[u8; 16]; 10] {
+        let mut rk = [[0u8; 16]; 10];
+        let mut a: [u8; 16] = key.as_ref()[..16].try_into().unwrap();
+        let mut b: [u8; 16] = key.as_ref()[16..].try_into().unwrap();
+        rk[0] = a;
+        rk[1] = b;
+
+        // Feistel network F[C_i], the round key pair is taken every eight steps
+        for (i, c) in C.iter().enumerate() {
+            let mut next = a;
+            lsx(&mut next, c);
+            xor(&mut next, &b);
+            b = a;
+            a = next;
+            if (i + 1).is_multiple_of(8) {
+                rk[(i + 1) / 4] = a;
+                rk[(i + 1) / 4 + 1] = b;
+            }
+        }
+        a.zeroize();
+        b.zeroize();
+        rk
+    }
+}
+
+impl BlockCipher for Kuznyechik {
+    const BLOCK_SIZE: usize = 16;
+    const KEY_SIZE: usize = 32;
+    type Block = [u8; 16];
+    type Key = KuznyechikKey;
+
+    fn new(key: &Self::Key) -> Self {
+        Self { round_keys: Self::key_expansion(key) }
+    }
+
+    fn encrypt_block(&self, block: &mut Self::Block) {
+        for k in &self.round_keys[..9] {
+            lsx(block, k);
+        }
+        xor(block, &self.round_keys[9]);
+    }
+
+    fn decrypt_block(&self, block: &mut Self::Block) {
+        xor(block, &self.round_keys[9]);
+        for k in self.round_keys[..9].iter().rev() {
+            l_inv(block);
+            for byte in block.iter_mut() {
+                *byte = PI_INV[*byte as usize];
+            }
+            xor(block, k);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // RFC 7801, Section 5
+    const KEY: [u8; 32] = [
+        0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+        0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef
+    ];
+
+    #[test]
+    fn rfc7801_transformations() {
+        let mut a = [0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x00];
+        for byte in a.iter_mut() {
+            *byte = PI[*byte as usize];
+        }
+        assert_eq!(a, [0xb6, 0x6c, 0xd8, 0x88, 0x7d, 0x38, 0xe8, 0xd7, 0x77, 0x65, 0xae, 0xea, 0x0c, 0x9a, 0x7e, 0xfc]);
+
+        let a = r([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01, 0x00]);
+        assert_eq!(a, [0x94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01]);
+        assert_eq!(r_inv(&a), [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn rfc7801_round_keys() {
+        let rk = Kuznyechik::key_expansion(&KuznyechikKey::from(KEY));
+        assert_eq!(rk[2], [0xdb, 0x31, 0x48, 0x53, 0x15, 0x69, 0x43, 0x43, 0x22, 0x8d, 0x6a, 0xef, 0x8c, 0xc7, 0x8c, 0x44]);
+        assert_eq!(rk[9], [0x72, 0xe9, 0xdd, 0x74, 0x16, 0xbc, 0xf4, 0x5b, 0x75, 0x5d, 0xba, 0xa8, 0x8e, 0x4a, 0x40, 0x43]);
+    }
+
+    #[test]
+    fn rfc7801_block() {
+        let plaintext = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x00, 0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88];
+        let cipher = Kuznyechik::new(&KuznyechikKey::from(KEY));
+        let mut block = plaintext;
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, [0x7f, 0x67, 0x9d, 0x90, 0xbe, 0xbc, 0x24, 0x30, 0x5a, 0x46, 0x8d, 0x42, 0xb9, 0xd4, 0xed, 0xcd]);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, plaintext);
+    }
+}
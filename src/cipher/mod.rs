@@ -1,7 +1,47 @@
+use crate::error::SymcError;
 use zeroize::Zeroize;
 
 pub mod aes;
 
+#[cfg(feature = "chacha20")]
+pub mod chacha20;
+
+#[cfg(feature = "salsa20")]
+pub mod salsa20;
+
+#[cfg(feature = "sm4")]
+pub mod sm4;
+
+#[cfg(feature = "kuznyechik")]
+pub mod kuznyechik;
+
+#[cfg(feature = "camellia")]
+pub mod camellia;
+
+#[cfg(feature = "aria")]
+pub mod aria;
+
+#[cfg(feature = "twofish")]
+pub mod twofish;
+
+#[cfg(feature = "serpent")]
+pub mod serpent;
+
+#[cfg(feature = "speck")]
+pub mod speck;
+
+#[cfg(feature = "simon")]
+pub mod simon;
+
+#[cfg(feature = "present")]
+pub mod present;
+
+#[cfg(feature = "legacy")]
+pub mod tdea;
+
+#[cfg(feature = "legacy")]
+pub mod blowfish;
+
 pub mod mode;
 
 pub trait BlockCipher: Zeroize + Clone {
@@ -15,4 +55,21 @@ pub trait BlockCipher: Zeroize + Clone {
     fn encrypt_block(&self, block: &mut Self::Block);
 
     fn decrypt_block(&self, block: &mut Self::Block);
+}
+
+pub trait StreamCipher: Zeroize + Clone {
+    const KEY_SIZE: usize;
+    const NONCE_SIZE: usize;
+    type Key: AsRef<[u8]> + Default + Clone + Zeroize;
+    type Nonce: AsRef<[u8]> + AsMut<[u8]> + Clone + Default + Zeroize;
+
+    fn new(key: &Self::Key, nonce: &Self::Nonce) -> Self;
+
+    /// XORs the next `input.len()` keystream bytes into `output`, encryption and decryption are the same operation.
+    fn apply_keystream(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError>;
+
+    fn process(key: &Self::Key, nonce: &Self::Nonce, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let mut cipher = Self::new(key, nonce);
+        cipher.apply_keystream(input, output)
+    }
 }
\ No newline at end of file
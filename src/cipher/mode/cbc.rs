@@ -152,9 +152,9 @@ impl<C: BlockCipher, P: Padding> SymcDecryptor for CbcDecryptor<C, P> {
             return Ok(0);
         }
 
-        // 计算剩余的 input 处理完 16 字节对齐后还剩多少，确保 tail_len 一定存在并且小于等于 16 字节
-        let tail_len = ((input.len() - remaining - 1) & 0xF) + 1;
-        // 除去 tail_len，本次需要处理的 input 长度，head_len 一定是 16 字节对齐的
+        // 计算剩余的 input 处理完块对齐后还剩多少，确保 tail_len 一定存在并且小于等于一个块
+        let tail_len = ((input.len() - remaining - 1) % block_size) + 1;
+        // 除去 tail_len，本次需要处理的 input 长度，head_len 一定是块对齐的
         let head_len = input.len() - tail_len - remaining;
 
         // 总计 head_len 加上一个块 (之前遗留的数据 + input 补充的数据，一定是一个块的大小)
@@ -176,10 +176,10 @@ impl<C: BlockCipher, P: Padding> SymcDecryptor for CbcDecryptor<C, P> {
         written += block_size;
         self.buffer_len = 0;
 
-        // 每 16 字节循环处理 head 整块
+        // 每个块循环处理 head 整块
         let mut process_len = 0;
         while process_len < head_len {
-            // 拿取 input remaining 长度后的每 16 字节数据
+            // 拿取 input remaining 长度后的每个块
             self.buffer.as_mut().copy_from_slice(&input[(remaining + process_len)..(remaining + process_len + block_size)]);
             // 保存 iv 值到 output
             output[written..(written + block_size)].copy_from_slice(self.iv.as_mut());
@@ -194,7 +194,7 @@ impl<C: BlockCipher, P: Padding> SymcDecryptor for CbcDecryptor<C, P> {
             process_len += block_size;
         }
 
-        // 拷贝剩下的 tail，tail_len 一定小于或等于 16 字节
+        // 拷贝剩下的 tail，tail_len 一定小于或等于一个块
         self.buffer.as_mut()[..tail_len].copy_from_slice(&input[(remaining + head_len)..]);
         self.buffer_len = tail_len;
 
@@ -0,0 +1,372 @@
+use zeroize::Zeroize;
+
+use crate::aead::Aead;
+use crate::cipher::BlockCipher;
+use crate::error::SymcError;
+use crate::mac::constant_time_eq;
+
+pub const CCM_BLOCK_SIZE: usize = 16;
+pub const CCM_TAG_SIZE: usize = 16;
+pub const CCM_MIN_NONCE_SIZE: usize = 7;
+pub const CCM_MAX_NONCE_SIZE: usize = 13;
+
+#[inline]
+fn is_valid_tag_size(tag_size: usize) -> bool {
+    // M ∈ {4, 6, 8, 10, 12, 14, 16}
+    (4..=16).contains(&tag_size) && tag_size.is_multiple_of(2)
+}
+
+#[inline]
+fn is_valid_star_tag_size(tag_size: usize) -> bool {
+    // CCM* additionally allows M = 0, encryption only
+    tag_size == 0 || is_valid_tag_size(tag_size)
+}
+
+/// CBC-MAC over the formatted input B0 || encoded A || P, zero padded per segment.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct CbcMac<'a, C: BlockCipher> {
+    #[zeroize(skip)]
+    cipher: &'a C,
+    state: C::Block,
+    buffer_len: usize
+}
+
+impl<'a, C: BlockCipher> CbcMac<'a, C> {
+    fn new(cipher: &'a C) -> Self {
+        Self {
+            cipher,
+            state: C::Block::default(),
+            buffer_len: 0
+        }
+    }
+
+    fn update(&mut self, input: &[u8]) {
+        for byte in input {
+            self.state.as_mut()[self.buffer_len] ^= *byte;
+            self.buffer_len += 1;
+            if self.buffer_len == CCM_BLOCK_SIZE {
+                self.cipher.encrypt_block(&mut self.state);
+                self.buffer_len = 0;
+            }
+        }
+    }
+
+    fn pad(&mut self) {
+        if self.buffer_len > 0 {
+            self.cipher.encrypt_block(&mut self.state);
+            self.buffer_len = 0;
+        }
+    }
+
+    fn finalize(mut self) -> C::Block {
+        self.pad();
+        self.state.clone()
+    }
+}
+
+/// Shared CCM/CCM* engine, `tag_size == 0` is only reachable through `CcmStar`.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct CcmCore<C: BlockCipher> {
+    cipher: C,
+    tag_size: usize
+}
+
+impl<C: BlockCipher> CcmCore<C> {
+    fn new(key: &C::Key) -> Self {
+        assert_eq!(C::BLOCK_SIZE, CCM_BLOCK_SIZE, "CCM requires a 128-bit block cipher");
+
+        Self {
+            cipher: C::new(key),
+            tag_size: CCM_TAG_SIZE
+        }
+    }
+
+    fn check_lengths(nonce: &[u8], input_len: usize, output_len: usize) -> Result<(), SymcError> {
+        if !(CCM_MIN_NONCE_SIZE..=CCM_MAX_NONCE_SIZE).contains(&nonce.len()) {
+            return Err(SymcError::InvalidLength);
+        }
+
+        // 0 <= l(m) < 2^(8L)
+        let l = 15 - nonce.len();
+        if l < 8 && (input_len as u64) >> (8 * l) != 0 {
+            return Err(SymcError::InvalidInputLength);
+        }
+
+        if output_len < input_len {
+            return Err(SymcError::BufferTooSmall);
+        }
+        Ok(())
+    }
+
+    /// Counter block A_i = Flags || Nonce N || Counter i
+    fn counter_block(nonce: &[u8], counter: u64) -> C::Block {
+        let l = 15 - nonce.len();
+        let mut block = C::Block::default();
+        let block_bytes = block.as_mut();
+        block_bytes[0] = (l - 1) as u8;
+        block_bytes[1..(1 + nonce.len())].copy_from_slice(nonce);
+        block_bytes[(1 + nonce.len())..].copy_from_slice(&counter.to_be_bytes()[(8 - l)..]);
+        block
+    }
+
+    /// B0 = Flags || Nonce N || l(m), Flags = 64 * Adata + 8 * M' + L'
+    fn first_block(nonce: &[u8], aad_len: usize, input_len: usize, tag_size: usize) -> C::Block {
+        let l = 15 - nonce.len();
+        let adata = if aad_len > 0 { 0x40 } else { 0 };
+        let m = if tag_size > 0 { ((tag_size - 2) / 2) as u8 } else { 0 };
+
+        let mut block = C::Block::default();
+        let block_bytes = block.as_mut();
+        block_bytes[0] = adata | (m << 3) | (l - 1) as u8;
+        block_bytes[1..(1 + nonce.len())].copy_from_slice(nonce);
+        block_bytes[(1 + nonce.len())..].copy_from_slice(&(input_len as u64).to_be_bytes()[(8 - l)..]);
+        block
+    }
+
+    /// Encoded length prefix of the associated data, returns the number of bytes used.
+    fn encode_aad_len(aad_len: usize, output: &mut [u8; 10]) -> usize {
+        let aad_len = aad_len as u64;
+        if aad_len < (1 << 16) - (1 << 8) {
+            output[..2].copy_from_slice(&(aad_len as u16).to_be_bytes());
+            2
+        } else if aad_len <= u32::MAX as u64 {
+            output[..2].copy_from_slice(&[0xff, 0xfe]);
+            output[2..6].copy_from_slice(&(aad_len as u32).to_be_bytes());
+            6
+        } else {
+            output[..2].copy_from_slice(&[0xff, 0xff]);
+            output[2..].copy_from_slice(&aad_len.to_be_bytes());
+            10
+        }
+    }
+
+    /// T = first-M-bytes( CBC-MAC(B0 || encoded A || P) ) ⊕ S0
+    fn compute_tag(&self, nonce: &[u8], aad: &[u8], plaintext: &[u8], tag: &mut [u8]) {
+        if tag.is_empty() {
+            return;
+        }
+
+        let mut mac = CbcMac::new(&self.cipher);
+        mac.update(Self::first_block(nonce, aad.len(), plaintext.len(), tag.len()).as_ref());
+        if !aad.is_empty() {
+            let mut encoded_len = [0u8; 10];
+            let encoded_len_size = Self::encode_aad_len(aad.len(), &mut encoded_len);
+            mac.update(&encoded_len[..encoded_len_size]);
+            mac.update(aad);
+            mac.pad();
+        }
+        mac.update(plaintext);
+        let mut t = mac.finalize();
+
+        let mut s0 = Self::counter_block(nonce, 0);
+        self.cipher.encrypt_block(&mut s0);
+        tag.iter_mut()
+            .zip(t.as_ref().iter())
+            .zip(s0.as_ref().iter())
+            .for_each(|((o, t), s)| *o = *t ^ *s);
+        t.zeroize();
+        s0.zeroize();
+    }
+
+    /// C = P ⊕ first-l(m)-bytes(S1 || S2 || ...)
+    fn ctr(&self, nonce: &[u8], input: &[u8], output: &mut [u8]) {
+        for (i, (in_chunk, out_chunk)) in input.chunks(CCM_BLOCK_SIZE).zip(output.chunks_mut(CCM_BLOCK_SIZE)).enumerate() {
+            let mut keystream_block = Self::counter_block(nonce, i as u64 + 1);
+            self.cipher.encrypt_block(&mut keystream_block);
+            out_chunk.iter_mut()
+                .zip(in_chunk.iter())
+                .zip(keystream_block.as_ref().iter())
+                .for_each(|((o, i), k)| *o = *i ^ *k);
+            keystream_block.zeroize();
+        }
+    }
+
+    fn encrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        Self::check_lengths(nonce, input.len(), output.len())?;
+
+        self.compute_tag(nonce, aad, input, tag);
+        self.ctr(nonce, input, &mut output[..input.len()]);
+        Ok(input.len())
+    }
+
+    fn decrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        Self::check_lengths(nonce, input.len(), output.len())?;
+
+        let output = &mut output[..input.len()];
+        self.ctr(nonce, input, output);
+
+        let mut expected_tag = [0u8; 16];
+        self.compute_tag(nonce, aad, output, &mut expected_tag[..tag.len()]);
+        let verified = constant_time_eq(&expected_tag[..tag.len()], tag);
+        expected_tag.zeroize();
+
+        if !verified {
+            // the tag covers the plaintext, so wipe what was decrypted
+            output.zeroize();
+            return Err(SymcError::AuthenticationFailed);
+        }
+        Ok(input.len())
+    }
+}
+
+/// CCM (RFC 3610, SP 800-38C), the nonce length (7..=13 bytes) is taken from each call.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Ccm<C: BlockCipher> {
+    core: CcmCore<C>
+}
+
+impl<C: BlockCipher> Ccm<C> {
+    /// Creates a CCM instance producing `tag_size` byte tags, M ∈ {4, 6, ..., 16}.
+    pub fn with_tag_size(key: &C::Key, tag_size: usize) -> Result<Self, SymcError> {
+        if !is_valid_tag_size(tag_size) {
+            return Err(SymcError::InvalidLength);
+        }
+
+        let mut core = CcmCore::new(key);
+        core.tag_size = tag_size;
+        Ok(Self { core })
+    }
+}
+
+impl<C: BlockCipher> Aead for Ccm<C> {
+    const KEY_SIZE: usize = C::KEY_SIZE;
+    const TAG_SIZE: usize = CCM_TAG_SIZE;
+    type Key = C::Key;
+
+    fn new(key: &Self::Key) -> Self {
+        Self { core: CcmCore::new(key) }
+    }
+
+    fn tag_size(&self) -> usize {
+        self.core.tag_size
+    }
+
+    fn encrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        if tag.len() != self.core.tag_size {
+            return Err(SymcError::InvalidLength);
+        }
+        self.core.encrypt_detached(nonce, aad, input, output, tag)
+    }
+
+    fn decrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        // the tag length is fixed by the instance, a truncated or empty tag must not verify
+        if tag.len() != self.core.tag_size {
+            return Err(SymcError::InvalidLength);
+        }
+        self.core.decrypt_detached(nonce, aad, input, tag, output)
+    }
+}
+
+/// CCM* (IEEE 802.15.4), CCM extended with an encryption only mode when the tag size is 0.
+///
+/// With a 0 byte tag neither the payload nor the associated data is authenticated.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct CcmStar<C: BlockCipher> {
+    core: CcmCore<C>
+}
+
+impl<C: BlockCipher> CcmStar<C> {
+    /// Creates a CCM* instance producing `tag_size` byte tags, M ∈ {0, 4, 6, ..., 16}.
+    pub fn with_tag_size(key: &C::Key, tag_size: usize) -> Result<Self, SymcError> {
+        if !is_valid_star_tag_size(tag_size) {
+            return Err(SymcError::InvalidLength);
+        }
+
+        let mut core = CcmCore::new(key);
+        core.tag_size = tag_size;
+        Ok(Self { core })
+    }
+}
+
+impl<C: BlockCipher> Aead for CcmStar<C> {
+    const KEY_SIZE: usize = C::KEY_SIZE;
+    const TAG_SIZE: usize = CCM_TAG_SIZE;
+    type Key = C::Key;
+
+    fn new(key: &Self::Key) -> Self {
+        Self { core: CcmCore::new(key) }
+    }
+
+    fn tag_size(&self) -> usize {
+        self.core.tag_size
+    }
+
+    fn encrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        if tag.len() != self.core.tag_size {
+            return Err(SymcError::InvalidLength);
+        }
+        self.core.encrypt_detached(nonce, aad, input, output, tag)
+    }
+
+    fn decrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        // an empty tag only skips authentication on an instance built with tag size 0
+        if tag.len() != self.core.tag_size {
+            return Err(SymcError::InvalidLength);
+        }
+        self.core.decrypt_detached(nonce, aad, input, tag, output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::Aes128;
+
+    /// Formatting example from NIST SP 800-38C, Appendix C.1
+    const NONCE: [u8; 7] = [0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16];
+
+    #[test]
+    fn ccm_first_block_formatting() {
+        let b0 = CcmCore::<Aes128>::first_block(&NONCE, 8, 4, 4);
+        assert_eq!(b0, [
+            0x4f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04,
+        ]);
+
+        let b0 = CcmCore::<Aes128>::first_block(&NONCE, 0, 4, 0);
+        assert_eq!(b0[0], 0x07);
+    }
+
+    #[test]
+    fn ccm_counter_block_formatting() {
+        let a1 = CcmCore::<Aes128>::counter_block(&NONCE, 1);
+        assert_eq!(a1, [
+            0x07, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        ]);
+    }
+
+    #[test]
+    fn ccm_aad_length_encoding() {
+        let mut encoded = [0u8; 10];
+        assert_eq!(CcmCore::<Aes128>::encode_aad_len(8, &mut encoded), 2);
+        assert_eq!(&encoded[..2], &[0x00, 0x08]);
+
+        assert_eq!(CcmCore::<Aes128>::encode_aad_len(0xff00, &mut encoded), 6);
+        assert_eq!(&encoded[..6], &[0xff, 0xfe, 0x00, 0x00, 0xff, 0x00]);
+    }
+
+    #[test]
+    fn ccm_message_length_limit() {
+        // a 13 byte nonce leaves L = 2, so l(m) must be below 2^16
+        let nonce = [0u8; 13];
+        assert_eq!(CcmCore::<Aes128>::check_lengths(&nonce, 0xffff, 0xffff), Ok(()));
+        assert_eq!(CcmCore::<Aes128>::check_lengths(&nonce, 0x10000, 0x10000), Err(SymcError::InvalidInputLength));
+        assert_eq!(CcmCore::<Aes128>::check_lengths(&nonce[..6], 0, 0), Err(SymcError::InvalidLength));
+    }
+
+    #[test]
+    fn ccm_tag_sizes() {
+        assert!(is_valid_tag_size(4));
+        assert!(is_valid_tag_size(16));
+        assert!(!is_valid_tag_size(0));
+        assert!(!is_valid_tag_size(5));
+        assert!(!is_valid_tag_size(18));
+        assert!(is_valid_star_tag_size(0));
+        assert!(!is_valid_star_tag_size(2));
+    }
+}
@@ -0,0 +1,502 @@
+use crate::cipher::BlockCipher;
+use crate::error::SymcError;
+use super::{SymcDecryptor, SymcEncryptor};
+
+/// An OpenPGP decryptor recovers everything it needs from the ciphertext, so `new` and `reset`
+/// take an empty array.
+pub type OpenPgpCfbIv = [u8; 0];
+
+macro_rules! cfb_struct {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        pub struct $name<C: BlockCipher> {
+            cipher: C,
+            register: C::Block,
+            buffer: C::Block,
+            buffer_len: usize,
+        }
+        impl<C: BlockCipher> Clone for $name<C>
+        where C: Clone, C::Block: Clone {
+            fn clone(&self) -> Self {
+                Self {
+                    cipher: self.cipher.clone(),
+                    register: self.register.clone(),
+                    buffer: self.buffer.clone(),
+                    buffer_len: self.buffer_len,
+                }
+            }
+        }
+        impl<C: BlockCipher> $name<C> {
+            fn from_cipher(cipher: C, register: C::Block) -> Self {
+                Self {
+                    cipher,
+                    register,
+                    buffer: Default::default(),
+                    buffer_len: 0
+                }
+            }
+        }
+    };
+}
+
+cfb_struct!(
+    /// CFB with a full block segment (SP 800-38A, 6.3), CFB-128 for 128-bit block ciphers.
+    ///
+    /// Like CTR, a trailing partial segment is held back and written by `finalize`.
+    CfbEncryptor
+);
+cfb_struct!(
+    /// CFB with a full block segment (SP 800-38A, 6.3), CFB-128 for 128-bit block ciphers.
+    CfbDecryptor
+);
+cfb_struct!(
+    /// CFB-8, one block cipher call per byte, every byte is written by `update`.
+    Cfb8Encryptor
+);
+cfb_struct!(
+    /// CFB-8, one block cipher call per byte, every byte is written by `update`.
+    Cfb8Decryptor
+);
+cfb_struct!(
+    /// CFB-1, one block cipher call per bit, bits are processed most significant first.
+    Cfb1Encryptor
+);
+cfb_struct!(
+    /// CFB-1, one block cipher call per bit, bits are processed most significant first.
+    Cfb1Decryptor
+);
+
+/// Encrypts the register and returns it as the next keystream block.
+#[inline]
+fn keystream<C: BlockCipher>(cipher: &C, register: &C::Block) -> C::Block {
+    let mut block = register.clone();
+    cipher.encrypt_block(&mut block);
+    block
+}
+
+/// Shifts `byte` into the right end of the register.
+#[inline]
+fn shift_byte<C: BlockCipher>(register: &mut C::Block, byte: u8) {
+    let register = register.as_mut();
+    register.copy_within(1.., 0);
+    register[C::BLOCK_SIZE - 1] = byte;
+}
+
+/// Shifts the low bit of `bit` into the right end of the register.
+#[inline]
+fn shift_bit<C: BlockCipher>(register: &mut C::Block, bit: u8) {
+    let register = register.as_mut();
+    for i in 0..C::BLOCK_SIZE - 1 {
+        register[i] = (register[i] << 1) | (register[i + 1] >> 7);
+    }
+    register[C::BLOCK_SIZE - 1] = (register[C::BLOCK_SIZE - 1] << 1) | bit;
+}
+
+/// Runs the full block segment over `input` with CTR-style buffering, `decrypt` selects
+/// whether the input or the output is fed back into the register.
+fn process_blocks<C: BlockCipher>(
+    cipher: &C,
+    register: &mut C::Block,
+    buffer: &mut C::Block,
+    buffer_len: &mut usize,
+    input: &[u8],
+    output: &mut [u8],
+    decrypt: bool
+) -> Result<usize, SymcError> {
+    let block_size = C::BLOCK_SIZE;
+    let mut written = 0;
+
+    if output.len() < (*buffer_len + input.len()) / block_size * block_size {
+        return Err(SymcError::BufferTooSmall);
+    }
+
+    let remaining = block_size - *buffer_len;
+    if remaining > input.len() {
+        buffer.as_mut()[*buffer_len..(*buffer_len + input.len())].copy_from_slice(input);
+        *buffer_len += input.len();
+        return Ok(0);
+    }
+
+    buffer.as_mut()[*buffer_len..].copy_from_slice(&input[..remaining]);
+    let mut segment = |segment: &[u8], out: &mut [u8]| {
+        let keystream_block = keystream(cipher, register);
+        out.iter_mut()
+            .zip(segment.iter())
+            .zip(keystream_block.as_ref().iter())
+            .for_each(|((o, i), k)| *o = *i ^ *k);
+        register.as_mut().copy_from_slice(if decrypt { segment } else { &*out });
+    };
+
+    segment(buffer.as_ref(), &mut output[..block_size]);
+    written += block_size;
+    *buffer_len = 0;
+
+    let mut chunks = input[remaining..].chunks_exact(block_size);
+    for chunk in &mut chunks {
+        segment(chunk, &mut output[written..(written + block_size)]);
+        written += block_size;
+    }
+
+    let remainder = chunks.remainder();
+    buffer.as_mut()[..remainder.len()].copy_from_slice(remainder);
+    *buffer_len = remainder.len();
+
+    Ok(written)
+}
+
+/// Applies the keystream to the held back partial segment.
+fn finish_blocks<C: BlockCipher>(cipher: &C, register: &C::Block, buffer: &C::Block, buffer_len: usize, output: &mut [u8]) -> Result<usize, SymcError> {
+    if output.len() < buffer_len {
+        return Err(SymcError::BufferTooSmall);
+    }
+
+    if buffer_len == 0 {
+        return Ok(0);
+    }
+
+    let keystream_block = keystream(cipher, register);
+    output.iter_mut()
+        .zip(&buffer.as_ref()[..buffer_len])
+        .zip(&keystream_block.as_ref()[..buffer_len])
+        .for_each(|((o, i), k)| *o = *i ^ *k);
+
+    Ok(buffer_len)
+}
+
+impl<C: BlockCipher> SymcEncryptor for CfbEncryptor<C> {
+    type Key = C::Key;
+    type IV = C::Block;
+
+    fn new(key: &Self::Key, iv: &Self::IV) -> Self {
+        Self::from_cipher(C::new(key), iv.clone())
+    }
+
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        process_blocks(&self.cipher, &mut self.register, &mut self.buffer, &mut self.buffer_len, input, output, false)
+    }
+
+    fn finalize(self, output: &mut [u8]) -> Result<usize, SymcError> {
+        finish_blocks(&self.cipher, &self.register, &self.buffer, self.buffer_len, output)
+    }
+
+    fn reset(&mut self, iv: &Self::IV) {
+        self.register = iv.clone();
+        self.buffer_len = 0;
+    }
+}
+
+impl<C: BlockCipher> SymcDecryptor for CfbDecryptor<C> {
+    type Key = C::Key;
+    type IV = C::Block;
+
+    fn new(key: &Self::Key, iv: &Self::IV) -> Self {
+        Self::from_cipher(C::new(key), iv.clone())
+    }
+
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        process_blocks(&self.cipher, &mut self.register, &mut self.buffer, &mut self.buffer_len, input, output, true)
+    }
+
+    fn finalize(self, output: &mut [u8]) -> Result<usize, SymcError> {
+        finish_blocks(&self.cipher, &self.register, &self.buffer, self.buffer_len, output)
+    }
+
+    fn reset(&mut self, iv: &Self::IV) {
+        self.register = iv.clone();
+        self.buffer_len = 0;
+    }
+}
+
+macro_rules! cfb_stream_impl {
+    ($name:ident, $trait:ident, |$cipher:ident, $register:ident, $input:ident| $body:expr) => {
+        impl<C: BlockCipher> $trait for $name<C> {
+            type Key = C::Key;
+            type IV = C::Block;
+
+            fn new(key: &Self::Key, iv: &Self::IV) -> Self {
+                Self::from_cipher(C::new(key), iv.clone())
+            }
+
+            fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+                if output.len() < input.len() {
+                    return Err(SymcError::BufferTooSmall);
+                }
+
+                let $cipher = &self.cipher;
+                let $register = &mut self.register;
+                for (&$input, o) in input.iter().zip(output.iter_mut()) {
+                    *o = $body;
+                }
+
+                Ok(input.len())
+            }
+
+            fn finalize(self, _output: &mut [u8]) -> Result<usize, SymcError> {
+                Ok(0)
+            }
+
+            fn reset(&mut self, iv: &Self::IV) {
+                self.register = iv.clone();
+            }
+        }
+    };
+}
+
+cfb_stream_impl!(Cfb8Encryptor, SymcEncryptor, |cipher, register, p| {
+    let c = p ^ keystream(cipher, register).as_ref()[0];
+    shift_byte::<C>(register, c);
+    c
+});
+
+cfb_stream_impl!(Cfb8Decryptor, SymcDecryptor, |cipher, register, c| {
+    let p = c ^ keystream(cipher, register).as_ref()[0];
+    shift_byte::<C>(register, c);
+    p
+});
+
+cfb_stream_impl!(Cfb1Encryptor, SymcEncryptor, |cipher, register, p| {
+    let mut c = 0u8;
+    for shift in (0..8).rev() {
+        let bit = ((p >> shift) ^ (keystream(cipher, register).as_ref()[0] >> 7)) & 1;
+        shift_bit::<C>(register, bit);
+        c |= bit << shift;
+    }
+    c
+});
+
+cfb_stream_impl!(Cfb1Decryptor, SymcDecryptor, |cipher, register, c| {
+    let mut p = 0u8;
+    for shift in (0..8).rev() {
+        let bit = (c >> shift) & 1;
+        p |= (bit ^ (keystream(cipher, register).as_ref()[0] >> 7)) << shift;
+        shift_bit::<C>(register, bit);
+    }
+    p
+});
+
+/// OpenPGP CFB with resynchronisation (RFC 4880, 13.9), as used by Symmetrically Encrypted
+/// Data packets.
+///
+/// The IV is the random prefix. The output starts with the `BLOCK_SIZE + 2` byte encrypted
+/// prefix, after which the register is resynchronised and full block CFB follows.
+pub struct OpenPgpCfbEncryptor<C: BlockCipher> {
+    inner: CfbEncryptor<C>,
+    header_head: [u8; 2],
+    header_tail: C::Block,
+    header_written: bool,
+}
+impl<C: BlockCipher> Clone for OpenPgpCfbEncryptor<C>
+where C: Clone, C::Block: Clone {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            header_head: self.header_head,
+            header_tail: self.header_tail.clone(),
+            header_written: self.header_written,
+        }
+    }
+}
+
+impl<C: BlockCipher> OpenPgpCfbEncryptor<C> {
+    /// Encrypts the prefix and its repeated last two bytes, then resynchronises the register
+    /// on the last `BLOCK_SIZE` bytes written.
+    fn start(&mut self, prefix: &C::Block) {
+        let block_size = C::BLOCK_SIZE;
+        let cipher = &self.inner.cipher;
+
+        let mut first = keystream(cipher, &Default::default());
+        first.as_mut().iter_mut()
+            .zip(prefix.as_ref().iter())
+            .for_each(|(b, p)| *b ^= *p);
+
+        let check = keystream(cipher, &first);
+        self.header_head.copy_from_slice(&first.as_ref()[..2]);
+        self.header_tail.as_mut()[..block_size - 2].copy_from_slice(&first.as_ref()[2..]);
+        self.header_tail.as_mut()[block_size - 2] = check.as_ref()[0] ^ prefix.as_ref()[block_size - 2];
+        self.header_tail.as_mut()[block_size - 1] = check.as_ref()[1] ^ prefix.as_ref()[block_size - 1];
+
+        self.inner.reset(&self.header_tail);
+        self.header_written = false;
+    }
+
+    /// Writes the encrypted prefix on the first call, returning its length.
+    fn write_header(&mut self, output: &mut [u8]) -> usize {
+        if self.header_written {
+            return 0;
+        }
+        output[..2].copy_from_slice(&self.header_head);
+        output[2..(C::BLOCK_SIZE + 2)].copy_from_slice(self.header_tail.as_ref());
+        self.header_written = true;
+        C::BLOCK_SIZE + 2
+    }
+
+    fn header_len(&self) -> usize {
+        if self.header_written { 0 } else { C::BLOCK_SIZE + 2 }
+    }
+}
+
+impl<C: BlockCipher> SymcEncryptor for OpenPgpCfbEncryptor<C> {
+    type Key = C::Key;
+    type IV = C::Block;
+
+    fn new(key: &Self::Key, iv: &Self::IV) -> Self {
+        let mut encryptor = Self {
+            inner: CfbEncryptor::from_cipher(C::new(key), Default::default()),
+            header_head: [0u8; 2],
+            header_tail: Default::default(),
+            header_written: false,
+        };
+        encryptor.start(iv);
+        encryptor
+    }
+
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let block_size = C::BLOCK_SIZE;
+        let header_len = self.header_len();
+        if output.len() < header_len + (self.inner.buffer_len + input.len()) / block_size * block_size {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        let written = self.write_header(output);
+        Ok(written + self.inner.update(input, &mut output[written..])?)
+    }
+
+    fn finalize(mut self, output: &mut [u8]) -> Result<usize, SymcError> {
+        if output.len() < self.header_len() + self.inner.buffer_len {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        let written = self.write_header(output);
+        Ok(written + self.inner.finalize(&mut output[written..])?)
+    }
+
+    fn reset(&mut self, iv: &Self::IV) {
+        self.start(iv);
+    }
+}
+
+/// Decrypts OpenPGP CFB, dropping the `BLOCK_SIZE + 2` byte prefix from the output.
+///
+/// The repeated prefix bytes are not checked, that quick check is a known decryption oracle
+/// and integrity has to come from the packet's MDC or signature.
+pub struct OpenPgpCfbDecryptor<C: BlockCipher> {
+    inner: CfbDecryptor<C>,
+    header_tail: C::Block,
+    header_len: usize,
+}
+impl<C: BlockCipher> Clone for OpenPgpCfbDecryptor<C>
+where C: Clone, C::Block: Clone {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            header_tail: self.header_tail.clone(),
+            header_len: self.header_len,
+        }
+    }
+}
+
+impl<C: BlockCipher> SymcDecryptor for OpenPgpCfbDecryptor<C> {
+    type Key = C::Key;
+    type IV = OpenPgpCfbIv;
+
+    fn new(key: &Self::Key, _iv: &Self::IV) -> Self {
+        Self {
+            inner: CfbDecryptor::from_cipher(C::new(key), Default::default()),
+            header_tail: Default::default(),
+            header_len: 0,
+        }
+    }
+
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let prefix_len = C::BLOCK_SIZE + 2;
+        let take = (prefix_len - self.header_len).min(input.len());
+
+        // only the last BLOCK_SIZE bytes of the prefix are needed to resynchronise
+        for &byte in &input[..take] {
+            if self.header_len >= 2 {
+                self.header_tail.as_mut()[self.header_len - 2] = byte;
+            }
+            self.header_len += 1;
+        }
+        if take > 0 && self.header_len == prefix_len {
+            self.inner.reset(&self.header_tail);
+        }
+
+        self.inner.update(&input[take..], output)
+    }
+
+    fn finalize(self, output: &mut [u8]) -> Result<usize, SymcError> {
+        if self.header_len != C::BLOCK_SIZE + 2 {
+            return Err(SymcError::InvalidInputLength);
+        }
+        self.inner.finalize(output)
+    }
+
+    fn reset(&mut self, _iv: &Self::IV) {
+        self.inner.reset(&Default::default());
+        self.header_len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::{Aes128, Aes128Key};
+
+    /// Test vector from NIST SP 800-38A, Appendix F.3.1
+    const KEY: [u8; 16] = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+        0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+    ];
+    const IV: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    ];
+
+    #[test]
+    fn shift_bit_carries_across_bytes() {
+        let mut register = [0x80u8, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff];
+        shift_bit::<Aes128>(&mut register, 1);
+        assert_eq!(register[..3], [0x00, 0x02, 0x00]);
+        assert_eq!(register[14..], [0x01, 0xff]);
+    }
+
+    #[test]
+    fn cfb1_register_after_two_bytes() {
+        let key = Aes128Key::from(KEY);
+        let mut encryptor = Cfb1Encryptor::<Aes128>::new(&key, &IV);
+        let mut output = [0u8; 2];
+        encryptor.update(&[0x6b, 0xc1], &mut output).unwrap();
+        assert_eq!(output, [0x68, 0xb3]);
+
+        // sixteen bits were shifted in, so the register is IV[2..] || C
+        assert_eq!(encryptor.register[..14], IV[2..]);
+        assert_eq!(encryptor.register[14..], output);
+    }
+
+    #[test]
+    fn cfb_holds_back_partial_segment() {
+        let key = Aes128Key::from(KEY);
+        let mut encryptor = CfbEncryptor::<Aes128>::new(&key, &IV);
+        let mut output = [0u8; 16];
+        assert_eq!(encryptor.update(&[0x6b; 5], &mut output).unwrap(), 0);
+        assert_eq!(encryptor.buffer_len, 5);
+        assert_eq!(encryptor.finalize(&mut output[..4]), Err(SymcError::BufferTooSmall));
+    }
+
+    #[test]
+    fn openpgp_header_resynchronises() {
+        let key = Aes128Key::from(KEY);
+        let mut encryptor = OpenPgpCfbEncryptor::<Aes128>::new(&key, &IV);
+        let mut output = [0u8; 18];
+        assert_eq!(encryptor.update(&[], &mut output).unwrap(), 18);
+        assert_eq!(encryptor.inner.register, output[2..]);
+
+        // the repeated prefix bytes decrypt to the last two bytes of the prefix
+        let mut check = [0u8; 16];
+        check.copy_from_slice(&output[..16]);
+        Aes128::new(&key).encrypt_block(&mut check);
+        assert_eq!(output[16] ^ check[0], IV[14]);
+        assert_eq!(output[17] ^ check[1], IV[15]);
+    }
+}
@@ -1,35 +1,196 @@
 use zeroize::Zeroize;
 
 use crate::cipher::BlockCipher;
+use crate::error::SymcError;
 use super::{SymcDecryptor, SymcEncryptor};
 
-#[derive(Clone, Default, Zeroize)]
+/// Largest counter field, the full block of a 128-bit block cipher.
+const MAX_COUNTER_SIZE: usize = 16;
+
+/// Byte order of the counter block, read as a single integer.
+///
+/// The counter is its low bytes, so a big-endian counter sits at the end of the block and a
+/// little-endian one at the start, with the nonce filling the rest.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CtrEndian {
+    #[default]
+    Big,
+    Little
+}
+
+/// Nonce and initial counter value, the counter takes whatever the nonce leaves of the block.
+///
+/// `From<[u8; 12]>` and `From<[u8; 4]>` give a 32-bit big-endian counter starting at zero for
+/// 128-bit and 64-bit block ciphers. The default is an all-zero nonce that leaves a 32-bit
+/// counter on any block size, it reads as 12 bytes until it is used with a cipher.
+/// `CtrEncryptor::new` and `CtrDecryptor::new` panic if the nonce leaves no room for a counter.
+///
+/// The nonce bytes used to be the public tuple field `.0` with an infallible conversion to
+/// `[u8; 12]`. Read them through `AsRef<[u8]>`, or use `<[u8; 12]>::try_from`, which fails
+/// for nonces of any other length.
+#[derive(Clone, Zeroize)]
 #[zeroize(drop)]
-pub struct CtrNonce(pub [u8; 12]);
+pub struct CtrNonce {
+    bytes: [u8; MAX_COUNTER_SIZE],
+    len: usize,
+    counter: u128,
+    #[zeroize(skip)]
+    endian: CtrEndian,
+    // the default nonce is sized by the cipher, anything written into it fixes the length
+    fit_block: bool
+}
+impl Default for CtrNonce {
+    fn default() -> Self {
+        Self { bytes: [0u8; MAX_COUNTER_SIZE], len: 12, counter: 0, endian: CtrEndian::Big, fit_block: true }
+    }
+}
 impl AsRef<[u8]> for CtrNonce {
     fn as_ref(&self) -> &[u8] {
-        &self.0
+        &self.bytes[..self.len]
     }
 }
 impl AsMut<[u8]> for CtrNonce {
     fn as_mut(&mut self) -> &mut [u8] {
-        &mut self.0
+        self.fit_block = false;
+        &mut self.bytes[..self.len]
     }
 }
 impl From<[u8; 12]> for CtrNonce {
     fn from(array: [u8; 12]) -> Self {
-        Self(array)
+        Self::new(&array, 0, CtrEndian::Big).unwrap()
+    }
+}
+impl From<[u8; 4]> for CtrNonce {
+    fn from(array: [u8; 4]) -> Self {
+        Self::new(&array, 0, CtrEndian::Big).unwrap()
+    }
+}
+/// Fails with `SymcError::InvalidLength` unless the nonce is exactly 12 bytes.
+impl TryFrom<CtrNonce> for [u8; 12] {
+    type Error = SymcError;
+
+    fn try_from(nonce: CtrNonce) -> Result<Self, Self::Error> {
+        nonce.as_ref().try_into().map_err(|_| SymcError::InvalidLength)
+    }
+}
+
+impl CtrNonce {
+    /// Nonce followed by a counter starting at `initial_counter`, e.g. RFC 3686 is a 12 byte
+    /// nonce with an initial counter of 1 and WinZip AES is an 8 byte zero nonce with a
+    /// little-endian counter starting at 1.
+    pub fn new(nonce: &[u8], initial_counter: u128, endian: CtrEndian) -> Result<Self, SymcError> {
+        if nonce.len() >= MAX_COUNTER_SIZE {
+            return Err(SymcError::InvalidLength);
+        }
+
+        let mut bytes = [0u8; MAX_COUNTER_SIZE];
+        bytes[..nonce.len()].copy_from_slice(nonce);
+        Ok(Self { bytes, len: nonce.len(), counter: initial_counter, endian, fit_block: false })
+    }
+
+    /// Splits a full initial counter block, as given in SP 800-38A, into the nonce and a
+    /// `counter_size` byte counter.
+    pub fn from_counter_block(block: &[u8], counter_size: usize, endian: CtrEndian) -> Result<Self, SymcError> {
+        if block.len() > MAX_COUNTER_SIZE || counter_size == 0 || counter_size > block.len() {
+            return Err(SymcError::InvalidLength);
+        }
+
+        let mut counter = [0u8; MAX_COUNTER_SIZE];
+        let nonce = match endian {
+            CtrEndian::Big => {
+                let (nonce, value) = block.split_at(block.len() - counter_size);
+                counter[MAX_COUNTER_SIZE - counter_size..].copy_from_slice(value);
+                nonce
+            }
+            CtrEndian::Little => {
+                let (value, nonce) = block.split_at(counter_size);
+                counter[..counter_size].copy_from_slice(value);
+                nonce
+            }
+        };
+        let initial_counter = match endian {
+            CtrEndian::Big => u128::from_be_bytes(counter),
+            CtrEndian::Little => u128::from_le_bytes(counter)
+        };
+
+        Self::new(nonce, initial_counter, endian)
     }
+
+    /// Nonce length used with `C`.
+    #[inline]
+    fn len_for<C: BlockCipher>(&self) -> usize {
+        if self.fit_block { C::BLOCK_SIZE.saturating_sub(4) } else { self.len }
+    }
+}
+
+/// Position in the counter space, `remaining` is how many counter values are still unused.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct Counter {
+    value: u128,
+    remaining: u128,
+    size: usize,
+    #[zeroize(skip)]
+    endian: CtrEndian
 }
-impl From<CtrNonce> for [u8; 12] {
-    fn from(output: CtrNonce) -> Self {
-        output.0
+
+impl Counter {
+    fn new<C: BlockCipher>(nonce: &CtrNonce) -> Self {
+        let nonce_len = nonce.len_for::<C>();
+        assert!(nonce_len < C::BLOCK_SIZE && C::BLOCK_SIZE <= MAX_COUNTER_SIZE, "CTR nonce leaves no room for the counter");
+
+        let size = C::BLOCK_SIZE - nonce_len;
+        let max = u128::MAX >> (8 * (MAX_COUNTER_SIZE - size));
+        // an initial value outside the counter field leaves nothing to encrypt with
+        let remaining = max.checked_sub(nonce.counter).map_or(0, |left| left.saturating_add(1));
+        Self { value: nonce.counter, remaining, size, endian: nonce.endian }
+    }
+
+    /// Fails without touching the counter unless `blocks` more values are left.
+    #[inline]
+    fn reserve(&self, blocks: usize) -> Result<(), SymcError> {
+        if blocks as u128 > self.remaining {
+            return Err(SymcError::InvalidInputLength);
+        }
+        Ok(())
+    }
+
+    /// Writes the current value into the counter field of `block` and steps to the next one.
+    #[inline]
+    fn next<C: BlockCipher>(&mut self, block: &mut C::Block) {
+        let block = block.as_mut();
+        match self.endian {
+            CtrEndian::Big => block[C::BLOCK_SIZE - self.size..].copy_from_slice(&self.value.to_be_bytes()[MAX_COUNTER_SIZE - self.size..]),
+            CtrEndian::Little => block[..self.size].copy_from_slice(&self.value.to_le_bytes()[..self.size])
+        }
+        self.value = self.value.wrapping_add(1);
+        self.remaining -= 1;
     }
 }
 
+#[inline]
+fn nonce_counter_block<C: BlockCipher>(nonce: &CtrNonce) -> C::Block {
+    let mut block: C::Block = Default::default();
+    let nonce_bytes = &nonce.bytes[..nonce.len_for::<C>()];
+    match nonce.endian {
+        CtrEndian::Big => block.as_mut()[..nonce_bytes.len()].copy_from_slice(nonce_bytes),
+        CtrEndian::Little => block.as_mut()[C::BLOCK_SIZE - nonce_bytes.len()..].copy_from_slice(nonce_bytes)
+    }
+    block
+}
+
+#[inline]
+fn next_keystream<C: BlockCipher>(cipher: &C, nonce_counter: &C::Block, counter: &mut Counter) -> C::Block {
+    let mut block = nonce_counter.clone();
+    counter.next::<C>(&mut block);
+    cipher.encrypt_block(&mut block);
+    block
+}
+
 pub struct CtrEncryptor<C: BlockCipher> {
     cipher: C,
     nonce_counter: C::Block,
+    counter: Counter,
     buffer: C::Block,
     buffer_len: usize,
 }
@@ -39,6 +200,7 @@ where C: Clone, C::Block: Clone {
         Self {
             cipher: self.cipher.clone(),
             nonce_counter: self.nonce_counter.clone(),
+            counter: self.counter.clone(),
             buffer: self.buffer.clone(),
             buffer_len: self.buffer_len,
         }
@@ -48,6 +210,7 @@ where C: Clone, C::Block: Clone {
 pub struct CtrDecryptor<C: BlockCipher>{
     cipher: C,
     nonce_counter: C::Block,
+    counter: Counter,
     buffer: C::Block,
     buffer_len: usize,
 }
@@ -57,6 +220,7 @@ where C: Clone, C::Block: Clone {
         Self {
             cipher: self.cipher.clone(),
             nonce_counter: self.nonce_counter.clone(),
+            counter: self.counter.clone(),
             buffer: self.buffer.clone(),
             buffer_len: self.buffer_len,
         }
@@ -68,64 +232,55 @@ impl<C: BlockCipher> SymcEncryptor for CtrEncryptor<C> {
     type IV = CtrNonce;
     
     fn new(key: &Self::Key, iv: &Self::IV) -> Self {
-        let mut nonce_counter_block: C::Block = Default::default();
-        nonce_counter_block.as_mut()[..12].copy_from_slice(iv.as_ref());
-
+        // the counter checks the nonce fits the block before it is copied in
+        let counter = Counter::new::<C>(iv);
         Self {
             cipher: C::new(key),
-            nonce_counter: nonce_counter_block,
+            nonce_counter: nonce_counter_block::<C>(iv),
+            counter,
             buffer: Default::default(),
             buffer_len: 0
         }
     }
 
-    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, crate::error::SymcError> {
+    /// Returns `SymcError::InvalidInputLength`, before writing anything, if the input would
+    /// run past the counter space.
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
         let block_size = C::BLOCK_SIZE;
         let mut written = 0;
 
-        if output.len() < (self.buffer_len + input.len()) / block_size * block_size {
-            return Err(crate::error::SymcError::BufferTooSmall);
+        let blocks = (self.buffer_len + input.len()) / block_size;
+        if output.len() < blocks * block_size {
+            return Err(SymcError::BufferTooSmall);
         }
+        self.counter.reserve(blocks)?;
 
         let remaining = block_size - self.buffer_len;
         if remaining > input.len() {
-            self.buffer.as_mut()[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(&input);
+            self.buffer.as_mut()[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(input);
             self.buffer_len += input.len();
             return Ok(0);
         }
 
         self.buffer.as_mut()[self.buffer_len..].copy_from_slice(&input[..remaining]);
-        let mut keystream_block = self.nonce_counter.clone();
-        self.cipher.encrypt_block(&mut keystream_block);
+        let keystream_block = next_keystream(&self.cipher, &self.nonce_counter, &mut self.counter);
         output[..block_size].iter_mut()
             .zip(self.buffer.as_ref().iter())
             .zip(keystream_block.as_ref().iter())
             .for_each(|((o, b), k)| *o = *b ^ *k);
 
-        let counter_bytes = &mut self.nonce_counter.as_mut()[12..];
-        let counter = u32::from_be_bytes(counter_bytes.try_into().unwrap());
-
-        counter_bytes.copy_from_slice(&counter.wrapping_add(1).to_be_bytes());
         written += block_size;
         self.buffer_len = 0;
 
         let mut chunks = input[remaining..].chunks_exact(block_size);
         for chunk in &mut chunks {
-            let keystream_block = {
-                let mut temp = self.nonce_counter.clone();
-                self.cipher.encrypt_block(&mut temp);
-                temp
-            };
+            let keystream_block = next_keystream(&self.cipher, &self.nonce_counter, &mut self.counter);
 
             output[written..(written + block_size)].iter_mut()
                 .zip(chunk.iter())
                 .zip(keystream_block.as_ref().iter())
                 .for_each(|((o, i), k)| *o = *i ^ *k);
 
-            let counter_bytes = &mut self.nonce_counter.as_mut()[12..];
-            let counter = u32::from_be_bytes(counter_bytes.try_into().unwrap());
-            counter_bytes.copy_from_slice(&counter.wrapping_add(1).to_be_bytes());
-
             written += block_size;
         }
 
@@ -138,17 +293,17 @@ impl<C: BlockCipher> SymcEncryptor for CtrEncryptor<C> {
         Ok(written)
     }
 
-    fn finalize(self, output: &mut [u8]) -> Result<usize, crate::error::SymcError> {
+    fn finalize(mut self, output: &mut [u8]) -> Result<usize, SymcError> {
         if output.len() < self.buffer_len {
-            return Err(crate::error::SymcError::BufferTooSmall);
+            return Err(SymcError::BufferTooSmall);
         }
 
         if self.buffer_len == 0 {
             return Ok(0);
         }
+        self.counter.reserve(1)?;
 
-        let mut keystream_block = self.nonce_counter;
-        self.cipher.encrypt_block(&mut keystream_block);
+        let keystream_block = next_keystream(&self.cipher, &self.nonce_counter, &mut self.counter);
 
         output.iter_mut()
             .zip(&self.buffer.as_ref()[..self.buffer_len])
@@ -159,8 +314,8 @@ impl<C: BlockCipher> SymcEncryptor for CtrEncryptor<C> {
     }
 
     fn reset(&mut self, iv: &Self::IV) {
-        self.nonce_counter.as_mut()[..12].copy_from_slice(iv.as_ref());
-        self.nonce_counter.as_mut()[12..].fill(0);
+        self.counter = Counter::new::<C>(iv);
+        self.nonce_counter = nonce_counter_block::<C>(iv);
         self.buffer_len = 0;
     }
 }
@@ -170,64 +325,54 @@ impl<C: BlockCipher> SymcDecryptor for CtrDecryptor<C> {
     type IV = CtrNonce;
     
     fn new(key: &Self::Key, iv: &Self::IV) -> Self {
-        let mut nonce_counter_block: C::Block = Default::default();
-        nonce_counter_block.as_mut()[..12].copy_from_slice(iv.as_ref());
-
+        let counter = Counter::new::<C>(iv);
         Self {
             cipher: C::new(key),
-            nonce_counter: nonce_counter_block,
+            nonce_counter: nonce_counter_block::<C>(iv),
+            counter,
             buffer: Default::default(),
             buffer_len: 0
         }
     }
 
-    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, crate::error::SymcError> {
+    /// Returns `SymcError::InvalidInputLength`, before writing anything, if the input would
+    /// run past the counter space.
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
         let block_size = C::BLOCK_SIZE;
         let mut written = 0;
 
-        if output.len() < (self.buffer_len + input.len()) / block_size * block_size {
-            return Err(crate::error::SymcError::BufferTooSmall);
+        let blocks = (self.buffer_len + input.len()) / block_size;
+        if output.len() < blocks * block_size {
+            return Err(SymcError::BufferTooSmall);
         }
+        self.counter.reserve(blocks)?;
 
         let remaining = block_size - self.buffer_len;
         if remaining > input.len() {
-            self.buffer.as_mut()[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(&input);
+            self.buffer.as_mut()[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(input);
             self.buffer_len += input.len();
             return Ok(0);
         }
 
         self.buffer.as_mut()[self.buffer_len..].copy_from_slice(&input[..remaining]);
-        let mut keystream_block = self.nonce_counter.clone();
-        self.cipher.encrypt_block(&mut keystream_block);
+        let keystream_block = next_keystream(&self.cipher, &self.nonce_counter, &mut self.counter);
         output[..block_size].iter_mut()
             .zip(self.buffer.as_ref().iter())
             .zip(keystream_block.as_ref().iter())
             .for_each(|((o, b), k)| *o = *b ^ *k);
 
-        let counter_bytes = &mut self.nonce_counter.as_mut()[12..];
-        let counter = u32::from_be_bytes(counter_bytes.try_into().unwrap());
-
-        counter_bytes.copy_from_slice(&counter.wrapping_add(1).to_be_bytes());
         written += block_size;
         self.buffer_len = 0;
 
         let mut chunks = input[remaining..].chunks_exact(block_size);
         for chunk in &mut chunks {
-            let keystream_block = {
-                let mut temp = self.nonce_counter.clone();
-                self.cipher.encrypt_block(&mut temp);
-                temp
-            };
+            let keystream_block = next_keystream(&self.cipher, &self.nonce_counter, &mut self.counter);
 
             output[written..(written + block_size)].iter_mut()
                 .zip(chunk.iter())
                 .zip(keystream_block.as_ref().iter())
                 .for_each(|((o, i), k)| *o = *i ^ *k);
 
-            let counter_bytes = &mut self.nonce_counter.as_mut()[12..];
-            let counter = u32::from_be_bytes(counter_bytes.try_into().unwrap());
-            counter_bytes.copy_from_slice(&counter.wrapping_add(1).to_be_bytes());
-
             written += block_size;
         }
 
@@ -240,17 +385,17 @@ impl<C: BlockCipher> SymcDecryptor for CtrDecryptor<C> {
         Ok(written)
     }
 
-    fn finalize(self, output: &mut [u8]) -> Result<usize, crate::error::SymcError> {
+    fn finalize(mut self, output: &mut [u8]) -> Result<usize, SymcError> {
         if output.len() < self.buffer_len {
-            return Err(crate::error::SymcError::BufferTooSmall);
+            return Err(SymcError::BufferTooSmall);
         }
 
         if self.buffer_len == 0 {
             return Ok(0);
         }
+        self.counter.reserve(1)?;
 
-        let mut keystream_block = self.nonce_counter;
-        self.cipher.encrypt_block(&mut keystream_block);
+        let keystream_block = next_keystream(&self.cipher, &self.nonce_counter, &mut self.counter);
 
         output.iter_mut()
             .zip(&self.buffer.as_ref()[..self.buffer_len])
@@ -261,12 +406,13 @@ impl<C: BlockCipher> SymcDecryptor for CtrDecryptor<C> {
     }
 
     fn reset(&mut self, iv: &Self::IV) {
-        self.nonce_counter.as_mut()[..12].copy_from_slice(iv.as_ref());
-        self.nonce_counter.as_mut()[12..].fill(0);
+        self.counter = Counter::new::<C>(iv);
+        self.nonce_counter = nonce_counter_block::<C>(iv);
         self.buffer_len = 0;
     }
 }
 
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -383,4 +529,97 @@ mod tests {
             assert_eq!(&output[..(P2.len() - 1)], &P2[..(P2.len() - 1)]);
         }
     }
-}
\ No newline at end of file
+    mod counter_tests {
+        use super::*;
+
+        #[test]
+        fn ctr_counter_block_layout() {
+            let nonce = CtrNonce::new(&[0xaa; 8], 0x0102, CtrEndian::Big).unwrap();
+            let mut counter = Counter::new::<Aes128>(&nonce);
+            let mut block = nonce_counter_block::<Aes128>(&nonce);
+            counter.next::<Aes128>(&mut block);
+            assert_eq!(block, [0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0, 0, 0, 0, 0, 0, 0x01, 0x02]);
+
+            let nonce = CtrNonce::new(&[0xaa; 8], 0x0102, CtrEndian::Little).unwrap();
+            let mut counter = Counter::new::<Aes128>(&nonce);
+            let mut block = nonce_counter_block::<Aes128>(&nonce);
+            counter.next::<Aes128>(&mut block);
+            assert_eq!(block, [0x02, 0x01, 0, 0, 0, 0, 0, 0, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa]);
+        }
+
+        #[test]
+        fn ctr_from_counter_block() {
+            let block: [u8; 16] = core::array::from_fn(|i| i as u8);
+
+            let nonce = CtrNonce::from_counter_block(&block, 4, CtrEndian::Big).unwrap();
+            assert_eq!(nonce.as_ref(), &block[..12]);
+            assert_eq!(nonce.counter, 0x0c0d0e0f);
+
+            let nonce = CtrNonce::from_counter_block(&block, 4, CtrEndian::Little).unwrap();
+            assert_eq!(nonce.as_ref(), &block[4..]);
+            assert_eq!(nonce.counter, 0x03020100);
+
+            assert!(CtrNonce::from_counter_block(&block, 0, CtrEndian::Big).is_err());
+            assert!(CtrNonce::from_counter_block(&block, 17, CtrEndian::Big).is_err());
+        }
+
+        #[test]
+        fn ctr_counter_exhaustion() {
+            let key = Aes128Key::from(KEY);
+            let nonce = CtrNonce::new(&[0u8; 15], 0xfe, CtrEndian::Big).unwrap();
+            let mut output = [0u8; 48];
+
+            // counter values 0xfe and 0xff are left
+            let mut encryptor = CtrEncryptor::<Aes128>::new(&key, &nonce);
+            assert_eq!(encryptor.update(&[0u8; 48], &mut output), Err(SymcError::InvalidInputLength));
+            assert_eq!(encryptor.update(&[0u8; 40], &mut output), Ok(32));
+            assert_eq!(encryptor.clone().finalize(&mut output), Err(SymcError::InvalidInputLength));
+            assert_eq!(encryptor.update(&[0u8; 8], &mut output), Err(SymcError::InvalidInputLength));
+
+            let mut encryptor = CtrEncryptor::<Aes128>::new(&key, &nonce);
+            assert_eq!(encryptor.update(&[0u8; 20], &mut output), Ok(16));
+            assert_eq!(encryptor.finalize(&mut output), Ok(4));
+        }
+
+        #[test]
+        fn ctr_initial_counter_out_of_range() {
+            let key = Aes128Key::from(KEY);
+            let nonce = CtrNonce::new(&[0u8; 15], 0x100, CtrEndian::Big).unwrap();
+            let mut decryptor = CtrDecryptor::<Aes128>::new(&key, &nonce);
+            let mut output = [0u8; 16];
+            assert_eq!(decryptor.update(&[0u8; 16], &mut output), Err(SymcError::InvalidInputLength));
+        }
+
+        #[test]
+        fn ctr_full_block_counter() {
+            let nonce = CtrNonce::new(&[], u128::MAX - 1, CtrEndian::Big).unwrap();
+            let counter = Counter::new::<Aes128>(&nonce);
+            assert_eq!(counter.remaining, 2);
+
+            let nonce = CtrNonce::new(&[], 0, CtrEndian::Little).unwrap();
+            let counter = Counter::new::<Aes128>(&nonce);
+            assert_eq!(counter.remaining, u128::MAX);
+        }
+
+        #[test]
+        fn ctr_default_nonce_leaves_32_bit_counter() {
+            let counter = Counter::new::<Aes128>(&CtrNonce::default());
+            assert_eq!(counter.size, 4);
+            assert_eq!(counter.remaining, 1 << 32);
+        }
+
+        #[test]
+        fn ctr_nonce_into_array() {
+            let nonce = CtrNonce::from([7u8; 12]);
+            assert_eq!(<[u8; 12]>::try_from(nonce), Ok([7u8; 12]));
+            let nonce = CtrNonce::from([7u8; 4]);
+            assert_eq!(<[u8; 12]>::try_from(nonce), Err(SymcError::InvalidLength));
+        }
+
+        #[test]
+        fn ctr_nonce_fills_block() {
+            assert!(CtrNonce::new(&[0u8; 16], 0, CtrEndian::Big).is_err());
+            assert!(CtrNonce::new(&[0u8; 15], 0, CtrEndian::Little).is_ok());
+        }
+    }
+}
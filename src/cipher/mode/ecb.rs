@@ -0,0 +1,267 @@
+use core::marker::PhantomData;
+
+use crate::cipher::BlockCipher;
+use crate::error::SymcError;
+use crate::padding::Padding;
+use super::{SymcDecryptor, SymcEncryptor};
+
+/// ECB has no IV, `new` and `reset` take an empty array.
+pub type EcbIv = [u8; 0];
+
+/// Electronic codebook mode (SP 800-38A, 6.1).
+///
+/// Equal plaintext blocks give equal ciphertext blocks, so this is not a general purpose
+/// encryption mode. It exists for single-block uses such as key check values and QUIC header
+/// protection masks, and for decrypting legacy data.
+pub struct EcbEncryptor<C: BlockCipher, P: Padding> {
+    cipher: C,
+    buffer: C::Block,
+    buffer_len: usize,
+    _phantom: PhantomData<P>
+}
+impl<C: BlockCipher, P: Padding> Clone for EcbEncryptor<C, P>
+where C: Clone, C::Block: Clone {
+    fn clone(&self) -> Self {
+        Self {
+            cipher: self.cipher.clone(),
+            buffer: self.buffer.clone(),
+            buffer_len: self.buffer_len,
+            _phantom: PhantomData
+        }
+    }
+}
+
+/// Electronic codebook mode decryption, the last full block is held back until `finalize`
+/// so the padding can be removed.
+pub struct EcbDecryptor<C: BlockCipher, P: Padding> {
+    cipher: C,
+    buffer: C::Block,
+    buffer_len: usize,
+    _phantom: PhantomData<P>
+}
+impl<C: BlockCipher, P: Padding> Clone for EcbDecryptor<C, P>
+where C: Clone, C::Block: Clone {
+    fn clone(&self) -> Self {
+        Self {
+            cipher: self.cipher.clone(),
+            buffer: self.buffer.clone(),
+            buffer_len: self.buffer_len,
+            _phantom: PhantomData
+        }
+    }
+}
+
+impl<C: BlockCipher, P: Padding> SymcEncryptor for EcbEncryptor<C, P> {
+    type Key = C::Key;
+    type IV = EcbIv;
+
+    fn new(key: &Self::Key, _iv: &Self::IV) -> Self {
+        Self {
+            cipher: C::new(key),
+            buffer: Default::default(),
+            buffer_len: 0,
+            _phantom: PhantomData
+        }
+    }
+
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let block_size = C::BLOCK_SIZE;
+        let mut written = 0;
+
+        if output.len() < (self.buffer_len + input.len()) / block_size * block_size {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        let remaining = block_size - self.buffer_len;
+        if remaining > input.len() {
+            self.buffer.as_mut()[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(input);
+            self.buffer_len += input.len();
+            return Ok(0);
+        }
+
+        self.buffer.as_mut()[self.buffer_len..].copy_from_slice(&input[..remaining]);
+        self.cipher.encrypt_block(&mut self.buffer);
+        output[..block_size].copy_from_slice(self.buffer.as_ref());
+        written += block_size;
+        self.buffer_len = 0;
+
+        let mut chunks = input[remaining..].chunks_exact(block_size);
+        for chunk in &mut chunks {
+            self.buffer.as_mut().copy_from_slice(chunk);
+            self.cipher.encrypt_block(&mut self.buffer);
+            output[written..(written + block_size)].copy_from_slice(self.buffer.as_ref());
+            written += block_size;
+        }
+
+        let remainder = chunks.remainder();
+        self.buffer.as_mut()[..remainder.len()].copy_from_slice(remainder);
+        self.buffer_len = remainder.len();
+
+        Ok(written)
+    }
+
+    fn finalize(self, output: &mut [u8]) -> Result<usize, SymcError> {
+        let mut final_block: C::Block = Default::default();
+        let padded_len = P::pad(&self.buffer.as_ref()[..self.buffer_len], final_block.as_mut(), C::BLOCK_SIZE)?;
+        if padded_len == 0 {
+            return Ok(0);
+        }
+        self.cipher.encrypt_block(&mut final_block);
+
+        if output.len() < padded_len {
+            return Err(SymcError::BufferTooSmall);
+        }
+        output[..padded_len].copy_from_slice(&final_block.as_ref()[..padded_len]);
+
+        Ok(padded_len)
+    }
+
+    fn reset(&mut self, _iv: &Self::IV) {
+        self.buffer_len = 0;
+    }
+}
+
+impl<C: BlockCipher, P: Padding> SymcDecryptor for EcbDecryptor<C, P> {
+    type Key = C::Key;
+    type IV = EcbIv;
+
+    fn new(key: &Self::Key, _iv: &Self::IV) -> Self {
+        Self {
+            cipher: C::new(key),
+            buffer: Default::default(),
+            buffer_len: 0,
+            _phantom: PhantomData
+        }
+    }
+
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let block_size = C::BLOCK_SIZE;
+        let mut written = 0;
+
+        let remaining = block_size - self.buffer_len;
+        if remaining >= input.len() {
+            self.buffer.as_mut()[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(input);
+            self.buffer_len += input.len();
+            return Ok(0);
+        }
+
+        // keep 1..=block_size bytes back so finalize always has the padded block
+        let tail_len = ((input.len() - remaining - 1) % block_size) + 1;
+        let head_len = input.len() - tail_len - remaining;
+
+        if output.len() < head_len + block_size {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        self.buffer.as_mut()[self.buffer_len..].copy_from_slice(&input[..remaining]);
+        self.cipher.decrypt_block(&mut self.buffer);
+        output[..block_size].copy_from_slice(self.buffer.as_ref());
+        written += block_size;
+
+        for chunk in input[remaining..(remaining + head_len)].chunks_exact(block_size) {
+            self.buffer.as_mut().copy_from_slice(chunk);
+            self.cipher.decrypt_block(&mut self.buffer);
+            output[written..(written + block_size)].copy_from_slice(self.buffer.as_ref());
+            written += block_size;
+        }
+
+        self.buffer.as_mut()[..tail_len].copy_from_slice(&input[(remaining + head_len)..]);
+        self.buffer_len = tail_len;
+
+        Ok(written)
+    }
+
+    fn finalize(mut self, output: &mut [u8]) -> Result<usize, SymcError> {
+        let block_size = C::BLOCK_SIZE;
+
+        if self.buffer_len == 0 {
+            return P::unpad(&[], block_size);
+        }
+        if self.buffer_len != block_size {
+            return Err(SymcError::InvalidPadding);
+        }
+
+        self.cipher.decrypt_block(&mut self.buffer);
+
+        let unpadded_len = P::unpad(self.buffer.as_ref(), block_size)?;
+        if output.len() < unpadded_len {
+            return Err(SymcError::BufferTooSmall);
+        }
+        output[..unpadded_len].copy_from_slice(&self.buffer.as_ref()[..unpadded_len]);
+
+        Ok(unpadded_len)
+    }
+
+    fn reset(&mut self, _iv: &Self::IV) {
+        self.buffer_len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cipher::aes::{Aes128, Aes128Key}, padding::{NoPadding, Pkcs7}};
+
+    /// Test vector from NIST SP 800-38A, Appendix F.1.1
+    const KEY: [u8; 16] = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+        0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+    ];
+    const P1: [u8; 16] = [
+        0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96,
+        0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+    ];
+    const C1: [u8; 16] = [
+        0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a, 0x36, 0x60,
+        0xa8, 0x9e, 0xca, 0xf3, 0x24, 0x66, 0xef, 0x97,
+    ];
+
+    #[test]
+    fn ecb_encrypt_split_update() {
+        let key = Aes128Key::from(KEY);
+        let mut encryptor = EcbEncryptor::<Aes128, NoPadding>::new(&key, &[]);
+        let mut output = [0u8; 16];
+
+        assert_eq!(encryptor.update(&P1[..7], &mut output).unwrap(), 0);
+        assert_eq!(encryptor.buffer_len, 7);
+        assert_eq!(encryptor.update(&P1[7..], &mut output).unwrap(), 16);
+        assert_eq!(encryptor.buffer_len, 0);
+        assert_eq!(output, C1);
+        assert_eq!(encryptor.finalize(&mut []).unwrap(), 0);
+    }
+
+    #[test]
+    fn ecb_decrypt_holds_last_block() {
+        let key = Aes128Key::from(KEY);
+        let mut decryptor = EcbDecryptor::<Aes128, NoPadding>::new(&key, &[]);
+        let mut output = [0u8; 16];
+
+        assert_eq!(decryptor.update(&C1, &mut output).unwrap(), 0);
+        assert_eq!(decryptor.buffer_len, 16);
+        assert_eq!(decryptor.finalize(&mut output).unwrap(), 16);
+        assert_eq!(output, P1);
+    }
+
+    #[test]
+    fn ecb_pkcs7_full_padding_block() {
+        let key = Aes128Key::from(KEY);
+        let mut ciphertext = [0u8; 32];
+        let written = EcbEncryptor::<Aes128, Pkcs7>::encrypt(&key, &[], &P1, &mut ciphertext).unwrap();
+        assert_eq!(written, 32);
+        assert_eq!(ciphertext[..16], C1);
+
+        let mut plaintext = [0u8; 32];
+        let written = EcbDecryptor::<Aes128, Pkcs7>::decrypt(&key, &[], &ciphertext, &mut plaintext).unwrap();
+        assert_eq!(&plaintext[..written], &P1);
+    }
+
+    #[test]
+    fn ecb_decrypt_truncated() {
+        let key = Aes128Key::from(KEY);
+        let mut decryptor = EcbDecryptor::<Aes128, Pkcs7>::new(&key, &[]);
+        let mut output = [0u8; 16];
+
+        decryptor.update(&C1[..15], &mut output).unwrap();
+        assert_eq!(decryptor.finalize(&mut output), Err(SymcError::InvalidPadding));
+    }
+}
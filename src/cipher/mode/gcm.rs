@@ -0,0 +1,542 @@
+use zeroize::Zeroize;
+
+use crate::aead::Aead;
+use crate::cipher::BlockCipher;
+use crate::error::SymcError;
+use crate::mac::constant_time_eq;
+
+/// GF(2^128) reduction constant R = 11100001 || 0^120 (SP 800-38D, 6.3)
+const R: u128 = 0xE1 << 120;
+
+/// len(P) <= 2^39 - 256 bits (SP 800-38D, 5.2.1.1)
+const MAX_PAYLOAD_LEN: u64 = (1 << 36) - 32;
+
+/// len(A) <= 2^64 - 1 bits
+const MAX_AAD_LEN: u64 = (1 << 61) - 1;
+
+pub const GCM_BLOCK_SIZE: usize = 16;
+pub const GCM_TAG_SIZE: usize = 16;
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct Ghash {
+    h: u128,
+    y: u128,
+    buffer: [u8; 16],
+    buffer_len: usize
+}
+
+impl Ghash {
+    fn new(h: u128) -> Self {
+        Self {
+            h,
+            y: 0,
+            buffer: [0u8; 16],
+            buffer_len: 0
+        }
+    }
+
+    /// X • Y in GF(2^128), SP 800-38D Algorithm 1 without secret dependent branches.
+    fn gf_mul(x: u128, y: u128) -> u128 {
+        let mut z = 0u128;
+        let mut v = y;
+        for i in (0..128).rev() {
+            // Z ^= V if bit i of X is set
+            z ^= v & ((x >> i) & 1).wrapping_neg();
+            // V = V >> 1, reduce with R if the dropped bit was set
+            v = (v >> 1) ^ (R & (v & 1).wrapping_neg());
+        }
+        z
+    }
+
+    #[inline]
+    fn process_block(&mut self, block: &[u8; 16]) {
+        self.y = Self::gf_mul(self.y ^ u128::from_be_bytes(*block), self.h);
+    }
+
+    fn update(&mut self, input: &[u8]) {
+        let mut input_pos = 0;
+
+        if self.buffer_len > 0 {
+            let remaining = GCM_BLOCK_SIZE - self.buffer_len;
+            if remaining > input.len() {
+                self.buffer[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(input);
+                self.buffer_len += input.len();
+                return;
+            }
+            self.buffer[self.buffer_len..].copy_from_slice(&input[..remaining]);
+            let block = self.buffer;
+            self.process_block(&block);
+            self.buffer_len = 0;
+            input_pos += remaining;
+        }
+
+        let mut chunks = input[input_pos..].chunks_exact(GCM_BLOCK_SIZE);
+        for chunk in &mut chunks {
+            self.process_block(chunk.try_into().unwrap());
+        }
+
+        let remainder = chunks.remainder();
+        self.buffer[..remainder.len()].copy_from_slice(remainder);
+        self.buffer_len = remainder.len();
+    }
+
+    /// Zero pad the pending partial block, as done between A and C.
+    fn pad(&mut self) {
+        if self.buffer_len > 0 {
+            self.buffer[self.buffer_len..].fill(0);
+            let block = self.buffer;
+            self.process_block(&block);
+            self.buffer_len = 0;
+        }
+    }
+
+    /// S = GHASH(A || 0^v || C || 0^u || [len(A)]64 || [len(C)]64)
+    fn finalize(mut self, aad_len: u64, text_len: u64) -> u128 {
+        self.pad();
+        let mut len_block = [0u8; 16];
+        len_block[..8].copy_from_slice(&(aad_len * 8).to_be_bytes());
+        len_block[8..].copy_from_slice(&(text_len * 8).to_be_bytes());
+        self.process_block(&len_block);
+        self.y
+    }
+}
+
+#[inline]
+fn inc32(counter: &mut [u8; 16]) {
+    let value = u32::from_be_bytes(counter[12..].try_into().unwrap());
+    counter[12..].copy_from_slice(&value.wrapping_add(1).to_be_bytes());
+}
+
+#[inline]
+fn is_valid_tag_size(tag_size: usize) -> bool {
+    // t ∈ {128, 120, 112, 104, 96} bits, or 64 and 32 bits for some applications
+    matches!(tag_size, 4 | 8 | 12..=16)
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Gcm<C: BlockCipher> {
+    cipher: C,
+    h: u128,
+    tag_size: usize
+}
+
+impl<C: BlockCipher> Gcm<C> {
+    /// Creates a GCM instance producing `tag_size` byte (truncated) tags.
+    pub fn with_tag_size(key: &C::Key, tag_size: usize) -> Result<Self, SymcError> {
+        if !is_valid_tag_size(tag_size) {
+            return Err(SymcError::InvalidLength);
+        }
+
+        let mut gcm = <Self as Aead>::new(key);
+        gcm.tag_size = tag_size;
+        Ok(gcm)
+    }
+
+    /// Starts a streaming encryption with the given IV.
+    pub fn encryptor(&self, iv: &[u8]) -> Result<GcmEncryptor<C>, SymcError> {
+        let j0 = self.j0(iv)?;
+        let mut counter = j0;
+        inc32(&mut counter);
+
+        Ok(GcmEncryptor {
+            gcm: self.clone(),
+            j0,
+            counter,
+            ghash: Ghash::new(self.h),
+            aad_len: 0,
+            text_len: 0,
+            buffer: [0u8; 16],
+            buffer_len: 0
+        })
+    }
+
+    #[inline]
+    fn encrypt_block(&self, block: &[u8; 16]) -> [u8; 16] {
+        let mut cipher_block = C::Block::default();
+        cipher_block.as_mut().copy_from_slice(block);
+        self.cipher.encrypt_block(&mut cipher_block);
+
+        let mut output = [0u8; 16];
+        output.copy_from_slice(cipher_block.as_ref());
+        cipher_block.zeroize();
+        output
+    }
+
+    /// Pre-counter block J0 (SP 800-38D, 7.1 step 2)
+    fn j0(&self, iv: &[u8]) -> Result<[u8; 16], SymcError> {
+        if iv.is_empty() || iv.len() as u64 > u64::MAX / 8 {
+            return Err(SymcError::InvalidLength);
+        }
+
+        let mut j0 = [0u8; 16];
+        if iv.len() == 12 {
+            // J0 = IV || 0^31 || 1
+            j0[..12].copy_from_slice(iv);
+            j0[15] = 1;
+        } else {
+            // J0 = GHASH(IV || 0^(s+64) || [len(IV)]64)
+            let mut ghash = Ghash::new(self.h);
+            ghash.update(iv);
+            j0 = ghash.finalize(0, iv.len() as u64).to_be_bytes();
+        }
+        Ok(j0)
+    }
+
+    /// T = MSB_t(GCTR(J0, S))
+    fn compute_tag(&self, j0: &[u8; 16], s: u128, tag: &mut [u8]) {
+        let mut full_tag = self.encrypt_block(j0);
+        full_tag.iter_mut()
+            .zip(s.to_be_bytes().iter())
+            .for_each(|(t, s)| *t ^= *s);
+        tag.copy_from_slice(&full_tag[..tag.len()]);
+        full_tag.zeroize();
+    }
+
+    fn gctr(&self, counter: &mut [u8; 16], input: &[u8], output: &mut [u8]) {
+        for (in_chunk, out_chunk) in input.chunks(GCM_BLOCK_SIZE).zip(output.chunks_mut(GCM_BLOCK_SIZE)) {
+            let keystream_block = self.encrypt_block(counter);
+            out_chunk.iter_mut()
+                .zip(in_chunk.iter())
+                .zip(keystream_block.iter())
+                .for_each(|((o, i), k)| *o = *i ^ *k);
+            inc32(counter);
+        }
+    }
+}
+
+impl<C: BlockCipher> Aead for Gcm<C> {
+    const KEY_SIZE: usize = C::KEY_SIZE;
+    const TAG_SIZE: usize = GCM_TAG_SIZE;
+    type Key = C::Key;
+
+    fn new(key: &Self::Key) -> Self {
+        assert_eq!(C::BLOCK_SIZE, GCM_BLOCK_SIZE, "GCM requires a 128-bit block cipher");
+
+        let cipher = C::new(key);
+        let mut gcm = Self {
+            cipher,
+            h: 0,
+            tag_size: GCM_TAG_SIZE
+        };
+        // H = CIPH_K(0^128)
+        gcm.h = u128::from_be_bytes(gcm.encrypt_block(&[0u8; 16]));
+        gcm
+    }
+
+    fn tag_size(&self) -> usize {
+        self.tag_size
+    }
+
+    fn encrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        if tag.len() != self.tag_size {
+            return Err(SymcError::InvalidLength);
+        }
+        if output.len() < input.len() {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        let mut encryptor = self.encryptor(nonce)?;
+        encryptor.update_aad(aad)?;
+        let mut written = encryptor.update(input, output)?;
+        written += encryptor.finalize(&mut output[written..], tag)?;
+        Ok(written)
+    }
+
+    fn decrypt_detached(&self, nonce: &[u8], aad: &[u8], input: &[u8], tag: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        // the tag length is fixed by the instance, a shorter tag would weaken the forgery bound
+        if tag.len() != self.tag_size {
+            return Err(SymcError::InvalidLength);
+        }
+        if input.len() as u64 > MAX_PAYLOAD_LEN || aad.len() as u64 > MAX_AAD_LEN {
+            return Err(SymcError::InvalidInputLength);
+        }
+        if output.len() < input.len() {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        // authenticate the ciphertext before any plaintext is produced
+        let j0 = self.j0(nonce)?;
+        let mut ghash = Ghash::new(self.h);
+        ghash.update(aad);
+        ghash.pad();
+        ghash.update(input);
+        let s = ghash.finalize(aad.len() as u64, input.len() as u64);
+
+        let mut expected_tag = [0u8; 16];
+        self.compute_tag(&j0, s, &mut expected_tag[..tag.len()]);
+        let verified = constant_time_eq(&expected_tag[..tag.len()], tag);
+        expected_tag.zeroize();
+        if !verified {
+            return Err(SymcError::AuthenticationFailed);
+        }
+
+        let mut counter = j0;
+        inc32(&mut counter);
+        self.gctr(&mut counter, input, &mut output[..input.len()]);
+        counter.zeroize();
+
+        Ok(input.len())
+    }
+}
+
+/// Streaming GCM encryption, AAD must be supplied before the payload.
+///
+/// There is no streaming counterpart for decryption, `Gcm` checks the tag over the whole
+/// ciphertext before it decrypts anything.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct GcmEncryptor<C: BlockCipher> {
+    gcm: Gcm<C>,
+    j0: [u8; 16],
+    counter: [u8; 16],
+    ghash: Ghash,
+    aad_len: u64,
+    text_len: u64,
+    buffer: [u8; 16],
+    buffer_len: usize
+}
+
+impl<C: BlockCipher> GcmEncryptor<C> {
+    pub fn new(key: &C::Key, iv: &[u8]) -> Result<Self, SymcError> {
+        <Gcm<C> as Aead>::new(key).encryptor(iv)
+    }
+
+    pub fn update_aad(&mut self, aad: &[u8]) -> Result<(), SymcError> {
+        if self.text_len > 0 || self.buffer_len > 0 {
+            return Err(SymcError::InvalidInputLength);
+        }
+
+        self.aad_len = self.aad_len.checked_add(aad.len() as u64)
+            .filter(|len| *len <= MAX_AAD_LEN)
+            .ok_or(SymcError::InvalidInputLength)?;
+        self.ghash.update(aad);
+        Ok(())
+    }
+
+    pub fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        let block_size = GCM_BLOCK_SIZE;
+        let mut written = 0;
+
+        if output.len() < (self.buffer_len + input.len()) / block_size * block_size {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        let total_len = self.text_len + (self.buffer_len + input.len()) as u64;
+        if total_len > MAX_PAYLOAD_LEN {
+            return Err(SymcError::InvalidInputLength);
+        }
+
+        if self.text_len == 0 && self.buffer_len == 0 {
+            // A || 0^v
+            self.ghash.pad();
+        }
+
+        let remaining = block_size - self.buffer_len;
+        if remaining > input.len() {
+            self.buffer[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(input);
+            self.buffer_len += input.len();
+            return Ok(0);
+        }
+
+        self.buffer[self.buffer_len..].copy_from_slice(&input[..remaining]);
+        let block = self.buffer;
+        self.encrypt_full_block(&block, &mut output[..block_size]);
+        written += block_size;
+        self.buffer_len = 0;
+
+        let mut chunks = input[remaining..].chunks_exact(block_size);
+        for chunk in &mut chunks {
+            self.encrypt_full_block(chunk.try_into().unwrap(), &mut output[written..(written + block_size)]);
+            written += block_size;
+        }
+
+        let remainder = chunks.remainder();
+        if !remainder.is_empty() {
+            self.buffer[..remainder.len()].copy_from_slice(remainder);
+            self.buffer_len = remainder.len();
+        }
+
+        Ok(written)
+    }
+
+    /// Writes the remaining ciphertext into `output` and the tag into `tag`, which must be
+    /// exactly the tag size of the `Gcm` instance this encryptor came from.
+    pub fn finalize(self, output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        if tag.len() != self.gcm.tag_size {
+            return Err(SymcError::InvalidLength);
+        }
+        self.finish(output, tag)
+    }
+
+    /// `finalize` with the tag length left to the caller, as GMAC does.
+    fn finish(mut self, output: &mut [u8], tag: &mut [u8]) -> Result<usize, SymcError> {
+        if !is_valid_tag_size(tag.len()) {
+            return Err(SymcError::InvalidLength);
+        }
+        if output.len() < self.buffer_len {
+            return Err(SymcError::BufferTooSmall);
+        }
+
+        if self.text_len == 0 && self.buffer_len == 0 {
+            self.ghash.pad();
+        }
+
+        let buffer_len = self.buffer_len;
+        if buffer_len > 0 {
+            let keystream_block = self.gcm.encrypt_block(&self.counter);
+            output[..buffer_len].iter_mut()
+                .zip(self.buffer[..buffer_len].iter())
+                .zip(keystream_block.iter())
+                .for_each(|((o, p), k)| *o = *p ^ *k);
+            self.ghash.update(&output[..buffer_len]);
+            self.text_len += buffer_len as u64;
+        }
+
+        let s = self.ghash.clone().finalize(self.aad_len, self.text_len);
+        self.gcm.compute_tag(&self.j0, s, tag);
+
+        Ok(buffer_len)
+    }
+
+    #[inline]
+    fn encrypt_full_block(&mut self, block: &[u8; 16], output: &mut [u8]) {
+        let keystream_block = self.gcm.encrypt_block(&self.counter);
+        output.iter_mut()
+            .zip(block.iter())
+            .zip(keystream_block.iter())
+            .for_each(|((o, p), k)| *o = *p ^ *k);
+        inc32(&mut self.counter);
+        self.ghash.update(output);
+        self.text_len += GCM_BLOCK_SIZE as u64;
+    }
+}
+
+/// GMAC, GCM restricted to authenticating data with an empty payload.
+///
+/// The wrapped encryptor zeroizes itself on drop.
+#[derive(Clone, Zeroize)]
+pub struct Gmac<C: BlockCipher> {
+    encryptor: GcmEncryptor<C>
+}
+
+impl<C: BlockCipher> Gmac<C> {
+    pub fn new(key: &C::Key, iv: &[u8]) -> Result<Self, SymcError> {
+        Ok(Self { encryptor: GcmEncryptor::new(key, iv)? })
+    }
+
+    pub fn update(&mut self, input: &[u8]) -> Result<(), SymcError> {
+        self.encryptor.update_aad(input)
+    }
+
+    pub fn finalize(self, tag: &mut [u8]) -> Result<(), SymcError> {
+        self.encryptor.finish(&mut [], tag)?;
+        Ok(())
+    }
+
+    pub fn verify(self, expected_tag: &[u8]) -> Result<(), SymcError> {
+        if !is_valid_tag_size(expected_tag.len()) {
+            return Err(SymcError::InvalidLength);
+        }
+
+        let mut tag = [0u8; 16];
+        self.finalize(&mut tag[..expected_tag.len()])?;
+        let verified = constant_time_eq(&tag[..expected_tag.len()], expected_tag);
+        tag.zeroize();
+
+        if verified {
+            Ok(())
+        } else {
+            Err(SymcError::AuthenticationFailed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::{Aes128, Aes128Key};
+
+    /// Test Case 2 from "The Galois/Counter Mode of Operation (GCM)", McGrew and Viega
+    const H: [u8; 16] = [
+        0x66, 0xe9, 0x4b, 0xd4, 0xef, 0x8a, 0x2c, 0x3b,
+        0x88, 0x4c, 0xfa, 0x59, 0xca, 0x34, 0x2b, 0x2e,
+    ];
+    const C: [u8; 16] = [
+        0x03, 0x88, 0xda, 0xce, 0x60, 0xb6, 0xa3, 0x92,
+        0xf3, 0x28, 0xc2, 0xb9, 0x71, 0xb2, 0xfe, 0x78,
+    ];
+    const GHASH_C: [u8; 16] = [
+        0xf3, 0x8c, 0xbb, 0x1a, 0xd6, 0x92, 0x23, 0xdc,
+        0xc3, 0x45, 0x7a, 0xe5, 0xb6, 0xb0, 0xf8, 0x85,
+    ];
+
+    #[test]
+    fn gcm_hash_subkey() {
+        let gcm = <Gcm<Aes128> as Aead>::new(&Aes128Key::from([0u8; 16]));
+        assert_eq!(gcm.h.to_be_bytes(), H);
+    }
+
+    #[test]
+    fn gcm_ghash_test_case_2() {
+        let mut ghash = Ghash::new(u128::from_be_bytes(H));
+        ghash.update(&C);
+        assert_eq!(ghash.finalize(0, C.len() as u64).to_be_bytes(), GHASH_C);
+    }
+
+    #[test]
+    fn gcm_ghash_incremental_update() {
+        let mut ghash = Ghash::new(u128::from_be_bytes(H));
+        ghash.update(&C[..5]);
+        assert_eq!(ghash.buffer_len, 5);
+        ghash.update(&C[5..]);
+        assert_eq!(ghash.buffer_len, 0);
+        assert_eq!(ghash.finalize(0, C.len() as u64).to_be_bytes(), GHASH_C);
+    }
+
+    #[test]
+    fn gcm_gf_mul_identity() {
+        // the multiplicative identity is the bit string 1 || 0^127
+        let one = 1u128 << 127;
+        let h = u128::from_be_bytes(H);
+        assert_eq!(Ghash::gf_mul(one, h), h);
+        assert_eq!(Ghash::gf_mul(h, one), h);
+        assert_eq!(Ghash::gf_mul(0, h), 0);
+    }
+
+    #[test]
+    fn gcm_inc32_wraps_low_word_only() {
+        let mut counter = [0xffu8; 16];
+        inc32(&mut counter);
+        assert_eq!(&counter[..12], &[0xffu8; 12]);
+        assert_eq!(&counter[12..], &[0u8; 4]);
+    }
+
+    #[test]
+    fn gcm_j0_96_bit_iv() {
+        let gcm = <Gcm<Aes128> as Aead>::new(&Aes128Key::from([0u8; 16]));
+        let iv = [0xcau8; 12];
+        let j0 = gcm.j0(&iv).unwrap();
+        assert_eq!(&j0[..12], &iv);
+        assert_eq!(&j0[12..], &[0, 0, 0, 1]);
+        assert_eq!(gcm.j0(&[]), Err(SymcError::InvalidLength));
+    }
+
+    #[test]
+    fn gcm_invalid_tag_sizes() {
+        let key = Aes128Key::from([0u8; 16]);
+        assert!(Gcm::<Aes128>::with_tag_size(&key, 12).is_ok());
+        assert_eq!(Gcm::<Aes128>::with_tag_size(&key, 11).err(), Some(SymcError::InvalidLength));
+        assert_eq!(Gcm::<Aes128>::with_tag_size(&key, 17).err(), Some(SymcError::InvalidLength));
+    }
+
+    #[test]
+    fn gcm_aad_after_payload_rejected() {
+        let mut encryptor = GcmEncryptor::<Aes128>::new(&Aes128Key::from([0u8; 16]), &[0u8; 12]).unwrap();
+        let mut output = [0u8; 16];
+        encryptor.update_aad(b"header").unwrap();
+        encryptor.update(b"payload", &mut output).unwrap();
+        assert_eq!(encryptor.update_aad(b"late"), Err(SymcError::InvalidInputLength));
+    }
+}
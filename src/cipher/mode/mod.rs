@@ -2,7 +2,13 @@ use crate::error::SymcError;
 use zeroize::Zeroize;
 
 pub mod cbc;
+pub mod ccm;
+pub mod cfb;
 pub mod ctr;
+#[cfg(feature = "hazmat")]
+pub mod ecb;
+pub mod gcm;
+pub mod ofb;
 
 pub trait SymcEncryptor: Sized + Clone {
     type Key: AsRef<[u8]> + Default + Clone + Zeroize;
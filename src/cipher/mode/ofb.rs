@@ -0,0 +1,207 @@
+use crate::cipher::BlockCipher;
+use crate::error::SymcError;
+use super::{SymcDecryptor, SymcEncryptor};
+
+/// Output feedback mode (SP 800-38A, 6.4). The keystream is the IV encrypted over and over,
+/// so an IV must never be reused under the same key.
+pub struct OfbEncryptor<C: BlockCipher> {
+    cipher: C,
+    register: C::Block,
+    buffer: C::Block,
+    buffer_len: usize,
+}
+impl<C: BlockCipher> Clone for OfbEncryptor<C>
+where C: Clone, C::Block: Clone {
+    fn clone(&self) -> Self {
+        Self {
+            cipher: self.cipher.clone(),
+            register: self.register.clone(),
+            buffer: self.buffer.clone(),
+            buffer_len: self.buffer_len,
+        }
+    }
+}
+
+pub struct OfbDecryptor<C: BlockCipher> {
+    cipher: C,
+    register: C::Block,
+    buffer: C::Block,
+    buffer_len: usize,
+}
+impl<C: BlockCipher> Clone for OfbDecryptor<C>
+where C: Clone, C::Block: Clone {
+    fn clone(&self) -> Self {
+        Self {
+            cipher: self.cipher.clone(),
+            register: self.register.clone(),
+            buffer: self.buffer.clone(),
+            buffer_len: self.buffer_len,
+        }
+    }
+}
+
+/// XORs whole blocks of keystream into `output`, a trailing partial block is buffered for
+/// `finalize`. Encryption and decryption are the same operation.
+fn process_blocks<C: BlockCipher>(
+    cipher: &C,
+    register: &mut C::Block,
+    buffer: &mut C::Block,
+    buffer_len: &mut usize,
+    input: &[u8],
+    output: &mut [u8]
+) -> Result<usize, SymcError> {
+    let block_size = C::BLOCK_SIZE;
+    let mut written = 0;
+
+    if output.len() < (*buffer_len + input.len()) / block_size * block_size {
+        return Err(SymcError::BufferTooSmall);
+    }
+
+    let remaining = block_size - *buffer_len;
+    if remaining > input.len() {
+        buffer.as_mut()[*buffer_len..(*buffer_len + input.len())].copy_from_slice(input);
+        *buffer_len += input.len();
+        return Ok(0);
+    }
+
+    buffer.as_mut()[*buffer_len..].copy_from_slice(&input[..remaining]);
+    cipher.encrypt_block(register);
+    output[..block_size].iter_mut()
+        .zip(buffer.as_ref().iter())
+        .zip(register.as_ref().iter())
+        .for_each(|((o, b), k)| *o = *b ^ *k);
+    written += block_size;
+    *buffer_len = 0;
+
+    let mut chunks = input[remaining..].chunks_exact(block_size);
+    for chunk in &mut chunks {
+        cipher.encrypt_block(register);
+        output[written..(written + block_size)].iter_mut()
+            .zip(chunk.iter())
+            .zip(register.as_ref().iter())
+            .for_each(|((o, i), k)| *o = *i ^ *k);
+        written += block_size;
+    }
+
+    let remainder = chunks.remainder();
+    buffer.as_mut()[..remainder.len()].copy_from_slice(remainder);
+    *buffer_len = remainder.len();
+
+    Ok(written)
+}
+
+fn finish_blocks<C: BlockCipher>(cipher: &C, mut register: C::Block, buffer: &C::Block, buffer_len: usize, output: &mut [u8]) -> Result<usize, SymcError> {
+    if output.len() < buffer_len {
+        return Err(SymcError::BufferTooSmall);
+    }
+
+    if buffer_len == 0 {
+        return Ok(0);
+    }
+
+    cipher.encrypt_block(&mut register);
+    output.iter_mut()
+        .zip(&buffer.as_ref()[..buffer_len])
+        .zip(&register.as_ref()[..buffer_len])
+        .for_each(|((o, b), k)| *o = *b ^ *k);
+
+    Ok(buffer_len)
+}
+
+impl<C: BlockCipher> SymcEncryptor for OfbEncryptor<C> {
+    type Key = C::Key;
+    type IV = C::Block;
+
+    fn new(key: &Self::Key, iv: &Self::IV) -> Self {
+        Self {
+            cipher: C::new(key),
+            register: iv.clone(),
+            buffer: Default::default(),
+            buffer_len: 0
+        }
+    }
+
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        process_blocks(&self.cipher, &mut self.register, &mut self.buffer, &mut self.buffer_len, input, output)
+    }
+
+    fn finalize(self, output: &mut [u8]) -> Result<usize, SymcError> {
+        finish_blocks(&self.cipher, self.register.clone(), &self.buffer, self.buffer_len, output)
+    }
+
+    fn reset(&mut self, iv: &Self::IV) {
+        self.register = iv.clone();
+        self.buffer_len = 0;
+    }
+}
+
+impl<C: BlockCipher> SymcDecryptor for OfbDecryptor<C> {
+    type Key = C::Key;
+    type IV = C::Block;
+
+    fn new(key: &Self::Key, iv: &Self::IV) -> Self {
+        Self {
+            cipher: C::new(key),
+            register: iv.clone(),
+            buffer: Default::default(),
+            buffer_len: 0
+        }
+    }
+
+    fn update(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        process_blocks(&self.cipher, &mut self.register, &mut self.buffer, &mut self.buffer_len, input, output)
+    }
+
+    fn finalize(self, output: &mut [u8]) -> Result<usize, SymcError> {
+        finish_blocks(&self.cipher, self.register.clone(), &self.buffer, self.buffer_len, output)
+    }
+
+    fn reset(&mut self, iv: &Self::IV) {
+        self.register = iv.clone();
+        self.buffer_len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cipher::aes::{Aes128, Aes128Key};
+
+    /// Test vector from NIST SP 800-38A, Appendix F.4.1
+    const KEY: [u8; 16] = [
+        0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6,
+        0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+    ];
+    const IV: [u8; 16] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    ];
+    /// First output block O1 = CIPH_K(IV)
+    const O1: [u8; 16] = [
+        0x50, 0xfe, 0x67, 0xcc, 0x99, 0x6d, 0x32, 0xb6,
+        0xda, 0x09, 0x37, 0xe9, 0x9b, 0xaf, 0xec, 0x60,
+    ];
+
+    #[test]
+    fn ofb_register_holds_output_block() {
+        let key = Aes128Key::from(KEY);
+        let mut encryptor = OfbEncryptor::<Aes128>::new(&key, &IV);
+        let mut output = [0u8; 16];
+
+        assert_eq!(encryptor.update(&[0u8; 16], &mut output).unwrap(), 16);
+        assert_eq!(output, O1);
+        assert_eq!(encryptor.register, O1);
+    }
+
+    #[test]
+    fn ofb_finalize_partial_block() {
+        let key = Aes128Key::from(KEY);
+        let mut encryptor = OfbEncryptor::<Aes128>::new(&key, &IV);
+        let mut output = [0u8; 5];
+
+        assert_eq!(encryptor.update(&[0u8; 5], &mut output).unwrap(), 0);
+        assert_eq!(encryptor.buffer_len, 5);
+        assert_eq!(encryptor.finalize(&mut output).unwrap(), 5);
+        assert_eq!(output, O1[..5]);
+    }
+}
@@ -0,0 +1,188 @@
+use zeroize::Zeroize;
+use super::BlockCipher;
+
+const SBOX: [u8; 16] = [0xc, 0x5, 0x6, 0xb, 0x9, 0x0, 0xa, 0xd, 0x3, 0xe, 0xf, 0x8, 0x4, 0x7, 0x1, 0x2];
+const SBOX_INV: [u8; 16] = [0x5, 0xe, 0xf, 0x8, 0xc, 0x1, 0x2, 0xd, 0xb, 0x4, 0x6, 0x3, 0x0, 0x7, 0x9, 0xa];
+
+#[inline]
+fn s_layer(state: u64, sbox: &[u8; 16]) -> u64 {
+    let mut out = 0;
+    for i in 0..16 {
+        out |= (sbox[((state >> (4 * i)) & 0xf) as usize] as u64) << (4 * i);
+    }
+    out
+}
+
+/// Bit `i` moves to bit `16 * i mod 63`, bit 63 stays put.
+#[inline]
+fn p_layer(state: u64) -> u64 {
+    let mut out = 0;
+    for i in 0..64 {
+        let target = if i == 63 { 63 } else { (16 * i) % 63 };
+        out |= ((state >> i) & 1) << target;
+    }
+    out
+}
+
+#[inline]
+fn p_layer_inv(state: u64) -> u64 {
+    let mut out = 0;
+    for i in 0..64 {
+        let source = if i == 63 { 63 } else { (16 * i) % 63 };
+        out |= ((state >> source) & 1) << i;
+    }
+    out
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct PresentCore {
+    rk: [u64; 32]
+}
+
+impl PresentCore {
+    fn encrypt_block(&self, block: &mut [u8; 8]) {
+        let mut state = u64::from_be_bytes(*block);
+        for &k in &self.rk[..31] {
+            state = p_layer(s_layer(state ^ k, &SBOX));
+        }
+        *block = (state ^ self.rk[31]).to_be_bytes();
+    }
+
+    fn decrypt_block(&self, block: &mut [u8; 8]) {
+        let mut state = u64::from_be_bytes(*block) ^ self.rk[31];
+        for &k in self.rk[..31].iter().rev() {
+            state = s_layer(p_layer_inv(state), &SBOX_INV) ^ k;
+        }
+        *block = state.to_be_bytes();
+    }
+}
+
+macro_rules! present_impl {
+    ($name:ident, $key:ident, $size:literal, $schedule:ident, $doc:literal) => {
+        #[derive(Clone, Default, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $key([u8; $size]);
+        impl AsRef<[u8]> for $key {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $key {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $size]> for $key {
+            fn from(array: [u8; $size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$key> for [u8; $size] {
+            fn from(output: $key) -> [u8; $size] {
+                output.0
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Zeroize, Clone)]
+        #[zeroize(drop)]
+        pub struct $name {
+            core: PresentCore
+        }
+
+        impl BlockCipher for $name {
+            const BLOCK_SIZE: usize = 8;
+            const KEY_SIZE: usize = $size;
+            type Block = [u8; 8];
+            type Key = $key;
+
+            fn new(key: &Self::Key) -> Self {
+                Self { core: PresentCore { rk: $schedule(&key.0) } }
+            }
+
+            fn encrypt_block(&self, block: &mut Self::Block) {
+                self.core.encrypt_block(block);
+            }
+
+            fn decrypt_block(&self, block: &mut Self::Block) {
+                self.core.decrypt_block(block);
+            }
+        }
+    };
+}
+
+/// The 80-bit key register sits in the low bits of a `u128`, the round key is its top 64 bits.
+fn schedule80(key: &[u8; 10]) -> [u64; 32] {
+    const MASK: u128 = (1 << 80) - 1;
+    let mut bytes = [0u8; 16];
+    bytes[6..].copy_from_slice(key);
+    let mut k = u128::from_be_bytes(bytes);
+
+    let mut rk = [0u64; 32];
+    for (counter, round_key) in rk.iter_mut().enumerate() {
+        *round_key = (k >> 16) as u64;
+        k = ((k << 61) | (k >> 19)) & MASK;
+        k = (k & !(0xf << 76)) | ((SBOX[(k >> 76) as usize] as u128) << 76);
+        k ^= ((counter + 1) as u128) << 15;
+    }
+
+    bytes.zeroize();
+    k.zeroize();
+    rk
+}
+
+fn schedule128(key: &[u8; 16]) -> [u64; 32] {
+    let mut k = u128::from_be_bytes(*key);
+
+    let mut rk = [0u64; 32];
+    for (counter, round_key) in rk.iter_mut().enumerate() {
+        *round_key = (k >> 64) as u64;
+        k = k.rotate_left(61);
+        let top = ((SBOX[(k >> 124) as usize] << 4) | SBOX[((k >> 120) & 0xf) as usize]) as u128;
+        k = (k & !(0xff << 120)) | (top << 120);
+        k ^= ((counter + 1) as u128) << 62;
+    }
+
+    k.zeroize();
+    rk
+}
+
+present_impl!(Present80, Present80Key, 10, schedule80, "PRESENT with an 80-bit key.");
+present_impl!(Present128, Present128Key, 16, schedule128, "PRESENT with a 128-bit key.");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check<C: BlockCipher<Block = [u8; 8]>>(key: C::Key, plaintext: u64, ciphertext: u64) {
+        let cipher = C::new(&key);
+        let mut block = plaintext.to_be_bytes();
+        cipher.encrypt_block(&mut block);
+        assert_eq!(u64::from_be_bytes(block), ciphertext);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(u64::from_be_bytes(block), plaintext);
+    }
+
+    #[test]
+    fn sbox_inverse() {
+        for x in 0..16 {
+            assert_eq!(SBOX_INV[SBOX[x] as usize] as usize, x);
+        }
+    }
+
+    /// PRESENT: An Ultra-Lightweight Block Cipher, Appendix I
+    #[test]
+    fn present80() {
+        check::<Present80>([0x00; 10].into(), 0x0000000000000000, 0x5579c1387b228445);
+        check::<Present80>([0xff; 10].into(), 0x0000000000000000, 0xe72c46c0f5945049);
+        check::<Present80>([0x00; 10].into(), 0xffffffffffffffff, 0xa112ffc72f68417b);
+        check::<Present80>([0xff; 10].into(), 0xffffffffffffffff, 0x3333dcd3213210d2);
+    }
+
+    #[test]
+    fn present128() {
+        check::<Present128>([0x00; 16].into(), 0x0000000000000000, 0x96db702a2e6900af);
+        check::<Present128>([0xff; 16].into(), 0xffffffffffffffff, 0x628d9fbd4218e5b4);
+    }
+}
@@ -0,0 +1,312 @@
+use zeroize::Zeroize;
+
+use crate::error::SymcError;
+use super::StreamCipher;
+
+/// "expand 32-byte k"
+const SIGMA: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+
+const SALSA20_BLOCK_SIZE: usize = 64;
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Salsa20Key([u8; 32]);
+impl AsRef<[u8]> for Salsa20Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Salsa20Key {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 32]> for Salsa20Key {
+    fn from(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+}
+impl From<Salsa20Key> for [u8; 32] {
+    fn from(output: Salsa20Key) -> [u8; 32] {
+        output.0
+    }
+}
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Salsa20Nonce([u8; 8]);
+impl AsRef<[u8]> for Salsa20Nonce {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Salsa20Nonce {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 8]> for Salsa20Nonce {
+    fn from(array: [u8; 8]) -> Self {
+        Self(array)
+    }
+}
+impl From<Salsa20Nonce> for [u8; 8] {
+    fn from(output: Salsa20Nonce) -> [u8; 8] {
+        output.0
+    }
+}
+
+#[inline(always)]
+fn quarter_round(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize) {
+    state[b] ^= state[a].wrapping_add(state[d]).rotate_left(7);
+    state[c] ^= state[b].wrapping_add(state[a]).rotate_left(9);
+    state[d] ^= state[c].wrapping_add(state[b]).rotate_left(13);
+    state[a] ^= state[d].wrapping_add(state[c]).rotate_left(18);
+}
+
+/// `rounds` rounds, as `rounds / 2` iterations of a column round followed by a row round
+#[inline]
+fn salsa_rounds(state: &mut [u32; 16], rounds: usize) {
+    for _ in 0..rounds / 2 {
+        // column rounds
+        quarter_round(state, 0, 4, 8, 12);
+        quarter_round(state, 5, 9, 13, 1);
+        quarter_round(state, 10, 14, 2, 6);
+        quarter_round(state, 15, 3, 7, 11);
+        // row rounds
+        quarter_round(state, 0, 1, 2, 3);
+        quarter_round(state, 5, 6, 7, 4);
+        quarter_round(state, 10, 11, 8, 9);
+        quarter_round(state, 15, 12, 13, 14);
+    }
+}
+
+/// Salsa20 core, the rounds with the input added back in
+fn salsa_block(input: &[u32; 16], rounds: usize, output: &mut [u8; SALSA20_BLOCK_SIZE]) {
+    let mut working_state = *input;
+    salsa_rounds(&mut working_state, rounds);
+
+    for (i, word) in working_state.iter().enumerate() {
+        let bytes = word.wrapping_add(input[i]).to_le_bytes();
+        output[i * 4..(i + 1) * 4].copy_from_slice(&bytes);
+    }
+    working_state.zeroize();
+}
+
+/// Key and constants in their diagonal positions, words 6 to 9 are left for the nonce and counter.
+fn initial_state(key: &Salsa20Key) -> [u32; 16] {
+    let mut state = [0u32; 16];
+    state[0] = SIGMA[0];
+    state[5] = SIGMA[1];
+    state[10] = SIGMA[2];
+    state[15] = SIGMA[3];
+    for (i, chunk) in key.0.chunks_exact(4).enumerate() {
+        let word = u32::from_le_bytes(chunk.try_into().unwrap());
+        state[if i < 4 { 1 + i } else { 7 + i }] = word;
+    }
+    state
+}
+
+/// HSalsa20 (Extending the Salsa20 nonce, 2), derives a subkey from a key and a 128-bit nonce.
+#[cfg(feature = "xsalsa20")]
+pub fn hsalsa20(key: &Salsa20Key, nonce: &[u8; 16]) -> Salsa20Key {
+    let mut state = initial_state(key);
+    for (word, chunk) in state[6..10].iter_mut().zip(nonce.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+
+    salsa_rounds(&mut state, 20);
+
+    // the diagonal and the nonce words, without the feed-forward addition
+    let mut subkey = Salsa20Key::default();
+    for (chunk, &index) in subkey.0.chunks_exact_mut(4).zip([0, 5, 10, 15, 6, 7, 8, 9].iter()) {
+        chunk.copy_from_slice(&state[index].to_le_bytes());
+    }
+    state.zeroize();
+    subkey
+}
+
+macro_rules! salsa_impl {
+    ($name:ident, $rounds:literal, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $name {
+            state: [u32; 16],
+            // the next block counter, a u128 so running past 2^64 blocks can be detected
+            counter: u128,
+            keystream: [u8; SALSA20_BLOCK_SIZE],
+            keystream_pos: usize
+        }
+
+        impl $name {
+            /// Starts the keystream at block `counter`.
+            pub fn with_counter(key: &Salsa20Key, nonce: &Salsa20Nonce, counter: u64) -> Self {
+                let mut state = initial_state(key);
+                for (word, chunk) in state[6..8].iter_mut().zip(nonce.0.chunks_exact(4)) {
+                    *word = u32::from_le_bytes(chunk.try_into().unwrap());
+                }
+
+                Self {
+                    state,
+                    counter: counter as u128,
+                    keystream: [0u8; SALSA20_BLOCK_SIZE],
+                    keystream_pos: SALSA20_BLOCK_SIZE
+                }
+            }
+
+            fn next_keystream_block(&mut self) -> Result<(), SymcError> {
+                if self.counter > u64::MAX as u128 {
+                    return Err(SymcError::InvalidInputLength);
+                }
+
+                self.state[8] = self.counter as u32;
+                self.state[9] = (self.counter >> 32) as u32;
+                salsa_block(&self.state, $rounds, &mut self.keystream);
+                self.counter += 1;
+                self.keystream_pos = 0;
+                Ok(())
+            }
+        }
+
+        impl StreamCipher for $name {
+            const KEY_SIZE: usize = 32;
+            const NONCE_SIZE: usize = 8;
+            type Key = Salsa20Key;
+            type Nonce = Salsa20Nonce;
+
+            fn new(key: &Self::Key, nonce: &Self::Nonce) -> Self {
+                Self::with_counter(key, nonce, 0)
+            }
+
+            fn apply_keystream(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+                if output.len() < input.len() {
+                    return Err(SymcError::BufferTooSmall);
+                }
+
+                // make sure the whole input fits in the remaining counter space before writing anything
+                let buffered = (SALSA20_BLOCK_SIZE - self.keystream_pos) as u128;
+                let blocks_needed = (input.len() as u128).saturating_sub(buffered).div_ceil(SALSA20_BLOCK_SIZE as u128);
+                if blocks_needed > (1u128 << 64) - self.counter {
+                    return Err(SymcError::InvalidInputLength);
+                }
+
+                for (o, i) in output[..input.len()].iter_mut().zip(input.iter()) {
+                    if self.keystream_pos == SALSA20_BLOCK_SIZE {
+                        self.next_keystream_block()?;
+                    }
+                    *o = *i ^ self.keystream[self.keystream_pos];
+                    self.keystream_pos += 1;
+                }
+
+                Ok(input.len())
+            }
+        }
+    };
+}
+
+salsa_impl!(Salsa20, 20, "Salsa20/20 with a 64-bit nonce and a 64-bit block counter.");
+salsa_impl!(Salsa20_12, 12, "Salsa20/12, the eSTREAM reduced round variant.");
+
+#[cfg(feature = "xsalsa20")]
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct XSalsa20Nonce([u8; 24]);
+#[cfg(feature = "xsalsa20")]
+impl AsRef<[u8]> for XSalsa20Nonce {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+#[cfg(feature = "xsalsa20")]
+impl AsMut<[u8]> for XSalsa20Nonce {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+#[cfg(feature = "xsalsa20")]
+impl From<[u8; 24]> for XSalsa20Nonce {
+    fn from(array: [u8; 24]) -> Self {
+        Self(array)
+    }
+}
+#[cfg(feature = "xsalsa20")]
+impl From<XSalsa20Nonce> for [u8; 24] {
+    fn from(output: XSalsa20Nonce) -> [u8; 24] {
+        output.0
+    }
+}
+
+/// XSalsa20, Salsa20 keyed with HSalsa20(key, nonce[..16]) and the nonce nonce[16..].
+#[cfg(feature = "xsalsa20")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct XSalsa20 {
+    inner: Salsa20
+}
+
+#[cfg(feature = "xsalsa20")]
+impl XSalsa20 {
+    pub fn with_counter(key: &Salsa20Key, nonce: &XSalsa20Nonce, counter: u64) -> Self {
+        let subkey = hsalsa20(key, nonce.0[..16].try_into().unwrap());
+        let salsa_nonce = Salsa20Nonce(nonce.0[16..].try_into().unwrap());
+
+        Self { inner: Salsa20::with_counter(&subkey, &salsa_nonce, counter) }
+    }
+}
+
+#[cfg(feature = "xsalsa20")]
+impl StreamCipher for XSalsa20 {
+    const KEY_SIZE: usize = 32;
+    const NONCE_SIZE: usize = 24;
+    type Key = Salsa20Key;
+    type Nonce = XSalsa20Nonce;
+
+    fn new(key: &Self::Key, nonce: &Self::Nonce) -> Self {
+        Self::with_counter(key, nonce, 0)
+    }
+
+    fn apply_keystream(&mut self, input: &[u8], output: &mut [u8]) -> Result<usize, SymcError> {
+        self.inner.apply_keystream(input, output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The Salsa20 family of stream ciphers, 3, quarterround example
+    #[test]
+    fn salsa20_quarter_round() {
+        let mut state = [0u32; 16];
+        state[..4].copy_from_slice(&[0xe7e8c006, 0xc4f9417d, 0x6479b4b2, 0x68c67137]);
+        quarter_round(&mut state, 0, 1, 2, 3);
+        assert_eq!(&state[..4], &[0xe876d72b, 0x9361dfd5, 0xf1460244, 0x948541a3]);
+    }
+
+    #[test]
+    fn salsa20_counter_exhaustion() {
+        let mut salsa = Salsa20::with_counter(&Salsa20Key::default(), &Salsa20Nonce::default(), u64::MAX);
+        let mut output = [0u8; 128];
+        assert_eq!(salsa.apply_keystream(&[0u8; 65], &mut output), Err(SymcError::InvalidInputLength));
+        assert_eq!(salsa.apply_keystream(&[0u8; 64], &mut output), Ok(64));
+        assert_eq!(salsa.apply_keystream(&[0u8; 1], &mut output), Err(SymcError::InvalidInputLength));
+    }
+
+    /// NaCl tests/core1.c, the HSalsa20 step of crypto_box_beforenm
+    #[cfg(feature = "xsalsa20")]
+    #[test]
+    fn hsalsa20_test_vector() {
+        let shared: [u8; 32] = [
+            0x4a, 0x5d, 0x9d, 0x5b, 0xa4, 0xce, 0x2d, 0xe1, 0x72, 0x8e, 0x3b, 0xf4, 0x80, 0x35, 0x0f, 0x25,
+            0xe0, 0x7e, 0x21, 0xc9, 0x47, 0xd1, 0x9e, 0x33, 0x76, 0xf0, 0x9b, 0x3c, 0x1e, 0x16, 0x17, 0x42,
+        ];
+        let expected: [u8; 32] = [
+            0x1b, 0x27, 0x55, 0x64, 0x73, 0xe9, 0x85, 0xd4, 0x62, 0xcd, 0x51, 0x19, 0x7a, 0x9a, 0x46, 0xc7,
+            0x60, 0x09, 0x54, 0x9e, 0xac, 0x64, 0x74, 0xf2, 0x06, 0xc4, 0xee, 0x08, 0x44, 0xf6, 0x83, 0x89,
+        ];
+
+        let subkey = hsalsa20(&shared.into(), &[0u8; 16]);
+        assert_eq!(subkey.as_ref(), &expected);
+    }
+}
@@ -0,0 +1,291 @@
+use zeroize::Zeroize;
+use super::BlockCipher;
+
+const SBOX: [[u8; 16]; 8] = [
+    [0x3, 0x8, 0xf, 0x1, 0xa, 0x6, 0x5, 0xb, 0xe, 0xd, 0x4, 0x2, 0x7, 0x0, 0x9, 0xc],
+    [0xf, 0xc, 0x2, 0x7, 0x9, 0x0, 0x5, 0xa, 0x1, 0xb, 0xe, 0x8, 0x6, 0xd, 0x3, 0x4],
+    [0x8, 0x6, 0x7, 0x9, 0x3, 0xc, 0xa, 0xf, 0xd, 0x1, 0xe, 0x4, 0x0, 0xb, 0x5, 0x2],
+    [0x0, 0xf, 0xb, 0x8, 0xc, 0x9, 0x6, 0x3, 0xd, 0x1, 0x2, 0x4, 0xa, 0x7, 0x5, 0xe],
+    [0x1, 0xf, 0x8, 0x3, 0xc, 0x0, 0xb, 0x6, 0x2, 0x5, 0x4, 0xa, 0x9, 0xe, 0x7, 0xd],
+    [0xf, 0x5, 0x2, 0xb, 0x4, 0xa, 0x9, 0xc, 0x0, 0x3, 0xe, 0x8, 0xd, 0x6, 0x7, 0x1],
+    [0x7, 0x2, 0xc, 0x5, 0x8, 0x4, 0x6, 0xb, 0xe, 0x9, 0x1, 0xf, 0xd, 0x3, 0xa, 0x0],
+    [0x1, 0xd, 0xf, 0x0, 0xe, 0x8, 0x2, 0xb, 0x7, 0x4, 0xc, 0xa, 0x9, 0x3, 0x5, 0x6]
+];
+
+const PHI: u32 = 0x9e3779b9;
+
+/// Algebraic normal form of every output bit, as all-ones/all-zeros masks per monomial.
+///
+/// Evaluating the S-boxes this way keeps the bitsliced rounds free of table lookups.
+type Anf = [[[u32; 16]; 4]; 8];
+
+const fn build_anf(sboxes: &[[u8; 16]; 8]) -> Anf {
+    let mut anf = [[[0u32; 16]; 4]; 8];
+    let mut s = 0;
+    while s < 8 {
+        let mut bit = 0;
+        while bit < 4 {
+            let mut f = [0u8; 16];
+            let mut x = 0;
+            while x < 16 {
+                f[x] = (sboxes[s][x] >> bit) & 1;
+                x += 1;
+            }
+            // Moebius transform, truth table to ANF coefficients
+            let mut var = 0;
+            while var < 4 {
+                let mut m = 0;
+                while m < 16 {
+                    if m & (1 << var) != 0 {
+                        f[m] ^= f[m ^ (1 << var)];
+                    }
+                    m += 1;
+                }
+                var += 1;
+            }
+            let mut m = 0;
+            while m < 16 {
+                anf[s][bit][m] = 0u32.wrapping_sub(f[m] as u32);
+                m += 1;
+            }
+            bit += 1;
+        }
+        s += 1;
+    }
+    anf
+}
+
+const fn invert(sboxes: &[[u8; 16]; 8]) -> [[u8; 16]; 8] {
+    let mut inv = [[0u8; 16]; 8];
+    let mut s = 0;
+    while s < 8 {
+        let mut x = 0;
+        while x < 16 {
+            inv[s][sboxes[s][x] as usize] = x as u8;
+            x += 1;
+        }
+        s += 1;
+    }
+    inv
+}
+
+const SBOX_ANF: Anf = build_anf(&SBOX);
+const SBOX_INV_ANF: Anf = build_anf(&invert(&SBOX));
+
+/// Applies one S-box to all 32 columns of the bitsliced state, bit 0 of each nibble in `x[0]`.
+#[inline]
+fn sbox(anf: &[[u32; 16]; 4], x: [u32; 4]) -> [u32; 4] {
+    let mut monomials = [!0u32; 16];
+    for m in 1..16usize {
+        let low = m.trailing_zeros() as usize;
+        monomials[m] = monomials[m & (m - 1)] & x[low];
+    }
+
+    let mut y = [0u32; 4];
+    for (out, coefficients) in y.iter_mut().zip(anf) {
+        for (monomial, coefficient) in monomials.iter().zip(coefficients) {
+            *out ^= monomial & coefficient;
+        }
+    }
+    y
+}
+
+#[inline]
+fn linear_transform(mut x: [u32; 4]) -> [u32; 4] {
+    x[0] = x[0].rotate_left(13);
+    x[2] = x[2].rotate_left(3);
+    x[1] ^= x[0] ^ x[2];
+    x[3] ^= x[2] ^ (x[0] << 3);
+    x[1] = x[1].rotate_left(1);
+    x[3] = x[3].rotate_left(7);
+    x[0] ^= x[1] ^ x[3];
+    x[2] ^= x[3] ^ (x[1] << 7);
+    x[0] = x[0].rotate_left(5);
+    x[2] = x[2].rotate_left(22);
+    x
+}
+
+#[inline]
+fn inverse_linear_transform(mut x: [u32; 4]) -> [u32; 4] {
+    x[2] = x[2].rotate_right(22);
+    x[0] = x[0].rotate_right(5);
+    x[2] ^= x[3] ^ (x[1] << 7);
+    x[0] ^= x[1] ^ x[3];
+    x[3] = x[3].rotate_right(7);
+    x[1] = x[1].rotate_right(1);
+    x[3] ^= x[2] ^ (x[0] << 3);
+    x[1] ^= x[0] ^ x[2];
+    x[2] = x[2].rotate_right(3);
+    x[0] = x[0].rotate_right(13);
+    x
+}
+
+#[inline]
+fn xor(a: [u32; 4], b: &[u32; 4]) -> [u32; 4] {
+    [a[0] ^ b[0], a[1] ^ b[1], a[2] ^ b[2], a[3] ^ b[3]]
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct SerpentCore {
+    k: [[u32; 4]; 33]
+}
+
+impl SerpentCore {
+    /// Keys shorter than 256 bits are padded with a single 1 bit, then zeros.
+    fn new(key: &[u8]) -> Self {
+        let mut padded = [0u8; 32];
+        padded[..key.len()].copy_from_slice(key);
+        if key.len() < 32 {
+            padded[key.len()] = 1;
+        }
+
+        let mut w = [0u32; 140];
+        for (word, bytes) in w.iter_mut().zip(padded.chunks_exact(4)) {
+            *word = u32::from_le_bytes(bytes.try_into().unwrap());
+        }
+        for i in 8..140 {
+            w[i] = (w[i - 8] ^ w[i - 5] ^ w[i - 3] ^ w[i - 1] ^ PHI ^ (i as u32 - 8)).rotate_left(11);
+        }
+
+        let mut k = [[0u32; 4]; 33];
+        for (i, subkey) in k.iter_mut().enumerate() {
+            let prekey = [w[4 * i + 8], w[4 * i + 9], w[4 * i + 10], w[4 * i + 11]];
+            *subkey = sbox(&SBOX_ANF[(35 - i) % 8], prekey);
+        }
+
+        padded.zeroize();
+        w.zeroize();
+        SerpentCore { k }
+    }
+
+    fn encrypt_block(&self, block: &mut [u8; 16]) {
+        let mut x: [u32; 4] = core::array::from_fn(|i| u32::from_le_bytes(block[4 * i..4 * i + 4].try_into().unwrap()));
+
+        for round in 0..32 {
+            x = sbox(&SBOX_ANF[round % 8], xor(x, &self.k[round]));
+            x = if round < 31 { linear_transform(x) } else { xor(x, &self.k[32]) };
+        }
+
+        for (chunk, word) in block.chunks_exact_mut(4).zip(x) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    fn decrypt_block(&self, block: &mut [u8; 16]) {
+        let mut x: [u32; 4] = core::array::from_fn(|i| u32::from_le_bytes(block[4 * i..4 * i + 4].try_into().unwrap()));
+
+        for round in (0..32).rev() {
+            x = if round < 31 { inverse_linear_transform(x) } else { xor(x, &self.k[32]) };
+            x = xor(sbox(&SBOX_INV_ANF[round % 8], x), &self.k[round]);
+        }
+
+        for (chunk, word) in block.chunks_exact_mut(4).zip(x) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+}
+
+macro_rules! serpent_impl {
+    ($name:ident, $key:ident, $size:literal, $doc:literal) => {
+        #[derive(Clone, Default, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $key([u8; $size]);
+        impl AsRef<[u8]> for $key {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $key {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $size]> for $key {
+            fn from(array: [u8; $size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$key> for [u8; $size] {
+            fn from(output: $key) -> [u8; $size] {
+                output.0
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Zeroize, Clone)]
+        #[zeroize(drop)]
+        pub struct $name {
+            core: SerpentCore
+        }
+
+        impl BlockCipher for $name {
+            const BLOCK_SIZE: usize = 16;
+            const KEY_SIZE: usize = $size;
+            type Block = [u8; 16];
+            type Key = $key;
+
+            fn new(key: &Self::Key) -> Self {
+                Self { core: SerpentCore::new(&key.0) }
+            }
+
+            fn encrypt_block(&self, block: &mut Self::Block) {
+                self.core.encrypt_block(block);
+            }
+
+            fn decrypt_block(&self, block: &mut Self::Block) {
+                self.core.decrypt_block(block);
+            }
+        }
+    };
+}
+
+serpent_impl!(Serpent128, Serpent128Key, 16, "Serpent with a 128-bit key, in the NESSIE byte order used by Linux and VeraCrypt.");
+serpent_impl!(Serpent192, Serpent192Key, 24, "Serpent with a 192-bit key, in the NESSIE byte order used by Linux and VeraCrypt.");
+serpent_impl!(Serpent256, Serpent256Key, 32, "Serpent with a 256-bit key, in the NESSIE byte order used by Linux and VeraCrypt.");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check(key: &[u8], plaintext: [u8; 16], ciphertext: [u8; 16]) {
+        let core = SerpentCore::new(key);
+        let mut block = plaintext;
+        core.encrypt_block(&mut block);
+        assert_eq!(block, ciphertext);
+        core.decrypt_block(&mut block);
+        assert_eq!(block, plaintext);
+    }
+
+    #[test]
+    fn sbox_anf_matches_table() {
+        for (s, table) in SBOX.iter().enumerate() {
+            for (x, &y) in table.iter().enumerate() {
+                let input: [u32; 4] = core::array::from_fn(|bit| ((x >> bit) & 1) as u32);
+                let output = sbox(&SBOX_ANF[s], input);
+                let value = (0..4).fold(0, |acc, bit| acc | ((output[bit] & 1) << bit));
+                assert_eq!(value, y as u32);
+            }
+        }
+    }
+
+    /// NESSIE Set 1, vector 0
+    #[test]
+    fn nessie_set1_vector0() {
+        let mut key = [0u8; 32];
+        key[0] = 0x80;
+        check(&key[..16], [0u8; 16], [0x26, 0x4e, 0x54, 0x81, 0xef, 0xf4, 0x2a, 0x46, 0x06, 0xab, 0xda, 0x06, 0xc0, 0xbf, 0xda, 0x3d]);
+        check(&key[..24], [0u8; 16], [0x9e, 0x27, 0x4e, 0xad, 0x9b, 0x73, 0x7b, 0xb2, 0x1e, 0xfc, 0xfc, 0xa5, 0x48, 0x60, 0x26, 0x89]);
+        check(&key, [0u8; 16], [0xa2, 0x23, 0xaa, 0x12, 0x88, 0x46, 0x3c, 0x0e, 0x2b, 0xe3, 0x8e, 0xbd, 0x82, 0x56, 0x16, 0xc0]);
+    }
+
+    /// NESSIE Set 2, vector 0
+    #[test]
+    fn nessie_set2_vector0() {
+        let mut plaintext = [0u8; 16];
+        plaintext[0] = 0x80;
+        check(&[0u8; 16], plaintext, [0xa3, 0xb3, 0x5d, 0xe7, 0xc3, 0x58, 0xdd, 0xd8, 0x26, 0x44, 0x67, 0x8c, 0x64, 0xb8, 0xbc, 0xbb]);
+        check(&[0u8; 24], plaintext, [0x23, 0xf5, 0xf4, 0x32, 0xad, 0x68, 0x7e, 0x0d, 0x45, 0x74, 0xc1, 0x64, 0x59, 0x61, 0x8a, 0xbb]);
+        check(&[0u8; 32], plaintext, [0x83, 0x14, 0x67, 0x5e, 0x8a, 0xd5, 0xc3, 0xec, 0xd8, 0x3d, 0x85, 0x2b, 0xcf, 0x7f, 0x56, 0x6e]);
+    }
+}
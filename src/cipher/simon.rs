@@ -0,0 +1,163 @@
+use zeroize::Zeroize;
+use super::BlockCipher;
+
+/// Bit `i` of the result is the `i`-th character of the sequence as printed in the paper.
+const fn z_sequence(bits: &[u8; 62]) -> u64 {
+    let mut z = 0u64;
+    let mut i = 0;
+    while i < 62 {
+        z |= ((bits[i] - b'0') as u64) << i;
+        i += 1;
+    }
+    z
+}
+
+const Z2: u64 = z_sequence(b"10101111011100000011010010011000101000010001111110010110110011");
+const Z3: u64 = z_sequence(b"11011011101011000110010111100000010010001010011100110100001111");
+const Z4: u64 = z_sequence(b"11010001111001101011011000100000010111000011001010010011101111");
+
+// Same byte order as SPECK: little-endian words, y first.
+macro_rules! simon_impl {
+    ($name:ident, $key:ident, $word:ty, $block_size:literal, $key_size:literal, $rounds:literal, $z:ident, $doc:literal) => {
+        #[derive(Clone, Default, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $key([u8; $key_size]);
+        impl AsRef<[u8]> for $key {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $key {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $key_size]> for $key {
+            fn from(array: [u8; $key_size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$key> for [u8; $key_size] {
+            fn from(output: $key) -> [u8; $key_size] {
+                output.0
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Zeroize, Clone)]
+        #[zeroize(drop)]
+        pub struct $name {
+            rk: [$word; $rounds]
+        }
+
+        impl $name {
+            #[inline]
+            fn f(x: $word) -> $word {
+                (x.rotate_left(1) & x.rotate_left(8)) ^ x.rotate_left(2)
+            }
+        }
+
+        impl BlockCipher for $name {
+            const BLOCK_SIZE: usize = $block_size;
+            const KEY_SIZE: usize = $key_size;
+            type Block = [u8; $block_size];
+            type Key = $key;
+
+            fn new(key: &Self::Key) -> Self {
+                const W: usize = $block_size / 2;
+                const M: usize = $key_size / W;
+
+                let mut rk = [0; $rounds];
+                for (i, chunk) in key.0.chunks_exact(W).enumerate() {
+                    rk[i] = <$word>::from_le_bytes(chunk.try_into().unwrap());
+                }
+                for i in M..$rounds {
+                    let mut tmp = rk[i - 1].rotate_right(3);
+                    if M == 4 {
+                        tmp ^= rk[i - 3];
+                    }
+                    tmp ^= tmp.rotate_right(1);
+                    let z = (($z >> ((i - M) % 62)) & 1) as $word;
+                    rk[i] = !rk[i - M] ^ tmp ^ z ^ 3;
+                }
+
+                Self { rk }
+            }
+
+            fn encrypt_block(&self, block: &mut Self::Block) {
+                const W: usize = $block_size / 2;
+                let mut y = <$word>::from_le_bytes(block[..W].try_into().unwrap());
+                let mut x = <$word>::from_le_bytes(block[W..].try_into().unwrap());
+
+                for &k in self.rk.iter() {
+                    (x, y) = (y ^ Self::f(x) ^ k, x);
+                }
+
+                block[..W].copy_from_slice(&y.to_le_bytes());
+                block[W..].copy_from_slice(&x.to_le_bytes());
+            }
+
+            fn decrypt_block(&self, block: &mut Self::Block) {
+                const W: usize = $block_size / 2;
+                let mut y = <$word>::from_le_bytes(block[..W].try_into().unwrap());
+                let mut x = <$word>::from_le_bytes(block[W..].try_into().unwrap());
+
+                for &k in self.rk.iter().rev() {
+                    (x, y) = (y, x ^ Self::f(y) ^ k);
+                }
+
+                block[..W].copy_from_slice(&y.to_le_bytes());
+                block[W..].copy_from_slice(&x.to_le_bytes());
+            }
+        }
+    };
+}
+
+simon_impl!(Simon64_96, Simon64_96Key, u32, 8, 12, 42, Z2, "SIMON64/96, a 64-bit block with a 96-bit key.");
+simon_impl!(Simon64_128, Simon64_128Key, u32, 8, 16, 44, Z3, "SIMON64/128, a 64-bit block with a 128-bit key.");
+simon_impl!(Simon128_128, Simon128_128Key, u64, 16, 16, 68, Z2, "SIMON128/128, a 128-bit block with a 128-bit key.");
+simon_impl!(Simon128_192, Simon128_192Key, u64, 16, 24, 69, Z3, "SIMON128/192, a 128-bit block with a 192-bit key.");
+simon_impl!(Simon128_256, Simon128_256Key, u64, 16, 32, 72, Z4, "SIMON128/256, a 128-bit block with a 256-bit key.");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key<const N: usize>() -> [u8; N] {
+        core::array::from_fn(|i| i as u8)
+    }
+
+    /// 32-bit words skip the top half of each 64-bit slot, see the SPECK tests.
+    fn key64<const N: usize>() -> [u8; N] {
+        core::array::from_fn(|i| (i / 4 * 8 + i % 4) as u8)
+    }
+
+    fn check<C: BlockCipher>(key: C::Key, plaintext: C::Block, ciphertext: C::Block) {
+        let cipher = C::new(&key);
+        let mut block = plaintext.clone();
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block.as_ref(), ciphertext.as_ref());
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block.as_ref(), plaintext.as_ref());
+    }
+
+    /// The SIMON and SPECK Families of Lightweight Block Ciphers, Appendix B
+    #[test]
+    fn simon64() {
+        check::<Simon64_96>(key64().into(), *b"cling ro", [0xc8, 0x8f, 0x1a, 0x11, 0x7f, 0xe2, 0xa2, 0x5c]);
+        check::<Simon64_128>(key64().into(), *b"und like", [0x7a, 0xa0, 0xdf, 0xb9, 0x20, 0xfc, 0xc8, 0x44]);
+    }
+
+    #[test]
+    fn simon128() {
+        check::<Simon128_128>(key().into(), *b" travellers desc", [
+            0xbc, 0x0b, 0x4e, 0xf8, 0x2a, 0x83, 0xaa, 0x65, 0x3f, 0xfe, 0x54, 0x1e, 0x1e, 0x1b, 0x68, 0x49
+        ]);
+        check::<Simon128_192>(key().into(), *b"ribe when there ", [
+            0x5b, 0xb8, 0x97, 0x25, 0x6e, 0x8d, 0x9c, 0x6c, 0x4f, 0x0d, 0xdc, 0xfc, 0xef, 0x61, 0xac, 0xc4
+        ]);
+        check::<Simon128_256>(key().into(), *b"is a simoom in t", [
+            0x68, 0xb8, 0xe7, 0xef, 0x87, 0x2a, 0xf7, 0x3b, 0xa0, 0xa3, 0xc8, 0xaf, 0x79, 0x55, 0x2b, 0x8d
+        ]);
+    }
+}
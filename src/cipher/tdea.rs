@@ -0,0 +1,344 @@
+use zeroize::Zeroize;
+use super::BlockCipher;
+
+// FIPS 46-3 tables, bit positions counted from 1 at the most significant bit
+
+const IP: [u8; 64] = [
+    58, 50, 42, 34, 26, 18, 10, 2, 60, 52, 44, 36, 28, 20, 12, 4,
+    62, 54, 46, 38, 30, 22, 14, 6, 64, 56, 48, 40, 32, 24, 16, 8,
+    57, 49, 41, 33, 25, 17, 9, 1, 59, 51, 43, 35, 27, 19, 11, 3,
+    61, 53, 45, 37, 29, 21, 13, 5, 63, 55, 47, 39, 31, 23, 15, 7
+];
+
+const FP: [u8; 64] = [
+    40, 8, 48, 16, 56, 24, 64, 32, 39, 7, 47, 15, 55, 23, 63, 31,
+    38, 6, 46, 14, 54, 22, 62, 30, 37, 5, 45, 13, 53, 21, 61, 29,
+    36, 4, 44, 12, 52, 20, 60, 28, 35, 3, 43, 11, 51, 19, 59, 27,
+    34, 2, 42, 10, 50, 18, 58, 26, 33, 1, 41, 9, 49, 17, 57, 25
+];
+
+const E: [u8; 48] = [
+    32, 1, 2, 3, 4, 5, 4, 5, 6, 7, 8, 9,
+    8, 9, 10, 11, 12, 13, 12, 13, 14, 15, 16, 17,
+    16, 17, 18, 19, 20, 21, 20, 21, 22, 23, 24, 25,
+    24, 25, 26, 27, 28, 29, 28, 29, 30, 31, 32, 1
+];
+
+const P: [u8; 32] = [
+    16, 7, 20, 21, 29, 12, 28, 17, 1, 15, 23, 26, 5, 18, 31, 10,
+    2, 8, 24, 14, 32, 27, 3, 9, 19, 13, 30, 6, 22, 11, 4, 25
+];
+
+const PC1: [u8; 56] = [
+    57, 49, 41, 33, 25, 17, 9, 1, 58, 50, 42, 34, 26, 18,
+    10, 2, 59, 51, 43, 35, 27, 19, 11, 3, 60, 52, 44, 36,
+    63, 55, 47, 39, 31, 23, 15, 7, 62, 54, 46, 38, 30, 22,
+    14, 6, 61, 53, 45, 37, 29, 21, 13, 5, 28, 20, 12, 4
+];
+
+const PC2: [u8; 48] = [
+    14, 17, 11, 24, 1, 5, 3, 28, 15, 6, 21, 10,
+    23, 19, 12, 4, 26, 8, 16, 7, 27, 20, 13, 2,
+    41, 52, 31, 37, 47, 55, 30, 40, 51, 45, 33, 48,
+    44, 49, 39, 56, 34, 53, 46, 42, 50, 36, 29, 32
+];
+
+const SHIFTS: [u32; 16] = [1, 1, 2, 2, 2, 2, 2, 2, 1, 2, 2, 2, 2, 2, 2, 1];
+
+const S: [[u8; 64]; 8] = [
+    [
+        14, 4, 13, 1, 2, 15, 11, 8, 3, 10, 6, 12, 5, 9, 0, 7,
+        0, 15, 7, 4, 14, 2, 13, 1, 10, 6, 12, 11, 9, 5, 3, 8,
+        4, 1, 14, 8, 13, 6, 2, 11, 15, 12, 9, 7, 3, 10, 5, 0,
+        15, 12, 8, 2, 4, 9, 1, 7, 5, 11, 3, 14, 10, 0, 6, 13
+    ],
+    [
+        15, 1, 8, 14, 6, 11, 3, 4, 9, 7, 2, 13, 12, 0, 5, 10,
+        3, 13, 4, 7, 15, 2, 8, 14, 12, 0, 1, 10, 6, 9, 11, 5,
+        0, 14, 7, 11, 10, 4, 13, 1, 5, 8, 12, 6, 9, 3, 2, 15,
+        13, 8, 10, 1, 3, 15, 4, 2, 11, 6, 7, 12, 0, 5, 14, 9
+    ],
+    [
+        10, 0, 9, 14, 6, 3, 15, 5, 1, 13, 12, 7, 11, 4, 2, 8,
+        13, 7, 0, 9, 3, 4, 6, 10, 2, 8, 5, 14, 12, 11, 15, 1,
+        13, 6, 4, 9, 8, 15, 3, 0, 11, 1, 2, 12, 5, 10, 14, 7,
+        1, 10, 13, 0, 6, 9, 8, 7, 4, 15, 14, 3, 11, 5, 2, 12
+    ],
+    [
+        7, 13, 14, 3, 0, 6, 9, 10, 1, 2, 8, 5, 11, 12, 4, 15,
+        13, 8, 11, 5, 6, 15, 0, 3, 4, 7, 2, 12, 1, 10, 14, 9,
+        10, 6, 9, 0, 12, 11, 7, 13, 15, 1, 3, 14, 5, 2, 8, 4,
+        3, 15, 0, 6, 10, 1, 13, 8, 9, 4, 5, 11, 12, 7, 2, 14
+    ],
+    [
+        2, 12, 4, 1, 7, 10, 11, 6, 8, 5, 3, 15, 13, 0, 14, 9,
+        14, 11, 2, 12, 4, 7, 13, 1, 5, 0, 15, 10, 3, 9, 8, 6,
+        4, 2, 1, 11, 10, 13, 7, 8, 15, 9, 12, 5, 6, 3, 0, 14,
+        11, 8, 12, 7, 1, 14, 2, 13, 6, 15, 0, 9, 10, 4, 5, 3
+    ],
+    [
+        12, 1, 10, 15, 9, 2, 6, 8, 0, 13, 3, 4, 14, 7, 5, 11,
+        10, 15, 4, 2, 7, 12, 9, 5, 6, 1, 13, 14, 0, 11, 3, 8,
+        9, 14, 15, 5, 2, 8, 12, 3, 7, 0, 4, 10, 1, 13, 11, 6,
+        4, 3, 2, 12, 9, 5, 15, 10, 11, 14, 1, 7, 6, 0, 8, 13
+    ],
+    [
+        4, 11, 2, 14, 15, 0, 8, 13, 3, 12, 9, 7, 5, 10, 6, 1,
+        13, 0, 11, 7, 4, 9, 1, 10, 14, 3, 5, 12, 2, 15, 8, 6,
+        1, 4, 11, 13, 12, 3, 7, 14, 10, 15, 6, 8, 0, 5, 9, 2,
+        6, 11, 13, 8, 1, 4, 10, 7, 9, 5, 0, 15, 14, 2, 3, 12
+    ],
+    [
+        13, 2, 8, 4, 6, 15, 11, 1, 10, 9, 3, 14, 5, 0, 12, 7,
+        1, 15, 13, 8, 10, 3, 7, 4, 12, 5, 6, 11, 0, 14, 9, 2,
+        7, 11, 4, 1, 9, 12, 14, 2, 0, 6, 10, 13, 15, 3, 5, 8,
+        2, 1, 14, 7, 4, 10, 8, 13, 15, 12, 9, 0, 3, 5, 6, 11
+    ]
+];
+
+/// Gathers the bits named by `table` out of the low `input_bits` bits of `input`.
+const fn permute(input: u64, input_bits: u32, table: &[u8]) -> u64 {
+    let mut output = 0u64;
+    let mut i = 0;
+    while i < table.len() {
+        output = (output << 1) | ((input >> (input_bits - table[i] as u32)) & 1);
+        i += 1;
+    }
+    output
+}
+
+// S-box i for each 6-bit input, already passed through P
+const SP: [[u32; 64]; 8] = {
+    let mut sp = [[0u32; 64]; 8];
+    let mut i = 0;
+    while i < 8 {
+        let mut six = 0;
+        while six < 64 {
+            // outer bits select the row, inner bits the column
+            let row = ((six >> 4) & 2) | (six & 1);
+            let col = (six >> 1) & 0xf;
+            let value = (S[i][row * 16 + col] as u64) << (28 - 4 * i);
+            sp[i][six] = permute(value, 32, &P) as u32;
+            six += 1;
+        }
+        i += 1;
+    }
+    sp
+};
+
+#[inline]
+fn f(r: u32, subkey: u64) -> u32 {
+    let x = permute(r as u64, 32, &E) ^ subkey;
+    let mut output = 0u32;
+    for (i, sp) in SP.iter().enumerate() {
+        output ^= sp[((x >> (42 - 6 * i)) & 0x3f) as usize];
+    }
+    output
+}
+
+/// Single DES, only used as the building block of TDEA.
+#[derive(Zeroize, Clone)]
+#[zeroize(drop)]
+struct Des {
+    subkeys: [u64; 16]
+}
+
+impl Des {
+    fn new(key: &[u8]) -> Self {
+        let key = permute(u64::from_be_bytes(key.try_into().unwrap()), 64, &PC1);
+        let (mut c, mut d) = ((key >> 28) as u32, (key & 0x0fff_ffff) as u32);
+
+        let mut subkeys = [0u64; 16];
+        for (subkey, shift) in subkeys.iter_mut().zip(SHIFTS) {
+            c = ((c << shift) | (c >> (28 - shift))) & 0x0fff_ffff;
+            d = ((d << shift) | (d >> (28 - shift))) & 0x0fff_ffff;
+            *subkey = permute(((c as u64) << 28) | d as u64, 56, &PC2);
+        }
+        c.zeroize();
+        d.zeroize();
+        Self { subkeys }
+    }
+
+    fn crypt(block: u64, subkeys: impl Iterator<Item = u64>) -> u64 {
+        let block = permute(block, 64, &IP);
+        let (mut l, mut r) = ((block >> 32) as u32, block as u32);
+        for subkey in subkeys {
+            (l, r) = (r, l ^ f(r, subkey));
+        }
+        // the halves are swapped once more after the last round
+        permute(((r as u64) << 32) | l as u64, 64, &FP)
+    }
+
+    #[inline]
+    fn encrypt(&self, block: u64) -> u64 {
+        Self::crypt(block, self.subkeys.iter().copied())
+    }
+
+    #[inline]
+    fn decrypt(&self, block: u64) -> u64 {
+        Self::crypt(block, self.subkeys.iter().rev().copied())
+    }
+}
+
+/// Encrypt-decrypt-encrypt with three DES keys, the first repeated as the third for keying option 2.
+#[derive(Zeroize, Clone)]
+#[zeroize(drop)]
+struct Ede {
+    k1: Des,
+    k2: Des,
+    k3: Des
+}
+
+impl Ede {
+    fn encrypt_block(&self, block: &mut [u8; 8]) {
+        let x = u64::from_be_bytes(*block);
+        *block = self.k3.encrypt(self.k2.decrypt(self.k1.encrypt(x))).to_be_bytes();
+    }
+
+    fn decrypt_block(&self, block: &mut [u8; 8]) {
+        let x = u64::from_be_bytes(*block);
+        *block = self.k1.decrypt(self.k2.encrypt(self.k3.decrypt(x))).to_be_bytes();
+    }
+}
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Tdea2Key([u8; 16]);
+impl AsRef<[u8]> for Tdea2Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Tdea2Key {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 16]> for Tdea2Key {
+    fn from(array: [u8; 16]) -> Self {
+        Self(array)
+    }
+}
+impl From<Tdea2Key> for [u8; 16] {
+    fn from(output: Tdea2Key) -> [u8; 16] {
+        output.0
+    }
+}
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Tdea3Key([u8; 24]);
+impl AsRef<[u8]> for Tdea3Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Tdea3Key {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 24]> for Tdea3Key {
+    fn from(array: [u8; 24]) -> Self {
+        Self(array)
+    }
+}
+impl From<Tdea3Key> for [u8; 24] {
+    fn from(output: Tdea3Key) -> [u8; 24] {
+        output.0
+    }
+}
+
+/// Two-key TDEA (SP 800-67 keying option 2), K1 || K2 with K3 = K1. Parity bits are ignored.
+#[derive(Zeroize, Clone)]
+#[zeroize(drop)]
+pub struct Tdea2 {
+    ede: Ede
+}
+
+impl BlockCipher for Tdea2 {
+    const BLOCK_SIZE: usize = 8;
+    const KEY_SIZE: usize = 16;
+    type Block = [u8; 8];
+    type Key = Tdea2Key;
+
+    fn new(key: &Self::Key) -> Self {
+        let k1 = Des::new(&key.0[..8]);
+        let k2 = Des::new(&key.0[8..]);
+        Self { ede: Ede { k3: k1.clone(), k1, k2 } }
+    }
+
+    fn encrypt_block(&self, block: &mut Self::Block) {
+        self.ede.encrypt_block(block);
+    }
+
+    fn decrypt_block(&self, block: &mut Self::Block) {
+        self.ede.decrypt_block(block);
+    }
+}
+
+/// Three-key TDEA (SP 800-67 keying option 1), K1 || K2 || K3. Parity bits are ignored.
+#[derive(Zeroize, Clone)]
+#[zeroize(drop)]
+pub struct Tdea3 {
+    ede: Ede
+}
+
+impl BlockCipher for Tdea3 {
+    const BLOCK_SIZE: usize = 8;
+    const KEY_SIZE: usize = 24;
+    type Block = [u8; 8];
+    type Key = Tdea3Key;
+
+    fn new(key: &Self::Key) -> Self {
+        Self {
+            ede: Ede {
+                k1: Des::new(&key.0[..8]),
+                k2: Des::new(&key.0[8..16]),
+                k3: Des::new(&key.0[16..])
+            }
+        }
+    }
+
+    fn encrypt_block(&self, block: &mut Self::Block) {
+        self.ede.encrypt_block(block);
+    }
+
+    fn decrypt_block(&self, block: &mut Self::Block) {
+        self.ede.decrypt_block(block);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn fp_inverts_ip() {
+        let x = 0x0123456789abcdef;
+        assert_eq!(permute(permute(x, 64, &IP), 64, &FP), x);
+    }
+
+    /// Single DES worked example by J. Orlin Grabbe, "The DES Algorithm Illustrated"
+    #[test]
+    fn des_worked_example() {
+        let des = Des::new(&0x133457799bbcdff1u64.to_be_bytes());
+        assert_eq!(des.subkeys[0], 0b000110_110000_001011_101111_111111_000111_000001_110010);
+        assert_eq!(des.subkeys[15], 0b110010_110011_110110_001011_000011_100001_011111_110101);
+        assert_eq!(des.encrypt(0x0123456789abcdef), 0x85e813540f0ab405);
+        assert_eq!(des.decrypt(0x85e813540f0ab405), 0x0123456789abcdef);
+    }
+
+    /// With K1 = K2 = K3, EDE collapses to single DES.
+    #[test]
+    fn tdea_degenerates_to_des() {
+        let key = 0x133457799bbcdff1u64.to_be_bytes();
+        let mut key3 = [0u8; 24];
+        key3.chunks_exact_mut(8).for_each(|k| k.copy_from_slice(&key));
+
+        let cipher = Tdea3::new(&Tdea3Key::from(key3));
+        let mut block = 0x0123456789abcdefu64.to_be_bytes();
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, 0x85e813540f0ab405u64.to_be_bytes());
+    }
+}
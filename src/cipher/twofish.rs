@@ -0,0 +1,313 @@
+use zeroize::Zeroize;
+use super::BlockCipher;
+
+const Q0_T: [[u8; 16]; 4] = [
+    [0x8, 0x1, 0x7, 0xd, 0x6, 0xf, 0x3, 0x2, 0x0, 0xb, 0x5, 0x9, 0xe, 0xc, 0xa, 0x4],
+    [0xe, 0xc, 0xb, 0x8, 0x1, 0x2, 0x3, 0x5, 0xf, 0x4, 0xa, 0x6, 0x7, 0x0, 0x9, 0xd],
+    [0xb, 0xa, 0x5, 0xe, 0x6, 0xd, 0x9, 0x0, 0xc, 0x8, 0xf, 0x3, 0x2, 0x4, 0x7, 0x1],
+    [0xd, 0x7, 0xf, 0x4, 0x1, 0x2, 0x6, 0xe, 0x9, 0xb, 0x3, 0x0, 0x8, 0x5, 0xc, 0xa]
+];
+
+const Q1_T: [[u8; 16]; 4] = [
+    [0x2, 0x8, 0xb, 0xd, 0xf, 0x7, 0x6, 0xe, 0x3, 0x1, 0x9, 0x4, 0x0, 0xa, 0xc, 0x5],
+    [0x1, 0xe, 0x2, 0xb, 0x4, 0xc, 0x3, 0x7, 0x6, 0xd, 0xa, 0x5, 0xf, 0x9, 0x0, 0x8],
+    [0x4, 0xc, 0x7, 0x5, 0x1, 0x6, 0x9, 0xa, 0x0, 0xe, 0xd, 0x8, 0x2, 0xb, 0x3, 0xf],
+    [0xb, 0x9, 0x5, 0x1, 0xc, 0x3, 0xd, 0xe, 0x6, 0x4, 0x7, 0xf, 0x2, 0x0, 0x8, 0xa]
+];
+
+const MDS: [[u8; 4]; 4] = [
+    [0x01, 0xef, 0x5b, 0x5b],
+    [0x5b, 0xef, 0xef, 0x01],
+    [0xef, 0x5b, 0x01, 0xef],
+    [0xef, 0x01, 0xef, 0x5b]
+];
+
+const RS: [[u8; 8]; 4] = [
+    [0x01, 0xa4, 0x55, 0x87, 0x5a, 0x58, 0xdb, 0x9e],
+    [0xa4, 0x56, 0x82, 0xf3, 0x1e, 0xc6, 0x68, 0xe5],
+    [0x02, 0xa1, 0xfc, 0xc1, 0x47, 0xae, 0x3d, 0x19],
+    [0xa4, 0x55, 0x87, 0x5a, 0x58, 0xdb, 0x9e, 0x03]
+];
+
+const RHO: u32 = 0x01010101;
+
+/// The fixed permutations q0 and q1, built from their 4-bit tables (Twofish paper, 4.3.5).
+const fn build_q(t: &[[u8; 16]; 4]) -> [u8; 256] {
+    const fn ror4(x: u8) -> u8 {
+        ((x >> 1) | (x << 3)) & 0xf
+    }
+
+    let mut q = [0u8; 256];
+    let mut x = 0;
+    while x < 256 {
+        let a0 = (x >> 4) as u8;
+        let b0 = (x & 0xf) as u8;
+        let a1 = a0 ^ b0;
+        let b1 = a0 ^ ror4(b0) ^ ((a0 << 3) & 0xf);
+        let a2 = t[0][a1 as usize];
+        let b2 = t[1][b1 as usize];
+        let a3 = a2 ^ b2;
+        let b3 = a2 ^ ror4(b2) ^ ((a2 << 3) & 0xf);
+        let a4 = t[2][a3 as usize];
+        let b4 = t[3][b3 as usize];
+        q[x] = (b4 << 4) | a4;
+        x += 1;
+    }
+    q
+}
+
+const Q0: [u8; 256] = build_q(&Q0_T);
+const Q1: [u8; 256] = build_q(&Q1_T);
+
+#[inline]
+fn gf_mul(mut a: u8, mut b: u8, poly: u16) -> u8 {
+    let mut result = 0u8;
+    while b != 0 {
+        result ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = 0u8.wrapping_sub(a >> 7);
+        a = (a << 1) ^ (carry & (poly & 0xff) as u8);
+        b >>= 1;
+    }
+    result
+}
+
+/// Multiplies byte `y` at input position `column` by the MDS matrix, giving its share of the output word.
+#[inline]
+fn mds_column(column: usize, y: u8) -> u32 {
+    let mut out = [0u8; 4];
+    for (row, byte) in out.iter_mut().enumerate() {
+        *byte = gf_mul(MDS[row][column], y, 0x169);
+    }
+    u32::from_le_bytes(out)
+}
+
+/// The keyed byte permutations of h, before the MDS multiply. `l` holds k words, in the order h consumes them.
+#[inline]
+fn h_bytes(x: u32, l: &[u32]) -> [u8; 4] {
+    let mut y = x.to_le_bytes();
+    let k = l.len();
+    let l: [[u8; 4]; 4] = core::array::from_fn(|i| l.get(i).copied().unwrap_or(0).to_le_bytes());
+
+    if k == 4 {
+        y = [Q1[y[0] as usize] ^ l[3][0], Q0[y[1] as usize] ^ l[3][1], Q0[y[2] as usize] ^ l[3][2], Q1[y[3] as usize] ^ l[3][3]];
+    }
+    if k >= 3 {
+        y = [Q1[y[0] as usize] ^ l[2][0], Q1[y[1] as usize] ^ l[2][1], Q0[y[2] as usize] ^ l[2][2], Q0[y[3] as usize] ^ l[2][3]];
+    }
+    [
+        Q1[(Q0[(Q0[y[0] as usize] ^ l[1][0]) as usize] ^ l[0][0]) as usize],
+        Q0[(Q0[(Q1[y[1] as usize] ^ l[1][1]) as usize] ^ l[0][1]) as usize],
+        Q1[(Q1[(Q0[y[2] as usize] ^ l[1][2]) as usize] ^ l[0][2]) as usize],
+        Q0[(Q1[(Q1[y[3] as usize] ^ l[1][3]) as usize] ^ l[0][3]) as usize]
+    ]
+}
+
+fn h(x: u32, l: &[u32]) -> u32 {
+    h_bytes(x, l).iter().enumerate().fold(0, |acc, (column, &y)| acc ^ mds_column(column, y))
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct TwofishCore {
+    k: [u32; 40],
+    /// Key-dependent S-boxes with the MDS multiply folded in, so g is four lookups.
+    s: [[u32; 256]; 4]
+}
+
+impl TwofishCore {
+    fn new(key: &[u8]) -> Self {
+        let words = key.len() / 8;
+        let mut me = [0u32; 4];
+        let mut mo = [0u32; 4];
+        let mut sv = [0u32; 4];
+
+        for i in 0..words {
+            me[i] = u32::from_le_bytes(key[8 * i..8 * i + 4].try_into().unwrap());
+            mo[i] = u32::from_le_bytes(key[8 * i + 4..8 * i + 8].try_into().unwrap());
+
+            let mut s = [0u8; 4];
+            for (row, byte) in s.iter_mut().enumerate() {
+                for (col, &m) in key[8 * i..8 * i + 8].iter().enumerate() {
+                    *byte ^= gf_mul(RS[row][col], m, 0x14d);
+                }
+            }
+            // S is used in reverse order, S_{k-1} first
+            sv[words - 1 - i] = u32::from_le_bytes(s);
+        }
+
+        let mut k = [0u32; 40];
+        for i in 0..20 {
+            let a = h(2 * i as u32 * RHO, &me[..words]);
+            let b = h((2 * i as u32 + 1) * RHO, &mo[..words]).rotate_left(8);
+            k[2 * i] = a.wrapping_add(b);
+            k[2 * i + 1] = a.wrapping_add(b.wrapping_mul(2)).rotate_left(9);
+        }
+
+        let mut s = [[0u32; 256]; 4];
+        for x in 0..256 {
+            let y = h_bytes(x as u32 * RHO, &sv[..words]);
+            for (column, table) in s.iter_mut().enumerate() {
+                table[x] = mds_column(column, y[column]);
+            }
+        }
+
+        me.zeroize();
+        mo.zeroize();
+        sv.zeroize();
+        TwofishCore { k, s }
+    }
+
+    #[inline]
+    fn g(&self, x: u32) -> u32 {
+        let b = x.to_le_bytes();
+        self.s[0][b[0] as usize] ^ self.s[1][b[1] as usize] ^ self.s[2][b[2] as usize] ^ self.s[3][b[3] as usize]
+    }
+
+    fn encrypt_block(&self, block: &mut [u8; 16]) {
+        let mut r: [u32; 4] = core::array::from_fn(|i| u32::from_le_bytes(block[4 * i..4 * i + 4].try_into().unwrap()) ^ self.k[i]);
+
+        for round in 0..16 {
+            let t0 = self.g(r[0]);
+            let t1 = self.g(r[1].rotate_left(8));
+            let f0 = t0.wrapping_add(t1).wrapping_add(self.k[2 * round + 8]);
+            let f1 = t0.wrapping_add(t1.wrapping_mul(2)).wrapping_add(self.k[2 * round + 9]);
+            let r2 = (r[2] ^ f0).rotate_right(1);
+            let r3 = r[3].rotate_left(1) ^ f1;
+            r = [r2, r3, r[0], r[1]];
+        }
+
+        let out = [r[2] ^ self.k[4], r[3] ^ self.k[5], r[0] ^ self.k[6], r[1] ^ self.k[7]];
+        for (chunk, word) in block.chunks_exact_mut(4).zip(out) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+    }
+
+    fn decrypt_block(&self, block: &mut [u8; 16]) {
+        let c: [u32; 4] = core::array::from_fn(|i| u32::from_le_bytes(block[4 * i..4 * i + 4].try_into().unwrap()) ^ self.k[i + 4]);
+        let mut r = [c[2], c[3], c[0], c[1]];
+
+        for round in (0..16).rev() {
+            let t0 = self.g(r[2]);
+            let t1 = self.g(r[3].rotate_left(8));
+            let f0 = t0.wrapping_add(t1).wrapping_add(self.k[2 * round + 8]);
+            let f1 = t0.wrapping_add(t1.wrapping_mul(2)).wrapping_add(self.k[2 * round + 9]);
+            let r0 = r[0].rotate_left(1) ^ f0;
+            let r1 = (r[1] ^ f1).rotate_right(1);
+            r = [r[2], r[3], r0, r1];
+        }
+
+        for (i, (chunk, word)) in block.chunks_exact_mut(4).zip(r).enumerate() {
+            chunk.copy_from_slice(&(word ^ self.k[i]).to_le_bytes());
+        }
+    }
+}
+
+macro_rules! twofish_impl {
+    ($name:ident, $key:ident, $size:literal, $doc:literal) => {
+        #[derive(Clone, Default, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $key([u8; $size]);
+        impl AsRef<[u8]> for $key {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $key {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $size]> for $key {
+            fn from(array: [u8; $size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$key> for [u8; $size] {
+            fn from(output: $key) -> [u8; $size] {
+                output.0
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Zeroize, Clone)]
+        #[zeroize(drop)]
+        pub struct $name {
+            core: TwofishCore
+        }
+
+        impl BlockCipher for $name {
+            const BLOCK_SIZE: usize = 16;
+            const KEY_SIZE: usize = $size;
+            type Block = [u8; 16];
+            type Key = $key;
+
+            fn new(key: &Self::Key) -> Self {
+                Self { core: TwofishCore::new(&key.0) }
+            }
+
+            fn encrypt_block(&self, block: &mut Self::Block) {
+                self.core.encrypt_block(block);
+            }
+
+            fn decrypt_block(&self, block: &mut Self::Block) {
+                self.core.decrypt_block(block);
+            }
+        }
+    };
+}
+
+twofish_impl!(Twofish128, Twofish128Key, 16, "Twofish with a 128-bit key.");
+twofish_impl!(Twofish192, Twofish192Key, 24, "Twofish with a 192-bit key.");
+twofish_impl!(Twofish256, Twofish256Key, 32, "Twofish with a 256-bit key.");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn q_tables() {
+        assert_eq!(&Q0[..4], &[0xa9, 0x67, 0xb3, 0xe8]);
+        assert_eq!(&Q1[..4], &[0x75, 0xf3, 0xc6, 0xf4]);
+    }
+
+    /// ecb_tbl.txt from the AES submission: each step keys with the previous plaintext
+    /// and encrypts the previous ciphertext, I=49 is the last entry.
+    fn ecb_tbl(key_size: usize) -> [u8; 16] {
+        let mut key = [0u8; 32];
+        let mut pt = [0u8; 16];
+        for _ in 0..49 {
+            let core = TwofishCore::new(&key[..key_size]);
+            let mut ct = pt;
+            core.encrypt_block(&mut ct);
+
+            let mut decrypted = ct;
+            core.decrypt_block(&mut decrypted);
+            assert_eq!(decrypted, pt);
+
+            key.copy_within(..key_size - 16, 16);
+            key[..16].copy_from_slice(&pt);
+            pt = ct;
+        }
+        pt
+    }
+
+    #[test]
+    fn ecb_tbl_128() {
+        let cipher = Twofish128::new(&Twofish128Key::default());
+        let mut block = [0u8; 16];
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, [0x9f, 0x58, 0x9f, 0x5c, 0xf6, 0x12, 0x2c, 0x32, 0xb6, 0xbf, 0xec, 0x2f, 0x2a, 0xe8, 0xc3, 0x5a]);
+
+        assert_eq!(ecb_tbl(16), [0x5d, 0x9d, 0x4e, 0xef, 0xfa, 0x91, 0x51, 0x57, 0x55, 0x24, 0xf1, 0x15, 0x81, 0x5a, 0x12, 0xe0]);
+    }
+
+    #[test]
+    fn ecb_tbl_192() {
+        assert_eq!(ecb_tbl(24), [0xe7, 0x54, 0x49, 0x21, 0x2b, 0xee, 0xf9, 0xf4, 0xa3, 0x90, 0xbd, 0x86, 0x0a, 0x64, 0x09, 0x41]);
+    }
+
+    #[test]
+    fn ecb_tbl_256() {
+        assert_eq!(ecb_tbl(32), [0x37, 0xfe, 0x26, 0xff, 0x1c, 0xf6, 0x61, 0x75, 0xf5, 0xdd, 0xf4, 0xc3, 0x3b, 0x97, 0xa2, 0x05]);
+    }
+}
@@ -3,5 +3,7 @@ pub enum SymcError {
     InvalidLength,
     InvalidInputLength,
     InvalidPadding,
-    BufferTooSmall
+    BufferTooSmall,
+    AuthenticationFailed,
+    CollisionDetected
 }
\ No newline at end of file
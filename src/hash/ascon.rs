@@ -0,0 +1,363 @@
+#[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128"))]
+use crate::hash::XofReader;
+#[cfg(feature = "ascon-xof128")]
+use crate::error::SymcError;
+#[cfg(feature = "ascon-xof128")]
+use crate::hash::ExtendableOutput;
+#[cfg(feature = "ascon-hash256")]
+use crate::hash::Hasher;
+#[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128"))]
+use zeroize::Zeroize;
+
+/// Round constants c_0..c_15 (SP 800-232, Table 5), p[rnd] uses the last `rnd` of them.
+const ROUND_CONSTANTS: [u64; 16] = [
+    0x3c, 0x2d, 0x1e, 0x0f, 0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69, 0x5a, 0x4b
+];
+
+/// Ascon-p[rounds] (SP 800-232, 3), state words are little-endian.
+pub(crate) fn ascon_p(s: &mut [u64; 5], rounds: usize) {
+    for &c in &ROUND_CONSTANTS[16 - rounds..] {
+        // p_C
+        s[2] ^= c;
+
+        // p_S, the 5-bit S-box applied bitsliced across the words
+        s[0] ^= s[4];
+        s[4] ^= s[3];
+        s[2] ^= s[1];
+        let t = [!s[0] & s[1], !s[1] & s[2], !s[2] & s[3], !s[3] & s[4], !s[4] & s[0]];
+        s[0] ^= t[1];
+        s[1] ^= t[2];
+        s[2] ^= t[3];
+        s[3] ^= t[4];
+        s[4] ^= t[0];
+        s[1] ^= s[0];
+        s[0] ^= s[4];
+        s[3] ^= s[2];
+        s[2] = !s[2];
+
+        // p_L
+        s[0] ^= s[0].rotate_right(19) ^ s[0].rotate_right(28);
+        s[1] ^= s[1].rotate_right(61) ^ s[1].rotate_right(39);
+        s[2] ^= s[2].rotate_right(1) ^ s[2].rotate_right(6);
+        s[3] ^= s[3].rotate_right(10) ^ s[3].rotate_right(17);
+        s[4] ^= s[4].rotate_right(7) ^ s[4].rotate_right(41);
+    }
+}
+
+/// The 10* padding of a partial `len` byte block, as a word to XOR in.
+#[inline]
+pub(crate) fn pad(len: usize) -> u64 {
+    1u64 << (8 * len)
+}
+
+#[cfg(feature = "ascon-hash256")]
+const HASH256_IV: u64 = 0x0000080100cc0002;
+#[cfg(feature = "ascon-xof128")]
+const XOF128_IV: u64 = 0x0000080000cc0003;
+#[cfg(feature = "ascon-xof128")]
+const CXOF128_IV: u64 = 0x0000080000cc0004;
+
+/// Longest CXOF128 customization string, 2048 bits.
+#[cfg(feature = "ascon-xof128")]
+pub const CXOF128_MAX_CUSTOMIZATION: usize = 256;
+
+/// Ascon sponge with an 8 byte rate in S0, shared by the hash and the XOFs (SP 800-232, 5).
+#[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128"))]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub(crate) struct AsconSponge {
+    state: [u64; 5],
+    pos: usize
+}
+
+#[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128"))]
+impl AsconSponge {
+    fn new(iv: u64) -> Self {
+        let mut state = [iv, 0, 0, 0, 0];
+        ascon_p(&mut state, 12);
+        Self { state, pos: 0 }
+    }
+
+    fn absorb(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            if self.pos == 0 && input.len() >= 8 {
+                self.state[0] ^= u64::from_le_bytes(input[..8].try_into().unwrap());
+                ascon_p(&mut self.state, 12);
+                input = &input[8..];
+                continue;
+            }
+
+            self.state[0] ^= (input[0] as u64) << (8 * self.pos);
+            self.pos += 1;
+            input = &input[1..];
+            if self.pos == 8 {
+                ascon_p(&mut self.state, 12);
+                self.pos = 0;
+            }
+        }
+    }
+
+    /// Pads the last block and switches to squeezing.
+    fn finalize(&mut self) {
+        self.state[0] ^= pad(self.pos);
+        ascon_p(&mut self.state, 12);
+        self.pos = 0;
+    }
+
+    fn squeeze(&mut self, output: &mut [u8]) {
+        for byte in output {
+            if self.pos == 8 {
+                ascon_p(&mut self.state, 12);
+                self.pos = 0;
+            }
+            *byte = (self.state[0] >> (8 * self.pos)) as u8;
+            self.pos += 1;
+        }
+    }
+}
+
+/// Squeezing half of a finished Ascon sponge.
+#[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128"))]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct AsconReader {
+    sponge: AsconSponge
+}
+
+#[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128"))]
+impl AsconReader {
+    fn new(mut sponge: AsconSponge) -> Self {
+        sponge.finalize();
+        Self { sponge }
+    }
+}
+
+#[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128"))]
+impl XofReader for AsconReader {
+    fn squeeze(&mut self, output: &mut [u8]) {
+        self.sponge.squeeze(output);
+    }
+}
+
+#[cfg(feature = "ascon-hash256")]
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct AsconHash256Output([u8; 32]);
+#[cfg(feature = "ascon-hash256")]
+impl AsRef<[u8]> for AsconHash256Output {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+#[cfg(feature = "ascon-hash256")]
+impl AsMut<[u8]> for AsconHash256Output {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+#[cfg(feature = "ascon-hash256")]
+impl From<[u8; 32]> for AsconHash256Output {
+    fn from(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+}
+#[cfg(feature = "ascon-hash256")]
+impl From<AsconHash256Output> for [u8; 32] {
+    fn from(output: AsconHash256Output) -> Self {
+        output.0
+    }
+}
+
+#[cfg(feature = "ascon-hash256")]
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct AsconBlock([u8; 8]);
+#[cfg(feature = "ascon-hash256")]
+impl AsRef<[u8]> for AsconBlock {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+#[cfg(feature = "ascon-hash256")]
+impl AsMut<[u8]> for AsconBlock {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Ascon-Hash256 (SP 800-232, 5.1)
+#[cfg(feature = "ascon-hash256")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct AsconHash256 {
+    sponge: AsconSponge
+}
+
+#[cfg(feature = "ascon-hash256")]
+impl Default for AsconHash256 {
+    fn default() -> Self {
+        Self { sponge: AsconSponge::new(HASH256_IV) }
+    }
+}
+
+#[cfg(feature = "ascon-hash256")]
+impl Hasher for AsconHash256 {
+    const BLOCK_SIZE: usize = 8;
+    const OUTPUT_SIZE: usize = 32;
+    type HashBlock = AsconBlock;
+    type Output = AsconHash256Output;
+
+    fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    fn finalize(self) -> Self::Output where Self: Sized {
+        let mut output = AsconHash256Output::default();
+        AsconReader::new(self.sponge.clone()).squeeze(&mut output.0);
+        output
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Ascon-XOF128 (SP 800-232, 5.2)
+#[cfg(feature = "ascon-xof128")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct AsconXof128 {
+    sponge: AsconSponge
+}
+
+#[cfg(feature = "ascon-xof128")]
+impl Default for AsconXof128 {
+    fn default() -> Self {
+        Self { sponge: AsconSponge::new(XOF128_IV) }
+    }
+}
+
+#[cfg(feature = "ascon-xof128")]
+impl ExtendableOutput for AsconXof128 {
+    const BLOCK_SIZE: usize = 8;
+    type Reader = AsconReader;
+
+    fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    fn finalize_xof(self) -> Self::Reader where Self: Sized {
+        AsconReader::new(self.sponge.clone())
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Ascon-CXOF128 (SP 800-232, 5.3), Ascon-XOF128 with a customization string.
+#[cfg(feature = "ascon-xof128")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct AsconCxof128 {
+    sponge: AsconSponge,
+    initial: AsconSponge
+}
+
+#[cfg(feature = "ascon-xof128")]
+impl AsconCxof128 {
+    /// `customization` may be at most [`CXOF128_MAX_CUSTOMIZATION`] bytes.
+    pub fn new(customization: &[u8]) -> Result<Self, SymcError> {
+        if customization.len() > CXOF128_MAX_CUSTOMIZATION {
+            return Err(SymcError::InvalidLength);
+        }
+
+        // Z_0 is the bit length of Z, then Z itself with its own padding
+        let mut sponge = AsconSponge::new(CXOF128_IV);
+        sponge.absorb(&(customization.len() as u64 * 8).to_le_bytes());
+        sponge.absorb(customization);
+        sponge.state[0] ^= pad(sponge.pos);
+        ascon_p(&mut sponge.state, 12);
+        sponge.pos = 0;
+
+        Ok(Self { initial: sponge.clone(), sponge })
+    }
+}
+
+#[cfg(feature = "ascon-xof128")]
+impl Default for AsconCxof128 {
+    fn default() -> Self {
+        Self::new(&[]).unwrap()
+    }
+}
+
+#[cfg(feature = "ascon-xof128")]
+impl ExtendableOutput for AsconCxof128 {
+    const BLOCK_SIZE: usize = 8;
+    type Reader = AsconReader;
+
+    fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    fn finalize_xof(self) -> Self::Reader where Self: Sized {
+        AsconReader::new(self.sponge.clone())
+    }
+
+    fn reset(&mut self) {
+        self.sponge = self.initial.clone();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// SP 800-232, Table 12
+    #[test]
+    fn test_initial_states() {
+        let mut state = [0x0000080100cc0002, 0, 0, 0, 0];
+        ascon_p(&mut state, 12);
+        assert_eq!(state, [
+            0x9b1e5494e934d681, 0x4bc3a01e333751d2, 0xae65396c6b34b81a, 0x3c7fd4a4d56a4db3, 0x1a5c464906c5976d
+        ]);
+    }
+
+    #[cfg(feature = "ascon-hash256")]
+    #[test]
+    fn test_hash256_split_update() {
+        let message: [u8; 41] = core::array::from_fn(|i| i as u8);
+        let mut whole = AsconHash256::default();
+        whole.update(&message);
+
+        let mut split = AsconHash256::default();
+        split.update(&message[..3]);
+        split.update(&message[3..20]);
+        split.update(&message[20..]);
+        assert_eq!(whole.finalize().0, split.finalize().0);
+    }
+
+    #[cfg(feature = "ascon-xof128")]
+    #[test]
+    fn test_cxof128_customization() {
+        assert!(AsconCxof128::new(&[0u8; CXOF128_MAX_CUSTOMIZATION]).is_ok());
+        assert!(AsconCxof128::new(&[0u8; CXOF128_MAX_CUSTOMIZATION + 1]).is_err());
+
+        let mut first = [0u8; 32];
+        let mut cxof = AsconCxof128::new(b"custom").unwrap();
+        cxof.update(b"message");
+        cxof.clone().finalize_xof().squeeze(&mut first);
+
+        let mut second = [0u8; 32];
+        cxof.reset();
+        cxof.update(b"message");
+        cxof.finalize_xof().squeeze(&mut second);
+        assert_eq!(first, second);
+
+        let mut other = [0u8; 32];
+        let mut cxof = AsconCxof128::new(b"Custom").unwrap();
+        cxof.update(b"message");
+        cxof.finalize_xof().squeeze(&mut other);
+        assert_ne!(first, other);
+    }
+}
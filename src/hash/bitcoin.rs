@@ -0,0 +1,242 @@
+use crate::hash::Hasher;
+use crate::hash::sha256::{Sha256, Sha256Block};
+use zeroize::Zeroize;
+
+#[cfg(any(feature = "sha256d", feature = "tagged-hash"))]
+use crate::hash::sha256::Sha256Output;
+#[cfg(feature = "hash160")]
+use crate::hash::ripemd160::{Ripemd160, Ripemd160Output};
+
+#[cfg(feature = "tagged-hash")]
+use core::marker::PhantomData;
+
+/// SHA-256 applied twice, as used for Bitcoin block and transaction ids.
+#[cfg(feature = "sha256d")]
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Sha256d {
+    inner: Sha256
+}
+
+#[cfg(feature = "sha256d")]
+impl Hasher for Sha256d {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 32;
+    type HashBlock = Sha256Block;
+    type Output = Sha256Output;
+
+    fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    fn finalize(self) -> Self::Output where Self: Sized {
+        let first = self.inner.clone().finalize();
+        Sha256::compute(first.as_ref())
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+/// RIPEMD-160 of SHA-256, as used for Bitcoin addresses.
+#[cfg(feature = "hash160")]
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Hash160 {
+    inner: Sha256
+}
+
+#[cfg(feature = "hash160")]
+impl Hasher for Hash160 {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 20;
+    type HashBlock = Sha256Block;
+    type Output = Ripemd160Output;
+
+    fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    fn finalize(self) -> Self::Output where Self: Sized {
+        let first = self.inner.clone().finalize();
+        Ripemd160::compute(first.as_ref())
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+/// BIP-340 tagged hash, `SHA-256(SHA-256(tag) || SHA-256(tag) || msg)`.
+///
+/// The 64-byte tag prefix is exactly one block, so it is compressed once up front and
+/// every message afterwards starts from that midstate.
+#[cfg(feature = "tagged-hash")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct TaggedHash {
+    inner: Sha256,
+    midstate: [u32; 8]
+}
+
+#[cfg(feature = "tagged-hash")]
+impl TaggedHash {
+    pub fn new(tag: &[u8]) -> Self {
+        let tag_hash = Sha256::compute(tag);
+        let mut inner = Sha256::default();
+        inner.update(tag_hash.as_ref());
+        inner.update(tag_hash.as_ref());
+        let midstate = inner.midstate();
+        TaggedHash { inner, midstate }
+    }
+
+    /// Starts from a midstate returned by `midstate`, skipping the tag prefix entirely.
+    pub fn from_midstate(midstate: [u32; 8]) -> Self {
+        TaggedHash { inner: Sha256::from_midstate(midstate, 1), midstate }
+    }
+
+    /// SHA-256 chaining value after the tag prefix, suitable for a `HashTag::MIDSTATE` constant.
+    pub fn midstate(&self) -> [u32; 8] {
+        self.midstate
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    pub fn finalize(self) -> Sha256Output {
+        self.inner.clone().finalize()
+    }
+
+    pub fn reset(&mut self) {
+        self.inner = Sha256::from_midstate(self.midstate, 1);
+    }
+
+    pub fn compute(tag: &[u8], input: &[u8]) -> Sha256Output {
+        let mut hasher = Self::new(tag);
+        hasher.update(input);
+        hasher.finalize()
+    }
+}
+
+/// A tag known at compile time, identified by its precomputed midstate.
+#[cfg(feature = "tagged-hash")]
+pub trait HashTag {
+    const MIDSTATE: [u32; 8];
+}
+
+/// Tagged hash for a compile-time tag, usable wherever a `Hasher` is expected.
+#[cfg(feature = "tagged-hash")]
+pub struct Tagged<T: HashTag> {
+    inner: Sha256,
+    tag: PhantomData<T>
+}
+
+#[cfg(feature = "tagged-hash")]
+impl<T: HashTag> Clone for Tagged<T> {
+    fn clone(&self) -> Self {
+        Tagged { inner: self.inner.clone(), tag: PhantomData }
+    }
+}
+
+#[cfg(feature = "tagged-hash")]
+impl<T: HashTag> Default for Tagged<T> {
+    fn default() -> Self {
+        Tagged { inner: Sha256::from_midstate(T::MIDSTATE, 1), tag: PhantomData }
+    }
+}
+
+#[cfg(feature = "tagged-hash")]
+impl<T: HashTag> Zeroize for Tagged<T> {
+    fn zeroize(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+#[cfg(feature = "tagged-hash")]
+impl<T: HashTag> Drop for Tagged<T> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "tagged-hash")]
+impl<T: HashTag> Hasher for Tagged<T> {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 32;
+    type HashBlock = Sha256Block;
+    type Output = Sha256Output;
+
+    fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    fn finalize(self) -> Self::Output where Self: Sized {
+        self.inner.clone().finalize()
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// "BIP0340/challenge"
+#[cfg(feature = "tagged-hash")]
+pub struct Bip340Challenge;
+#[cfg(feature = "tagged-hash")]
+impl HashTag for Bip340Challenge {
+    const MIDSTATE: [u32; 8] = [
+        0x9cecba11, 0x23925381, 0x11679112, 0xd1627e0f,
+        0x97c87550, 0x003cc765, 0x90f61164, 0x33e9b66a
+    ];
+}
+
+/// "BIP0340/aux"
+#[cfg(feature = "tagged-hash")]
+pub struct Bip340Aux;
+#[cfg(feature = "tagged-hash")]
+impl HashTag for Bip340Aux {
+    const MIDSTATE: [u32; 8] = [
+        0x24dd3219, 0x4eba7e70, 0xca0fabb9, 0x0fa3166d,
+        0x3afbe4b1, 0x4c44df97, 0x4aac2739, 0x249e850a
+    ];
+}
+
+/// "BIP0340/nonce"
+#[cfg(feature = "tagged-hash")]
+pub struct Bip340Nonce;
+#[cfg(feature = "tagged-hash")]
+impl HashTag for Bip340Nonce {
+    const MIDSTATE: [u32; 8] = [
+        0x46615b35, 0xf4bfbff7, 0x9f8dc671, 0x83627ab3,
+        0x60217180, 0x57358661, 0x21a29e54, 0x68b07b4c
+    ];
+}
+
+#[cfg(feature = "tagged-hash")]
+pub type Bip340ChallengeHash = Tagged<Bip340Challenge>;
+#[cfg(feature = "tagged-hash")]
+pub type Bip340AuxHash = Tagged<Bip340Aux>;
+#[cfg(feature = "tagged-hash")]
+pub type Bip340NonceHash = Tagged<Bip340Nonce>;
+
+#[cfg(all(test, feature = "tagged-hash"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bip340_midstates() {
+        assert_eq!(TaggedHash::new(b"BIP0340/challenge").midstate(), Bip340Challenge::MIDSTATE);
+        assert_eq!(TaggedHash::new(b"BIP0340/aux").midstate(), Bip340Aux::MIDSTATE);
+        assert_eq!(TaggedHash::new(b"BIP0340/nonce").midstate(), Bip340Nonce::MIDSTATE);
+    }
+
+    #[test]
+    fn test_tagged_hash_from_midstate() {
+        let tagged = TaggedHash::new(b"some tag");
+        let mut resumed = TaggedHash::from_midstate(tagged.midstate());
+        resumed.update(b"msg");
+        assert_eq!(resumed.finalize().as_ref(), TaggedHash::compute(b"some tag", b"msg").as_ref());
+    }
+}
@@ -0,0 +1,552 @@
+use crate::error::SymcError;
+use crate::hash::Hasher;
+use zeroize::Zeroize;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+const SIGMA: [[usize; 16]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15],
+    [14, 10, 4, 8, 9, 15, 13, 6, 1, 12, 0, 2, 11, 7, 5, 3],
+    [11, 8, 12, 0, 5, 2, 15, 13, 10, 14, 3, 6, 7, 1, 9, 4],
+    [7, 9, 3, 1, 13, 12, 11, 14, 2, 6, 5, 10, 4, 0, 15, 8],
+    [9, 0, 5, 7, 2, 4, 10, 15, 14, 1, 11, 12, 6, 8, 3, 13],
+    [2, 12, 6, 10, 0, 11, 8, 3, 4, 13, 7, 5, 15, 14, 1, 9],
+    [12, 5, 1, 15, 14, 13, 4, 10, 0, 7, 6, 3, 9, 2, 8, 11],
+    [13, 11, 7, 14, 12, 1, 3, 9, 5, 0, 15, 4, 8, 6, 2, 10],
+    [6, 15, 14, 9, 11, 3, 0, 8, 12, 2, 13, 7, 1, 4, 10, 5],
+    [10, 2, 8, 4, 7, 6, 1, 5, 15, 11, 9, 14, 3, 12, 13, 0],
+];
+
+#[cfg(feature = "blake2b")]
+const BLAKE2B_IV: [u64; 8] = [
+    0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+    0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+];
+
+#[cfg(feature = "blake2s")]
+const BLAKE2S_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+// BLAKE2b and BLAKE2s (RFC 7693) differ only in word size, rounds, rotations and the parameter block layout.
+// The parameter block is `digest length, key length, fanout, depth, leaf length, node offset, node depth,
+// inner length, [reserved,] salt, personalization`, little-endian and XORed into the IV.
+macro_rules! blake2_impl {
+    (
+        $var:ident, $params:ident, $hasher:ident, $output:ident, $block:ident,
+        $word:ty, $counter:ty, $iv:ident, $rounds:expr, $rotations:expr,
+        $block_size:expr, $max_output:expr, $salt_size:expr, $offset_size:expr, $doc:expr
+    ) => {
+        /// Parameter block builder, lengths are checked by `build`.
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $params {
+            output_size: usize,
+            key: [u8; $max_output],
+            key_len: usize,
+            salt: [u8; $salt_size],
+            salt_len: usize,
+            personal: [u8; $salt_size],
+            personal_len: usize,
+            fanout: u8,
+            max_depth: u8,
+            leaf_length: u32,
+            node_offset: u64,
+            node_depth: u8,
+            inner_length: u8,
+            last_node: bool
+        }
+
+        impl Default for $params {
+            fn default() -> Self {
+                Self {
+                    output_size: $max_output,
+                    key: [0u8; $max_output],
+                    key_len: 0,
+                    salt: [0u8; $salt_size],
+                    salt_len: 0,
+                    personal: [0u8; $salt_size],
+                    personal_len: 0,
+                    fanout: 1,
+                    max_depth: 1,
+                    leaf_length: 0,
+                    node_offset: 0,
+                    node_depth: 0,
+                    inner_length: 0,
+                    last_node: false
+                }
+            }
+        }
+
+        impl $params {
+            pub const MAX_OUTPUT_SIZE: usize = $max_output;
+            pub const MAX_KEY_SIZE: usize = $max_output;
+            pub const SALT_SIZE: usize = $salt_size;
+            pub const PERSONAL_SIZE: usize = $salt_size;
+
+            /// Sequential mode with the maximum output size, no key, salt or personalization.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            pub fn output_size(mut self, output_size: usize) -> Self {
+                self.output_size = output_size;
+                self
+            }
+
+            pub fn key(mut self, key: &[u8]) -> Self {
+                self.key = [0u8; $max_output];
+                self.key_len = key.len();
+                let len = key.len().min($max_output);
+                self.key[..len].copy_from_slice(&key[..len]);
+                self
+            }
+
+            /// Salts shorter than `SALT_SIZE` are zero padded.
+            pub fn salt(mut self, salt: &[u8]) -> Self {
+                self.salt = [0u8; $salt_size];
+                self.salt_len = salt.len();
+                let len = salt.len().min($salt_size);
+                self.salt[..len].copy_from_slice(&salt[..len]);
+                self
+            }
+
+            /// Personalization strings shorter than `PERSONAL_SIZE` are zero padded.
+            pub fn personal(mut self, personal: &[u8]) -> Self {
+                self.personal = [0u8; $salt_size];
+                self.personal_len = personal.len();
+                let len = personal.len().min($salt_size);
+                self.personal[..len].copy_from_slice(&personal[..len]);
+                self
+            }
+
+            pub fn fanout(mut self, fanout: u8) -> Self {
+                self.fanout = fanout;
+                self
+            }
+
+            pub fn max_depth(mut self, max_depth: u8) -> Self {
+                self.max_depth = max_depth;
+                self
+            }
+
+            pub fn leaf_length(mut self, leaf_length: u32) -> Self {
+                self.leaf_length = leaf_length;
+                self
+            }
+
+            pub fn node_offset(mut self, node_offset: u64) -> Self {
+                self.node_offset = node_offset;
+                self
+            }
+
+            pub fn node_depth(mut self, node_depth: u8) -> Self {
+                self.node_depth = node_depth;
+                self
+            }
+
+            pub fn inner_length(mut self, inner_length: u8) -> Self {
+                self.inner_length = inner_length;
+                self
+            }
+
+            /// Marks the rightmost node of a tree level, it sets the last node flag f1 on the final block.
+            pub fn last_node(mut self, last_node: bool) -> Self {
+                self.last_node = last_node;
+                self
+            }
+
+            pub fn build(&self) -> Result<$var, SymcError> {
+                if self.output_size == 0 || self.output_size > $max_output
+                    || self.key_len > $max_output
+                    || self.salt_len > $salt_size
+                    || self.personal_len > $salt_size
+                    || self.max_depth == 0
+                    || self.inner_length as usize > $max_output
+                    || ($offset_size < 8 && self.node_offset >> (8 * $offset_size) != 0) {
+                    return Err(SymcError::InvalidLength);
+                }
+                Ok($var::from_params(self))
+            }
+
+            fn initial_state(&self) -> [$word; 8] {
+                const WORD_SIZE: usize = core::mem::size_of::<$word>();
+                let mut block = [0u8; 8 * WORD_SIZE];
+                block[0] = self.output_size as u8;
+                block[1] = self.key_len as u8;
+                block[2] = self.fanout;
+                block[3] = self.max_depth;
+                block[4..8].copy_from_slice(&self.leaf_length.to_le_bytes());
+                block[8..8 + $offset_size].copy_from_slice(&self.node_offset.to_le_bytes()[..$offset_size]);
+                block[8 + $offset_size] = self.node_depth;
+                block[9 + $offset_size] = self.inner_length;
+                block[8 * WORD_SIZE - 2 * $salt_size..8 * WORD_SIZE - $salt_size].copy_from_slice(&self.salt);
+                block[8 * WORD_SIZE - $salt_size..].copy_from_slice(&self.personal);
+
+                let mut state = $iv;
+                for (i, word) in state.iter_mut().enumerate() {
+                    *word ^= <$word>::from_le_bytes(block[i * WORD_SIZE..(i + 1) * WORD_SIZE].try_into().unwrap());
+                }
+                state
+            }
+        }
+
+        #[doc = $doc]
+        ///
+        /// Output size is chosen at runtime, see the fixed size hashers for `Hasher`.
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $var {
+            state: [$word; 8],
+            counter: $counter,
+            buffer: [u8; $block_size],
+            buffer_len: usize,
+            output_size: usize,
+            last_node: bool,
+            initial: [$word; 8],
+            key_block: [u8; $block_size],
+            keyed: bool
+        }
+
+        impl $var {
+            pub const BLOCK_SIZE: usize = $block_size;
+
+            pub fn new(output_size: usize) -> Result<Self, SymcError> {
+                $params::new().output_size(output_size).build()
+            }
+
+            /// Keyed hashing (RFC 7693, 2.5), the key is processed as a zero padded first block.
+            pub fn new_keyed(key: &[u8], output_size: usize) -> Result<Self, SymcError> {
+                $params::new().output_size(output_size).key(key).build()
+            }
+
+            fn from_params(params: &$params) -> Self {
+                let mut key_block = [0u8; $block_size];
+                key_block[..params.key_len].copy_from_slice(&params.key[..params.key_len]);
+
+                let mut hasher = Self {
+                    state: [0; 8],
+                    counter: 0,
+                    buffer: [0u8; $block_size],
+                    buffer_len: 0,
+                    output_size: params.output_size,
+                    last_node: params.last_node,
+                    initial: params.initial_state(),
+                    key_block,
+                    keyed: params.key_len > 0
+                };
+                hasher.reset();
+                hasher
+            }
+
+            pub fn output_size(&self) -> usize {
+                self.output_size
+            }
+
+            pub fn update(&mut self, mut input: &[u8]) {
+                while !input.is_empty() {
+                    // the last block is compressed with the final flag, so a full buffer waits for more input
+                    if self.buffer_len == $block_size {
+                        self.counter = self.counter.wrapping_add($block_size);
+                        Self::compress(&mut self.state, &self.buffer, self.counter, false, false);
+                        self.buffer_len = 0;
+                    }
+
+                    let take = ($block_size - self.buffer_len).min(input.len());
+                    self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&input[..take]);
+                    self.buffer_len += take;
+                    input = &input[take..];
+                }
+            }
+
+            /// `output` must be exactly `output_size()` bytes.
+            pub fn finalize_into(mut self, output: &mut [u8]) -> Result<(), SymcError> {
+                self.finalize_inner(output)
+            }
+
+            #[cfg(feature = "alloc")]
+            pub fn finalize_vec(mut self) -> Vec<u8> {
+                let mut output = alloc::vec![0u8; self.output_size];
+                // the buffer always matches output_size
+                let _ = self.finalize_inner(&mut output);
+                output
+            }
+
+            pub fn reset(&mut self) {
+                self.state = self.initial;
+                self.counter = 0;
+                if self.keyed {
+                    self.buffer = self.key_block;
+                    self.buffer_len = $block_size;
+                } else {
+                    self.buffer = [0u8; $block_size];
+                    self.buffer_len = 0;
+                }
+            }
+
+            pub(crate) fn finalize_inner(&mut self, output: &mut [u8]) -> Result<(), SymcError> {
+                const WORD_SIZE: usize = core::mem::size_of::<$word>();
+                if output.len() != self.output_size {
+                    return Err(SymcError::InvalidLength);
+                }
+
+                self.counter = self.counter.wrapping_add(self.buffer_len as $counter);
+                self.buffer[self.buffer_len..].fill(0);
+                Self::compress(&mut self.state, &self.buffer, self.counter, true, self.last_node);
+
+                let mut digest = [0u8; 8 * WORD_SIZE];
+                for (chunk, word) in digest.chunks_exact_mut(WORD_SIZE).zip(self.state.iter()) {
+                    chunk.copy_from_slice(&word.to_le_bytes());
+                }
+                output.copy_from_slice(&digest[..self.output_size]);
+                digest.zeroize();
+                Ok(())
+            }
+
+            fn compress(state: &mut [$word; 8], block: &[u8; $block_size], counter: $counter, last: bool, last_node: bool) {
+                const WORD_SIZE: usize = core::mem::size_of::<$word>();
+                const ROTATIONS: [u32; 4] = $rotations;
+
+                let mut m = [0 as $word; 16];
+                for (i, word) in m.iter_mut().enumerate() {
+                    *word = <$word>::from_le_bytes(block[i * WORD_SIZE..(i + 1) * WORD_SIZE].try_into().unwrap());
+                }
+
+                let mut v = [0 as $word; 16];
+                v[..8].copy_from_slice(state);
+                v[8..].copy_from_slice(&$iv);
+                v[12] ^= counter as $word;
+                v[13] ^= (counter >> (8 * WORD_SIZE)) as $word;
+                if last {
+                    v[14] = !v[14];
+                    if last_node {
+                        v[15] = !v[15];
+                    }
+                }
+
+                let g = |v: &mut [$word; 16], a: usize, b: usize, c: usize, d: usize, x: $word, y: $word| {
+                    v[a] = v[a].wrapping_add(v[b]).wrapping_add(x);
+                    v[d] = (v[d] ^ v[a]).rotate_right(ROTATIONS[0]);
+                    v[c] = v[c].wrapping_add(v[d]);
+                    v[b] = (v[b] ^ v[c]).rotate_right(ROTATIONS[1]);
+                    v[a] = v[a].wrapping_add(v[b]).wrapping_add(y);
+                    v[d] = (v[d] ^ v[a]).rotate_right(ROTATIONS[2]);
+                    v[c] = v[c].wrapping_add(v[d]);
+                    v[b] = (v[b] ^ v[c]).rotate_right(ROTATIONS[3]);
+                };
+
+                for round in 0..$rounds {
+                    let s = &SIGMA[round % 10];
+                    g(&mut v, 0, 4, 8, 12, m[s[0]], m[s[1]]);
+                    g(&mut v, 1, 5, 9, 13, m[s[2]], m[s[3]]);
+                    g(&mut v, 2, 6, 10, 14, m[s[4]], m[s[5]]);
+                    g(&mut v, 3, 7, 11, 15, m[s[6]], m[s[7]]);
+                    g(&mut v, 0, 5, 10, 15, m[s[8]], m[s[9]]);
+                    g(&mut v, 1, 6, 11, 12, m[s[10]], m[s[11]]);
+                    g(&mut v, 2, 7, 8, 13, m[s[12]], m[s[13]]);
+                    g(&mut v, 3, 4, 9, 14, m[s[14]], m[s[15]]);
+                }
+
+                for i in 0..8 {
+                    state[i] ^= v[i] ^ v[i + 8];
+                }
+                m.zeroize();
+                v.zeroize();
+            }
+        }
+
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $output<const N: usize>([u8; N]);
+        impl<const N: usize> Default for $output<N> {
+            fn default() -> Self {
+                Self([0u8; N])
+            }
+        }
+        impl<const N: usize> AsRef<[u8]> for $output<N> {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl<const N: usize> AsMut<[u8]> for $output<N> {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl<const N: usize> From<[u8; N]> for $output<N> {
+            fn from(array: [u8; N]) -> Self {
+                Self(array)
+            }
+        }
+        impl<const N: usize> From<$output<N>> for [u8; N] {
+            fn from(output: $output<N>) -> Self {
+                output.0
+            }
+        }
+
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $block([u8; $block_size]);
+        impl Default for $block {
+            fn default() -> Self {
+                Self([0u8; $block_size])
+            }
+        }
+        impl AsRef<[u8]> for $block {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $block {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $block_size]> for $block {
+            fn from(array: [u8; $block_size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$block> for [u8; $block_size] {
+            fn from(block: $block) -> Self {
+                block.0
+            }
+        }
+
+        #[doc = $doc]
+        ///
+        /// `N` byte output, checked at compile time.
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $hasher<const N: usize> {
+            core: $var
+        }
+
+        impl<const N: usize> $hasher<N> {
+            const VALID_OUTPUT_SIZE: () = assert!(N >= 1 && N <= $max_output, "unsupported BLAKE2 output size");
+
+            /// Salted, personalized or tree hashing, the output size of `params` is replaced by `N`.
+            pub fn with_params(params: &$params) -> Result<Self, SymcError> {
+                let () = Self::VALID_OUTPUT_SIZE;
+                Ok(Self { core: params.clone().output_size(N).build()? })
+            }
+        }
+
+        impl<const N: usize> Default for $hasher<N> {
+            fn default() -> Self {
+                let () = Self::VALID_OUTPUT_SIZE;
+                Self { core: $var::from_params(&$params::new().output_size(N)) }
+            }
+        }
+
+        impl<const N: usize> Hasher for $hasher<N> {
+            const BLOCK_SIZE: usize = $block_size;
+            const OUTPUT_SIZE: usize = N;
+            type HashBlock = $block;
+            type Output = $output<N>;
+
+            fn update(&mut self, input: &[u8]) {
+                self.core.update(input);
+            }
+
+            fn finalize(mut self) -> Self::Output where Self: Sized {
+                let mut output = $output::default();
+                // N is the configured output size
+                let _ = self.core.finalize_inner(&mut output.0);
+                output
+            }
+
+            fn reset(&mut self) {
+                self.core.reset();
+            }
+        }
+    };
+}
+
+#[cfg(feature = "blake2b")]
+blake2_impl!(
+    Blake2bVar, Blake2bParams, Blake2b, Blake2bOutput, Blake2bBlock,
+    u64, u128, BLAKE2B_IV, 12, [32, 24, 16, 63],
+    128, 64, 16, 8, "BLAKE2b (RFC 7693)"
+);
+
+#[cfg(feature = "blake2s")]
+blake2_impl!(
+    Blake2sVar, Blake2sParams, Blake2s, Blake2sOutput, Blake2sBlock,
+    u32, u64, BLAKE2S_IV, 10, [16, 12, 8, 7],
+    64, 32, 8, 6, "BLAKE2s (RFC 7693)"
+);
+
+#[cfg(feature = "blake2b")]
+pub type Blake2b512 = Blake2b<64>;
+#[cfg(feature = "blake2b")]
+pub type Blake2b384 = Blake2b<48>;
+#[cfg(feature = "blake2b")]
+pub type Blake2b256 = Blake2b<32>;
+#[cfg(feature = "blake2b")]
+pub type Blake2b160 = Blake2b<20>;
+
+#[cfg(feature = "blake2s")]
+pub type Blake2s256 = Blake2s<32>;
+#[cfg(feature = "blake2s")]
+pub type Blake2s224 = Blake2s<28>;
+#[cfg(feature = "blake2s")]
+pub type Blake2s160 = Blake2s<20>;
+#[cfg(feature = "blake2s")]
+pub type Blake2s128 = Blake2s<16>;
+
+#[cfg(all(test, feature = "blake2b", feature = "blake2s"))]
+mod test {
+    use super::*;
+
+    /// Test vectors from RFC 7693, Appendix A and B
+    #[test]
+    fn test_blake2_rfc7693_abc() {
+        assert_eq!(Blake2b512::compute(b"abc").as_ref(), &[
+            0xba, 0x80, 0xa5, 0x3f, 0x98, 0x1c, 0x4d, 0x0d, 0x6a, 0x27, 0x97, 0xb6, 0x9f, 0x12, 0xf6, 0xe9,
+            0x4c, 0x21, 0x2f, 0x14, 0x68, 0x5a, 0xc4, 0xb7, 0x4b, 0x12, 0xbb, 0x6f, 0xdb, 0xff, 0xa2, 0xd1,
+            0x7d, 0x87, 0xc5, 0x39, 0x2a, 0xab, 0x79, 0x2d, 0xc2, 0x52, 0xd5, 0xde, 0x45, 0x33, 0xcc, 0x95,
+            0x18, 0xd3, 0x8a, 0xa8, 0xdb, 0xf1, 0x92, 0x5a, 0xb9, 0x23, 0x86, 0xed, 0xd4, 0x00, 0x99, 0x23,
+        ]);
+        assert_eq!(Blake2s256::compute(b"abc").as_ref(), &[
+            0x50, 0x8c, 0x5e, 0x8c, 0x32, 0x7c, 0x14, 0xe2, 0xe1, 0xa7, 0x2b, 0xa3, 0x4e, 0xeb, 0x45, 0x2f,
+            0x37, 0x45, 0x8b, 0x20, 0x9e, 0xd6, 0x3a, 0x29, 0x4d, 0x99, 0x9b, 0x4c, 0x86, 0x67, 0x59, 0x82,
+        ]);
+    }
+
+    #[test]
+    fn test_blake2_params_validation() {
+        assert_eq!(Blake2bParams::new().output_size(0).build().err(), Some(SymcError::InvalidLength));
+        assert_eq!(Blake2bParams::new().output_size(65).build().err(), Some(SymcError::InvalidLength));
+        assert_eq!(Blake2bParams::new().key(&[0u8; 65]).build().err(), Some(SymcError::InvalidLength));
+        assert_eq!(Blake2bParams::new().salt(&[0u8; 17]).build().err(), Some(SymcError::InvalidLength));
+        assert_eq!(Blake2sParams::new().personal(&[0u8; 9]).build().err(), Some(SymcError::InvalidLength));
+        assert_eq!(Blake2sParams::new().node_offset(1 << 48).build().err(), Some(SymcError::InvalidLength));
+        assert!(Blake2bParams::new().node_offset(u64::MAX).build().is_ok());
+    }
+
+    #[test]
+    fn test_blake2_finalize_length() {
+        let hasher = Blake2bVar::new(20).unwrap();
+        assert_eq!(hasher.clone().finalize_into(&mut [0u8; 32]), Err(SymcError::InvalidLength));
+        assert!(hasher.finalize_into(&mut [0u8; 20]).is_ok());
+    }
+
+    #[test]
+    fn test_blake2_keyed_reset() {
+        let mut hasher = Blake2sVar::new_keyed(b"key", 32).unwrap();
+        hasher.update(b"discarded");
+        hasher.reset();
+        hasher.update(b"message");
+
+        let mut fresh = Blake2sVar::new_keyed(b"key", 32).unwrap();
+        fresh.update(b"message");
+
+        let mut output1 = [0u8; 32];
+        let mut output2 = [0u8; 32];
+        hasher.finalize_into(&mut output1).unwrap();
+        fresh.finalize_into(&mut output2).unwrap();
+        assert_eq!(output1, output2);
+    }
+}
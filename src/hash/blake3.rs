@@ -0,0 +1,585 @@
+use crate::error::SymcError;
+use crate::hash::{Hasher, XofReader};
+use crate::mac::constant_time_eq;
+use zeroize::Zeroize;
+
+const IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+const MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+const BLOCK_LEN: usize = 64;
+pub const BLAKE3_CHUNK_LEN: usize = 1024;
+
+const CHUNK_START: u32 = 1 << 0;
+const CHUNK_END: u32 = 1 << 1;
+const PARENT: u32 = 1 << 2;
+const ROOT: u32 = 1 << 3;
+const KEYED_HASH: u32 = 1 << 4;
+const DERIVE_KEY_CONTEXT: u32 = 1 << 5;
+const DERIVE_KEY_MATERIAL: u32 = 1 << 6;
+
+// enough for 2^64 bytes of input
+const MAX_DEPTH: usize = 54;
+
+#[cfg(feature = "std")]
+const PARALLEL_BATCH: usize = 64;
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Blake3Output([u8; 32]);
+impl AsRef<[u8]> for Blake3Output {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Blake3Output {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 32]> for Blake3Output {
+    fn from(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+}
+impl From<Blake3Output> for [u8; 32] {
+    fn from(output: Blake3Output) -> Self {
+        output.0
+    }
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Blake3Block([u8; 64]);
+impl Default for Blake3Block {
+    fn default() -> Self {
+        Self([0u8; 64])
+    }
+}
+impl AsRef<[u8]> for Blake3Block {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Blake3Block {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 64]> for Blake3Block {
+    fn from(array: [u8; 64]) -> Self {
+        Self(array)
+    }
+}
+impl From<Blake3Block> for [u8; 64] {
+    fn from(block: Blake3Block) -> Self {
+        block.0
+    }
+}
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Blake3Key([u8; 32]);
+impl AsRef<[u8]> for Blake3Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Blake3Key {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 32]> for Blake3Key {
+    fn from(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+}
+
+/// Chaining value of a chunk or subtree that is not the root of the tree.
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Blake3ChainingValue([u8; 32]);
+impl AsRef<[u8]> for Blake3ChainingValue {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Blake3ChainingValue {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 32]> for Blake3ChainingValue {
+    fn from(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+}
+impl From<Blake3ChainingValue> for [u8; 32] {
+    fn from(cv: Blake3ChainingValue) -> Self {
+        cv.0
+    }
+}
+
+fn words_from_le_bytes<const N: usize>(bytes: &[u8]) -> [u32; N] {
+    let mut words = [0u32; N];
+    for (word, chunk) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes(chunk.try_into().unwrap());
+    }
+    words
+}
+
+fn words_to_le_bytes(words: &[u32], bytes: &mut [u8]) {
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(words) {
+        chunk.copy_from_slice(&word.to_le_bytes());
+    }
+}
+
+fn g(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, x: u32, y: u32) {
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(x);
+    state[d] = (state[d] ^ state[a]).rotate_right(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(12);
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(y);
+    state[d] = (state[d] ^ state[a]).rotate_right(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(7);
+}
+
+fn compress(cv: &[u32; 8], block_words: &[u32; 16], counter: u64, block_len: u32, flags: u32) -> [u32; 16] {
+    let mut state = [
+        cv[0], cv[1], cv[2], cv[3], cv[4], cv[5], cv[6], cv[7],
+        IV[0], IV[1], IV[2], IV[3], counter as u32, (counter >> 32) as u32, block_len, flags,
+    ];
+    let mut m = *block_words;
+
+    for round in 0..7 {
+        g(&mut state, 0, 4, 8, 12, m[0], m[1]);
+        g(&mut state, 1, 5, 9, 13, m[2], m[3]);
+        g(&mut state, 2, 6, 10, 14, m[4], m[5]);
+        g(&mut state, 3, 7, 11, 15, m[6], m[7]);
+        g(&mut state, 0, 5, 10, 15, m[8], m[9]);
+        g(&mut state, 1, 6, 11, 12, m[10], m[11]);
+        g(&mut state, 2, 7, 8, 13, m[12], m[13]);
+        g(&mut state, 3, 4, 9, 14, m[14], m[15]);
+        if round < 6 {
+            m = core::array::from_fn(|i| m[MSG_PERMUTATION[i]]);
+        }
+    }
+
+    for i in 0..8 {
+        state[i] ^= state[i + 8];
+        state[i + 8] ^= cv[i];
+    }
+    m.zeroize();
+    state
+}
+
+/// Inputs of the last compression of a node, kept back until it is known whether the node is the root.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct Output {
+    input_cv: [u32; 8],
+    block_words: [u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32
+}
+
+impl Output {
+    fn chaining_value(&self) -> [u32; 8] {
+        let state = compress(&self.input_cv, &self.block_words, self.counter, self.block_len, self.flags);
+        core::array::from_fn(|i| state[i])
+    }
+
+    fn root_output_block(&self, output_block_counter: u64) -> [u8; 64] {
+        let mut state = compress(&self.input_cv, &self.block_words, output_block_counter, self.block_len, self.flags | ROOT);
+        let mut block = [0u8; 64];
+        words_to_le_bytes(&state, &mut block);
+        state.zeroize();
+        block
+    }
+}
+
+fn parent_output(left: &[u32; 8], right: &[u32; 8], key: &[u32; 8], flags: u32) -> Output {
+    let mut block_words = [0u32; 16];
+    block_words[..8].copy_from_slice(left);
+    block_words[8..].copy_from_slice(right);
+    Output { input_cv: *key, block_words, counter: 0, block_len: BLOCK_LEN as u32, flags: PARENT | flags }
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+struct ChunkState {
+    cv: [u32; 8],
+    chunk_counter: u64,
+    block: [u8; BLOCK_LEN],
+    block_len: usize,
+    blocks_compressed: usize,
+    flags: u32
+}
+
+impl ChunkState {
+    fn new(key: &[u32; 8], chunk_counter: u64, flags: u32) -> Self {
+        Self { cv: *key, chunk_counter, block: [0u8; BLOCK_LEN], block_len: 0, blocks_compressed: 0, flags }
+    }
+
+    fn len(&self) -> usize {
+        BLOCK_LEN * self.blocks_compressed + self.block_len
+    }
+
+    fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 { CHUNK_START } else { 0 }
+    }
+
+    fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            // the last block of a chunk gets CHUNK_END, so a full block waits for more input
+            if self.block_len == BLOCK_LEN {
+                let block_words = words_from_le_bytes(&self.block);
+                let state = compress(&self.cv, &block_words, self.chunk_counter, BLOCK_LEN as u32, self.flags | self.start_flag());
+                self.cv = core::array::from_fn(|i| state[i]);
+                self.blocks_compressed += 1;
+                self.block = [0u8; BLOCK_LEN];
+                self.block_len = 0;
+            }
+
+            let take = (BLOCK_LEN - self.block_len).min(input.len());
+            self.block[self.block_len..self.block_len + take].copy_from_slice(&input[..take]);
+            self.block_len += take;
+            input = &input[take..];
+        }
+    }
+
+    fn output(&self) -> Output {
+        Output {
+            input_cv: self.cv,
+            block_words: words_from_le_bytes(&self.block),
+            counter: self.chunk_counter,
+            block_len: self.block_len as u32,
+            flags: self.flags | self.start_flag() | CHUNK_END
+        }
+    }
+}
+
+/// BLAKE3 with 32 byte output through `Hasher`, any length through `finalize_xof`.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Blake3 {
+    key: [u32; 8],
+    chunk_state: ChunkState,
+    cv_stack: [[u32; 8]; MAX_DEPTH],
+    cv_stack_len: usize,
+    flags: u32,
+    chunk_offset: u64
+}
+
+impl Default for Blake3 {
+    fn default() -> Self {
+        Self::with_key_words(IV, 0)
+    }
+}
+
+impl Blake3 {
+    fn with_key_words(key: [u32; 8], flags: u32) -> Self {
+        Self {
+            key,
+            chunk_state: ChunkState::new(&key, 0, flags),
+            cv_stack: [[0u32; 8]; MAX_DEPTH],
+            cv_stack_len: 0,
+            flags,
+            chunk_offset: 0
+        }
+    }
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn new_keyed(key: &Blake3Key) -> Self {
+        Self::with_key_words(words_from_le_bytes(&key.0), KEYED_HASH)
+    }
+
+    /// Key derivation mode, `context` should be a hardcoded, globally unique string.
+    pub fn new_derive_key(context: &str) -> Self {
+        let mut context_hasher = Self::with_key_words(IV, DERIVE_KEY_CONTEXT);
+        context_hasher.update(context.as_bytes());
+        let mut context_key = context_hasher.root_output().root_output_block(0);
+        let key_words = words_from_le_bytes(&context_key[..32]);
+        context_key.zeroize();
+        Self::with_key_words(key_words, DERIVE_KEY_MATERIAL)
+    }
+
+    pub fn keyed_hash(key: &Blake3Key, input: &[u8]) -> Blake3Output {
+        let mut hasher = Self::new_keyed(key);
+        hasher.update(input);
+        hasher.finalize()
+    }
+
+    pub fn derive_key(context: &str, key_material: &[u8]) -> Blake3Output {
+        let mut hasher = Self::new_derive_key(context);
+        hasher.update(key_material);
+        hasher.finalize()
+    }
+
+    fn push_cv(&mut self, mut new_cv: [u32; 8], mut total_chunks: u64) {
+        // every completed subtree is merged as soon as its sibling is known
+        while total_chunks & 1 == 0 {
+            self.cv_stack_len -= 1;
+            new_cv = parent_output(&self.cv_stack[self.cv_stack_len], &new_cv, &self.key, self.flags).chaining_value();
+            total_chunks >>= 1;
+        }
+        self.cv_stack[self.cv_stack_len] = new_cv;
+        self.cv_stack_len += 1;
+    }
+
+    fn finish_chunk(&mut self) {
+        let cv = self.chunk_state.output().chaining_value();
+        let next_chunk = self.chunk_state.chunk_counter + 1;
+        self.push_cv(cv, next_chunk - self.chunk_offset);
+        self.chunk_state = ChunkState::new(&self.key, next_chunk, self.flags);
+    }
+
+    fn root_output(&self) -> Output {
+        let mut output = self.chunk_state.output();
+        for cv in self.cv_stack[..self.cv_stack_len].iter().rev() {
+            output = parent_output(cv, &output.chaining_value(), &self.key, self.flags);
+        }
+        output
+    }
+
+    pub fn update(&mut self, mut input: &[u8]) {
+        while !input.is_empty() {
+            if self.chunk_state.len() == BLAKE3_CHUNK_LEN {
+                self.finish_chunk();
+            }
+
+            let take = (BLAKE3_CHUNK_LEN - self.chunk_state.len()).min(input.len());
+            self.chunk_state.update(&input[..take]);
+            input = &input[take..];
+        }
+    }
+
+    /// Same result as `update`, with whole chunks of `input` hashed on up to `threads` threads.
+    #[cfg(feature = "std")]
+    pub fn update_parallel(&mut self, mut input: &[u8], threads: usize) {
+        let threads = threads.clamp(1, PARALLEL_BATCH);
+        let fill = ((BLAKE3_CHUNK_LEN - self.chunk_state.len()) % BLAKE3_CHUNK_LEN).min(input.len());
+        self.update(&input[..fill]);
+        input = &input[fill..];
+
+        // the last chunk is kept back, it may turn out to be the root
+        while threads > 1 && input.len() > 2 * BLAKE3_CHUNK_LEN {
+            if self.chunk_state.len() == BLAKE3_CHUNK_LEN {
+                self.finish_chunk();
+            }
+
+            let chunks = ((input.len() - 1) / BLAKE3_CHUNK_LEN).min(PARALLEL_BATCH);
+            let first_chunk = self.chunk_state.chunk_counter;
+            let chunks_per_thread = chunks.div_ceil(threads);
+            let (key, flags) = (self.key, self.flags);
+
+            let mut cvs = [[0u32; 8]; PARALLEL_BATCH];
+            std::thread::scope(|scope| {
+                let parts = cvs[..chunks].chunks_mut(chunks_per_thread)
+                    .zip(input[..chunks * BLAKE3_CHUNK_LEN].chunks(chunks_per_thread * BLAKE3_CHUNK_LEN));
+                for (t, (part, data)) in parts.enumerate() {
+                    scope.spawn(move || {
+                        let first_index = first_chunk + (t * chunks_per_thread) as u64;
+                        for (i, (cv, chunk)) in part.iter_mut().zip(data.chunks(BLAKE3_CHUNK_LEN)).enumerate() {
+                            let mut chunk_state = ChunkState::new(&key, first_index + i as u64, flags);
+                            chunk_state.update(chunk);
+                            *cv = chunk_state.output().chaining_value();
+                        }
+                    });
+                }
+            });
+
+            for (i, cv) in cvs[..chunks].iter().enumerate() {
+                self.push_cv(*cv, first_chunk + i as u64 + 1 - self.chunk_offset);
+            }
+            self.chunk_state = ChunkState::new(&self.key, first_chunk + chunks as u64, self.flags);
+            cvs.zeroize();
+            input = &input[chunks * BLAKE3_CHUNK_LEN..];
+        }
+
+        self.update(input);
+    }
+
+    /// Output reader positioned at byte 0, the first 32 bytes are the hash.
+    pub fn finalize_xof(&self) -> Blake3Reader {
+        Blake3Reader { output: self.root_output(), position: 0 }
+    }
+
+    /// Hashes a subtree that starts `offset` bytes into the whole input, for use with `finalize_chaining_value`.
+    /// The offset must be a multiple of `BLAKE3_CHUNK_LEN` and is only accepted before any input.
+    pub fn set_input_offset(&mut self, offset: u64) -> Result<(), SymcError> {
+        if !offset.is_multiple_of(BLAKE3_CHUNK_LEN as u64) || self.cv_stack_len != 0 || self.chunk_state.len() != 0 {
+            return Err(SymcError::InvalidLength);
+        }
+        self.chunk_offset = offset / BLAKE3_CHUNK_LEN as u64;
+        self.chunk_state = ChunkState::new(&self.key, self.chunk_offset, self.flags);
+        Ok(())
+    }
+
+    /// Chaining value of the input as a non-root subtree, at most `max_subtree_len(offset)` bytes long.
+    pub fn finalize_chaining_value(&self) -> Blake3ChainingValue {
+        let mut cv = Blake3ChainingValue::default();
+        words_to_le_bytes(&self.root_output().chaining_value(), &mut cv.0);
+        cv
+    }
+
+    /// Checks a subtree received so far against its trusted chaining value, in constant time.
+    pub fn verify_chaining_value(&self, expected: &Blake3ChainingValue) -> bool {
+        constant_time_eq(self.finalize_chaining_value().as_ref(), expected.as_ref())
+    }
+
+    /// Chaining value of a non-root parent node, using the key and mode of this hasher.
+    pub fn merge_subtrees(&self, left: &Blake3ChainingValue, right: &Blake3ChainingValue) -> Blake3ChainingValue {
+        let output = parent_output(&words_from_le_bytes(&left.0), &words_from_le_bytes(&right.0), &self.key, self.flags);
+        let mut cv = Blake3ChainingValue::default();
+        words_to_le_bytes(&output.chaining_value(), &mut cv.0);
+        cv
+    }
+
+    /// Hash of the whole input from the chaining values of the root's two children.
+    pub fn merge_subtrees_root(&self, left: &Blake3ChainingValue, right: &Blake3ChainingValue) -> Blake3Output {
+        let output = parent_output(&words_from_le_bytes(&left.0), &words_from_le_bytes(&right.0), &self.key, self.flags);
+        let mut block = output.root_output_block(0);
+        let mut result = Blake3Output::default();
+        result.0.copy_from_slice(&block[..32]);
+        block.zeroize();
+        result
+    }
+}
+
+/// Largest subtree that can start at `offset`, `None` at offset 0 where the subtree is unbounded.
+pub fn max_subtree_len(offset: u64) -> Option<u64> {
+    if offset == 0 {
+        return None;
+    }
+    let chunks = offset / BLAKE3_CHUNK_LEN as u64;
+    Some((1u64 << chunks.trailing_zeros()) * BLAKE3_CHUNK_LEN as u64)
+}
+
+/// Input length of the left child of a node covering `input_len` bytes, `None` for a single chunk.
+pub fn left_subtree_len(input_len: u64) -> Option<u64> {
+    if input_len <= BLAKE3_CHUNK_LEN as u64 {
+        return None;
+    }
+    let full_chunks = (input_len - 1) / BLAKE3_CHUNK_LEN as u64;
+    Some((1u64 << (63 - full_chunks.leading_zeros())) * BLAKE3_CHUNK_LEN as u64)
+}
+
+impl Hasher for Blake3 {
+    const BLOCK_SIZE: usize = BLOCK_LEN;
+    const OUTPUT_SIZE: usize = 32;
+    type HashBlock = Blake3Block;
+    type Output = Blake3Output;
+
+    fn update(&mut self, input: &[u8]) {
+        Blake3::update(self, input);
+    }
+
+    fn finalize(self) -> Self::Output where Self: Sized {
+        let mut output = Blake3Output::default();
+        self.finalize_xof().squeeze(&mut output.0);
+        output
+    }
+
+    fn reset(&mut self) {
+        self.chunk_state = ChunkState::new(&self.key, self.chunk_offset, self.flags);
+        self.cv_stack_len = 0;
+    }
+}
+
+/// BLAKE3 extendable output, seekable to any byte position.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Blake3Reader {
+    output: Output,
+    position: u64
+}
+
+impl Blake3Reader {
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn seek(&mut self, position: u64) {
+        self.position = position;
+    }
+}
+
+impl XofReader for Blake3Reader {
+    fn squeeze(&mut self, mut output: &mut [u8]) {
+        while !output.is_empty() {
+            let mut block = self.output.root_output_block(self.position / 64);
+            let offset = (self.position % 64) as usize;
+            let take = (64 - offset).min(output.len());
+            output[..take].copy_from_slice(&block[offset..offset + take]);
+            block.zeroize();
+            self.position += take as u64;
+            output = &mut output[take..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_subtree_lengths() {
+        assert_eq!(max_subtree_len(0), None);
+        assert_eq!(max_subtree_len(1024), Some(1024));
+        assert_eq!(max_subtree_len(4096), Some(4096));
+        assert_eq!(max_subtree_len(3 * 2048), Some(2048));
+        assert_eq!(left_subtree_len(1024), None);
+        assert_eq!(left_subtree_len(1025), Some(1024));
+        assert_eq!(left_subtree_len(4096), Some(2048));
+        assert_eq!(left_subtree_len(4097), Some(4096));
+    }
+
+    #[test]
+    fn test_input_offset_rules() {
+        let mut hasher = Blake3::new();
+        assert_eq!(hasher.set_input_offset(100), Err(SymcError::InvalidLength));
+        assert!(hasher.set_input_offset(2048).is_ok());
+        hasher.update(b"data");
+        assert_eq!(hasher.set_input_offset(4096), Err(SymcError::InvalidLength));
+    }
+
+    #[test]
+    fn test_reader_seek() {
+        let mut hasher = Blake3::new();
+        hasher.update(b"abc");
+        let mut reader = hasher.finalize_xof();
+        let mut full = [0u8; 200];
+        reader.squeeze(&mut full);
+        assert_eq!(reader.position(), 200);
+
+        reader.seek(61);
+        let mut part = [0u8; 70];
+        reader.squeeze(&mut part);
+        assert_eq!(&part[..], &full[61..131]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_update_parallel_matches_serial() {
+        let input: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let mut serial = Blake3::new();
+        serial.update(&input[..77]);
+        serial.update(&input[77..]);
+
+        for threads in [0, 1, 2, 3, 8] {
+            let mut parallel = Blake3::new();
+            parallel.update(&input[..77]);
+            parallel.update_parallel(&input[77..], threads);
+            assert_eq!(parallel.finalize().as_ref(), serial.clone().finalize().as_ref());
+        }
+    }
+}
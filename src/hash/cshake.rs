@@ -0,0 +1,239 @@
+#[cfg(feature = "parallelhash")]
+use crate::error::SymcError;
+use crate::hash::ExtendableOutput;
+use crate::hash::keccak::{KeccakReader, KeccakSponge, Shake128Reader, Shake256Reader, CSHAKE_DOMAIN, SHAKE_DOMAIN};
+use zeroize::Zeroize;
+
+#[cfg(any(feature = "tuplehash", feature = "parallelhash"))]
+use crate::hash::XofReader;
+#[cfg(all(feature = "alloc", any(feature = "tuplehash", feature = "parallelhash")))]
+use alloc::vec::Vec;
+
+macro_rules! cshake_impl {
+    ($name:ident, $rate:expr, $reader:ident, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $name {
+            sponge: KeccakSponge<$rate>,
+            initial: KeccakSponge<$rate>,
+            domain: u8
+        }
+
+        impl $name {
+            /// `function_name` is reserved for NIST defined functions, applications pick a `customization` string.
+            pub fn new(function_name: &[u8], customization: &[u8]) -> Self {
+                // with both strings empty cSHAKE is defined to be plain SHAKE
+                if function_name.is_empty() && customization.is_empty() {
+                    return Self {
+                        sponge: KeccakSponge::default(),
+                        initial: KeccakSponge::default(),
+                        domain: SHAKE_DOMAIN
+                    };
+                }
+
+                let sponge = KeccakSponge::cshake(function_name, customization);
+                Self { initial: sponge.clone(), sponge, domain: CSHAKE_DOMAIN }
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new(&[], &[])
+            }
+        }
+
+        impl ExtendableOutput for $name {
+            const BLOCK_SIZE: usize = $rate;
+            type Reader = $reader;
+
+            fn update(&mut self, input: &[u8]) {
+                self.sponge.absorb(input);
+            }
+
+            fn finalize_xof(self) -> Self::Reader where Self: Sized {
+                KeccakReader::new(self.sponge.clone(), self.domain)
+            }
+
+            fn reset(&mut self) {
+                self.sponge = self.initial.clone();
+            }
+        }
+    };
+}
+
+cshake_impl!(CShake128, 168, Shake128Reader, "cSHAKE128 (SP 800-185, 3)");
+cshake_impl!(CShake256, 136, Shake256Reader, "cSHAKE256 (SP 800-185, 3)");
+
+#[cfg(feature = "tuplehash")]
+macro_rules! tuplehash_impl {
+    ($name:ident, $rate:expr, $reader:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Every `update` call is one element of the tuple, so ("ab", "c") and ("a", "bc") hash differently.
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $name {
+            sponge: KeccakSponge<$rate>,
+            initial: KeccakSponge<$rate>
+        }
+
+        impl $name {
+            pub fn new(customization: &[u8]) -> Self {
+                let sponge = KeccakSponge::cshake(b"TupleHash", customization);
+                Self { initial: sponge.clone(), sponge }
+            }
+
+            /// Absorbs the next element of the tuple.
+            pub fn update(&mut self, element: &[u8]) {
+                self.sponge.absorb_encode_string(element);
+            }
+
+            /// Fills `output`, its length is bound into the result.
+            pub fn finalize_into(self, output: &mut [u8]) {
+                let mut sponge = self.sponge.clone();
+                sponge.absorb_right_encode((output.len() as u64).wrapping_mul(8));
+                KeccakReader::new(sponge, CSHAKE_DOMAIN).squeeze(output);
+            }
+
+            /// TupleHashXOF, output of unbounded length.
+            pub fn finalize_xof(self) -> $reader {
+                let mut sponge = self.sponge.clone();
+                sponge.absorb_right_encode(0);
+                KeccakReader::new(sponge, CSHAKE_DOMAIN)
+            }
+
+            #[cfg(feature = "alloc")]
+            pub fn finalize_vec(self, length: usize) -> Vec<u8> {
+                let mut output = alloc::vec![0u8; length];
+                self.finalize_into(&mut output);
+                output
+            }
+
+            pub fn reset(&mut self) {
+                self.sponge = self.initial.clone();
+            }
+
+            pub fn compute_into(customization: &[u8], tuple: &[&[u8]], output: &mut [u8]) {
+                let mut hasher = Self::new(customization);
+                for element in tuple {
+                    hasher.update(element);
+                }
+                hasher.finalize_into(output);
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::new(&[])
+            }
+        }
+    };
+}
+
+#[cfg(feature = "tuplehash")]
+tuplehash_impl!(TupleHash128, 168, Shake128Reader, "TupleHash128 (SP 800-185, 5)");
+#[cfg(feature = "tuplehash")]
+tuplehash_impl!(TupleHash256, 136, Shake256Reader, "TupleHash256 (SP 800-185, 5)");
+
+#[cfg(feature = "parallelhash")]
+macro_rules! parallelhash_impl {
+    ($name:ident, $rate:expr, $chaining_size:expr, $reader:ident, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// The input is cut into `block_size` byte blocks, each hashed on its own with SHAKE,
+        /// and the chaining values are hashed together in the outer cSHAKE.
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $name {
+            sponge: KeccakSponge<$rate>,
+            initial: KeccakSponge<$rate>,
+            block_size: usize,
+            block: KeccakSponge<$rate>,
+            block_len: usize,
+            blocks: u64
+        }
+
+        impl $name {
+            pub fn new(block_size: usize, customization: &[u8]) -> Result<Self, SymcError> {
+                if block_size == 0 {
+                    return Err(SymcError::InvalidLength);
+                }
+
+                let mut sponge = KeccakSponge::cshake(b"ParallelHash", customization);
+                sponge.absorb_left_encode(block_size as u64);
+                Ok(Self {
+                    initial: sponge.clone(),
+                    sponge,
+                    block_size,
+                    block: KeccakSponge::default(),
+                    block_len: 0,
+                    blocks: 0
+                })
+            }
+
+            pub fn update(&mut self, mut input: &[u8]) {
+                while !input.is_empty() {
+                    let take = (self.block_size - self.block_len).min(input.len());
+                    self.block.absorb(&input[..take]);
+                    self.block_len += take;
+                    input = &input[take..];
+
+                    if self.block_len == self.block_size {
+                        self.absorb_block();
+                    }
+                }
+            }
+
+            /// Fills `output`, its length is bound into the result.
+            pub fn finalize_into(mut self, output: &mut [u8]) {
+                self.finish((output.len() as u64).wrapping_mul(8)).squeeze(output);
+            }
+
+            /// ParallelHashXOF, output of unbounded length.
+            pub fn finalize_xof(mut self) -> $reader {
+                self.finish(0)
+            }
+
+            #[cfg(feature = "alloc")]
+            pub fn finalize_vec(self, length: usize) -> Vec<u8> {
+                let mut output = alloc::vec![0u8; length];
+                self.finalize_into(&mut output);
+                output
+            }
+
+            pub fn reset(&mut self) {
+                self.sponge = self.initial.clone();
+                self.block = KeccakSponge::default();
+                self.block_len = 0;
+                self.blocks = 0;
+            }
+
+            /// z_i = cSHAKE(X_i, 2 * security, "", "") which is SHAKE(X_i)
+            fn absorb_block(&mut self) {
+                let mut chaining_value = [0u8; $chaining_size];
+                KeccakReader::new(core::mem::take(&mut self.block), SHAKE_DOMAIN).squeeze(&mut chaining_value);
+                self.sponge.absorb(&chaining_value);
+                self.block_len = 0;
+                self.blocks += 1;
+                chaining_value.zeroize();
+            }
+
+            fn finish(&mut self, output_bits: u64) -> $reader {
+                if self.block_len > 0 {
+                    self.absorb_block();
+                }
+
+                let mut sponge = self.sponge.clone();
+                sponge.absorb_right_encode(self.blocks);
+                sponge.absorb_right_encode(output_bits);
+                KeccakReader::new(sponge, CSHAKE_DOMAIN)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "parallelhash")]
+parallelhash_impl!(ParallelHash128, 168, 32, Shake128Reader, "ParallelHash128 (SP 800-185, 6)");
+#[cfg(feature = "parallelhash")]
+parallelhash_impl!(ParallelHash256, 136, 64, Shake256Reader, "ParallelHash256 (SP 800-185, 6)");
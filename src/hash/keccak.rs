@@ -0,0 +1,262 @@
+#[cfg(any(feature = "shake128", feature = "shake256", feature = "cshake"))]
+use crate::hash::XofReader;
+use zeroize::Zeroize;
+
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+// ρ rotation offsets and π lane order, walked along the (x, y) -> (y, 2x + 3y) cycle
+const RHO: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+const PI: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Domain separation suffixes (FIPS 202, 6.1 and 6.2), including the first padding bit.
+#[cfg(any(feature = "sha3-224", feature = "sha3-256", feature = "sha3-384", feature = "sha3-512"))]
+pub(crate) const SHA3_DOMAIN: u8 = 0x06;
+#[cfg(any(feature = "shake128", feature = "shake256", feature = "cshake"))]
+pub(crate) const SHAKE_DOMAIN: u8 = 0x1f;
+/// cSHAKE domain suffix (SP 800-185, 3.3).
+#[cfg(feature = "cshake")]
+pub(crate) const CSHAKE_DOMAIN: u8 = 0x04;
+
+/// Keccak-f[1600] permutation (FIPS 202, 3.3), lane (x, y) is state[x + 5y].
+pub(crate) fn keccak_f1600(state: &mut [u64; 25]) {
+    for rc in ROUND_CONSTANTS {
+        // θ
+        let mut c = [0u64; 5];
+        for x in 0..5 {
+            c[x] = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[x + 5 * y] ^= d;
+            }
+        }
+
+        // ρ and π
+        let mut last = state[1];
+        for i in 0..24 {
+            let next = state[PI[i]];
+            state[PI[i]] = last.rotate_left(RHO[i]);
+            last = next;
+        }
+
+        // χ
+        for y in 0..5 {
+            let row = [state[5 * y], state[5 * y + 1], state[5 * y + 2], state[5 * y + 3], state[5 * y + 4]];
+            for x in 0..5 {
+                state[x + 5 * y] = row[x] ^ (!row[(x + 1) % 5] & row[(x + 2) % 5]);
+            }
+        }
+
+        // ι
+        state[0] ^= rc;
+    }
+}
+
+/// Keccak sponge with a rate of `RATE` bytes, absorbing straight into the state.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub(crate) struct KeccakSponge<const RATE: usize> {
+    state: [u64; 25],
+    pos: usize
+}
+
+impl<const RATE: usize> Default for KeccakSponge<RATE> {
+    fn default() -> Self {
+        Self { state: [0u64; 25], pos: 0 }
+    }
+}
+
+impl<const RATE: usize> KeccakSponge<RATE> {
+    fn xor_byte(&mut self, index: usize, byte: u8) {
+        self.state[index / 8] ^= (byte as u64) << (8 * (index % 8));
+    }
+
+    fn byte(&self, index: usize) -> u8 {
+        (self.state[index / 8] >> (8 * (index % 8))) as u8
+    }
+
+    pub(crate) fn absorb(&mut self, input: &[u8]) {
+        for &byte in input {
+            self.xor_byte(self.pos, byte);
+            self.pos += 1;
+            if self.pos == RATE {
+                keccak_f1600(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+
+    /// left_encode(x) (SP 800-185, 2.3.1)
+    #[cfg(feature = "cshake")]
+    pub(crate) fn absorb_left_encode(&mut self, x: u64) {
+        let bytes = x.to_be_bytes();
+        let skip = (x.leading_zeros() as usize / 8).min(7);
+        self.absorb(&[(8 - skip) as u8]);
+        self.absorb(&bytes[skip..]);
+    }
+
+    /// right_encode(x) (SP 800-185, 2.3.1)
+    #[cfg(any(feature = "kmac", feature = "tuplehash", feature = "parallelhash"))]
+    pub(crate) fn absorb_right_encode(&mut self, x: u64) {
+        let bytes = x.to_be_bytes();
+        let skip = (x.leading_zeros() as usize / 8).min(7);
+        self.absorb(&bytes[skip..]);
+        self.absorb(&[(8 - skip) as u8]);
+    }
+
+    /// encode_string(s) = left_encode(len(s) in bits) || s (SP 800-185, 2.3.2)
+    #[cfg(feature = "cshake")]
+    pub(crate) fn absorb_encode_string(&mut self, s: &[u8]) {
+        self.absorb_left_encode((s.len() as u64).wrapping_mul(8));
+        self.absorb(s);
+    }
+
+    /// Starts bytepad(.., RATE) (SP 800-185, 2.3.3), closed again by `fill_block`.
+    #[cfg(feature = "cshake")]
+    pub(crate) fn start_bytepad(&mut self) {
+        self.absorb_left_encode(RATE as u64);
+    }
+
+    /// Zero-fills up to the next rate boundary, the zero bytes of bytepad do not change the state.
+    #[cfg(feature = "cshake")]
+    pub(crate) fn fill_block(&mut self) {
+        if self.pos != 0 {
+            keccak_f1600(&mut self.state);
+            self.pos = 0;
+        }
+    }
+
+    /// Sponge after bytepad(encode_string(N) || encode_string(S), RATE), the cSHAKE prefix (SP 800-185, 3.3).
+    #[cfg(feature = "cshake")]
+    pub(crate) fn cshake(function_name: &[u8], customization: &[u8]) -> Self {
+        let mut sponge = Self::default();
+        sponge.start_bytepad();
+        sponge.absorb_encode_string(function_name);
+        sponge.absorb_encode_string(customization);
+        sponge.fill_block();
+        sponge
+    }
+
+    /// Applies `domain` || pad10*1 and switches to squeezing.
+    pub(crate) fn finalize(&mut self, domain: u8) {
+        self.xor_byte(self.pos, domain);
+        self.xor_byte(RATE - 1, 0x80);
+        keccak_f1600(&mut self.state);
+        self.pos = 0;
+    }
+
+    pub(crate) fn squeeze(&mut self, output: &mut [u8]) {
+        for byte in output {
+            if self.pos == RATE {
+                keccak_f1600(&mut self.state);
+                self.pos = 0;
+            }
+            *byte = self.byte(self.pos);
+            self.pos += 1;
+        }
+    }
+}
+
+/// Squeezing half of a finished sponge, returned by the extendable-output functions.
+#[cfg(any(feature = "shake128", feature = "shake256", feature = "cshake"))]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct KeccakReader<const RATE: usize> {
+    sponge: KeccakSponge<RATE>
+}
+
+#[cfg(any(feature = "shake128", feature = "shake256", feature = "cshake"))]
+impl<const RATE: usize> KeccakReader<RATE> {
+    pub(crate) fn new(mut sponge: KeccakSponge<RATE>, domain: u8) -> Self {
+        sponge.finalize(domain);
+        Self { sponge }
+    }
+}
+
+#[cfg(any(feature = "shake128", feature = "shake256", feature = "cshake"))]
+impl<const RATE: usize> XofReader for KeccakReader<RATE> {
+    fn squeeze(&mut self, output: &mut [u8]) {
+        self.sponge.squeeze(output);
+    }
+}
+
+#[cfg(any(feature = "shake128", feature = "cshake"))]
+pub type Shake128Reader = KeccakReader<168>;
+#[cfg(any(feature = "shake256", feature = "cshake"))]
+pub type Shake256Reader = KeccakReader<136>;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_keccak_f1600_zero_state() {
+        // first lanes of Keccak-f[1600] applied to the all-zero state
+        let mut state = [0u64; 25];
+        keccak_f1600(&mut state);
+        assert_eq!(state[0], 0xf1258f7940e1dde7);
+        assert_eq!(state[1], 0x84d5ccf933c0478a);
+        assert_eq!(state[24], 0xeaf1ff7b5ceca249);
+    }
+
+    #[test]
+    fn test_sponge_split_absorb() {
+        let mut sponge1 = KeccakSponge::<136>::default();
+        sponge1.absorb(&[0x5a; 300]);
+        let mut sponge2 = KeccakSponge::<136>::default();
+        sponge2.absorb(&[0x5a; 135]);
+        sponge2.absorb(&[0x5a; 1]);
+        sponge2.absorb(&[0x5a; 164]);
+        assert_eq!(sponge1.state, sponge2.state);
+        assert_eq!(sponge1.pos, 300 % 136);
+    }
+
+    #[cfg(feature = "shake128")]
+    #[test]
+    fn test_sponge_squeeze_across_blocks() {
+        let mut reader1 = KeccakReader::new(KeccakSponge::<168>::default(), SHAKE_DOMAIN);
+        let mut reader2 = reader1.clone();
+
+        let mut long = [0u8; 400];
+        reader1.squeeze(&mut long);
+
+        let mut pieces = [0u8; 400];
+        for chunk in pieces.chunks_mut(7) {
+            reader2.squeeze(chunk);
+        }
+        assert_eq!(long, pieces);
+    }
+
+    #[cfg(any(feature = "kmac", feature = "tuplehash", feature = "parallelhash"))]
+    #[test]
+    fn test_left_right_encode() {
+        let encoded = |f: fn(&mut KeccakSponge<168>, u64), x: u64| {
+            let mut sponge = KeccakSponge::<168>::default();
+            f(&mut sponge, x);
+            let mut bytes = [0u8; 9];
+            for (i, byte) in bytes.iter_mut().enumerate().take(sponge.pos) {
+                *byte = sponge.byte(i);
+            }
+            (sponge.pos, bytes)
+        };
+
+        assert_eq!(encoded(KeccakSponge::absorb_left_encode, 0), (2, [1, 0, 0, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(encoded(KeccakSponge::absorb_left_encode, 168), (2, [1, 168, 0, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(encoded(KeccakSponge::absorb_left_encode, 256), (3, [2, 1, 0, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(encoded(KeccakSponge::absorb_right_encode, 0), (2, [0, 1, 0, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(encoded(KeccakSponge::absorb_right_encode, 256), (3, [1, 0, 2, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(encoded(KeccakSponge::absorb_right_encode, u64::MAX), (9, [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 8]));
+    }
+}
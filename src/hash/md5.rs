@@ -0,0 +1,212 @@
+use crate::hash::Hasher;
+use zeroize::Zeroize;
+
+const H0: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+const S: [u32; 64] = [
+    7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22, 7, 12, 17, 22,
+    5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20, 5, 9, 14, 20,
+    4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23, 4, 11, 16, 23,
+    6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21, 6, 10, 15, 21
+];
+
+const K: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee, 0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be, 0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa, 0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed, 0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c, 0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05, 0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039, 0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1, 0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391
+];
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Md5Output([u8; 16]);
+impl AsRef<[u8]> for Md5Output {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Md5Output {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 16]> for Md5Output {
+    fn from(array: [u8; 16]) -> Self {
+        Self(array)
+    }
+}
+impl From<Md5Output> for [u8; 16] {
+    fn from(output: Md5Output) -> Self {
+        output.0
+    }
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Md5Block([u8; 64]);
+impl Default for Md5Block {
+    fn default() -> Self {
+        Md5Block([0u8; 64])
+    }
+}
+impl AsRef<[u8]> for Md5Block {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Md5Block {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 64]> for Md5Block {
+    fn from(array: [u8; 64]) -> Self {
+        Self(array)
+    }
+}
+impl From<Md5Block> for [u8; 64] {
+    fn from(output: Md5Block) -> [u8; 64] {
+        output.0
+    }
+}
+
+/// MD5 (RFC 1321). Broken for collision resistance, only for interoperability with legacy formats.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Md5 {
+    state: [u32; 4],
+    length: u64,
+    buffer: [u8; 64]
+}
+
+impl Default for Md5 {
+    fn default() -> Self {
+        Md5 {
+            state: H0,
+            length: 0,
+            buffer: [0; 64]
+        }
+    }
+}
+
+impl Md5 {
+    fn process_block(&mut self, block: &[u8; Self::BLOCK_SIZE]) {
+        let mut m = [0u32; 16];
+        for (i, word) in m.iter_mut().enumerate() {
+            *word = u32::from_le_bytes(block[i * 4..i * 4 + 4].try_into().unwrap());
+        }
+
+        let [mut a, mut b, mut c, mut d] = self.state;
+        for i in 0..64 {
+            let (f, g) = match i {
+                0..=15 => ((b & c) | (!b & d), i),
+                16..=31 => ((d & b) | (!d & c), (5 * i + 1) % 16),
+                32..=47 => (b ^ c ^ d, (3 * i + 5) % 16),
+                _ => (c ^ (b | !d), (7 * i) % 16)
+            };
+            let temp = d;
+            d = c;
+            c = b;
+            b = b.wrapping_add(a.wrapping_add(f).wrapping_add(K[i]).wrapping_add(m[g]).rotate_left(S[i]));
+            a = temp;
+        }
+
+        self.state[0] = self.state[0].wrapping_add(a);
+        self.state[1] = self.state[1].wrapping_add(b);
+        self.state[2] = self.state[2].wrapping_add(c);
+        self.state[3] = self.state[3].wrapping_add(d);
+
+        m.zeroize();
+    }
+}
+
+impl Hasher for Md5 {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 16;
+    type HashBlock = Md5Block;
+    type Output = Md5Output;
+
+    fn update(&mut self, input: &[u8]) {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+        let mut input_pos = 0;
+
+        self.length = self.length.saturating_add(input.len() as u64);
+
+        if buffer_pos > 0 {
+            let remaining_len = Self::BLOCK_SIZE - buffer_pos;
+            if remaining_len > input.len() {
+                self.buffer[buffer_pos..buffer_pos + input.len()].copy_from_slice(input);
+                return;
+            } else {
+                self.buffer[buffer_pos..Self::BLOCK_SIZE].copy_from_slice(&input[..remaining_len]);
+                let block = self.buffer;
+                self.process_block(&block);
+                input_pos += remaining_len;
+            }
+        }
+
+        while input_pos + Self::BLOCK_SIZE <= input.len() {
+            let block = &input[input_pos..input_pos + Self::BLOCK_SIZE];
+            self.process_block(block.try_into().unwrap());
+            input_pos += Self::BLOCK_SIZE;
+        }
+
+        let remaining_input = &input[input_pos..];
+        if !remaining_input.is_empty() {
+            self.buffer[..remaining_input.len()].copy_from_slice(remaining_input);
+        }
+    }
+
+    fn finalize(mut self) -> Self::Output where Self: Sized {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+
+        self.buffer[buffer_pos] = 0x80;
+        if buffer_pos + 1 > Self::BLOCK_SIZE - 8 {
+            self.buffer[(buffer_pos + 1)..].fill(0);
+            let block = self.buffer;
+            self.process_block(&block);
+            self.buffer.fill(0);
+        } else {
+            self.buffer[(buffer_pos + 1)..(Self::BLOCK_SIZE - 8)].fill(0);
+        }
+
+        let total_bits = self.length.wrapping_mul(8);
+        self.buffer[(Self::BLOCK_SIZE - 8)..].copy_from_slice(&total_bits.to_le_bytes());
+        let block = self.buffer;
+        self.process_block(&block);
+
+        let mut result = [0u8; 16];
+        for (i, word) in self.state.iter().enumerate() {
+            result[i * 4..(i + 1) * 4].copy_from_slice(&word.to_le_bytes());
+        }
+        Md5Output(result)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_md5_output_conversions() {
+        let array = [1u8; 16];
+        let output = Md5Output::from(array);
+        let back_to_array: [u8; 16] = output.into();
+        assert_eq!(array, back_to_array);
+    }
+
+    #[test]
+    fn test_md5_initial_state() {
+        let hasher = Md5::default();
+        assert_eq!(hasher.state, H0);
+        assert_eq!(hasher.length, 0);
+    }
+}
@@ -12,9 +12,54 @@ mod sha256;
 #[cfg(feature = "sha384")]
 mod sha384;
 
-#[cfg(feature = "sha512")]
+#[cfg(any(feature = "sha512", feature = "sha512-224", feature = "sha512-256", feature = "sha512t"))]
 mod sha512;
 
+#[cfg(any(feature = "sha512-224", feature = "sha512-256", feature = "sha512t"))]
+mod sha512t;
+
+#[cfg(any(feature = "sha3-224", feature = "sha3-256", feature = "sha3-384", feature = "sha3-512", feature = "shake128", feature = "shake256", feature = "cshake"))]
+pub(crate) mod keccak;
+
+#[cfg(any(feature = "sha3-224", feature = "sha3-256", feature = "sha3-384", feature = "sha3-512"))]
+mod sha3;
+
+#[cfg(any(feature = "shake128", feature = "shake256"))]
+mod shake;
+
+#[cfg(feature = "cshake")]
+mod cshake;
+
+#[cfg(any(feature = "blake2b", feature = "blake2s"))]
+mod blake2;
+
+#[cfg(feature = "blake3")]
+mod blake3;
+
+#[cfg(feature = "ripemd160")]
+mod ripemd160;
+
+#[cfg(feature = "sm3")]
+mod sm3;
+
+#[cfg(feature = "streebog")]
+mod streebog;
+
+#[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128", feature = "ascon-aead128"))]
+pub(crate) mod ascon;
+
+#[cfg(any(feature = "sha256d", feature = "hash160", feature = "tagged-hash"))]
+mod bitcoin;
+
+#[cfg(feature = "legacy")]
+mod sha1;
+
+#[cfg(feature = "legacy")]
+mod sha1dc;
+
+#[cfg(feature = "legacy")]
+mod md5;
+
 pub trait Hasher: Clone + Default + Zeroize {
     const BLOCK_SIZE: usize;
     const OUTPUT_SIZE: usize;
@@ -55,16 +100,149 @@ pub trait Hasher: Clone + Default + Zeroize {
     }
 }
 
+/// Reads output from a finished extendable-output function, each call continues where the last one stopped.
+pub trait XofReader: Clone + Zeroize {
+    fn squeeze(&mut self, output: &mut [u8]);
+
+    #[cfg(feature = "alloc")]
+    fn squeeze_vec(&mut self, length: usize) -> Vec<u8> {
+        let mut output = alloc::vec![0u8; length];
+        self.squeeze(&mut output);
+        output
+    }
+}
+
+/// Hash functions with output of any length, such as SHAKE.
+pub trait ExtendableOutput: Clone + Default + Zeroize {
+    const BLOCK_SIZE: usize;
+    type Reader: XofReader;
+
+    fn update(&mut self, input: &[u8]);
+
+    fn finalize_xof(self) -> Self::Reader where Self: Sized;
+
+    fn reset(&mut self);
+
+    fn finalize_into(self, output: &mut [u8]) where Self: Sized {
+        self.finalize_xof().squeeze(output);
+    }
+
+    fn compute_into(input: &[u8], output: &mut [u8]) where Self: Sized {
+        let mut xof = Self::default();
+        xof.update(input);
+        xof.finalize_into(output);
+    }
+
+    fn finalize_xof_and_reset(&mut self) -> Self::Reader {
+        let clone = (*self).clone();
+        let reader = clone.finalize_xof();
+        self.reset();
+        reader
+    }
+
+    #[cfg(feature = "alloc")]
+    fn finalize_xof_vec(self, length: usize) -> Vec<u8> where Self: Sized {
+        self.finalize_xof().squeeze_vec(length)
+    }
+}
+
 pub mod prelude {
     #[cfg(feature = "sha224")]
     pub use super::sha224::Sha224;
 
     #[cfg(feature = "sha256")]
-    pub use super::sha256::Sha256;
+    pub use super::sha256::{Sha256, Sha256Output};
 
     #[cfg(feature = "sha384")]
     pub use super::sha384::Sha384;
 
     #[cfg(feature = "sha512")]
     pub use super::sha512::Sha512;
-}
\ No newline at end of file
+
+    #[cfg(feature = "sha512-224")]
+    pub use super::sha512t::{Sha512_224, Sha512_224Output};
+
+    #[cfg(feature = "sha512-256")]
+    pub use super::sha512t::{Sha512_256, Sha512_256Output};
+
+    #[cfg(feature = "sha512t")]
+    pub use super::sha512t::{Sha512T, sha512_t_iv};
+
+    #[cfg(feature = "sha3-224")]
+    pub use super::sha3::Sha3_224;
+
+    #[cfg(feature = "sha3-256")]
+    pub use super::sha3::Sha3_256;
+
+    #[cfg(feature = "sha3-384")]
+    pub use super::sha3::Sha3_384;
+
+    #[cfg(feature = "sha3-512")]
+    pub use super::sha3::Sha3_512;
+
+    #[cfg(feature = "shake128")]
+    pub use super::shake::Shake128;
+
+    #[cfg(feature = "shake256")]
+    pub use super::shake::Shake256;
+
+    #[cfg(any(feature = "shake128", feature = "cshake"))]
+    pub use super::keccak::Shake128Reader;
+
+    #[cfg(any(feature = "shake256", feature = "cshake"))]
+    pub use super::keccak::Shake256Reader;
+
+    #[cfg(feature = "cshake")]
+    pub use super::cshake::{CShake128, CShake256};
+
+    #[cfg(feature = "tuplehash")]
+    pub use super::cshake::{TupleHash128, TupleHash256};
+
+    #[cfg(feature = "parallelhash")]
+    pub use super::cshake::{ParallelHash128, ParallelHash256};
+
+    #[cfg(feature = "blake2b")]
+    pub use super::blake2::{Blake2b, Blake2b160, Blake2b256, Blake2b384, Blake2b512, Blake2bOutput, Blake2bParams, Blake2bVar};
+
+    #[cfg(feature = "blake2s")]
+    pub use super::blake2::{Blake2s, Blake2s128, Blake2s160, Blake2s224, Blake2s256, Blake2sOutput, Blake2sParams, Blake2sVar};
+
+    #[cfg(feature = "blake3")]
+    pub use super::blake3::{Blake3, Blake3ChainingValue, Blake3Key, Blake3Output, Blake3Reader, BLAKE3_CHUNK_LEN, left_subtree_len, max_subtree_len};
+
+    #[cfg(feature = "ripemd160")]
+    pub use super::ripemd160::{Ripemd160, Ripemd160Output};
+
+    #[cfg(feature = "sm3")]
+    pub use super::sm3::{Sm3, Sm3Output};
+
+    #[cfg(feature = "streebog")]
+    pub use super::streebog::{Streebog256, Streebog256Output, Streebog512, Streebog512Output};
+
+    #[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128"))]
+    pub use super::ascon::AsconReader;
+
+    #[cfg(feature = "ascon-hash256")]
+    pub use super::ascon::{AsconHash256, AsconHash256Output};
+
+    #[cfg(feature = "ascon-xof128")]
+    pub use super::ascon::{AsconCxof128, AsconXof128, CXOF128_MAX_CUSTOMIZATION};
+
+    #[cfg(feature = "sha256d")]
+    pub use super::bitcoin::Sha256d;
+
+    #[cfg(feature = "hash160")]
+    pub use super::bitcoin::Hash160;
+
+    #[cfg(feature = "tagged-hash")]
+    pub use super::bitcoin::{Bip340Aux, Bip340AuxHash, Bip340Challenge, Bip340ChallengeHash, Bip340Nonce, Bip340NonceHash, HashTag, Tagged, TaggedHash};
+
+    #[cfg(feature = "legacy")]
+    pub use super::sha1::{Sha1, Sha1Output};
+
+    #[cfg(feature = "legacy")]
+    pub use super::sha1dc::Sha1Checked;
+
+    #[cfg(feature = "legacy")]
+    pub use super::md5::{Md5, Md5Output};
+}
@@ -0,0 +1,259 @@
+use crate::hash::Hasher;
+use zeroize::Zeroize;
+
+const H0: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+// message word selection and rotation amounts, left and right lines
+const R_LEFT: [usize; 80] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15,
+    7, 4, 13, 1, 10, 6, 15, 3, 12, 0, 9, 5, 2, 14, 11, 8,
+    3, 10, 14, 4, 9, 15, 8, 1, 2, 7, 0, 6, 13, 11, 5, 12,
+    1, 9, 11, 10, 0, 8, 12, 4, 13, 3, 7, 15, 14, 5, 6, 2,
+    4, 0, 5, 9, 7, 12, 2, 10, 14, 1, 3, 8, 11, 6, 15, 13
+];
+
+const R_RIGHT: [usize; 80] = [
+    5, 14, 7, 0, 9, 2, 11, 4, 13, 6, 15, 8, 1, 10, 3, 12,
+    6, 11, 3, 7, 0, 13, 5, 10, 14, 15, 8, 12, 4, 9, 1, 2,
+    15, 5, 1, 3, 7, 14, 6, 9, 11, 8, 12, 2, 10, 0, 4, 13,
+    8, 6, 4, 1, 3, 11, 15, 0, 5, 12, 2, 13, 9, 7, 10, 14,
+    12, 15, 10, 4, 1, 5, 8, 7, 6, 2, 13, 14, 0, 3, 9, 11
+];
+
+const S_LEFT: [u32; 80] = [
+    11, 14, 15, 12, 5, 8, 7, 9, 11, 13, 14, 15, 6, 7, 9, 8,
+    7, 6, 8, 13, 11, 9, 7, 15, 7, 12, 15, 9, 11, 7, 13, 12,
+    11, 13, 6, 7, 14, 9, 13, 15, 14, 8, 13, 6, 5, 12, 7, 5,
+    11, 12, 14, 15, 14, 15, 9, 8, 9, 14, 5, 6, 8, 6, 5, 12,
+    9, 15, 5, 11, 6, 8, 13, 12, 5, 12, 13, 14, 11, 8, 5, 6
+];
+
+const S_RIGHT: [u32; 80] = [
+    8, 9, 9, 11, 13, 15, 15, 5, 7, 7, 8, 11, 14, 14, 12, 6,
+    9, 13, 15, 7, 12, 8, 9, 11, 7, 7, 12, 7, 6, 15, 13, 11,
+    9, 7, 15, 11, 8, 6, 6, 14, 12, 13, 5, 14, 13, 13, 7, 5,
+    15, 5, 8, 11, 14, 14, 6, 14, 6, 9, 12, 9, 12, 5, 15, 8,
+    8, 5, 12, 9, 12, 5, 14, 6, 8, 13, 6, 5, 15, 13, 11, 11
+];
+
+const K_LEFT: [u32; 5] = [0x00000000, 0x5a827999, 0x6ed9eba1, 0x8f1bbcdc, 0xa953fd4e];
+const K_RIGHT: [u32; 5] = [0x50a28be6, 0x5c4dd124, 0x6d703ef3, 0x7a6d76e9, 0x00000000];
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Ripemd160Output([u8; 20]);
+impl AsRef<[u8]> for Ripemd160Output {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Ripemd160Output {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 20]> for Ripemd160Output {
+    fn from(array: [u8; 20]) -> Self {
+        Self(array)
+    }
+}
+impl From<Ripemd160Output> for [u8; 20] {
+    fn from(output: Ripemd160Output) -> Self {
+        output.0
+    }
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Ripemd160Block([u8; 64]);
+impl Default for Ripemd160Block {
+    fn default() -> Self {
+        Ripemd160Block([0u8; 64])
+    }
+}
+impl AsRef<[u8]> for Ripemd160Block {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Ripemd160Block {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 64]> for Ripemd160Block {
+    fn from(array: [u8; 64]) -> Self {
+        Self(array)
+    }
+}
+impl From<Ripemd160Block> for [u8; 64] {
+    fn from(output: Ripemd160Block) -> [u8; 64] {
+        output.0
+    }
+}
+
+/// f_j from the RIPEMD-160 specification, `round` is j / 16.
+fn f(round: usize, x: u32, y: u32, z: u32) -> u32 {
+    match round {
+        0 => x ^ y ^ z,
+        1 => (x & y) | (!x & z),
+        2 => (x | !y) ^ z,
+        3 => (x & z) | (y & !z),
+        _ => x ^ (y | !z),
+    }
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Ripemd160 {
+    state: [u32; 5],
+    length: u64,
+    buffer: [u8; 64]
+}
+
+impl Default for Ripemd160 {
+    fn default() -> Self {
+        Ripemd160 {
+            state: H0,
+            length: 0,
+            buffer: [0; 64]
+        }
+    }
+}
+
+impl Ripemd160 {
+    fn process_block(&mut self, block: &[u8; Self::BLOCK_SIZE]) {
+        let mut x = [0u32; 16];
+        for (i, word) in x.iter_mut().enumerate() {
+            *word = u32::from_le_bytes(block[i * 4..i * 4 + 4].try_into().unwrap());
+        }
+
+        let [mut al, mut bl, mut cl, mut dl, mut el] = self.state;
+        let [mut ar, mut br, mut cr, mut dr, mut er] = self.state;
+
+        for j in 0..80 {
+            let round = j / 16;
+
+            let t = al
+                .wrapping_add(f(round, bl, cl, dl))
+                .wrapping_add(x[R_LEFT[j]])
+                .wrapping_add(K_LEFT[round])
+                .rotate_left(S_LEFT[j])
+                .wrapping_add(el);
+            al = el;
+            el = dl;
+            dl = cl.rotate_left(10);
+            cl = bl;
+            bl = t;
+
+            // the right line runs the boolean functions in reverse order
+            let t = ar
+                .wrapping_add(f(4 - round, br, cr, dr))
+                .wrapping_add(x[R_RIGHT[j]])
+                .wrapping_add(K_RIGHT[round])
+                .rotate_left(S_RIGHT[j])
+                .wrapping_add(er);
+            ar = er;
+            er = dr;
+            dr = cr.rotate_left(10);
+            cr = br;
+            br = t;
+        }
+
+        let t = self.state[1].wrapping_add(cl).wrapping_add(dr);
+        self.state[1] = self.state[2].wrapping_add(dl).wrapping_add(er);
+        self.state[2] = self.state[3].wrapping_add(el).wrapping_add(ar);
+        self.state[3] = self.state[4].wrapping_add(al).wrapping_add(br);
+        self.state[4] = self.state[0].wrapping_add(bl).wrapping_add(cr);
+        self.state[0] = t;
+
+        x.zeroize();
+    }
+}
+
+impl Hasher for Ripemd160 {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 20;
+    type HashBlock = Ripemd160Block;
+    type Output = Ripemd160Output;
+
+    fn update(&mut self, input: &[u8]) {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+        let mut input_pos = 0;
+
+        self.length = self.length.saturating_add(input.len() as u64);
+
+        if buffer_pos > 0 {
+            let remaining_len = Self::BLOCK_SIZE - buffer_pos;
+            if remaining_len > input.len() {
+                self.buffer[buffer_pos..buffer_pos + input.len()].copy_from_slice(input);
+                return;
+            } else {
+                self.buffer[buffer_pos..Self::BLOCK_SIZE].copy_from_slice(&input[..remaining_len]);
+                let block = self.buffer;
+                self.process_block(&block);
+                input_pos += remaining_len;
+            }
+        }
+
+        while input_pos + Self::BLOCK_SIZE <= input.len() {
+            let block = &input[input_pos..input_pos + Self::BLOCK_SIZE];
+            self.process_block(block.try_into().unwrap());
+            input_pos += Self::BLOCK_SIZE;
+        }
+
+        let remaining_input = &input[input_pos..];
+        if !remaining_input.is_empty() {
+            self.buffer[..remaining_input.len()].copy_from_slice(remaining_input);
+        }
+    }
+
+    fn finalize(mut self) -> Self::Output where Self: Sized {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+
+        self.buffer[buffer_pos] = 0x80;
+        if buffer_pos + 1 > Self::BLOCK_SIZE - 8 {
+            self.buffer[(buffer_pos + 1)..].fill(0);
+            let block = self.buffer;
+            self.process_block(&block);
+            self.buffer.fill(0);
+        } else {
+            self.buffer[(buffer_pos + 1)..(Self::BLOCK_SIZE - 8)].fill(0);
+        }
+
+        let total_bits = self.length.wrapping_mul(8);
+        self.buffer[(Self::BLOCK_SIZE - 8)..].copy_from_slice(&total_bits.to_le_bytes());
+        let block = self.buffer;
+        self.process_block(&block);
+
+        let mut result = [0u8; 20];
+        for (i, word) in self.state.iter().enumerate() {
+            result[i * 4..(i + 1) * 4].copy_from_slice(&word.to_le_bytes());
+        }
+        Ripemd160Output(result)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ripemd160_output_conversions() {
+        let array = [1u8; 20];
+        let output = Ripemd160Output::from(array);
+        let back_to_array: [u8; 20] = output.into();
+        assert_eq!(array, back_to_array);
+    }
+
+    #[test]
+    fn test_ripemd160_initial_state() {
+        let hasher = Ripemd160::default();
+        assert_eq!(hasher.state, H0);
+        assert_eq!(hasher.length, 0);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::hash::Hasher;
+use zeroize::Zeroize;
+
+pub(crate) const H0: [u32; 5] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476, 0xc3d2e1f0];
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Sha1Output([u8; 20]);
+impl AsRef<[u8]> for Sha1Output {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Sha1Output {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 20]> for Sha1Output {
+    fn from(array: [u8; 20]) -> Self {
+        Self(array)
+    }
+}
+impl From<Sha1Output> for [u8; 20] {
+    fn from(output: Sha1Output) -> Self {
+        output.0
+    }
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Sha1Block([u8; 64]);
+impl Default for Sha1Block {
+    fn default() -> Self {
+        Sha1Block([0u8; 64])
+    }
+}
+impl AsRef<[u8]> for Sha1Block {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Sha1Block {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 64]> for Sha1Block {
+    fn from(array: [u8; 64]) -> Self {
+        Self(array)
+    }
+}
+impl From<Sha1Block> for [u8; 64] {
+    fn from(output: Sha1Block) -> [u8; 64] {
+        output.0
+    }
+}
+
+/// Message schedule W[0..80] of one block (FIPS 180-4, 6.1.2).
+pub(crate) fn expand(block: &[u8; 64]) -> [u32; 80] {
+    let mut w = [0u32; 80];
+    for t in 0..16 {
+        w[t] = u32::from_be_bytes(block[t * 4..t * 4 + 4].try_into().unwrap());
+    }
+    for t in 16..80 {
+        w[t] = (w[t - 3] ^ w[t - 8] ^ w[t - 14] ^ w[t - 16]).rotate_left(1);
+    }
+    w
+}
+
+/// f_t(b, c, d) + K_t
+fn f_k(t: usize, b: u32, c: u32, d: u32) -> u32 {
+    match t {
+        0..=19 => (d ^ (b & (c ^ d))).wrapping_add(0x5a827999),
+        20..=39 => (b ^ c ^ d).wrapping_add(0x6ed9eba1),
+        40..=59 => ((b & c) | (b & d) | (c & d)).wrapping_add(0x8f1bbcdc),
+        _ => (b ^ c ^ d).wrapping_add(0xca62c1d6),
+    }
+}
+
+/// Step t on the working variables [a, b, c, d, e].
+pub(crate) fn step(s: &mut [u32; 5], t: usize, w: &[u32; 80]) {
+    let temp = s[0].rotate_left(5)
+        .wrapping_add(f_k(t, s[1], s[2], s[3]))
+        .wrapping_add(s[4])
+        .wrapping_add(w[t]);
+    *s = [temp, s[0], s[1].rotate_left(30), s[2], s[3]];
+}
+
+/// Undoes step t, the working variables before the step are recovered from the ones after it.
+pub(crate) fn step_back(s: &mut [u32; 5], t: usize, w: &[u32; 80]) {
+    let (a, b, c, d) = (s[1], s[2].rotate_right(30), s[3], s[4]);
+    let e = s[0].wrapping_sub(a.rotate_left(5).wrapping_add(f_k(t, b, c, d)).wrapping_add(w[t]));
+    *s = [a, b, c, d, e];
+}
+
+pub(crate) fn compress(state: &mut [u32; 5], w: &[u32; 80]) {
+    let mut s = *state;
+    for t in 0..80 {
+        step(&mut s, t, w);
+    }
+    for (h, v) in state.iter_mut().zip(s) {
+        *h = h.wrapping_add(v);
+    }
+}
+
+/// SHA-1 (FIPS 180-4). Broken for collision resistance, see `Sha1Checked` for untrusted input.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Sha1 {
+    state: [u32; 5],
+    length: u64,
+    buffer: [u8; 64]
+}
+
+impl Default for Sha1 {
+    fn default() -> Self {
+        Sha1 {
+            state: H0,
+            length: 0,
+            buffer: [0; 64]
+        }
+    }
+}
+
+impl Sha1 {
+    fn process_block(&mut self, block: &[u8; Self::BLOCK_SIZE]) {
+        let mut w = expand(block);
+        compress(&mut self.state, &w);
+        w.zeroize();
+    }
+}
+
+impl Hasher for Sha1 {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 20;
+    type HashBlock = Sha1Block;
+    type Output = Sha1Output;
+
+    fn update(&mut self, input: &[u8]) {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+        let mut input_pos = 0;
+
+        self.length = self.length.saturating_add(input.len() as u64);
+
+        if buffer_pos > 0 {
+            let remaining_len = Self::BLOCK_SIZE - buffer_pos;
+            if remaining_len > input.len() {
+                self.buffer[buffer_pos..buffer_pos + input.len()].copy_from_slice(input);
+                return;
+            } else {
+                self.buffer[buffer_pos..Self::BLOCK_SIZE].copy_from_slice(&input[..remaining_len]);
+                let block = self.buffer;
+                self.process_block(&block);
+                input_pos += remaining_len;
+            }
+        }
+
+        while input_pos + Self::BLOCK_SIZE <= input.len() {
+            let block = &input[input_pos..input_pos + Self::BLOCK_SIZE];
+            self.process_block(block.try_into().unwrap());
+            input_pos += Self::BLOCK_SIZE;
+        }
+
+        let remaining_input = &input[input_pos..];
+        if !remaining_input.is_empty() {
+            self.buffer[..remaining_input.len()].copy_from_slice(remaining_input);
+        }
+    }
+
+    fn finalize(mut self) -> Self::Output where Self: Sized {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+
+        self.buffer[buffer_pos] = 0x80;
+        if buffer_pos + 1 > Self::BLOCK_SIZE - 8 {
+            self.buffer[(buffer_pos + 1)..].fill(0);
+            let block = self.buffer;
+            self.process_block(&block);
+            self.buffer.fill(0);
+        } else {
+            self.buffer[(buffer_pos + 1)..(Self::BLOCK_SIZE - 8)].fill(0);
+        }
+
+        let total_bits = self.length * 8;
+        self.buffer[(Self::BLOCK_SIZE - 8)..].copy_from_slice(&total_bits.to_be_bytes());
+        let block = self.buffer;
+        self.process_block(&block);
+
+        let mut result = [0u8; 20];
+        for (i, word) in self.state.iter().enumerate() {
+            result[i * 4..(i + 1) * 4].copy_from_slice(&word.to_be_bytes());
+        }
+        Sha1Output(result)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sha1_output_conversions() {
+        let array = [1u8; 20];
+        let output = Sha1Output::from(array);
+        let back_to_array: [u8; 20] = output.into();
+        assert_eq!(array, back_to_array);
+    }
+
+    #[test]
+    fn test_sha1_step_back() {
+        let w = expand(&[0x5a; 64]);
+        for t in [0, 19, 20, 45, 79] {
+            let before = [1, 2, 3, 4, 5];
+            let mut s = before;
+            step(&mut s, t, &w);
+            step_back(&mut s, t, &w);
+            assert_eq!(s, before);
+        }
+    }
+}
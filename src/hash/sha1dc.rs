@@ -0,0 +1,383 @@
+use crate::error::SymcError;
+use crate::hash::Hasher;
+use crate::hash::sha1::{H0, Sha1Block, Sha1Output, compress, expand, step, step_back};
+use zeroize::Zeroize;
+
+struct DisturbanceVector {
+    recompress_step: usize,
+    dm_seed: [u32; 16]
+}
+
+// Disturbance vectors checked by sha1dc (Stevens and Shumow, "Speeding up detection of SHA-1 collision
+// attacks using unavoidable attack conditions"). Each message difference is given by its first
+// 16 words, the rest follows from the message expansion.
+const DISTURBANCE_VECTORS: [DisturbanceVector; 32] = [
+    // I(43,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0x08000000, 0x9800000c, 0xd8000010, 0x08000010, 0xb8000010, 0x98000000, 0x60000000, 0x00000008,
+        0xc0000000, 0x90000014, 0x10000010, 0xb8000014, 0x28000000, 0x20000010, 0x48000000, 0x08000018,
+    ] },
+    // I(44,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0xb4000008, 0x08000000, 0x9800000c, 0xd8000010, 0x08000010, 0xb8000010, 0x98000000, 0x60000000,
+        0x00000008, 0xc0000000, 0x90000014, 0x10000010, 0xb8000014, 0x28000000, 0x20000010, 0x48000000,
+    ] },
+    // I(45,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0xf4000014, 0xb4000008, 0x08000000, 0x9800000c, 0xd8000010, 0x08000010, 0xb8000010, 0x98000000,
+        0x60000000, 0x00000008, 0xc0000000, 0x90000014, 0x10000010, 0xb8000014, 0x28000000, 0x20000010,
+    ] },
+    // I(46,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0x2c000010, 0xf4000014, 0xb4000008, 0x08000000, 0x9800000c, 0xd8000010, 0x08000010, 0xb8000010,
+        0x98000000, 0x60000000, 0x00000008, 0xc0000000, 0x90000014, 0x10000010, 0xb8000014, 0x28000000,
+    ] },
+    // I(46,2)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0xb0000040, 0xd0000053, 0xd0000022, 0x20000000, 0x60000032, 0x60000043, 0x20000040, 0xe0000042,
+        0x60000002, 0x80000001, 0x00000020, 0x00000003, 0x40000052, 0x40000040, 0xe0000052, 0xa0000000,
+    ] },
+    // I(47,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0xc8000010, 0x2c000010, 0xf4000014, 0xb4000008, 0x08000000, 0x9800000c, 0xd8000010, 0x08000010,
+        0xb8000010, 0x98000000, 0x60000000, 0x00000008, 0xc0000000, 0x90000014, 0x10000010, 0xb8000014,
+    ] },
+    // I(47,2)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0x20000043, 0xb0000040, 0xd0000053, 0xd0000022, 0x20000000, 0x60000032, 0x60000043, 0x20000040,
+        0xe0000042, 0x60000002, 0x80000001, 0x00000020, 0x00000003, 0x40000052, 0x40000040, 0xe0000052,
+    ] },
+    // I(48,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0xb800000a, 0xc8000010, 0x2c000010, 0xf4000014, 0xb4000008, 0x08000000, 0x9800000c, 0xd8000010,
+        0x08000010, 0xb8000010, 0x98000000, 0x60000000, 0x00000008, 0xc0000000, 0x90000014, 0x10000010,
+    ] },
+    // I(48,2)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0xe000002a, 0x20000043, 0xb0000040, 0xd0000053, 0xd0000022, 0x20000000, 0x60000032, 0x60000043,
+        0x20000040, 0xe0000042, 0x60000002, 0x80000001, 0x00000020, 0x00000003, 0x40000052, 0x40000040,
+    ] },
+    // I(49,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0x18000000, 0xb800000a, 0xc8000010, 0x2c000010, 0xf4000014, 0xb4000008, 0x08000000, 0x9800000c,
+        0xd8000010, 0x08000010, 0xb8000010, 0x98000000, 0x60000000, 0x00000008, 0xc0000000, 0x90000014,
+    ] },
+    // I(49,2)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0x60000000, 0xe000002a, 0x20000043, 0xb0000040, 0xd0000053, 0xd0000022, 0x20000000, 0x60000032,
+        0x60000043, 0x20000040, 0xe0000042, 0x60000002, 0x80000001, 0x00000020, 0x00000003, 0x40000052,
+    ] },
+    // I(50,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0x0800000c, 0x18000000, 0xb800000a, 0xc8000010, 0x2c000010, 0xf4000014, 0xb4000008, 0x08000000,
+        0x9800000c, 0xd8000010, 0x08000010, 0xb8000010, 0x98000000, 0x60000000, 0x00000008, 0xc0000000,
+    ] },
+    // I(50,2)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0x20000030, 0x60000000, 0xe000002a, 0x20000043, 0xb0000040, 0xd0000053, 0xd0000022, 0x20000000,
+        0x60000032, 0x60000043, 0x20000040, 0xe0000042, 0x60000002, 0x80000001, 0x00000020, 0x00000003,
+    ] },
+    // I(51,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0xe8000000, 0x0800000c, 0x18000000, 0xb800000a, 0xc8000010, 0x2c000010, 0xf4000014, 0xb4000008,
+        0x08000000, 0x9800000c, 0xd8000010, 0x08000010, 0xb8000010, 0x98000000, 0x60000000, 0x00000008,
+    ] },
+    // I(51,2)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0xa0000003, 0x20000030, 0x60000000, 0xe000002a, 0x20000043, 0xb0000040, 0xd0000053, 0xd0000022,
+        0x20000000, 0x60000032, 0x60000043, 0x20000040, 0xe0000042, 0x60000002, 0x80000001, 0x00000020,
+    ] },
+    // I(52,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0x04000010, 0xe8000000, 0x0800000c, 0x18000000, 0xb800000a, 0xc8000010, 0x2c000010, 0xf4000014,
+        0xb4000008, 0x08000000, 0x9800000c, 0xd8000010, 0x08000010, 0xb8000010, 0x98000000, 0x60000000,
+    ] },
+    // II(45,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0xec000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x2c000004, 0xbc000018, 0xb0000010, 0x0000000c,
+        0xb8000010, 0x08000018, 0x78000010, 0x08000014, 0x70000010, 0xb800001c, 0xe8000000, 0xb0000004,
+    ] },
+    // II(46,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0x2400001c, 0xec000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x2c000004, 0xbc000018, 0xb0000010,
+        0x0000000c, 0xb8000010, 0x08000018, 0x78000010, 0x08000014, 0x70000010, 0xb800001c, 0xe8000000,
+    ] },
+    // II(46,2)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0x90000070, 0xb0000053, 0x30000008, 0x00000043, 0xd0000072, 0xb0000010, 0xf0000062, 0xc0000042,
+        0x00000030, 0xe0000042, 0x20000060, 0xe0000041, 0x20000050, 0xc0000041, 0xe0000072, 0xa0000003,
+    ] },
+    // II(47,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0x20000010, 0x2400001c, 0xec000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x2c000004, 0xbc000018,
+        0xb0000010, 0x0000000c, 0xb8000010, 0x08000018, 0x78000010, 0x08000014, 0x70000010, 0xb800001c,
+    ] },
+    // II(48,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0xbc00001a, 0x20000010, 0x2400001c, 0xec000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x2c000004,
+        0xbc000018, 0xb0000010, 0x0000000c, 0xb8000010, 0x08000018, 0x78000010, 0x08000014, 0x70000010,
+    ] },
+    // II(49,0)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0x3c000004, 0xbc00001a, 0x20000010, 0x2400001c, 0xec000014, 0x0c000002, 0xc0000010, 0xb400001c,
+        0x2c000004, 0xbc000018, 0xb0000010, 0x0000000c, 0xb8000010, 0x08000018, 0x78000010, 0x08000014,
+    ] },
+    // II(49,2)
+    DisturbanceVector { recompress_step: 58, dm_seed: [
+        0xf0000010, 0xf000006a, 0x80000040, 0x90000070, 0xb0000053, 0x30000008, 0x00000043, 0xd0000072,
+        0xb0000010, 0xf0000062, 0xc0000042, 0x00000030, 0xe0000042, 0x20000060, 0xe0000041, 0x20000050,
+    ] },
+    // II(50,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0xb400001c, 0x3c000004, 0xbc00001a, 0x20000010, 0x2400001c, 0xec000014, 0x0c000002, 0xc0000010,
+        0xb400001c, 0x2c000004, 0xbc000018, 0xb0000010, 0x0000000c, 0xb8000010, 0x08000018, 0x78000010,
+    ] },
+    // II(50,2)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0xd0000072, 0xf0000010, 0xf000006a, 0x80000040, 0x90000070, 0xb0000053, 0x30000008, 0x00000043,
+        0xd0000072, 0xb0000010, 0xf0000062, 0xc0000042, 0x00000030, 0xe0000042, 0x20000060, 0xe0000041,
+    ] },
+    // II(51,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0xc0000010, 0xb400001c, 0x3c000004, 0xbc00001a, 0x20000010, 0x2400001c, 0xec000014, 0x0c000002,
+        0xc0000010, 0xb400001c, 0x2c000004, 0xbc000018, 0xb0000010, 0x0000000c, 0xb8000010, 0x08000018,
+    ] },
+    // II(51,2)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0x00000043, 0xd0000072, 0xf0000010, 0xf000006a, 0x80000040, 0x90000070, 0xb0000053, 0x30000008,
+        0x00000043, 0xd0000072, 0xb0000010, 0xf0000062, 0xc0000042, 0x00000030, 0xe0000042, 0x20000060,
+    ] },
+    // II(52,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0x0c000002, 0xc0000010, 0xb400001c, 0x3c000004, 0xbc00001a, 0x20000010, 0x2400001c, 0xec000014,
+        0x0c000002, 0xc0000010, 0xb400001c, 0x2c000004, 0xbc000018, 0xb0000010, 0x0000000c, 0xb8000010,
+    ] },
+    // II(53,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0xcc000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x3c000004, 0xbc00001a, 0x20000010, 0x2400001c,
+        0xec000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x2c000004, 0xbc000018, 0xb0000010, 0x0000000c,
+    ] },
+    // II(54,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0x0400001c, 0xcc000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x3c000004, 0xbc00001a, 0x20000010,
+        0x2400001c, 0xec000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x2c000004, 0xbc000018, 0xb0000010,
+    ] },
+    // II(55,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0x00000010, 0x0400001c, 0xcc000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x3c000004, 0xbc00001a,
+        0x20000010, 0x2400001c, 0xec000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x2c000004, 0xbc000018,
+    ] },
+    // II(56,0)
+    DisturbanceVector { recompress_step: 65, dm_seed: [
+        0x2600001a, 0x00000010, 0x0400001c, 0xcc000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x3c000004,
+        0xbc00001a, 0x20000010, 0x2400001c, 0xec000014, 0x0c000002, 0xc0000010, 0xb400001c, 0x2c000004,
+    ] },
+];
+
+const fn expand_differences() -> [[u32; 80]; 32] {
+    let mut dms = [[0u32; 80]; 32];
+    let mut i = 0;
+    while i < 32 {
+        let mut t = 0;
+        while t < 16 {
+            dms[i][t] = DISTURBANCE_VECTORS[i].dm_seed[t];
+            t += 1;
+        }
+        while t < 80 {
+            dms[i][t] = (dms[i][t - 3] ^ dms[i][t - 8] ^ dms[i][t - 14] ^ dms[i][t - 16]).rotate_left(1);
+            t += 1;
+        }
+        i += 1;
+    }
+    dms
+}
+
+const MESSAGE_DIFFERENCES: [[u32; 80]; 32] = expand_differences();
+
+/// SHA-1 with sha1dc collision detection (counter-cryptanalysis).
+///
+/// Every block is checked for the near-collision differences used by known attacks such as SHAttered.
+/// When one is found, `collision_detected` is set and `finalize` returns the sha1dc "safe hash"
+/// instead, so the two colliding messages no longer hash alike. Hashing is several times slower than `Sha1`.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Sha1Checked {
+    state: [u32; 5],
+    length: u64,
+    buffer: [u8; 64],
+    collision: bool
+}
+
+impl Default for Sha1Checked {
+    fn default() -> Self {
+        Sha1Checked {
+            state: H0,
+            length: 0,
+            buffer: [0; 64],
+            collision: false
+        }
+    }
+}
+
+impl Sha1Checked {
+    pub fn collision_detected(&self) -> bool {
+        self.collision
+    }
+
+    /// Returns `SymcError::CollisionDetected` for attack-crafted input, the plain SHA-1 digest otherwise.
+    pub fn finalize_checked(mut self) -> Result<Sha1Output, SymcError> {
+        let output = self.finalize_inner();
+        if self.collision {
+            return Err(SymcError::CollisionDetected);
+        }
+        Ok(output)
+    }
+
+    fn finalize_inner(&mut self) -> Sha1Output {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+
+        self.buffer[buffer_pos] = 0x80;
+        if buffer_pos + 1 > Self::BLOCK_SIZE - 8 {
+            self.buffer[(buffer_pos + 1)..].fill(0);
+            let block = self.buffer;
+            self.process_block(&block);
+            self.buffer.fill(0);
+        } else {
+            self.buffer[(buffer_pos + 1)..(Self::BLOCK_SIZE - 8)].fill(0);
+        }
+
+        let total_bits = self.length * 8;
+        self.buffer[(Self::BLOCK_SIZE - 8)..].copy_from_slice(&total_bits.to_be_bytes());
+        let block = self.buffer;
+        self.process_block(&block);
+
+        let mut result = [0u8; 20];
+        for (i, word) in self.state.iter().enumerate() {
+            result[i * 4..(i + 1) * 4].copy_from_slice(&word.to_be_bytes());
+        }
+        Sha1Output::from(result)
+    }
+
+    fn process_block(&mut self, block: &[u8; Self::BLOCK_SIZE]) {
+        let mut w = expand(block);
+
+        let mut s = self.state;
+        let mut saved = [[0u32; 5]; 2];
+        for t in 0..80 {
+            if t == 58 {
+                saved[0] = s;
+            } else if t == 65 {
+                saved[1] = s;
+            }
+            step(&mut s, t, &w);
+        }
+        let mut ihv = self.state;
+        for (h, v) in ihv.iter_mut().zip(s) {
+            *h = h.wrapping_add(v);
+        }
+
+        // a colliding partner block m ^ dm reaches the same chaining value, so recompute from
+        // the intermediate state both ways and compare
+        for (dv, dm) in DISTURBANCE_VECTORS.iter().zip(MESSAGE_DIFFERENCES.iter()) {
+            let mut w2 = [0u32; 80];
+            for t in 0..80 {
+                w2[t] = w[t] ^ dm[t];
+            }
+
+            let start = if dv.recompress_step == 58 { saved[0] } else { saved[1] };
+            let mut ihv2 = start;
+            for t in (0..dv.recompress_step).rev() {
+                step_back(&mut ihv2, t, &w2);
+            }
+            let mut s2 = start;
+            for t in dv.recompress_step..80 {
+                step(&mut s2, t, &w2);
+            }
+
+            let mut diff = 0;
+            for i in 0..5 {
+                diff |= ihv2[i].wrapping_add(s2[i]) ^ ihv[i];
+            }
+            w2.zeroize();
+            if diff == 0 {
+                self.collision = true;
+                // sha1dc safe hash: two extra compressions of the same block
+                compress(&mut ihv, &w);
+                compress(&mut ihv, &w);
+                break;
+            }
+        }
+
+        self.state = ihv;
+        w.zeroize();
+    }
+}
+
+impl Hasher for Sha1Checked {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 20;
+    type HashBlock = Sha1Block;
+    type Output = Sha1Output;
+
+    fn update(&mut self, input: &[u8]) {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+        let mut input_pos = 0;
+
+        self.length = self.length.saturating_add(input.len() as u64);
+
+        if buffer_pos > 0 {
+            let remaining_len = Self::BLOCK_SIZE - buffer_pos;
+            if remaining_len > input.len() {
+                self.buffer[buffer_pos..buffer_pos + input.len()].copy_from_slice(input);
+                return;
+            } else {
+                self.buffer[buffer_pos..Self::BLOCK_SIZE].copy_from_slice(&input[..remaining_len]);
+                let block = self.buffer;
+                self.process_block(&block);
+                input_pos += remaining_len;
+            }
+        }
+
+        while input_pos + Self::BLOCK_SIZE <= input.len() {
+            let block = &input[input_pos..input_pos + Self::BLOCK_SIZE];
+            self.process_block(block.try_into().unwrap());
+            input_pos += Self::BLOCK_SIZE;
+        }
+
+        let remaining_input = &input[input_pos..];
+        if !remaining_input.is_empty() {
+            self.buffer[..remaining_input.len()].copy_from_slice(remaining_input);
+        }
+    }
+
+    fn finalize(mut self) -> Self::Output where Self: Sized {
+        self.finalize_inner()
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_message_differences_expand() {
+        // dm[16..80] must follow the SHA-1 message expansion of dm[0..16]
+        for dm in MESSAGE_DIFFERENCES.iter() {
+            for t in 16..80 {
+                assert_eq!(dm[t], (dm[t - 3] ^ dm[t - 8] ^ dm[t - 14] ^ dm[t - 16]).rotate_left(1));
+            }
+        }
+    }
+
+    #[test]
+    fn test_no_false_positive() {
+        let mut hasher = Sha1Checked::default();
+        hasher.update(&[0x61; 1000]);
+        assert!(!hasher.collision_detected());
+        assert!(hasher.finalize_checked().is_ok());
+    }
+}
@@ -80,6 +80,22 @@ impl Default for Sha256 {
 }
 
 impl Sha256 {
+    /// Resumes hashing from a chaining value taken after `blocks` whole blocks of input.
+    #[cfg(feature = "tagged-hash")]
+    pub(crate) fn from_midstate(state: [u32; 8], blocks: u64) -> Self {
+        Sha256 {
+            state,
+            length: blocks * Self::BLOCK_SIZE as u64,
+            buffer: [0; 64]
+        }
+    }
+
+    /// Chaining value so far, only meaningful on a block boundary.
+    #[cfg(feature = "tagged-hash")]
+    pub(crate) fn midstate(&self) -> [u32; 8] {
+        self.state
+    }
+
     const K: [u32; 64] = [
         0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1,
         0x923f82a4, 0xab1c5ed5, 0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3,
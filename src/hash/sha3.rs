@@ -0,0 +1,147 @@
+use crate::hash::Hasher;
+use crate::hash::keccak::{KeccakSponge, SHA3_DOMAIN};
+use zeroize::Zeroize;
+
+// SHA3-n is Keccak[c = 2n] with a rate of 200 - 2n/8 bytes (FIPS 202, 6.1).
+// The rate is exposed as BLOCK_SIZE so the hashes drop into Hmac<H> (FIPS 198-1 with SHA-3).
+macro_rules! sha3_impl {
+    ($name:ident, $output:ident, $block:ident, $output_size:expr, $rate:expr, $doc:expr) => {
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $output([u8; $output_size]);
+        impl Default for $output {
+            fn default() -> Self {
+                Self([0u8; $output_size])
+            }
+        }
+        impl AsRef<[u8]> for $output {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $output {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $output_size]> for $output {
+            fn from(array: [u8; $output_size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$output> for [u8; $output_size] {
+            fn from(output: $output) -> Self {
+                output.0
+            }
+        }
+
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $block([u8; $rate]);
+        impl Default for $block {
+            fn default() -> Self {
+                Self([0u8; $rate])
+            }
+        }
+        impl AsRef<[u8]> for $block {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $block {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $rate]> for $block {
+            fn from(array: [u8; $rate]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$block> for [u8; $rate] {
+            fn from(block: $block) -> Self {
+                block.0
+            }
+        }
+
+        #[doc = $doc]
+        #[derive(Clone, Default, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $name {
+            sponge: KeccakSponge<$rate>
+        }
+
+        impl $name {
+            pub const BLOCK_SIZE: usize = $rate;
+        }
+
+        impl Hasher for $name {
+            const BLOCK_SIZE: usize = $rate;
+            const OUTPUT_SIZE: usize = $output_size;
+            type HashBlock = $block;
+            type Output = $output;
+
+            fn update(&mut self, input: &[u8]) {
+                self.sponge.absorb(input);
+            }
+
+            fn finalize(mut self) -> Self::Output where Self: Sized {
+                let mut result = [0u8; $output_size];
+                self.sponge.finalize(SHA3_DOMAIN);
+                self.sponge.squeeze(&mut result);
+                $output(result)
+            }
+
+            fn reset(&mut self) {
+                *self = Self::default();
+            }
+        }
+    };
+}
+
+#[cfg(feature = "sha3-224")]
+sha3_impl!(Sha3_224, Sha3_224Output, Sha3_224Block, 28, 144, "SHA3-224 (FIPS 202)");
+
+#[cfg(feature = "sha3-256")]
+sha3_impl!(Sha3_256, Sha3_256Output, Sha3_256Block, 32, 136, "SHA3-256 (FIPS 202)");
+
+#[cfg(feature = "sha3-384")]
+sha3_impl!(Sha3_384, Sha3_384Output, Sha3_384Block, 48, 104, "SHA3-384 (FIPS 202)");
+
+#[cfg(feature = "sha3-512")]
+sha3_impl!(Sha3_512, Sha3_512Output, Sha3_512Block, 64, 72, "SHA3-512 (FIPS 202)");
+
+#[cfg(all(test, feature = "sha3-256"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sha3_256_output_conversions() {
+        let array = [1u8; 32];
+        let output = Sha3_256Output::from(array);
+        let back_to_array: [u8; 32] = output.into();
+        assert_eq!(array, back_to_array);
+    }
+
+    #[test]
+    fn test_sha3_256_block_is_rate() {
+        assert_eq!(Sha3_256::BLOCK_SIZE, 136);
+        assert_eq!(Sha3_256Block::default().as_ref().len(), <Sha3_256 as Hasher>::BLOCK_SIZE);
+    }
+
+    #[test]
+    fn test_sha3_256_reset() {
+        let mut hasher = Sha3_256::default();
+        hasher.update(b"secret");
+        hasher.reset();
+        hasher.update(b"abc");
+        assert_eq!(hasher.finalize().as_ref(), Sha3_256::compute(b"abc").as_ref());
+    }
+
+    #[test]
+    fn test_sha3_256_output_zeroize() {
+        let mut output = Sha3_256Output::from([42u8; 32]);
+        output.zeroize();
+        assert_eq!(output.as_ref(), &[0u8; 32]);
+    }
+}
@@ -1,7 +1,7 @@
 use crate::hash::Hasher;
 use zeroize::Zeroize;
 
-const H0: [u64; 8] = [
+pub(crate) const H0: [u64; 8] = [
     0x6a09e667f3bcc908, 0xbb67ae8584caa73b,
     0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
     0x510e527fade682d1, 0x9b05688c2b3e6c1f,
@@ -87,6 +87,16 @@ impl Default for Sha512 {
 impl Sha512 {
     pub const BLOCK_SIZE: usize = 128;
 
+    /// SHA-512 compression started from another initial hash value, used by the SHA-512/t variants.
+    #[cfg(any(feature = "sha512-224", feature = "sha512-256", feature = "sha512t"))]
+    pub(crate) fn with_iv(iv: [u64; 8]) -> Self {
+        Sha512 {
+            state: iv,
+            length: 0,
+            buffer: [0u8; 128]
+        }
+    }
+
     const K: [u64; 80] = [
         0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
         0x3956c25bf348b538, 0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
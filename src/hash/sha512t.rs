@@ -0,0 +1,234 @@
+use crate::hash::Hasher;
+use crate::hash::sha512::Sha512;
+use zeroize::Zeroize;
+
+#[cfg(feature = "sha512t")]
+use crate::error::SymcError;
+#[cfg(feature = "sha512t")]
+use crate::hash::sha512::H0;
+#[cfg(any(feature = "sha512-224", feature = "sha512-256"))]
+use crate::hash::sha512::Sha512Block;
+
+#[cfg(all(feature = "sha512t", feature = "alloc"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "sha512-224")]
+const SHA512_224_IV: [u64; 8] = [
+    0x8c3d37c819544da2, 0x73e1996689dcd4d6,
+    0x1dfab7ae32ff9c82, 0x679dd514582f9fcf,
+    0x0f6d2b697bd44da8, 0x77e36f7304c48942,
+    0x3f9d85a86a1d36c8, 0x1112e6ad91d692a1
+];
+
+#[cfg(feature = "sha512-256")]
+const SHA512_256_IV: [u64; 8] = [
+    0x22312194fc2bf72c, 0x9f555fa3c84c64c2,
+    0x2393b86b6f53b151, 0x963877195940eabd,
+    0x96283ee2a88effe3, 0xbe5e1e2553863992,
+    0x2b0199fc2c85b8aa, 0x0eb72ddc81c52ca2
+];
+
+/// Initial hash value of SHA-512/t (FIPS 180-4, 5.3.6).
+///
+/// `t` must be below 512 and not 384.
+#[cfg(feature = "sha512t")]
+pub fn sha512_t_iv(t: usize) -> Result<[u64; 8], SymcError> {
+    if t == 0 || t >= 512 || t == 384 {
+        return Err(SymcError::InvalidLength);
+    }
+
+    let mut iv = H0;
+    for word in iv.iter_mut() {
+        *word ^= 0xa5a5a5a5a5a5a5a5;
+    }
+
+    // "SHA-512/t" with t in decimal
+    let mut digits = [0u8; 3];
+    let mut pos = digits.len();
+    let mut n = t;
+    while n > 0 {
+        pos -= 1;
+        digits[pos] = b'0' + (n % 10) as u8;
+        n /= 10;
+    }
+
+    let mut hasher = Sha512::with_iv(iv);
+    hasher.update(b"SHA-512/");
+    hasher.update(&digits[pos..]);
+    let output = hasher.finalize();
+
+    for (word, bytes) in iv.iter_mut().zip(output.as_ref().chunks_exact(8)) {
+        *word = u64::from_be_bytes(bytes.try_into().unwrap());
+    }
+    Ok(iv)
+}
+
+macro_rules! sha512_trunc_impl {
+    ($feature:literal, $name:ident, $output:ident, $size:literal, $iv:ident) => {
+        #[cfg(feature = $feature)]
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $output([u8; $size]);
+        #[cfg(feature = $feature)]
+        impl Default for $output {
+            fn default() -> Self {
+                $output([0u8; $size])
+            }
+        }
+        #[cfg(feature = $feature)]
+        impl AsRef<[u8]> for $output {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        #[cfg(feature = $feature)]
+        impl AsMut<[u8]> for $output {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        #[cfg(feature = $feature)]
+        impl From<[u8; $size]> for $output {
+            fn from(array: [u8; $size]) -> Self {
+                Self(array)
+            }
+        }
+        #[cfg(feature = $feature)]
+        impl From<$output> for [u8; $size] {
+            fn from(output: $output) -> Self {
+                output.0
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $name {
+            inner: Sha512
+        }
+
+        #[cfg(feature = $feature)]
+        impl Default for $name {
+            fn default() -> Self {
+                $name { inner: Sha512::with_iv($iv) }
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl Hasher for $name {
+            const BLOCK_SIZE: usize = 128;
+            const OUTPUT_SIZE: usize = $size;
+            type HashBlock = Sha512Block;
+            type Output = $output;
+
+            fn update(&mut self, input: &[u8]) {
+                self.inner.update(input);
+            }
+
+            fn finalize(self) -> Self::Output where Self: Sized {
+                let full = self.inner.clone().finalize();
+                let mut result = [0u8; $size];
+                result.copy_from_slice(&full.as_ref()[..$size]);
+                $output(result)
+            }
+
+            fn reset(&mut self) {
+                *self = Self::default();
+            }
+        }
+    };
+}
+
+sha512_trunc_impl!("sha512-224", Sha512_224, Sha512_224Output, 28, SHA512_224_IV);
+sha512_trunc_impl!("sha512-256", Sha512_256, Sha512_256Output, 32, SHA512_256_IV);
+
+/// SHA-512/t with `t` chosen at runtime, whole bytes only.
+#[cfg(feature = "sha512t")]
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Sha512T {
+    inner: Sha512,
+    iv: [u64; 8],
+    output_size: usize
+}
+
+#[cfg(feature = "sha512t")]
+impl Sha512T {
+    /// `t` is the output length in bits, a multiple of 8 accepted by [`sha512_t_iv`].
+    pub fn new(t: usize) -> Result<Self, SymcError> {
+        if !t.is_multiple_of(8) {
+            return Err(SymcError::InvalidLength);
+        }
+        let iv = sha512_t_iv(t)?;
+        Ok(Sha512T { inner: Sha512::with_iv(iv), iv, output_size: t / 8 })
+    }
+
+    pub fn output_size(&self) -> usize {
+        self.output_size
+    }
+
+    pub fn update(&mut self, input: &[u8]) {
+        self.inner.update(input);
+    }
+
+    /// `output` must be exactly `output_size()` bytes long.
+    pub fn finalize_into(self, output: &mut [u8]) -> Result<(), SymcError> {
+        if output.len() != self.output_size {
+            return Err(SymcError::InvalidLength);
+        }
+        let full = self.inner.clone().finalize();
+        output.copy_from_slice(&full.as_ref()[..self.output_size]);
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn finalize_vec(self) -> Vec<u8> {
+        let mut output = alloc::vec![0u8; self.output_size];
+        let full = self.inner.clone().finalize();
+        output.copy_from_slice(&full.as_ref()[..self.output_size]);
+        output
+    }
+
+    pub fn reset(&mut self) {
+        self.inner = Sha512::with_iv(self.iv);
+    }
+}
+
+#[cfg(all(test, feature = "sha512t"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sha512_t_iv_known_values() {
+        #[cfg(feature = "sha512-224")]
+        assert_eq!(sha512_t_iv(224).unwrap(), SHA512_224_IV);
+        #[cfg(feature = "sha512-256")]
+        assert_eq!(sha512_t_iv(256).unwrap(), SHA512_256_IV);
+    }
+
+    #[test]
+    fn test_sha512_t_iv_invalid() {
+        assert_eq!(sha512_t_iv(0), Err(SymcError::InvalidLength));
+        assert_eq!(sha512_t_iv(384), Err(SymcError::InvalidLength));
+        assert_eq!(sha512_t_iv(512), Err(SymcError::InvalidLength));
+        assert!(sha512_t_iv(1).is_ok());
+        assert!(sha512_t_iv(511).is_ok());
+    }
+
+    #[test]
+    fn test_sha512_t_reset() {
+        let mut hasher = Sha512T::new(160).unwrap();
+        hasher.update(b"garbage");
+        hasher.reset();
+        hasher.update(b"abc");
+
+        let mut fresh = Sha512T::new(160).unwrap();
+        fresh.update(b"abc");
+
+        let mut a = [0u8; 20];
+        let mut b = [0u8; 20];
+        hasher.finalize_into(&mut a).unwrap();
+        fresh.finalize_into(&mut b).unwrap();
+        assert_eq!(a, b);
+    }
+}
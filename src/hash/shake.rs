@@ -0,0 +1,59 @@
+use crate::hash::ExtendableOutput;
+use crate::hash::keccak::{KeccakReader, KeccakSponge, SHAKE_DOMAIN};
+#[cfg(feature = "shake128")]
+use crate::hash::keccak::Shake128Reader;
+#[cfg(feature = "shake256")]
+use crate::hash::keccak::Shake256Reader;
+use zeroize::Zeroize;
+
+/// SHAKE128 (FIPS 202, 6.2), Keccak[256] with a 168 byte rate.
+#[cfg(feature = "shake128")]
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Shake128 {
+    sponge: KeccakSponge<168>
+}
+
+#[cfg(feature = "shake128")]
+impl ExtendableOutput for Shake128 {
+    const BLOCK_SIZE: usize = 168;
+    type Reader = Shake128Reader;
+
+    fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    fn finalize_xof(self) -> Self::Reader where Self: Sized {
+        KeccakReader::new(self.sponge.clone(), SHAKE_DOMAIN)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// SHAKE256 (FIPS 202, 6.2), Keccak[512] with a 136 byte rate.
+#[cfg(feature = "shake256")]
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Shake256 {
+    sponge: KeccakSponge<136>
+}
+
+#[cfg(feature = "shake256")]
+impl ExtendableOutput for Shake256 {
+    const BLOCK_SIZE: usize = 136;
+    type Reader = Shake256Reader;
+
+    fn update(&mut self, input: &[u8]) {
+        self.sponge.absorb(input);
+    }
+
+    fn finalize_xof(self) -> Self::Reader where Self: Sized {
+        KeccakReader::new(self.sponge.clone(), SHAKE_DOMAIN)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
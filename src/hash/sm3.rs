@@ -0,0 +1,224 @@
+use crate::hash::Hasher;
+use zeroize::Zeroize;
+
+const IV: [u32; 8] = [
+    0x7380166f, 0x4914b2b9,
+    0x172442d7, 0xda8a0600,
+    0xa96f30bc, 0x163138aa,
+    0xe38dee4d, 0xb0fb0e4e
+];
+
+const T_LOW: u32 = 0x79cc4519;
+const T_HIGH: u32 = 0x7a879d8a;
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Sm3Output([u8; 32]);
+impl AsRef<[u8]> for Sm3Output {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Sm3Output {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 32]> for Sm3Output {
+    fn from(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+}
+impl From<Sm3Output> for [u8; 32] {
+    fn from(output: Sm3Output) -> Self {
+        output.0
+    }
+}
+
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Sm3Block([u8; 64]);
+impl Default for Sm3Block {
+    fn default() -> Self {
+        Sm3Block([0u8; 64])
+    }
+}
+impl AsRef<[u8]> for Sm3Block {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Sm3Block {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 64]> for Sm3Block {
+    fn from(array: [u8; 64]) -> Self {
+        Self(array)
+    }
+}
+impl From<Sm3Block> for [u8; 64] {
+    fn from(output: Sm3Block) -> [u8; 64] {
+        output.0
+    }
+}
+
+#[inline]
+fn p0(x: u32) -> u32 {
+    x ^ x.rotate_left(9) ^ x.rotate_left(17)
+}
+
+#[inline]
+fn p1(x: u32) -> u32 {
+    x ^ x.rotate_left(15) ^ x.rotate_left(23)
+}
+
+/// SM3 (GB/T 32905-2016).
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Sm3 {
+    state: [u32; 8],
+    length: u64,
+    buffer: [u8; 64]
+}
+
+impl Default for Sm3 {
+    fn default() -> Self {
+        Sm3 {
+            state: IV,
+            length: 0,
+            buffer: [0; 64]
+        }
+    }
+}
+
+impl Sm3 {
+    fn process_block(&mut self, block: &[u8; Self::BLOCK_SIZE]) {
+        // message expansion, W[0..68] and W'[j] = W[j] ^ W[j + 4]
+        let mut w = [0u32; 68];
+        for j in 0..16 {
+            w[j] = u32::from_be_bytes(block[j * 4..j * 4 + 4].try_into().unwrap());
+        }
+        for j in 16..68 {
+            w[j] = p1(w[j - 16] ^ w[j - 9] ^ w[j - 3].rotate_left(15)) ^ w[j - 13].rotate_left(7) ^ w[j - 6];
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+        for j in 0..64 {
+            let t = if j < 16 { T_LOW } else { T_HIGH };
+            let ss1 = a.rotate_left(12)
+                .wrapping_add(e)
+                .wrapping_add(t.rotate_left(j as u32 % 32))
+                .rotate_left(7);
+            let ss2 = ss1 ^ a.rotate_left(12);
+            let (ff, gg) = if j < 16 {
+                (a ^ b ^ c, e ^ f ^ g)
+            } else {
+                ((a & b) | (a & c) | (b & c), (e & f) | (!e & g))
+            };
+            let tt1 = ff.wrapping_add(d).wrapping_add(ss2).wrapping_add(w[j] ^ w[j + 4]);
+            let tt2 = gg.wrapping_add(h).wrapping_add(ss1).wrapping_add(w[j]);
+            d = c;
+            c = b.rotate_left(9);
+            b = a;
+            a = tt1;
+            h = g;
+            g = f.rotate_left(19);
+            f = e;
+            e = p0(tt2);
+        }
+
+        for (s, v) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *s ^= v;
+        }
+        w.zeroize();
+    }
+}
+
+impl Hasher for Sm3 {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 32;
+    type HashBlock = Sm3Block;
+    type Output = Sm3Output;
+
+    fn update(&mut self, input: &[u8]) {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+        let mut input_pos = 0;
+
+        self.length = self.length.saturating_add(input.len() as u64);
+
+        if buffer_pos > 0 {
+            let remaining_len = Self::BLOCK_SIZE - buffer_pos;
+            if remaining_len > input.len() {
+                self.buffer[buffer_pos..buffer_pos + input.len()].copy_from_slice(input);
+                return;
+            } else {
+                self.buffer[buffer_pos..Self::BLOCK_SIZE].copy_from_slice(&input[..remaining_len]);
+                let block = self.buffer;
+                self.process_block(&block);
+                input_pos += remaining_len;
+            }
+        }
+
+        while input_pos + Self::BLOCK_SIZE <= input.len() {
+            let block = &input[input_pos..input_pos + Self::BLOCK_SIZE];
+            self.process_block(block.try_into().unwrap());
+            input_pos += Self::BLOCK_SIZE;
+        }
+
+        let remaining_input = &input[input_pos..];
+        if !remaining_input.is_empty() {
+            self.buffer[..remaining_input.len()].copy_from_slice(remaining_input);
+        }
+    }
+
+    fn finalize(mut self) -> Self::Output where Self: Sized {
+        let buffer_pos = (self.length % Self::BLOCK_SIZE as u64) as usize;
+
+        self.buffer[buffer_pos] = 0x80;
+        if buffer_pos + 1 > Self::BLOCK_SIZE - 8 {
+            self.buffer[(buffer_pos + 1)..].fill(0);
+            let block = self.buffer;
+            self.process_block(&block);
+            self.buffer.fill(0);
+        } else {
+            self.buffer[(buffer_pos + 1)..(Self::BLOCK_SIZE - 8)].fill(0);
+        }
+
+        let total_bits = self.length.wrapping_mul(8);
+        self.buffer[(Self::BLOCK_SIZE - 8)..].copy_from_slice(&total_bits.to_be_bytes());
+        let block = self.buffer;
+        self.process_block(&block);
+
+        let mut result = [0u8; 32];
+        for (i, word) in self.state.iter().enumerate() {
+            result[i * 4..(i + 1) * 4].copy_from_slice(&word.to_be_bytes());
+        }
+        Sm3Output(result)
+    }
+
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sm3_output_conversions() {
+        let array = [1u8; 32];
+        let output = Sm3Output::from(array);
+        let back_to_array: [u8; 32] = output.into();
+        assert_eq!(array, back_to_array);
+    }
+
+    #[test]
+    fn test_sm3_initial_state() {
+        let hasher = Sm3::default();
+        assert_eq!(hasher.state, IV);
+        assert_eq!(hasher.length, 0);
+    }
+}
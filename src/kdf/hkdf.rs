@@ -0,0 +1,128 @@
+use crate::error::SymcError;
+use crate::hash::Hasher;
+use crate::mac::{Hmac, Mac};
+use zeroize::Zeroize;
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// HKDF (RFC 5869), holds HMAC keyed with the pseudorandom key ready for expansion.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Hkdf<H: Hasher> {
+    prk_hmac: Hmac<H>
+}
+
+impl<H: Hasher> Hkdf<H> {
+    /// At most 255 blocks of output can be expanded from one PRK.
+    pub const MAX_OUTPUT_SIZE: usize = 255 * H::OUTPUT_SIZE;
+
+    /// HKDF-Extract, PRK = HMAC-Hash(salt, IKM).
+    /// An empty `salt` is the same as the RFC default of HashLen zero bytes.
+    pub fn extract(salt: &[u8], ikm: &[u8]) -> H::Output {
+        Hmac::<H>::compute(salt, ikm)
+    }
+
+    /// Runs HKDF-Extract and keeps the PRK for expansion.
+    pub fn new(salt: &[u8], ikm: &[u8]) -> Self {
+        let prk = Self::extract(salt, ikm);
+        Self { prk_hmac: Hmac::<H>::new(prk.as_ref()) }
+    }
+
+    /// Skips HKDF-Extract for input that is already a uniformly random key of at least HashLen bytes.
+    pub fn from_prk(prk: &[u8]) -> Result<Self, SymcError> {
+        if prk.len() < H::OUTPUT_SIZE {
+            return Err(SymcError::InvalidLength);
+        }
+
+        Ok(Self { prk_hmac: Hmac::<H>::new(prk) })
+    }
+
+    /// HKDF-Expand, fills `okm` completely.
+    pub fn expand(&self, info: &[u8], okm: &mut [u8]) -> Result<(), SymcError> {
+        self.expand_multi_info(&[info], okm)
+    }
+
+    /// HKDF-Expand with `info` given as the concatenation of `info_parts`.
+    pub fn expand_multi_info(&self, info_parts: &[&[u8]], okm: &mut [u8]) -> Result<(), SymcError> {
+        if okm.len() > Self::MAX_OUTPUT_SIZE {
+            return Err(SymcError::InvalidLength);
+        }
+
+        // T(i) = HMAC-Hash(PRK, T(i - 1) || info || i), T(0) is empty
+        let mut block = H::Output::default();
+        for (i, chunk) in okm.chunks_mut(H::OUTPUT_SIZE).enumerate() {
+            let mut hmac = self.prk_hmac.clone();
+            if i > 0 {
+                hmac.update(block.as_ref());
+            }
+            for part in info_parts {
+                hmac.update(part);
+            }
+            hmac.update(&[(i + 1) as u8]);
+            block = hmac.finalize();
+
+            chunk.copy_from_slice(&block.as_ref()[..chunk.len()]);
+        }
+
+        block.zeroize();
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn expand_vec(&self, info: &[u8], length: usize) -> Result<Vec<u8>, SymcError> {
+        let mut okm = vec![0u8; length];
+        self.expand(info, &mut okm)?;
+        Ok(okm)
+    }
+
+    /// Extract and expand in one call.
+    pub fn derive(salt: &[u8], ikm: &[u8], info: &[u8], okm: &mut [u8]) -> Result<(), SymcError> {
+        Self::new(salt, ikm).expand(info, okm)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hash::prelude::*;
+
+    #[test]
+    fn test_hkdf_empty_salt_is_zero_salt() {
+        let ikm = [0x0bu8; 22];
+        let prk_empty = Hkdf::<Sha256>::extract(&[], &ikm);
+        let prk_zero = Hkdf::<Sha256>::extract(&[0u8; 32], &ikm);
+        assert_eq!(prk_empty.as_ref(), prk_zero.as_ref());
+    }
+
+    #[test]
+    fn test_hkdf_from_prk_matches_new() {
+        let prk = Hkdf::<Sha256>::extract(b"salt", b"input key material");
+        let hkdf1 = Hkdf::<Sha256>::new(b"salt", b"input key material");
+        let hkdf2 = Hkdf::<Sha256>::from_prk(prk.as_ref()).unwrap();
+
+        let mut okm1 = [0u8; 80];
+        let mut okm2 = [0u8; 80];
+        hkdf1.expand(b"info", &mut okm1).unwrap();
+        hkdf2.expand(b"info", &mut okm2).unwrap();
+        assert_eq!(okm1, okm2);
+    }
+
+    #[test]
+    fn test_hkdf_short_prk_rejected() {
+        assert!(Hkdf::<Sha256>::from_prk(&[0u8; 31]).is_err());
+        assert!(Hkdf::<Sha256>::from_prk(&[0u8; 32]).is_ok());
+    }
+
+    #[test]
+    fn test_hkdf_output_is_prefix_of_longer_output() {
+        let hkdf = Hkdf::<Sha512>::new(b"salt", b"ikm");
+        let mut short = [0u8; 70];
+        let mut long = [0u8; 200];
+        hkdf.expand(b"info", &mut short).unwrap();
+        hkdf.expand(b"info", &mut long).unwrap();
+        assert_eq!(&short[..], &long[..70]);
+    }
+}
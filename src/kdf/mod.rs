@@ -0,0 +1,43 @@
+#[cfg(feature = "hkdf")]
+mod hkdf;
+
+#[cfg(feature = "pbkdf2")]
+mod pbkdf2;
+
+#[cfg(feature = "hkdf")]
+pub use hkdf::Hkdf;
+
+#[cfg(feature = "pbkdf2")]
+pub use pbkdf2::Pbkdf2;
+
+pub mod prelude {
+    #[cfg(all(
+        any(feature = "hkdf", feature = "pbkdf2"),
+        any(feature = "sha224", feature = "sha256", feature = "sha384", feature = "sha512")
+    ))]
+    use crate::hash::prelude::*;
+
+    #[cfg(all(feature = "hkdf", feature = "sha224"))]
+    pub type HkdfSha224 = super::Hkdf<Sha224>;
+
+    #[cfg(all(feature = "hkdf", feature = "sha256"))]
+    pub type HkdfSha256 = super::Hkdf<Sha256>;
+
+    #[cfg(all(feature = "hkdf", feature = "sha384"))]
+    pub type HkdfSha384 = super::Hkdf<Sha384>;
+
+    #[cfg(all(feature = "hkdf", feature = "sha512"))]
+    pub type HkdfSha512 = super::Hkdf<Sha512>;
+
+    #[cfg(all(feature = "pbkdf2", feature = "sha224"))]
+    pub type Pbkdf2Sha224 = super::Pbkdf2<Sha224>;
+
+    #[cfg(all(feature = "pbkdf2", feature = "sha256"))]
+    pub type Pbkdf2Sha256 = super::Pbkdf2<Sha256>;
+
+    #[cfg(all(feature = "pbkdf2", feature = "sha384"))]
+    pub type Pbkdf2Sha384 = super::Pbkdf2<Sha384>;
+
+    #[cfg(all(feature = "pbkdf2", feature = "sha512"))]
+    pub type Pbkdf2Sha512 = super::Pbkdf2<Sha512>;
+}
@@ -0,0 +1,144 @@
+use crate::error::SymcError;
+use crate::hash::Hasher;
+use crate::mac::{Hmac, Mac};
+use zeroize::Zeroize;
+
+/// PBKDF2 (RFC 8018, 5.2) with HMAC as the PRF.
+///
+/// The password is absorbed once, every iteration then only clones the padded-key
+/// hasher states, so it costs two compressions for hashes whose output fits in a block.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Pbkdf2<H: Hasher> {
+    prf: Hmac<H>
+}
+
+impl<H: Hasher> Pbkdf2<H> {
+    pub fn new(password: &[u8]) -> Self {
+        Self { prf: Hmac::<H>::new(password) }
+    }
+
+    /// Derives `output.len()` bytes of key material into `output`.
+    pub fn derive(&self, salt: &[u8], iterations: u32, output: &mut [u8]) -> Result<(), SymcError> {
+        Self::check_params(iterations, output.len())?;
+
+        for (i, chunk) in output.chunks_mut(H::OUTPUT_SIZE).enumerate() {
+            self.derive_block(salt, iterations, (i + 1) as u32, chunk);
+        }
+        Ok(())
+    }
+
+    /// Derives directly into a key type, e.g. `Aes256Key`, filling its whole length.
+    pub fn derive_key<K: Default + AsMut<[u8]>>(&self, salt: &[u8], iterations: u32) -> Result<K, SymcError> {
+        let mut key = K::default();
+        self.derive(salt, iterations, key.as_mut())?;
+        Ok(key)
+    }
+
+    /// Same output as `derive`, with the output blocks T_1..T_l spread over up to `threads` threads.
+    #[cfg(feature = "std")]
+    pub fn derive_parallel(&self, salt: &[u8], iterations: u32, output: &mut [u8], threads: usize) -> Result<(), SymcError>
+    where Self: Sync {
+        Self::check_params(iterations, output.len())?;
+
+        let blocks = output.len().div_ceil(H::OUTPUT_SIZE);
+        let threads = threads.clamp(1, blocks.max(1));
+        if threads == 1 {
+            return self.derive(salt, iterations, output);
+        }
+
+        // each thread takes a run of whole blocks, so block indices stay contiguous
+        let blocks_per_thread = blocks.div_ceil(threads);
+        std::thread::scope(|scope| {
+            for (t, part) in output.chunks_mut(blocks_per_thread * H::OUTPUT_SIZE).enumerate() {
+                scope.spawn(move || {
+                    let first_index = t * blocks_per_thread + 1;
+                    for (i, chunk) in part.chunks_mut(H::OUTPUT_SIZE).enumerate() {
+                        self.derive_block(salt, iterations, (first_index + i) as u32, chunk);
+                    }
+                });
+            }
+        });
+        Ok(())
+    }
+
+    /// One-shot `Pbkdf2::new(password).derive(salt, iterations, output)`.
+    pub fn compute(password: &[u8], salt: &[u8], iterations: u32, output: &mut [u8]) -> Result<(), SymcError> {
+        Self::new(password).derive(salt, iterations, output)
+    }
+
+    fn check_params(iterations: u32, output_len: usize) -> Result<(), SymcError> {
+        // dkLen is limited to (2^32 - 1) * hLen
+        if iterations == 0 || output_len as u64 > u32::MAX as u64 * H::OUTPUT_SIZE as u64 {
+            return Err(SymcError::InvalidLength);
+        }
+        Ok(())
+    }
+
+    /// T_i = U_1 ^ U_2 ^ ... ^ U_c, truncated to `output.len()`
+    fn derive_block(&self, salt: &[u8], iterations: u32, index: u32, output: &mut [u8]) {
+        // U_1 = PRF(P, S || INT(i))
+        let mut prf = self.prf.clone();
+        prf.update(salt);
+        prf.update(&index.to_be_bytes());
+        let mut u = prf.finalize();
+        let mut t = u.clone();
+
+        // U_j = PRF(P, U_{j-1})
+        for _ in 1..iterations {
+            let next = self.prf.compute_from_states(u.as_ref());
+            u.zeroize();
+            u = next;
+            for (t_byte, u_byte) in t.as_mut().iter_mut().zip(u.as_ref()) {
+                *t_byte ^= u_byte;
+            }
+        }
+
+        output.copy_from_slice(&t.as_ref()[..output.len()]);
+        u.zeroize();
+        t.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hash::prelude::*;
+
+    #[test]
+    fn test_pbkdf2_states_match_hmac() {
+        let hmac = Hmac::<Sha256>::new(b"password");
+        let expected = Hmac::<Sha256>::compute(b"password", b"some iteration input");
+        assert_eq!(hmac.compute_from_states(b"some iteration input").as_ref(), expected.as_ref());
+    }
+
+    #[test]
+    fn test_pbkdf2_zero_iterations_rejected() {
+        let mut output = [0u8; 32];
+        assert_eq!(Pbkdf2::<Sha256>::compute(b"password", b"salt", 0, &mut output), Err(SymcError::InvalidLength));
+    }
+
+    #[test]
+    fn test_pbkdf2_output_is_prefix_of_longer_output() {
+        let pbkdf2 = Pbkdf2::<Sha256>::new(b"password");
+        let mut short = [0u8; 20];
+        let mut long = [0u8; 100];
+        pbkdf2.derive(b"salt", 3, &mut short).unwrap();
+        pbkdf2.derive(b"salt", 3, &mut long).unwrap();
+        assert_eq!(&short[..], &long[..20]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_pbkdf2_parallel_matches_serial() {
+        let pbkdf2 = Pbkdf2::<Sha256>::new(b"password");
+        let mut serial = [0u8; 250];
+        pbkdf2.derive(b"salt", 10, &mut serial).unwrap();
+
+        for threads in [0, 1, 2, 3, 8, 64] {
+            let mut parallel = [0u8; 250];
+            pbkdf2.derive_parallel(b"salt", 10, &mut parallel, threads).unwrap();
+            assert_eq!(serial, parallel);
+        }
+    }
+}
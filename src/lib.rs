@@ -5,10 +5,14 @@ extern crate alloc;
 
 pub mod error;
 
+pub mod aead;
+
 pub mod hash;
 
 pub mod cipher;
 
 pub mod mac;
 
+pub mod kdf;
+
 pub mod padding;
@@ -0,0 +1,127 @@
+use crate::error::SymcError;
+#[cfg(feature = "blake2b")]
+use crate::hash::prelude::{Blake2bOutput, Blake2bParams, Blake2bVar};
+#[cfg(feature = "blake2s")]
+use crate::hash::prelude::{Blake2sOutput, Blake2sParams, Blake2sVar};
+use crate::mac::Mac;
+use zeroize::Zeroize;
+
+// Keyed BLAKE2 (RFC 7693, 2.5) is a MAC on its own, no HMAC construction is needed.
+macro_rules! blake2_mac_impl {
+    ($name:ident, $var:ident, $params:ident, $output:ident, $max_output:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $name<const N: usize> {
+            core: $var
+        }
+
+        impl<const N: usize> $name<N> {
+            const VALID_OUTPUT_SIZE: () = assert!(N >= 1 && N <= $max_output, "unsupported BLAKE2 output size");
+
+            /// Keyed hashing with salt or personalization, the output size of `params` is replaced by `N`.
+            pub fn with_params(params: &$params) -> Result<Self, SymcError> {
+                let () = Self::VALID_OUTPUT_SIZE;
+                Ok(Self { core: params.clone().output_size(N).build()? })
+            }
+
+            /// Keyed hashing that fails with `SymcError::InvalidLength` on keys longer than the
+            /// maximum output size, as RFC 7693 and libsodium do.
+            pub fn new_from_slice(key: &[u8]) -> Result<Self, SymcError> {
+                Self::with_params(&$params::new().key(key))
+            }
+        }
+
+        impl<const N: usize> Mac for $name<N> {
+            const OUTPUT_SIZE: usize = N;
+            type Output = $output<N>;
+            type Key = [u8];
+
+            /// Keys longer than the maximum output size are replaced by their unkeyed BLAKE2
+            /// hash instead of failing. No specification defines this, tags under such keys
+            /// will not match other implementations, use `new_from_slice` to reject them.
+            fn new(key: &Self::Key) -> Self {
+                let mut digest = [0u8; $max_output];
+                let key = if key.len() > $max_output {
+                    let mut hasher = $var::new($max_output).expect("maximum output size is valid");
+                    hasher.update(key);
+                    hasher.finalize_into(&mut digest).expect("digest matches the output size");
+                    &digest[..]
+                } else {
+                    key
+                };
+
+                let mac = Self::with_params(&$params::new().key(key)).expect("key length is within the limit");
+                digest.zeroize();
+                mac
+            }
+
+            fn update(&mut self, input: &[u8]) {
+                self.core.update(input);
+            }
+
+            fn finalize(mut self) -> Self::Output where Self: Sized {
+                let mut output = $output::default();
+                // N is the configured output size
+                let _ = self.core.finalize_inner(output.as_mut());
+                output
+            }
+
+            fn reset(&mut self) {
+                self.core.reset();
+            }
+        }
+    };
+}
+
+#[cfg(feature = "blake2b")]
+blake2_mac_impl!(Blake2bMac, Blake2bVar, Blake2bParams, Blake2bOutput, 64, "Keyed BLAKE2b with an `N` byte tag");
+
+#[cfg(feature = "blake2s")]
+blake2_mac_impl!(Blake2sMac, Blake2sVar, Blake2sParams, Blake2sOutput, 32, "Keyed BLAKE2s with an `N` byte tag");
+
+#[cfg(feature = "blake2b")]
+pub type Blake2bMac512 = Blake2bMac<64>;
+#[cfg(feature = "blake2b")]
+pub type Blake2bMac256 = Blake2bMac<32>;
+
+#[cfg(feature = "blake2s")]
+pub type Blake2sMac256 = Blake2sMac<32>;
+#[cfg(feature = "blake2s")]
+pub type Blake2sMac128 = Blake2sMac<16>;
+
+#[cfg(all(test, feature = "blake2b"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_blake2b_mac_matches_keyed_hash() {
+        let mut hasher = Blake2bVar::new_keyed(b"key", 64).unwrap();
+        hasher.update(b"message");
+        let mut expected = [0u8; 64];
+        hasher.finalize_into(&mut expected).unwrap();
+
+        assert_eq!(Blake2bMac512::compute(b"key", b"message").as_ref(), &expected);
+    }
+
+    #[test]
+    fn test_blake2b_mac_long_key() {
+        let long_key = [0x5au8; 65];
+        let mut hasher = Blake2bVar::new(64).unwrap();
+        hasher.update(&long_key);
+        let mut hashed_key = [0u8; 64];
+        hasher.finalize_into(&mut hashed_key).unwrap();
+
+        assert_eq!(Blake2bMac512::compute(&long_key, b"message").as_ref(), Blake2bMac512::compute(&hashed_key, b"message").as_ref());
+        assert_ne!(Blake2bMac512::compute(&long_key, b"message").as_ref(), Blake2bMac512::compute(&long_key[..64], b"message").as_ref());
+    }
+
+    #[test]
+    fn test_blake2b_mac_new_from_slice() {
+        assert_eq!(Blake2bMac512::new_from_slice(&[0x5a; 65]).err(), Some(SymcError::InvalidLength));
+
+        let mut mac = Blake2bMac512::new_from_slice(&[0x5a; 64]).unwrap();
+        mac.update(b"message");
+        assert_eq!(mac.finalize().as_ref(), Blake2bMac512::compute(&[0x5a; 64], b"message").as_ref());
+    }
+}
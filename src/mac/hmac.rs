@@ -31,6 +31,19 @@ impl<H: Hasher> Hmac<H> {
 
         processed_key
     }
+
+    /// HMAC of `input` from the precomputed padded-key states, costs two compressions for short input.
+    /// Only meaningful on an instance that has not been updated.
+    #[cfg(feature = "pbkdf2")]
+    pub(crate) fn compute_from_states(&self, input: &[u8]) -> H::Output {
+        let mut inner_hasher = self.inner_hasher.clone();
+        inner_hasher.update(input);
+        let inner_hash = inner_hasher.finalize();
+
+        let mut outer_hasher = self.outer_hasher.clone();
+        outer_hasher.update(inner_hash.as_ref());
+        outer_hasher.finalize()
+    }
 }
 
 impl<H: Hasher> Mac for Hmac<H> {
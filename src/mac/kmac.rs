@@ -0,0 +1,132 @@
+use crate::hash::XofReader;
+use crate::hash::keccak::{KeccakReader, KeccakSponge, Shake128Reader, Shake256Reader, CSHAKE_DOMAIN};
+use crate::mac::Mac;
+use zeroize::Zeroize;
+
+// KMAC(K, X, L, S) = cSHAKE(bytepad(encode_string(K), rate) || X || right_encode(L), L, "KMAC", S)
+macro_rules! kmac_impl {
+    ($name:ident, $output:ident, $output_size:expr, $rate:expr, $reader:ident, $doc:expr) => {
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $output([u8; $output_size]);
+        impl Default for $output {
+            fn default() -> Self {
+                Self([0u8; $output_size])
+            }
+        }
+        impl AsRef<[u8]> for $output {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+        impl AsMut<[u8]> for $output {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+        impl From<[u8; $output_size]> for $output {
+            fn from(array: [u8; $output_size]) -> Self {
+                Self(array)
+            }
+        }
+        impl From<$output> for [u8; $output_size] {
+            fn from(output: $output) -> Self {
+                output.0
+            }
+        }
+
+        #[doc = $doc]
+        ///
+        /// Through `Mac` it produces a fixed size tag, `finalize_into` and `finalize_xof` give other lengths.
+        #[derive(Clone, Zeroize)]
+        #[zeroize(drop)]
+        pub struct $name {
+            sponge: KeccakSponge<$rate>,
+            initial: KeccakSponge<$rate>
+        }
+
+        impl $name {
+            pub fn new_customized(key: &[u8], customization: &[u8]) -> Self {
+                let mut sponge = KeccakSponge::cshake(b"KMAC", customization);
+                sponge.start_bytepad();
+                sponge.absorb_encode_string(key);
+                sponge.fill_block();
+                Self { initial: sponge.clone(), sponge }
+            }
+
+            /// Fills `output`, its length is bound into the tag.
+            pub fn finalize_into(self, output: &mut [u8]) {
+                let mut sponge = self.sponge.clone();
+                sponge.absorb_right_encode((output.len() as u64).wrapping_mul(8));
+                KeccakReader::new(sponge, CSHAKE_DOMAIN).squeeze(output);
+            }
+
+            /// KMACXOF (SP 800-185, 4.3.1), output of unbounded length.
+            pub fn finalize_xof(self) -> $reader {
+                let mut sponge = self.sponge.clone();
+                sponge.absorb_right_encode(0);
+                KeccakReader::new(sponge, CSHAKE_DOMAIN)
+            }
+        }
+
+        impl Mac for $name {
+            const OUTPUT_SIZE: usize = $output_size;
+            type Output = $output;
+            type Key = [u8];
+
+            fn new(key: &Self::Key) -> Self {
+                Self::new_customized(key, &[])
+            }
+
+            fn update(&mut self, input: &[u8]) {
+                self.sponge.absorb(input);
+            }
+
+            fn finalize(self) -> Self::Output where Self: Sized {
+                let mut output = $output::default();
+                self.finalize_into(output.as_mut());
+                output
+            }
+
+            fn reset(&mut self) {
+                self.sponge = self.initial.clone();
+            }
+        }
+    };
+}
+
+kmac_impl!(Kmac128, Kmac128Output, 32, 168, Shake128Reader, "KMAC128 (SP 800-185, 4) with a 32 byte tag");
+kmac_impl!(Kmac256, Kmac256Output, 64, 136, Shake256Reader, "KMAC256 (SP 800-185, 4) with a 64 byte tag");
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kmac_output_length_is_bound() {
+        let mut short = [0u8; 16];
+        Kmac128::new(b"key").finalize_into(&mut short);
+        let long = Kmac128::compute(b"key", &[]);
+        assert_ne!(&short[..], &long.as_ref()[..16]);
+    }
+
+    #[test]
+    fn test_kmac_xof_differs_from_fixed() {
+        let mut xof = [0u8; 32];
+        Kmac128::new(b"key").finalize_xof().squeeze(&mut xof);
+        let fixed = Kmac128::compute(b"key", &[]);
+        assert_ne!(&xof[..], fixed.as_ref());
+    }
+
+    #[test]
+    fn test_kmac_reset() {
+        let mut kmac = Kmac256::new_customized(b"key", b"custom");
+        kmac.update(b"discarded");
+        kmac.reset();
+        kmac.update(b"message");
+
+        let mut fresh = Kmac256::new_customized(b"key", b"custom");
+        fresh.update(b"message");
+        assert_eq!(kmac.finalize().as_ref(), fresh.finalize().as_ref());
+    }
+}
@@ -3,6 +3,18 @@ use zeroize::Zeroize;
 #[cfg(feature = "hmac")]
 mod hmac;
 
+#[cfg(feature = "hmac")]
+pub use hmac::Hmac;
+
+#[cfg(feature = "poly1305")]
+mod poly1305;
+
+#[cfg(feature = "kmac")]
+mod kmac;
+
+#[cfg(any(feature = "blake2b", feature = "blake2s"))]
+mod blake2;
+
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -57,7 +69,7 @@ pub trait Mac: Clone + Zeroize {
 }
 
 // Constant time comparison
-fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
     if a.len() != b.len() {
         return false;
     }
@@ -83,4 +95,52 @@ pub mod prelude {
 
     #[cfg(all(feature = "hmac", feature = "sha512"))]
     pub type HmacSha512 = super::hmac::Hmac<Sha512>;
-}
\ No newline at end of file
+
+    #[cfg(all(feature = "hmac", feature = "sha512-224"))]
+    pub type HmacSha512_224 = super::hmac::Hmac<Sha512_224>;
+
+    #[cfg(all(feature = "hmac", feature = "sha512-256"))]
+    pub type HmacSha512_256 = super::hmac::Hmac<Sha512_256>;
+
+    #[cfg(all(feature = "hmac", feature = "sha3-224"))]
+    pub type HmacSha3_224 = super::hmac::Hmac<Sha3_224>;
+
+    #[cfg(all(feature = "hmac", feature = "sha3-256"))]
+    pub type HmacSha3_256 = super::hmac::Hmac<Sha3_256>;
+
+    #[cfg(all(feature = "hmac", feature = "sha3-384"))]
+    pub type HmacSha3_384 = super::hmac::Hmac<Sha3_384>;
+
+    #[cfg(all(feature = "hmac", feature = "sha3-512"))]
+    pub type HmacSha3_512 = super::hmac::Hmac<Sha3_512>;
+
+    #[cfg(all(feature = "hmac", feature = "ripemd160"))]
+    pub type HmacRipemd160 = super::hmac::Hmac<Ripemd160>;
+
+    #[cfg(all(feature = "hmac", feature = "sm3"))]
+    pub type HmacSm3 = super::hmac::Hmac<Sm3>;
+
+    #[cfg(all(feature = "hmac", feature = "streebog"))]
+    pub type HmacStreebog256 = super::hmac::Hmac<Streebog256>;
+
+    #[cfg(all(feature = "hmac", feature = "streebog"))]
+    pub type HmacStreebog512 = super::hmac::Hmac<Streebog512>;
+
+    #[cfg(all(feature = "hmac", feature = "legacy"))]
+    pub type HmacSha1 = super::hmac::Hmac<Sha1>;
+
+    #[cfg(all(feature = "hmac", feature = "legacy"))]
+    pub type HmacMd5 = super::hmac::Hmac<Md5>;
+
+    #[cfg(feature = "poly1305")]
+    pub use super::poly1305::{Poly1305, Poly1305Key, Poly1305Output};
+
+    #[cfg(feature = "kmac")]
+    pub use super::kmac::{Kmac128, Kmac128Output, Kmac256, Kmac256Output};
+
+    #[cfg(feature = "blake2b")]
+    pub use super::blake2::{Blake2bMac, Blake2bMac256, Blake2bMac512};
+
+    #[cfg(feature = "blake2s")]
+    pub use super::blake2::{Blake2sMac, Blake2sMac128, Blake2sMac256};
+}
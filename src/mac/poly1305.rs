@@ -0,0 +1,313 @@
+use crate::mac::Mac;
+use zeroize::Zeroize;
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Poly1305Key([u8; 32]);
+impl AsRef<[u8]> for Poly1305Key {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Poly1305Key {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 32]> for Poly1305Key {
+    fn from(array: [u8; 32]) -> Self {
+        Self(array)
+    }
+}
+impl From<Poly1305Key> for [u8; 32] {
+    fn from(output: Poly1305Key) -> [u8; 32] {
+        output.0
+    }
+}
+
+#[derive(Clone, Default, Zeroize)]
+#[zeroize(drop)]
+pub struct Poly1305Output([u8; 16]);
+impl AsRef<[u8]> for Poly1305Output {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+impl AsMut<[u8]> for Poly1305Output {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+impl From<[u8; 16]> for Poly1305Output {
+    fn from(array: [u8; 16]) -> Self {
+        Self(array)
+    }
+}
+impl From<Poly1305Output> for [u8; 16] {
+    fn from(output: Poly1305Output) -> [u8; 16] {
+        output.0
+    }
+}
+
+const MASK_26: u32 = 0x3ffffff;
+
+/// Poly1305 one-time authenticator (RFC 8439, 2.5), with h and r held in 26-bit limbs.
+///
+/// A key must never be used for more than one message.
+#[derive(Clone, Zeroize)]
+#[zeroize(drop)]
+pub struct Poly1305 {
+    r: [u32; 5],
+    s: [u32; 4],
+    h: [u32; 5],
+    buffer: [u8; 16],
+    buffer_len: usize
+}
+
+impl Poly1305 {
+    pub const BLOCK_SIZE: usize = 16;
+
+    /// h = (h + m) * r mod 2^130 - 5, `hibit` is the 2^128 bit appended to full blocks
+    fn process_block(&mut self, block: &[u8; 16], hibit: u32) {
+        let t0 = u32::from_le_bytes(block[0..4].try_into().unwrap());
+        let t1 = u32::from_le_bytes(block[4..8].try_into().unwrap());
+        let t2 = u32::from_le_bytes(block[8..12].try_into().unwrap());
+        let t3 = u32::from_le_bytes(block[12..16].try_into().unwrap());
+
+        // h += m
+        let h0 = self.h[0] + (t0 & MASK_26);
+        let h1 = self.h[1] + (((t0 >> 26) | (t1 << 6)) & MASK_26);
+        let h2 = self.h[2] + (((t1 >> 20) | (t2 << 12)) & MASK_26);
+        let h3 = self.h[3] + (((t2 >> 14) | (t3 << 18)) & MASK_26);
+        let h4 = self.h[4] + ((t3 >> 8) | hibit);
+
+        let [r0, r1, r2, r3, r4] = self.r.map(|r| r as u64);
+        // 2^130 ≡ 5, so limbs that overflow past r4 wrap around multiplied by 5
+        let (s1, s2, s3, s4) = (r1 * 5, r2 * 5, r3 * 5, r4 * 5);
+        let [h0, h1, h2, h3, h4] = [h0, h1, h2, h3, h4].map(|h| h as u64);
+
+        // h *= r
+        let d0 = h0 * r0 + h1 * s4 + h2 * s3 + h3 * s2 + h4 * s1;
+        let mut d1 = h0 * r1 + h1 * r0 + h2 * s4 + h3 * s3 + h4 * s2;
+        let mut d2 = h0 * r2 + h1 * r1 + h2 * r0 + h3 * s4 + h4 * s3;
+        let mut d3 = h0 * r3 + h1 * r2 + h2 * r1 + h3 * r0 + h4 * s4;
+        let mut d4 = h0 * r4 + h1 * r3 + h2 * r2 + h3 * r1 + h4 * r0;
+
+        // partial reduction mod 2^130 - 5
+        let mut c = d0 >> 26;
+        let mut h0 = (d0 as u32) & MASK_26;
+        d1 += c;
+        c = d1 >> 26;
+        let mut h1 = (d1 as u32) & MASK_26;
+        d2 += c;
+        c = d2 >> 26;
+        let h2 = (d2 as u32) & MASK_26;
+        d3 += c;
+        c = d3 >> 26;
+        let h3 = (d3 as u32) & MASK_26;
+        d4 += c;
+        c = d4 >> 26;
+        let h4 = (d4 as u32) & MASK_26;
+        h0 += (c as u32) * 5;
+        let c = h0 >> 26;
+        h0 &= MASK_26;
+        h1 += c;
+
+        self.h = [h0, h1, h2, h3, h4];
+    }
+}
+
+impl Mac for Poly1305 {
+    const OUTPUT_SIZE: usize = 16;
+    type Output = Poly1305Output;
+    type Key = Poly1305Key;
+
+    fn new(key: &Self::Key) -> Self {
+        let key = key.as_ref();
+        let t0 = u32::from_le_bytes(key[0..4].try_into().unwrap());
+        let t1 = u32::from_le_bytes(key[4..8].try_into().unwrap());
+        let t2 = u32::from_le_bytes(key[8..12].try_into().unwrap());
+        let t3 = u32::from_le_bytes(key[12..16].try_into().unwrap());
+
+        // r &= 0xffffffc0ffffffc0ffffffc0fffffff, split into 26-bit limbs
+        let r = [
+            t0 & 0x3ffffff,
+            ((t0 >> 26) | (t1 << 6)) & 0x3ffff03,
+            ((t1 >> 20) | (t2 << 12)) & 0x3ffc0ff,
+            ((t2 >> 14) | (t3 << 18)) & 0x3f03fff,
+            (t3 >> 8) & 0x00fffff
+        ];
+        let s = [
+            u32::from_le_bytes(key[16..20].try_into().unwrap()),
+            u32::from_le_bytes(key[20..24].try_into().unwrap()),
+            u32::from_le_bytes(key[24..28].try_into().unwrap()),
+            u32::from_le_bytes(key[28..32].try_into().unwrap())
+        ];
+
+        Self {
+            r,
+            s,
+            h: [0u32; 5],
+            buffer: [0u8; 16],
+            buffer_len: 0
+        }
+    }
+
+    fn update(&mut self, input: &[u8]) {
+        let mut input_pos = 0;
+
+        if self.buffer_len > 0 {
+            let remaining = Self::BLOCK_SIZE - self.buffer_len;
+            if remaining > input.len() {
+                self.buffer[self.buffer_len..(self.buffer_len + input.len())].copy_from_slice(input);
+                self.buffer_len += input.len();
+                return;
+            }
+            self.buffer[self.buffer_len..].copy_from_slice(&input[..remaining]);
+            let block = self.buffer;
+            self.process_block(&block, 1 << 24);
+            self.buffer_len = 0;
+            input_pos += remaining;
+        }
+
+        let mut chunks = input[input_pos..].chunks_exact(Self::BLOCK_SIZE);
+        for chunk in &mut chunks {
+            self.process_block(chunk.try_into().unwrap(), 1 << 24);
+        }
+
+        let remainder = chunks.remainder();
+        self.buffer[..remainder.len()].copy_from_slice(remainder);
+        self.buffer_len = remainder.len();
+    }
+
+    fn finalize(mut self) -> Self::Output where Self: Sized {
+        // the last partial block is padded with 0x01 followed by zeros, without the 2^128 bit
+        if self.buffer_len > 0 {
+            self.buffer[self.buffer_len] = 1;
+            self.buffer[(self.buffer_len + 1)..].fill(0);
+            let block = self.buffer;
+            self.process_block(&block, 0);
+        }
+
+        // fully carry h
+        let [mut h0, mut h1, mut h2, mut h3, mut h4] = self.h;
+        let mut c = h1 >> 26;
+        h1 &= MASK_26;
+        h2 += c;
+        c = h2 >> 26;
+        h2 &= MASK_26;
+        h3 += c;
+        c = h3 >> 26;
+        h3 &= MASK_26;
+        h4 += c;
+        c = h4 >> 26;
+        h4 &= MASK_26;
+        h0 += c * 5;
+        c = h0 >> 26;
+        h0 &= MASK_26;
+        h1 += c;
+
+        // g = h + -p = h - (2^130 - 5)
+        let mut g0 = h0.wrapping_add(5);
+        c = g0 >> 26;
+        g0 &= MASK_26;
+        let mut g1 = h1.wrapping_add(c);
+        c = g1 >> 26;
+        g1 &= MASK_26;
+        let mut g2 = h2.wrapping_add(c);
+        c = g2 >> 26;
+        g2 &= MASK_26;
+        let mut g3 = h3.wrapping_add(c);
+        c = g3 >> 26;
+        g3 &= MASK_26;
+        let g4 = h4.wrapping_add(c).wrapping_sub(1 << 26);
+
+        // select h if h < p, or g if h >= p, without branching
+        let mask = (g4 >> 31).wrapping_sub(1);
+        h0 = (h0 & !mask) | (g0 & mask);
+        h1 = (h1 & !mask) | (g1 & mask);
+        h2 = (h2 & !mask) | (g2 & mask);
+        h3 = (h3 & !mask) | (g3 & mask);
+        h4 = (h4 & !mask) | (g4 & mask);
+
+        // h = h % 2^128
+        let w0 = h0 | (h1 << 26);
+        let w1 = (h1 >> 6) | (h2 << 20);
+        let w2 = (h2 >> 12) | (h3 << 14);
+        let w3 = (h3 >> 18) | (h4 << 8);
+
+        // tag = (h + s) % 2^128
+        let mut result = [0u8; 16];
+        let mut f = 0u64;
+        for (i, (w, s)) in [w0, w1, w2, w3].iter().zip(self.s.iter()).enumerate() {
+            f = (*w as u64) + (*s as u64) + (f >> 32);
+            result[i * 4..(i + 1) * 4].copy_from_slice(&(f as u32).to_le_bytes());
+        }
+        Poly1305Output(result)
+    }
+
+    fn reset(&mut self) {
+        self.h = [0u32; 5];
+        self.buffer = [0u8; 16];
+        self.buffer_len = 0;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Test vector from RFC 8439, 2.5.2
+    const KEY: [u8; 32] = [
+        0x85, 0xd6, 0xbe, 0x78, 0x57, 0x55, 0x6d, 0x33, 0x7f, 0x44, 0x52, 0xfe, 0x42, 0xd5, 0x06, 0xa8,
+        0x01, 0x03, 0x80, 0x8a, 0xfb, 0x0d, 0xb2, 0xfd, 0x4a, 0xbf, 0xf6, 0xaf, 0x41, 0x49, 0xf5, 0x1b,
+    ];
+    const MESSAGE: &[u8] = b"Cryptographic Forum Research Group";
+    const TAG: [u8; 16] = [
+        0xa8, 0x06, 0x1d, 0xc1, 0x30, 0x51, 0x36, 0xc6, 0xc2, 0x2b, 0x8b, 0xaf, 0x0c, 0x01, 0x27, 0xa9,
+    ];
+
+    #[test]
+    fn test_poly1305_rfc8439() {
+        let tag = Poly1305::compute(&KEY.into(), MESSAGE);
+        assert_eq!(tag.as_ref(), &TAG);
+    }
+
+    #[test]
+    fn test_poly1305_clamp() {
+        let poly = Poly1305::new(&[0xffu8; 32].into());
+        // r = 0x0ffffffc0ffffffc0ffffffc0fffffff
+        assert_eq!(poly.r, [0x3ffffff, 0x3ffff03, 0x3ffc0ff, 0x3f03fff, 0x00fffff]);
+    }
+
+    #[test]
+    fn test_poly1305_buffer_management() {
+        let mut poly = Poly1305::new(&KEY.into());
+        poly.update(&MESSAGE[..10]);
+        assert_eq!(poly.buffer_len, 10);
+        assert_eq!(poly.h, [0u32; 5]);
+        poly.update(&MESSAGE[10..]);
+        assert_eq!(poly.buffer_len, MESSAGE.len() % 16);
+        assert_eq!(poly.finalize().as_ref(), &TAG);
+    }
+
+    #[test]
+    fn test_poly1305_reset() {
+        let mut poly = Poly1305::new(&KEY.into());
+        poly.update(b"discarded");
+        poly.reset();
+        poly.update(MESSAGE);
+        assert_eq!(poly.finalize().as_ref(), &TAG);
+    }
+
+    /// Test vector #5 from RFC 8439, A.3, h reaches p and must be reduced
+    #[test]
+    fn test_poly1305_modular_wrap() {
+        let mut key = [0u8; 32];
+        key[0] = 0x02;
+        let tag = Poly1305::compute(&key.into(), &[0xffu8; 16]);
+        let mut expected = [0u8; 16];
+        expected[0] = 0x03;
+        assert_eq!(tag.as_ref(), &expected);
+    }
+}
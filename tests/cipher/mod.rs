@@ -2,9 +2,85 @@ use wovocrypt::cipher::aes::*;
 use wovocrypt::cipher::mode::{SymcEncryptor, SymcDecryptor};
 
 pub mod symc_cbc_test;
+pub mod symc_ccm_test;
+#[cfg(any(feature = "aegis128l", feature = "aegis256"))]
+pub mod symc_aegis_test;
+#[cfg(feature = "aria")]
+pub mod symc_aria_test;
+#[cfg(feature = "ascon-aead128")]
+pub mod symc_ascon_aead_test;
+#[cfg(feature = "camellia")]
+pub mod symc_camellia_test;
+pub mod symc_cfb_test;
+#[cfg(feature = "chacha20")]
+pub mod symc_chacha20_test;
+#[cfg(any(feature = "chacha20poly1305", feature = "xchacha20poly1305"))]
+pub mod symc_chacha20poly1305_test;
 pub mod symc_ctr_test;
+#[cfg(feature = "hazmat")]
+pub mod symc_ecb_test;
+pub mod symc_gcm_test;
+#[cfg(feature = "kuznyechik")]
+pub mod symc_kuznyechik_test;
+#[cfg(feature = "legacy")]
+pub mod symc_legacy_test;
+#[cfg(any(feature = "speck", feature = "simon", feature = "present"))]
+pub mod symc_lightweight_test;
+pub mod symc_ofb_test;
+#[cfg(feature = "salsa20")]
+pub mod symc_salsa20_test;
+#[cfg(feature = "secretbox")]
+pub mod symc_secretbox_test;
+#[cfg(feature = "serpent")]
+pub mod symc_serpent_test;
+#[cfg(feature = "sm4")]
+pub mod symc_sm4_test;
+#[cfg(feature = "twofish")]
+pub mod symc_twofish_test;
 
 pub struct SymcGoldData {
     pub plaintext: &'static [u8],
     pub ciphertext: &'static [u8],
-}
\ No newline at end of file
+}
+
+pub struct AeadGoldData {
+    pub key: &'static [u8],
+    pub iv: &'static [u8],
+    pub aad: &'static [u8],
+    pub plaintext: &'static [u8],
+    pub ciphertext: &'static [u8],
+    pub tag: &'static [u8],
+}
+
+pub fn aes128_key(key: &[u8]) -> Aes128Key {
+    Aes128Key::from(<[u8; 16]>::try_from(key).unwrap())
+}
+
+pub fn aes192_key(key: &[u8]) -> Aes192Key {
+    Aes192Key::from(<[u8; 24]>::try_from(key).unwrap())
+}
+
+pub fn aes256_key(key: &[u8]) -> Aes256Key {
+    Aes256Key::from(<[u8; 32]>::try_from(key).unwrap())
+}
+
+/// Encrypts `data.plaintext` in one call, checks it against `data.ciphertext` and decrypts it back.
+pub fn roundtrip<E: SymcEncryptor, D: SymcDecryptor<Key = E::Key, IV = E::IV>>(key: &E::Key, iv: &E::IV, data: &SymcGoldData) {
+    let mut out_ciphertext = [0u8; 64];
+    let mut encryptor = E::new(key, iv);
+
+    let mut encrypt_written = encryptor.update(data.plaintext, &mut out_ciphertext).expect("Encryption update failed");
+    encrypt_written += encryptor.finalize(&mut out_ciphertext[encrypt_written..data.ciphertext.len()]).expect("Encryption finalize failed");
+
+    assert_eq!(encrypt_written, data.ciphertext.len());
+    assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+    let mut out_plaintext = [0u8; 64];
+    let mut decryptor = D::new(key, iv);
+
+    let mut decrypt_written = decryptor.update(&out_ciphertext[..encrypt_written], &mut out_plaintext).expect("Decryption update failed");
+    decrypt_written += decryptor.finalize(&mut out_plaintext[decrypt_written..data.plaintext.len()]).expect("Decryption finalize failed");
+
+    assert_eq!(decrypt_written, data.plaintext.len());
+    assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+}
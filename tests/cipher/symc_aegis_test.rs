@@ -0,0 +1,297 @@
+use wovocrypt::aead::Aead;
+#[cfg(feature = "aegis128l")]
+use wovocrypt::aead::aegis::{Aegis128L, Aegis128LEncryptor};
+#[cfg(feature = "aegis256")]
+use wovocrypt::aead::aegis::{Aegis256, Aegis256Encryptor};
+use wovocrypt::error::SymcError;
+
+struct AegisGoldData {
+    aad: &'static [u8],
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+    tag128: &'static [u8],
+    tag256: &'static [u8],
+}
+
+const AAD: &[u8] = &[
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29,
+];
+const MESSAGE: &[u8] = &[
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+];
+
+// draft-irtf-cfrg-aegis-aead, Appendix A.2
+#[cfg(feature = "aegis128l")]
+const AEGIS128L_KEY: [u8; 16] = [0x10, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+#[cfg(feature = "aegis128l")]
+const AEGIS128L_NONCE: &[u8] = &[0x10, 0x00, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+
+#[cfg(feature = "aegis128l")]
+const AEGIS128L_GOLD_DATA: &[AegisGoldData] = &[
+    // Test Vector 1
+    AegisGoldData {
+        aad: &[],
+        plaintext: &[0u8; 16],
+        ciphertext: &[0xc1, 0xc0, 0xe5, 0x8b, 0xd9, 0x13, 0x00, 0x6f, 0xeb, 0xa0, 0x0f, 0x4b, 0x3c, 0xc3, 0x59, 0x4e],
+        tag128: &[0xab, 0xe0, 0xec, 0xe8, 0x0c, 0x24, 0x86, 0x8a, 0x22, 0x6a, 0x35, 0xd1, 0x6b, 0xda, 0xe3, 0x7a],
+        tag256: &[
+            0x25, 0x83, 0x5b, 0xfb, 0xb2, 0x16, 0x32, 0x17, 0x6c, 0xf0, 0x38, 0x40, 0x68, 0x7c, 0xb9, 0x68,
+            0xca, 0xce, 0x46, 0x17, 0xaf, 0x1b, 0xd0, 0xf7, 0xd0, 0x64, 0xc6, 0x39, 0xa5, 0xc7, 0x9e, 0xe4,
+        ],
+    },
+    // Test Vector 2
+    AegisGoldData {
+        aad: &[],
+        plaintext: &[],
+        ciphertext: &[],
+        tag128: &[0xc2, 0xb8, 0x79, 0xa6, 0x7d, 0xef, 0x9d, 0x74, 0xe6, 0xc1, 0x4f, 0x70, 0x8b, 0xbc, 0xc9, 0xb4],
+        tag256: &[
+            0x13, 0x60, 0xdc, 0x9d, 0xb8, 0xae, 0x42, 0x45, 0x5f, 0x6e, 0x5b, 0x6a, 0x9d, 0x48, 0x8e, 0xa4,
+            0xf2, 0x18, 0x4c, 0x4e, 0x12, 0x12, 0x02, 0x49, 0x33, 0x5c, 0x4e, 0xe8, 0x4b, 0xaf, 0xe2, 0x5d,
+        ],
+    },
+    // Test Vector 3
+    AegisGoldData {
+        aad: AAD.split_at(8).0,
+        plaintext: MESSAGE.split_at(32).0,
+        ciphertext: &[
+            0x79, 0xd9, 0x45, 0x93, 0xd8, 0xc2, 0x11, 0x9d, 0x7e, 0x8f, 0xd9, 0xb8, 0xfc, 0x77, 0x84, 0x5c,
+            0x5c, 0x07, 0x7a, 0x05, 0xb2, 0x52, 0x8b, 0x6a, 0xc5, 0x4b, 0x56, 0x3a, 0xed, 0x8e, 0xfe, 0x84,
+        ],
+        tag128: &[0xcc, 0x6f, 0x33, 0x72, 0xf6, 0xaa, 0x1b, 0xb8, 0x23, 0x88, 0xd6, 0x95, 0xc3, 0x96, 0x2d, 0x9a],
+        tag256: &[
+            0x02, 0x2c, 0xb7, 0x96, 0xfe, 0x7e, 0x0a, 0xe1, 0x19, 0x75, 0x25, 0xff, 0x67, 0xe3, 0x09, 0x48,
+            0x4c, 0xfb, 0xab, 0x65, 0x28, 0xdd, 0xef, 0x89, 0xf1, 0x7d, 0x74, 0xef, 0x8e, 0xcd, 0x82, 0xb3,
+        ],
+    },
+    // Test Vector 4
+    AegisGoldData {
+        aad: AAD.split_at(8).0,
+        plaintext: MESSAGE.split_at(14).0,
+        ciphertext: &[0x79, 0xd9, 0x45, 0x93, 0xd8, 0xc2, 0x11, 0x9d, 0x7e, 0x8f, 0xd9, 0xb8, 0xfc, 0x77],
+        tag128: &[0x5c, 0x04, 0xb3, 0xdb, 0xa8, 0x49, 0xb2, 0x70, 0x1e, 0xff, 0xbe, 0x32, 0xc7, 0xf0, 0xfa, 0xb7],
+        tag256: &[
+            0x86, 0xf1, 0xb8, 0x0b, 0xfb, 0x46, 0x3a, 0xba, 0x71, 0x1d, 0x15, 0x40, 0x5d, 0x09, 0x4b, 0xaf,
+            0x4a, 0x55, 0xa1, 0x5d, 0xbf, 0xec, 0x81, 0xa7, 0x6f, 0x35, 0xed, 0x0b, 0x9c, 0x8b, 0x04, 0xac,
+        ],
+    },
+    // Test Vector 5
+    AegisGoldData {
+        aad: AAD,
+        plaintext: MESSAGE.split_at(16).1,
+        ciphertext: &[
+            0xb3, 0x10, 0x52, 0xad, 0x1c, 0xca, 0x4e, 0x29, 0x1a, 0xbc, 0xf2, 0xdf, 0x35, 0x02, 0xe6, 0xbd,
+            0xb1, 0xbf, 0xd6, 0xdb, 0x36, 0x79, 0x8b, 0xe3, 0x60, 0x7b, 0x1f, 0x94, 0xd3, 0x44, 0x78, 0xaa,
+            0x7e, 0xde, 0x7f, 0x7a, 0x99, 0x0f, 0xec, 0x10,
+        ],
+        tag128: &[0x75, 0x42, 0xa7, 0x45, 0x73, 0x30, 0x14, 0xf9, 0x47, 0x44, 0x17, 0xb3, 0x37, 0x39, 0x95, 0x07],
+        tag256: &[
+            0xb9, 0x1e, 0x29, 0x47, 0xa3, 0x3d, 0xa8, 0xbe, 0xe8, 0x9b, 0x67, 0x94, 0xe6, 0x47, 0xba, 0xf0,
+            0xfc, 0x83, 0x5f, 0xf5, 0x74, 0xac, 0xa3, 0xfc, 0x27, 0xc3, 0x3b, 0xe0, 0xdb, 0x2a, 0xff, 0x98,
+        ],
+    },
+];
+
+// draft-irtf-cfrg-aegis-aead, Appendix A.3
+#[cfg(feature = "aegis256")]
+const AEGIS256_KEY: [u8; 32] = [
+    0x10, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+#[cfg(feature = "aegis256")]
+const AEGIS256_NONCE: &[u8] = &[
+    0x10, 0x00, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+#[cfg(feature = "aegis256")]
+const AEGIS256_GOLD_DATA: &[AegisGoldData] = &[
+    // Test Vector 1
+    AegisGoldData {
+        aad: &[],
+        plaintext: &[0u8; 16],
+        ciphertext: &[0x75, 0x4f, 0xc3, 0xd8, 0xc9, 0x73, 0x24, 0x6d, 0xcc, 0x6d, 0x74, 0x14, 0x12, 0xa4, 0xb2, 0x36],
+        tag128: &[0x3f, 0xe9, 0x19, 0x94, 0x76, 0x8b, 0x33, 0x2e, 0xd7, 0xf5, 0x70, 0xa1, 0x9e, 0xc5, 0x89, 0x6e],
+        tag256: &[
+            0x11, 0x81, 0xa1, 0xd1, 0x80, 0x91, 0x08, 0x2b, 0xf0, 0x26, 0x6f, 0x66, 0x29, 0x7d, 0x16, 0x7d,
+            0x2e, 0x68, 0xb8, 0x45, 0xf6, 0x1a, 0x3b, 0x05, 0x27, 0xd3, 0x1f, 0xc7, 0xb7, 0xb8, 0x9f, 0x13,
+        ],
+    },
+    // Test Vector 2
+    AegisGoldData {
+        aad: &[],
+        plaintext: &[],
+        ciphertext: &[],
+        tag128: &[0xe3, 0xde, 0xf9, 0x78, 0xa0, 0xf0, 0x54, 0xaf, 0xd1, 0xe7, 0x61, 0xd7, 0x55, 0x3a, 0xfb, 0xa3],
+        tag256: &[
+            0x6a, 0x34, 0x8c, 0x93, 0x0a, 0xdb, 0xd6, 0x54, 0x89, 0x6e, 0x16, 0x66, 0xaa, 0xd6, 0x7d, 0xe9,
+            0x89, 0xea, 0x75, 0xeb, 0xaa, 0x2b, 0x82, 0xfb, 0x58, 0x89, 0x77, 0xb1, 0xff, 0xec, 0x86, 0x4a,
+        ],
+    },
+    // Test Vector 3
+    AegisGoldData {
+        aad: AAD.split_at(8).0,
+        plaintext: MESSAGE.split_at(32).0,
+        ciphertext: &[
+            0xf3, 0x73, 0x07, 0x9e, 0xd8, 0x4b, 0x27, 0x09, 0xfa, 0xee, 0x37, 0x35, 0x84, 0x58, 0x5d, 0x60,
+            0xac, 0xcd, 0x19, 0x1d, 0xb3, 0x10, 0xef, 0x5d, 0x8b, 0x11, 0x83, 0x3d, 0xf9, 0xde, 0xc7, 0x11,
+        ],
+        tag128: &[0x8d, 0x86, 0xf9, 0x1e, 0xe6, 0x06, 0xe9, 0xff, 0x26, 0xa0, 0x1b, 0x64, 0xcc, 0xbd, 0xd9, 0x1d],
+        tag256: &[
+            0xb7, 0xd2, 0x8d, 0x0c, 0x3c, 0x0e, 0xbd, 0x40, 0x9f, 0xd2, 0x2b, 0x44, 0x16, 0x05, 0x03, 0x07,
+            0x3a, 0x54, 0x74, 0x12, 0xda, 0x08, 0x54, 0xbf, 0xb9, 0x72, 0x30, 0x20, 0xda, 0xb8, 0xda, 0x1a,
+        ],
+    },
+    // Test Vector 4
+    AegisGoldData {
+        aad: AAD.split_at(8).0,
+        plaintext: MESSAGE.split_at(14).0,
+        ciphertext: &[0xf3, 0x73, 0x07, 0x9e, 0xd8, 0x4b, 0x27, 0x09, 0xfa, 0xee, 0x37, 0x35, 0x84, 0x58],
+        tag128: &[0xc6, 0x0b, 0x9c, 0x2d, 0x33, 0xce, 0xb0, 0x58, 0xf9, 0x6e, 0x6d, 0xd0, 0x3c, 0x21, 0x56, 0x52],
+        tag256: &[
+            0x8c, 0x1c, 0xc7, 0x03, 0xc8, 0x12, 0x81, 0xbe, 0xe3, 0xf6, 0xd9, 0x96, 0x6e, 0x14, 0x94, 0x8b,
+            0x4a, 0x17, 0x5b, 0x2e, 0xfb, 0xdc, 0x31, 0xe6, 0x1a, 0x98, 0xb4, 0x46, 0x52, 0x35, 0xc2, 0xd9,
+        ],
+    },
+    // Test Vector 5
+    AegisGoldData {
+        aad: AAD,
+        plaintext: MESSAGE.split_at(16).1,
+        ciphertext: &[
+            0x57, 0x75, 0x4a, 0x7d, 0x09, 0x96, 0x3e, 0x7c, 0x78, 0x75, 0x83, 0xa2, 0xe7, 0xb8, 0x59, 0xbb,
+            0x24, 0xfa, 0x1e, 0x04, 0xd4, 0x9f, 0xd5, 0x50, 0xb2, 0x51, 0x1a, 0x35, 0x8e, 0x3b, 0xca, 0x25,
+            0x2a, 0x9b, 0x1b, 0x8b, 0x30, 0xcc, 0x4a, 0x67,
+        ],
+        tag128: &[0xab, 0x8a, 0x7d, 0x53, 0xfd, 0x0e, 0x98, 0xd7, 0x27, 0xac, 0xcc, 0xa9, 0x49, 0x25, 0xe1, 0x28],
+        tag256: &[
+            0xa3, 0xac, 0xa2, 0x70, 0xc0, 0x06, 0x09, 0x4d, 0x71, 0xc2, 0x0e, 0x69, 0x10, 0xb5, 0x16, 0x1c,
+            0x08, 0x26, 0xdf, 0x23, 0x3d, 0x08, 0x91, 0x9a, 0x56, 0x6e, 0xc2, 0xc0, 0x59, 0x90, 0xf7, 0x34,
+        ],
+    },
+];
+
+/// One-shot encryption and decryption, `aead` picks the tag size.
+fn aegis_gold_roundtrip<A: Aead>(aead: &A, nonce: &[u8], data: &AegisGoldData) {
+    let expected_tag = if aead.tag_size() == 16 { data.tag128 } else { data.tag256 };
+
+    let mut out_ciphertext = [0u8; 64];
+    let mut tag = [0u8; 32];
+    let encrypt_written = aead.encrypt_detached(nonce, data.aad, data.plaintext, &mut out_ciphertext, &mut tag[..expected_tag.len()]).expect("Encryption failed");
+    assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+    assert_eq!(&tag[..expected_tag.len()], expected_tag);
+
+    let mut sealed = [0u8; 96];
+    let sealed_len = aead.encrypt(nonce, data.aad, data.plaintext, &mut sealed).expect("Encryption failed");
+    assert_eq!(&sealed[..data.ciphertext.len()], data.ciphertext);
+    assert_eq!(&sealed[data.ciphertext.len()..sealed_len], expected_tag);
+
+    let mut opened = [0u8; 64];
+    let opened_len = aead.decrypt(nonce, data.aad, &sealed[..sealed_len], &mut opened).expect("Decryption failed");
+    assert_eq!(&opened[..opened_len], data.plaintext);
+}
+
+/// Tampering checks against the tag size `aead` was created with.
+fn aegis_tampering_releases_no_plaintext<A: Aead>(aead: &A, nonce: &[u8], data: &AegisGoldData) {
+    let (tag, other_tag) = if aead.tag_size() == 16 { (data.tag128, data.tag256) } else { (data.tag256, data.tag128) };
+    let mut out_plaintext = [0u8; 64];
+
+    let mut bad_tag = [0u8; 32];
+    bad_tag[..tag.len()].copy_from_slice(tag);
+    bad_tag[tag.len() - 1] ^= 1;
+    assert_eq!(aead.decrypt_detached(nonce, data.aad, data.ciphertext, &bad_tag[..tag.len()], &mut out_plaintext), Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 64]);
+
+    let mut bad_ciphertext = [0u8; 64];
+    bad_ciphertext[..data.ciphertext.len()].copy_from_slice(data.ciphertext);
+    bad_ciphertext[0] ^= 0x80;
+    assert_eq!(aead.decrypt_detached(nonce, data.aad, &bad_ciphertext[..data.ciphertext.len()], tag, &mut out_plaintext), Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 64]);
+
+    assert_eq!(aead.decrypt_detached(nonce, &data.aad[1..], data.ciphertext, tag, &mut out_plaintext), Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 64]);
+
+    // wrong nonce and tag sizes are rejected up front, including the valid tag of the other size
+    assert_eq!(aead.decrypt_detached(&nonce[1..], data.aad, data.ciphertext, tag, &mut out_plaintext), Err(SymcError::InvalidLength));
+    assert_eq!(aead.decrypt_detached(nonce, data.aad, data.ciphertext, &data.tag256[..24], &mut out_plaintext), Err(SymcError::InvalidLength));
+    assert_eq!(aead.decrypt_detached(nonce, data.aad, data.ciphertext, other_tag, &mut out_plaintext), Err(SymcError::InvalidLength));
+    assert_eq!(aead.encrypt_detached(nonce, data.aad, data.plaintext, &mut [0u8; 64], &mut [0u8; 32][..other_tag.len()]), Err(SymcError::InvalidLength));
+    assert_eq!(out_plaintext, [0u8; 64]);
+}
+
+/// Feeds AAD and payload through the streaming encryptor in uneven chunks.
+macro_rules! aegis_streaming {
+    ($encryptor:ident, $key:expr, $nonce:expr, $gold_data:expr) => {
+        for data in $gold_data {
+            for (tag_size, expected_tag) in [(16, data.tag128), (32, data.tag256)] {
+                let mut encryptor = $encryptor::new(&$key.into(), $nonce).unwrap();
+                for chunk in data.aad.chunks(5) {
+                    encryptor.update_aad(chunk).unwrap();
+                }
+
+                let mut out_ciphertext = [0u8; 64];
+                let mut written = 0;
+                for chunk in data.plaintext.chunks(7) {
+                    written += encryptor.update(chunk, &mut out_ciphertext[written..]).expect("Encryption update failed");
+                }
+                let mut tag = [0u8; 32];
+                written += encryptor.finalize(&mut out_ciphertext[written..], &mut tag[..tag_size]).expect("Encryption finalize failed");
+                assert_eq!(&out_ciphertext[..written], data.ciphertext);
+                assert_eq!(&tag[..tag_size], expected_tag);
+            }
+        }
+
+        // AAD can't follow the payload
+        let mut encryptor = $encryptor::new(&$key.into(), $nonce).unwrap();
+        encryptor.update(MESSAGE, &mut [0u8; 64]).unwrap();
+        assert_eq!(encryptor.update_aad(AAD), Err(SymcError::InvalidInputLength));
+    };
+}
+
+#[cfg(feature = "aegis128l")]
+#[test]
+fn aegis128l_roundtrip() {
+    for data in AEGIS128L_GOLD_DATA {
+        aegis_gold_roundtrip(&Aegis128L::new(&AEGIS128L_KEY.into()), AEGIS128L_NONCE, data);
+        aegis_gold_roundtrip(&Aegis128L::with_tag_size(&AEGIS128L_KEY.into(), 32).unwrap(), AEGIS128L_NONCE, data);
+    }
+    assert!(Aegis128L::with_tag_size(&AEGIS128L_KEY.into(), 24).is_err());
+}
+
+#[cfg(feature = "aegis128l")]
+#[test]
+fn aegis128l_streaming() {
+    aegis_streaming!(Aegis128LEncryptor, AEGIS128L_KEY, AEGIS128L_NONCE, AEGIS128L_GOLD_DATA);
+}
+
+#[cfg(feature = "aegis128l")]
+#[test]
+fn aegis128l_tampering_releases_no_plaintext() {
+    aegis_tampering_releases_no_plaintext(&Aegis128L::new(&AEGIS128L_KEY.into()), AEGIS128L_NONCE, &AEGIS128L_GOLD_DATA[4]);
+    aegis_tampering_releases_no_plaintext(&Aegis128L::with_tag_size(&AEGIS128L_KEY.into(), 32).unwrap(), AEGIS128L_NONCE, &AEGIS128L_GOLD_DATA[4]);
+}
+
+#[cfg(feature = "aegis256")]
+#[test]
+fn aegis256_roundtrip() {
+    for data in AEGIS256_GOLD_DATA {
+        aegis_gold_roundtrip(&Aegis256::new(&AEGIS256_KEY.into()), AEGIS256_NONCE, data);
+        aegis_gold_roundtrip(&Aegis256::with_tag_size(&AEGIS256_KEY.into(), 32).unwrap(), AEGIS256_NONCE, data);
+    }
+    assert!(Aegis256::with_tag_size(&AEGIS256_KEY.into(), 24).is_err());
+}
+
+#[cfg(feature = "aegis256")]
+#[test]
+fn aegis256_streaming() {
+    aegis_streaming!(Aegis256Encryptor, AEGIS256_KEY, AEGIS256_NONCE, AEGIS256_GOLD_DATA);
+}
+
+#[cfg(feature = "aegis256")]
+#[test]
+fn aegis256_tampering_releases_no_plaintext() {
+    aegis_tampering_releases_no_plaintext(&Aegis256::new(&AEGIS256_KEY.into()), AEGIS256_NONCE, &AEGIS256_GOLD_DATA[4]);
+    aegis_tampering_releases_no_plaintext(&Aegis256::with_tag_size(&AEGIS256_KEY.into(), 32).unwrap(), AEGIS256_NONCE, &AEGIS256_GOLD_DATA[4]);
+}
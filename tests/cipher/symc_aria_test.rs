@@ -0,0 +1,234 @@
+use super::*;
+
+use wovocrypt::cipher::mode::cbc::{CbcEncryptor, CbcDecryptor};
+use wovocrypt::cipher::mode::ctr::{CtrEncryptor, CtrDecryptor};
+use wovocrypt::cipher::aria::*;
+use wovocrypt::padding::*;
+
+// RFC 5794 test key, sliced for the smaller key sizes
+const KEY: [u8; 32] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+const IV: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const NONCE: [u8; 12] = [
+    0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb,
+];
+
+// NIST SP 800-38A plaintext, expected values from OpenSSL
+const PLAINTEXT: &[u8] = &[
+    0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+    0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+    0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+    0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+];
+const FOX: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+/// RFC 5794, a single block through CBC with a zero IV is the raw block cipher
+const ARIA_RFC_PLAINTEXT: [u8; 16] = [
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+];
+const ARIA128_RFC_CIPHERTEXT: [u8; 16] = [
+    0xd7, 0x18, 0xfb, 0xd6, 0xab, 0x64, 0x4c, 0x73, 0x9d, 0xa9, 0x5f, 0x3b, 0xe6, 0x45, 0x17, 0x78,
+];
+const ARIA192_RFC_CIPHERTEXT: [u8; 16] = [
+    0x26, 0x44, 0x9c, 0x18, 0x05, 0xdb, 0xe7, 0xaa, 0x25, 0xa4, 0x68, 0xce, 0x26, 0x3a, 0x9e, 0x79,
+];
+const ARIA256_RFC_CIPHERTEXT: [u8; 16] = [
+    0xf9, 0x2b, 0xd7, 0xc7, 0x9f, 0xb7, 0x2e, 0x2f, 0x2b, 0x8f, 0x80, 0xc1, 0x97, 0x2d, 0x24, 0xfc,
+];
+
+const ARIA128_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x67, 0xa1, 0xc9, 0xd1, 0x25, 0xdf, 0x31, 0x98, 0x9f, 0xdb, 0x98, 0xce, 0x0d, 0x2b, 0x25, 0xaa,
+            0x7a, 0x4c, 0xcd, 0x99, 0x99, 0xe8, 0x28, 0xe9, 0x58, 0xb5, 0x75, 0x68, 0x0e, 0xf1, 0x96, 0x8a,
+            0xe2, 0xbb, 0x22, 0xc1, 0xc3, 0x57, 0x1b, 0x85, 0x60, 0x39, 0x59, 0x85, 0x12, 0x57, 0x08, 0x35,
+            0x6e, 0x49, 0x06, 0x6b, 0x58, 0x8d, 0xa1, 0x05, 0xbd, 0x6a, 0x05, 0xc4, 0x46, 0xd4, 0x2b, 0x95,
+        ],
+    },
+];
+
+const ARIA128_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x0e, 0x48, 0x58, 0x49, 0x5c, 0xbb, 0x56, 0x4c, 0x8e, 0xc9, 0x0e, 0x1c, 0x28, 0x17, 0x72, 0x5a,
+            0x1b, 0xfd, 0x21, 0xc7, 0x75, 0x65, 0x48, 0x2f, 0x2b, 0xb5, 0xd1, 0xf1, 0x77, 0x1b, 0x78, 0x4b,
+            0xad, 0x2f, 0x0a, 0x23, 0x52, 0xc3, 0x6f, 0x3c, 0xb9, 0xdb, 0xb4, 0xc5, 0xb1, 0x8c, 0xb2, 0xb2,
+        ],
+    },
+];
+
+const ARIA128_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x75, 0xba, 0xe0, 0x4e, 0x77, 0xe5, 0x86, 0x0c, 0x44, 0x2a, 0x99, 0xbf, 0x51, 0xf0, 0xe1, 0xd2,
+            0x5f, 0xf1, 0xe0, 0x6e, 0x43, 0x66, 0xab, 0x01, 0xd3, 0x1e, 0x68, 0xdd, 0x70, 0xde, 0x95, 0x42,
+            0xca, 0x5d, 0x1e, 0x62, 0x6e, 0x73, 0x27, 0xd5, 0x7e, 0xdf, 0x55,
+        ],
+    },
+];
+
+const ARIA192_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0xf7, 0x98, 0x6c, 0x9f, 0x8c, 0xbf, 0x34, 0xa7, 0xdc, 0xb4, 0xb3, 0x04, 0xe7, 0x91, 0x76, 0xd5,
+            0x0c, 0x2c, 0x40, 0xab, 0xe6, 0xc5, 0xc1, 0x29, 0x80, 0x61, 0x60, 0xd8, 0x70, 0xe9, 0xda, 0x0a,
+            0x70, 0xb6, 0xcd, 0xcd, 0x08, 0xb1, 0x75, 0x77, 0x41, 0x1d, 0xd1, 0xf7, 0x71, 0x71, 0x1b, 0x19,
+            0x04, 0x3b, 0xcd, 0xbf, 0x59, 0x17, 0xb8, 0x7b, 0xae, 0xe2, 0x71, 0x26, 0xbe, 0x27, 0xbb, 0x1f,
+        ],
+    },
+];
+
+const ARIA192_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x38, 0x8d, 0x09, 0xa1, 0xb2, 0x6b, 0xfe, 0xd3, 0x18, 0x5e, 0x90, 0x09, 0xf1, 0xe0, 0xc3, 0x55,
+            0x15, 0xfc, 0x49, 0x17, 0xfa, 0xc7, 0xb1, 0x19, 0x84, 0x3d, 0xbf, 0x2c, 0xe6, 0x13, 0x80, 0x24,
+            0x14, 0x09, 0x42, 0xf2, 0xd2, 0xc9, 0x0e, 0x2c, 0x61, 0xbb, 0xf8, 0x46, 0x61, 0x4d, 0xfc, 0x1f,
+        ],
+    },
+];
+
+const ARIA192_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xbd, 0xdb, 0xe0, 0xfd, 0xce, 0x7b, 0x91, 0x97, 0x00, 0xe2, 0x98, 0x48, 0xec, 0xa4, 0x34, 0x6b,
+            0x59, 0xea, 0xaf, 0x90, 0x30, 0x4d, 0x77, 0xd9, 0xa1, 0xaf, 0xe5, 0xff, 0xdf, 0x71, 0xe6, 0x34,
+            0x0e, 0xf9, 0x75, 0x89, 0x0d, 0xe8, 0x40, 0xca, 0x83, 0xca, 0x50,
+        ],
+    },
+];
+
+const ARIA256_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x2e, 0xd3, 0x7f, 0x9a, 0xe5, 0x88, 0xf0, 0x6c, 0x5b, 0x2a, 0xf0, 0x92, 0x96, 0x48, 0x54, 0x9e,
+            0x1c, 0x8f, 0x88, 0x16, 0xa2, 0x89, 0xe5, 0xdf, 0x05, 0xcc, 0xdb, 0xd0, 0xc8, 0xec, 0x96, 0x7c,
+            0xbd, 0x34, 0x7b, 0x25, 0x1b, 0x2f, 0xba, 0x96, 0x9d, 0x55, 0xeb, 0xcf, 0x10, 0xa8, 0x63, 0x89,
+            0x73, 0x60, 0x33, 0x77, 0x5c, 0x2a, 0x31, 0x86, 0xcb, 0x82, 0x95, 0x58, 0x4f, 0x5a, 0x48, 0x4d,
+        ],
+    },
+];
+
+const ARIA256_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xe4, 0x0b, 0x26, 0x74, 0xd6, 0x38, 0x40, 0x0c, 0x82, 0xeb, 0xc4, 0x79, 0xd2, 0xed, 0x69, 0x25,
+            0xd8, 0x93, 0xb0, 0x22, 0x46, 0x72, 0xcb, 0xd6, 0xd3, 0x1c, 0xf9, 0xbd, 0x1f, 0xbd, 0x0d, 0x60,
+            0xe6, 0x15, 0xd2, 0xd6, 0x4c, 0x7b, 0x40, 0x1b, 0x44, 0x4c, 0x62, 0x46, 0x77, 0x00, 0x3c, 0xe3,
+        ],
+    },
+];
+
+const ARIA256_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x79, 0xed, 0xc8, 0x60, 0xfc, 0xc2, 0x13, 0x54, 0x06, 0x50, 0x34, 0xa9, 0xcf, 0x53, 0x04, 0xcf,
+            0x9c, 0x0c, 0x6e, 0xd2, 0x66, 0x86, 0x68, 0x50, 0x7e, 0x4d, 0xb1, 0x3b, 0xb8, 0x69, 0xb1, 0xda,
+            0x6b, 0x94, 0x28, 0xc8, 0x74, 0x89, 0x57, 0x03, 0xeb, 0xd9, 0x97,
+        ],
+    },
+];
+
+fn aria128_key() -> Aria128Key {
+    Aria128Key::from(<[u8; 16]>::try_from(&KEY[..16]).unwrap())
+}
+
+fn aria192_key() -> Aria192Key {
+    Aria192Key::from(<[u8; 24]>::try_from(&KEY[..24]).unwrap())
+}
+
+fn aria256_key() -> Aria256Key {
+    Aria256Key::from(KEY)
+}
+
+#[test]
+fn aria_rfc5794_through_cbc() {
+    roundtrip::<CbcEncryptor<Aria128, NoPadding>, CbcDecryptor<Aria128, NoPadding>>(&aria128_key(), &[0u8; 16], &SymcGoldData {
+        plaintext: &ARIA_RFC_PLAINTEXT,
+        ciphertext: &ARIA128_RFC_CIPHERTEXT,
+    });
+    roundtrip::<CbcEncryptor<Aria192, NoPadding>, CbcDecryptor<Aria192, NoPadding>>(&aria192_key(), &[0u8; 16], &SymcGoldData {
+        plaintext: &ARIA_RFC_PLAINTEXT,
+        ciphertext: &ARIA192_RFC_CIPHERTEXT,
+    });
+    roundtrip::<CbcEncryptor<Aria256, NoPadding>, CbcDecryptor<Aria256, NoPadding>>(&aria256_key(), &[0u8; 16], &SymcGoldData {
+        plaintext: &ARIA_RFC_PLAINTEXT,
+        ciphertext: &ARIA256_RFC_CIPHERTEXT,
+    });
+}
+
+#[test]
+fn aria128_cbc_nopadding_roundtrip() {
+    for data in ARIA128_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Aria128, NoPadding>, CbcDecryptor<Aria128, NoPadding>>(&aria128_key(), &IV, data);
+    }
+}
+
+#[test]
+fn aria128_cbc_pkcs7_roundtrip() {
+    for data in ARIA128_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Aria128, Pkcs7>, CbcDecryptor<Aria128, Pkcs7>>(&aria128_key(), &IV, data);
+    }
+}
+
+#[test]
+fn aria128_ctr_roundtrip() {
+    for data in ARIA128_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Aria128>, CtrDecryptor<Aria128>>(&aria128_key(), &NONCE.into(), data);
+    }
+}
+
+#[test]
+fn aria192_cbc_nopadding_roundtrip() {
+    for data in ARIA192_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Aria192, NoPadding>, CbcDecryptor<Aria192, NoPadding>>(&aria192_key(), &IV, data);
+    }
+}
+
+#[test]
+fn aria192_cbc_pkcs7_roundtrip() {
+    for data in ARIA192_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Aria192, Pkcs7>, CbcDecryptor<Aria192, Pkcs7>>(&aria192_key(), &IV, data);
+    }
+}
+
+#[test]
+fn aria192_ctr_roundtrip() {
+    for data in ARIA192_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Aria192>, CtrDecryptor<Aria192>>(&aria192_key(), &NONCE.into(), data);
+    }
+}
+
+#[test]
+fn aria256_cbc_nopadding_roundtrip() {
+    for data in ARIA256_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Aria256, NoPadding>, CbcDecryptor<Aria256, NoPadding>>(&aria256_key(), &IV, data);
+    }
+}
+
+#[test]
+fn aria256_cbc_pkcs7_roundtrip() {
+    for data in ARIA256_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Aria256, Pkcs7>, CbcDecryptor<Aria256, Pkcs7>>(&aria256_key(), &IV, data);
+    }
+}
+
+#[test]
+fn aria256_ctr_roundtrip() {
+    for data in ARIA256_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Aria256>, CtrDecryptor<Aria256>>(&aria256_key(), &NONCE.into(), data);
+    }
+}
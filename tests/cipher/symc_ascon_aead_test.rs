@@ -0,0 +1,177 @@
+use super::*;
+
+use wovocrypt::aead::Aead;
+use wovocrypt::aead::ascon::{AsconAead128, AsconAead128Encryptor, AsconAead128Key};
+use wovocrypt::error::SymcError;
+
+const KEY: &[u8] = &[
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const NONCE: &[u8] = &[
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+const AAD: &[u8] = &[
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0x3a, 0x3b, 0x3c, 0x3d, 0x3e, 0x3f,
+    0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+];
+const PLAINTEXT: &[u8] = &[
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+
+// NIST LWC reference KATs for Ascon-AEAD128, the AD and plaintext are prefixes of the above
+const ASCON_AEAD128_GOLD_DATA: &[AeadGoldData] = &[
+    // Count 1
+    AeadGoldData {
+        key: KEY,
+        iv: NONCE,
+        aad: &[],
+        plaintext: &[],
+        ciphertext: &[],
+        tag: &[0x4f, 0x9c, 0x27, 0x82, 0x11, 0xbe, 0xc9, 0x31, 0x6b, 0xf6, 0x8f, 0x46, 0xee, 0x8b, 0x2e, 0xc6],
+    },
+    // Count 2
+    AeadGoldData {
+        key: KEY,
+        iv: NONCE,
+        aad: AAD.split_at(1).0,
+        plaintext: &[],
+        ciphertext: &[],
+        tag: &[0xcc, 0xcb, 0x67, 0x4f, 0xe1, 0x8a, 0x09, 0xa2, 0x85, 0xd6, 0xab, 0x11, 0xb3, 0x56, 0x75, 0xc0],
+    },
+    // Count 3
+    AeadGoldData {
+        key: KEY,
+        iv: NONCE,
+        aad: AAD.split_at(2).0,
+        plaintext: &[],
+        ciphertext: &[],
+        tag: &[0xf6, 0x5b, 0x19, 0x15, 0x50, 0xc4, 0xdf, 0x9c, 0xfd, 0xd4, 0x46, 0x0e, 0xbb, 0xcc, 0xa7, 0x82],
+    },
+    AeadGoldData {
+        key: KEY,
+        iv: NONCE,
+        aad: AAD.split_at(16).0,
+        plaintext: &[],
+        ciphertext: &[],
+        tag: &[0xe4, 0x23, 0x0c, 0xdb, 0x83, 0x30, 0xee, 0x9d, 0xc0, 0xcf, 0xd7, 0xc7, 0xb3, 0x46, 0xe6, 0xdc],
+    },
+    AeadGoldData {
+        key: KEY,
+        iv: NONCE,
+        aad: &[],
+        plaintext: PLAINTEXT.split_at(1).0,
+        ciphertext: &[0xc8],
+        tag: &[0x4c, 0x4b, 0xc1, 0x95, 0x7c, 0xad, 0x5a, 0xa2, 0x66, 0x0f, 0x67, 0x32, 0x6c, 0x05, 0xee, 0xb7],
+    },
+    AeadGoldData {
+        key: KEY,
+        iv: NONCE,
+        aad: &[],
+        plaintext: PLAINTEXT.split_at(16).0,
+        ciphertext: &[0xc8, 0xe3, 0xfe, 0xce, 0x04, 0x4c, 0xe5, 0xca, 0xc3, 0xc8, 0x52, 0x11, 0x18, 0xb7, 0x82, 0x9b],
+        tag: &[0x97, 0xcc, 0xde, 0x36, 0x42, 0x01, 0xc1, 0xfc, 0x02, 0x91, 0xd9, 0x59, 0x1d, 0x27, 0xec, 0xa0],
+    },
+    AeadGoldData {
+        key: KEY,
+        iv: NONCE,
+        aad: AAD.split_at(31).0,
+        plaintext: PLAINTEXT.split_at(17).0,
+        ciphertext: &[
+            0x6b, 0x19, 0x0e, 0x7f, 0x86, 0x2e, 0x2c, 0x9b, 0xea, 0x74, 0x5d, 0x99, 0x4e, 0x12, 0x42, 0x9d,
+            0xe2,
+        ],
+        tag: &[0xbb, 0xfb, 0xa2, 0x95, 0xa1, 0xbc, 0x61, 0x73, 0xb7, 0x9d, 0xd8, 0x5a, 0x88, 0x6d, 0x27, 0x90],
+    },
+    AeadGoldData {
+        key: KEY,
+        iv: NONCE,
+        aad: AAD,
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0xeb, 0x14, 0xf0, 0x66, 0x40, 0x86, 0x4d, 0x9f, 0x9e, 0xbc, 0xa5, 0x46, 0x21, 0xd5, 0x98, 0x8a,
+            0xef, 0x7b, 0x3c, 0xae, 0x43, 0xce, 0x03, 0xd2, 0xb6, 0x7b, 0x2a, 0x62, 0xec, 0x53, 0x16, 0xfc,
+        ],
+        tag: &[0x5a, 0x0b, 0x6f, 0x67, 0xf8, 0x95, 0x55, 0x0c, 0x21, 0x04, 0xe4, 0x7a, 0x2e, 0xde, 0x0e, 0xc0],
+    },
+];
+
+fn ascon_key(key: &[u8]) -> AsconAead128Key {
+    <[u8; 16]>::try_from(key).unwrap().into()
+}
+
+#[test]
+fn ascon_aead128_roundtrip() {
+    for data in ASCON_AEAD128_GOLD_DATA {
+        let aead = AsconAead128::new(&ascon_key(data.key));
+
+        let mut out_ciphertext = [0u8; 32];
+        let mut tag = [0u8; 16];
+        let encrypt_written = aead.encrypt_detached(data.iv, data.aad, data.plaintext, &mut out_ciphertext, &mut tag).expect("Encryption failed");
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+        assert_eq!(&tag, data.tag);
+
+        let mut out_plaintext = [0u8; 32];
+        let decrypt_written = aead.decrypt_detached(data.iv, data.aad, data.ciphertext, data.tag, &mut out_plaintext).expect("Decryption failed");
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+
+        let mut sealed = [0u8; 48];
+        let sealed_len = aead.encrypt(data.iv, data.aad, data.plaintext, &mut sealed).expect("Encryption failed");
+        assert_eq!(&sealed[..data.ciphertext.len()], data.ciphertext);
+        assert_eq!(&sealed[data.ciphertext.len()..sealed_len], data.tag);
+
+        let mut opened = [0u8; 32];
+        let opened_len = aead.decrypt(data.iv, data.aad, &sealed[..sealed_len], &mut opened).expect("Decryption failed");
+        assert_eq!(&opened[..opened_len], data.plaintext);
+    }
+}
+
+#[test]
+fn ascon_aead128_streaming() {
+    for data in ASCON_AEAD128_GOLD_DATA {
+        let mut encryptor = AsconAead128Encryptor::new(&ascon_key(data.key), data.iv).unwrap();
+        for chunk in data.aad.chunks(5) {
+            encryptor.update_aad(chunk).unwrap();
+        }
+
+        let mut out_ciphertext = [0u8; 32];
+        let mut written = 0;
+        for chunk in data.plaintext.chunks(3) {
+            written += encryptor.update(chunk, &mut out_ciphertext[written..]).expect("Encryption update failed");
+        }
+        let mut tag = [0u8; 16];
+        encryptor.finalize(&mut tag).expect("Encryption finalize failed");
+        assert_eq!(&out_ciphertext[..written], data.ciphertext);
+        assert_eq!(&tag, data.tag);
+    }
+
+    // AAD can't follow the payload
+    let mut encryptor = AsconAead128Encryptor::new(&ascon_key(KEY), NONCE).unwrap();
+    encryptor.update(PLAINTEXT, &mut [0u8; 32]).unwrap();
+    assert_eq!(encryptor.update_aad(AAD), Err(SymcError::InvalidInputLength));
+}
+
+#[test]
+fn ascon_aead128_tampering_releases_no_plaintext() {
+    let data = ASCON_AEAD128_GOLD_DATA.last().unwrap();
+    let aead = AsconAead128::new(&ascon_key(data.key));
+    let mut out_plaintext = [0u8; 32];
+
+    let mut bad_tag = [0u8; 16];
+    bad_tag.copy_from_slice(data.tag);
+    bad_tag[0] ^= 1;
+    assert_eq!(aead.decrypt_detached(data.iv, data.aad, data.ciphertext, &bad_tag, &mut out_plaintext), Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 32]);
+
+    let mut bad_ciphertext = [0u8; 32];
+    bad_ciphertext.copy_from_slice(data.ciphertext);
+    bad_ciphertext[31] ^= 0x80;
+    assert_eq!(aead.decrypt_detached(data.iv, data.aad, &bad_ciphertext, data.tag, &mut out_plaintext), Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 32]);
+
+    assert_eq!(aead.decrypt_detached(data.iv, &data.aad[1..], data.ciphertext, data.tag, &mut out_plaintext), Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 32]);
+
+    assert_eq!(aead.decrypt_detached(&data.iv[1..], data.aad, data.ciphertext, data.tag, &mut out_plaintext), Err(SymcError::InvalidLength));
+    assert_eq!(aead.decrypt_detached(data.iv, data.aad, data.ciphertext, &data.tag[..12], &mut out_plaintext), Err(SymcError::InvalidLength));
+}
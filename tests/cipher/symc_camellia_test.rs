@@ -0,0 +1,234 @@
+use super::*;
+
+use wovocrypt::cipher::mode::cbc::{CbcEncryptor, CbcDecryptor};
+use wovocrypt::cipher::mode::ctr::{CtrEncryptor, CtrDecryptor};
+use wovocrypt::cipher::camellia::*;
+use wovocrypt::padding::*;
+
+// RFC 3713 test key, sliced for the smaller key sizes
+const KEY: [u8; 32] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10,
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+];
+const IV: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const NONCE: [u8; 12] = [
+    0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb,
+];
+
+// NIST SP 800-38A plaintext, expected values from OpenSSL
+const PLAINTEXT: &[u8] = &[
+    0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+    0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+    0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+    0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+];
+const FOX: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+/// RFC 3713, a single block through CBC with a zero IV is the raw block cipher
+const CAMELLIA_RFC_PLAINTEXT: [u8; 16] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10,
+];
+const CAMELLIA128_RFC_CIPHERTEXT: [u8; 16] = [
+    0x67, 0x67, 0x31, 0x38, 0x54, 0x96, 0x69, 0x73, 0x08, 0x57, 0x06, 0x56, 0x48, 0xea, 0xbe, 0x43,
+];
+const CAMELLIA192_RFC_CIPHERTEXT: [u8; 16] = [
+    0xb4, 0x99, 0x34, 0x01, 0xb3, 0xe9, 0x96, 0xf8, 0x4e, 0xe5, 0xce, 0xe7, 0xd7, 0x9b, 0x09, 0xb9,
+];
+const CAMELLIA256_RFC_CIPHERTEXT: [u8; 16] = [
+    0x9a, 0xcc, 0x23, 0x7d, 0xff, 0x16, 0xd7, 0x6c, 0x20, 0xef, 0x7c, 0x91, 0x9e, 0x3a, 0x75, 0x09,
+];
+
+const CAMELLIA128_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x6b, 0x9f, 0x90, 0x99, 0x5a, 0xac, 0xb3, 0xe9, 0xc4, 0x7d, 0xeb, 0xaa, 0x10, 0xfd, 0xe8, 0x09,
+            0x37, 0xad, 0xed, 0x36, 0xc7, 0xf0, 0xa6, 0xb2, 0x69, 0x2b, 0x9c, 0xc9, 0x75, 0x55, 0x49, 0x53,
+            0xeb, 0xdd, 0xe8, 0xc4, 0xce, 0x35, 0x9b, 0x3c, 0x8a, 0xcc, 0x6c, 0xdc, 0xb1, 0x31, 0xb3, 0xa9,
+            0x6e, 0x6c, 0xa1, 0x03, 0x9b, 0x20, 0xe1, 0xd6, 0x3b, 0xff, 0xa8, 0x76, 0xe7, 0xc0, 0x3e, 0x8e,
+        ],
+    },
+];
+
+const CAMELLIA128_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xb6, 0x59, 0xe5, 0x29, 0x57, 0x8b, 0xdf, 0x01, 0xf7, 0x4a, 0xa6, 0xcc, 0x76, 0xc4, 0x00, 0x01,
+            0x68, 0x6a, 0x2b, 0x29, 0x29, 0x4b, 0xfb, 0x44, 0x9f, 0xa5, 0x87, 0xc6, 0xcd, 0x6e, 0xc0, 0xa7,
+            0x53, 0xba, 0x85, 0x45, 0x88, 0xde, 0x71, 0x4c, 0x91, 0xc7, 0xdd, 0xdd, 0x17, 0xc7, 0xb1, 0x02,
+        ],
+    },
+];
+
+const CAMELLIA128_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xd8, 0x54, 0x6e, 0x32, 0xa5, 0xfe, 0xc1, 0x04, 0xbf, 0x22, 0xaf, 0xa1, 0x27, 0xb1, 0xdc, 0x0d,
+            0x96, 0xd8, 0xc9, 0x34, 0x72, 0x4f, 0xeb, 0xfa, 0x0d, 0x4b, 0x0e, 0xd2, 0x83, 0x8f, 0x65, 0x77,
+            0x92, 0xf6, 0xbf, 0xc1, 0x7f, 0x80, 0xdd, 0x21, 0x98, 0x3a, 0xe9,
+        ],
+    },
+];
+
+const CAMELLIA192_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x9d, 0xa5, 0x00, 0x6a, 0xa8, 0xfc, 0x26, 0xde, 0x71, 0xba, 0xa6, 0xb9, 0xbd, 0x5d, 0xad, 0x7d,
+            0xf2, 0xbb, 0x56, 0x9b, 0x7a, 0x3d, 0xb2, 0xa8, 0xf3, 0xad, 0x83, 0xd2, 0x18, 0x23, 0x8a, 0x79,
+            0x65, 0x90, 0x3c, 0x07, 0x95, 0xe5, 0xdd, 0x9c, 0xaa, 0x59, 0x69, 0xe9, 0x70, 0x34, 0x93, 0xe1,
+            0xe8, 0xc8, 0xec, 0x1e, 0xfe, 0x48, 0x46, 0x6b, 0x78, 0x2e, 0x5c, 0x25, 0x5a, 0x03, 0xbf, 0x77,
+        ],
+    },
+];
+
+const CAMELLIA192_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xe0, 0xbe, 0x6f, 0x48, 0x87, 0x54, 0xd4, 0xea, 0x74, 0xe8, 0x08, 0x06, 0xf7, 0x06, 0xf4, 0x21,
+            0xa2, 0x78, 0x07, 0x1b, 0x94, 0xde, 0x85, 0x92, 0x58, 0x74, 0x67, 0x8d, 0x66, 0xc8, 0x0b, 0x4f,
+            0x70, 0x8b, 0x30, 0x24, 0x79, 0x06, 0xd3, 0x06, 0x53, 0x39, 0x2f, 0x73, 0x06, 0x89, 0x60, 0x63,
+        ],
+    },
+];
+
+const CAMELLIA192_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x2c, 0xd8, 0xce, 0x1d, 0x9d, 0x81, 0x46, 0xbe, 0x80, 0xad, 0xd9, 0x21, 0xea, 0x4e, 0xce, 0x90,
+            0x4d, 0x59, 0x66, 0x64, 0x44, 0x1f, 0x6a, 0xd9, 0xb7, 0xc5, 0x15, 0x78, 0x28, 0x26, 0xc9, 0xbb,
+            0xf5, 0x44, 0xfc, 0x03, 0x09, 0x80, 0xff, 0xc5, 0x09, 0x6f, 0xe5,
+        ],
+    },
+];
+
+const CAMELLIA256_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0xb3, 0xf6, 0x06, 0xfe, 0x65, 0x4c, 0x92, 0x6d, 0xad, 0x34, 0x54, 0x5e, 0xc6, 0x60, 0x79, 0x28,
+            0xa9, 0x08, 0x9e, 0x01, 0x50, 0xce, 0x09, 0xd6, 0x5d, 0xe2, 0x53, 0xf5, 0x7a, 0x13, 0x76, 0x72,
+            0xbf, 0x50, 0xda, 0x7f, 0x64, 0x93, 0x5e, 0xc6, 0x9d, 0x1f, 0xde, 0x13, 0xd5, 0xb6, 0xc3, 0x09,
+            0xa3, 0x90, 0x17, 0xb6, 0xd2, 0xaf, 0x5b, 0x35, 0x76, 0x48, 0xa2, 0x11, 0x5c, 0xaf, 0xa2, 0x96,
+        ],
+    },
+];
+
+const CAMELLIA256_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xe3, 0x0a, 0x2d, 0x12, 0x55, 0x07, 0x13, 0xf5, 0x49, 0xec, 0x5d, 0x29, 0x33, 0x64, 0x8c, 0x11,
+            0x52, 0xa1, 0x00, 0x42, 0x6a, 0xb3, 0xfb, 0x70, 0xf8, 0x76, 0xa6, 0x3e, 0x68, 0xf6, 0xff, 0xc6,
+            0x5b, 0x40, 0x93, 0x8c, 0xb2, 0x88, 0xfe, 0x3e, 0x0c, 0xe1, 0x0b, 0x11, 0xf8, 0x7b, 0x71, 0x19,
+        ],
+    },
+];
+
+const CAMELLIA256_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x5e, 0x26, 0x49, 0xd5, 0xb9, 0xeb, 0x8a, 0xb9, 0x5b, 0x60, 0xeb, 0xcc, 0x0f, 0xa3, 0xb9, 0x1b,
+            0x0a, 0x23, 0xa5, 0x11, 0x6c, 0x8f, 0xaa, 0x7c, 0x1e, 0xbe, 0x73, 0x33, 0xc5, 0xe4, 0x2c, 0x92,
+            0x7a, 0x5c, 0x16, 0xc9, 0xf2, 0x13, 0xe2, 0x65, 0xd0, 0x37, 0xc7,
+        ],
+    },
+];
+
+fn camellia128_key() -> Camellia128Key {
+    Camellia128Key::from(<[u8; 16]>::try_from(&KEY[..16]).unwrap())
+}
+
+fn camellia192_key() -> Camellia192Key {
+    Camellia192Key::from(<[u8; 24]>::try_from(&KEY[..24]).unwrap())
+}
+
+fn camellia256_key() -> Camellia256Key {
+    Camellia256Key::from(KEY)
+}
+
+#[test]
+fn camellia_rfc3713_through_cbc() {
+    roundtrip::<CbcEncryptor<Camellia128, NoPadding>, CbcDecryptor<Camellia128, NoPadding>>(&camellia128_key(), &[0u8; 16], &SymcGoldData {
+        plaintext: &CAMELLIA_RFC_PLAINTEXT,
+        ciphertext: &CAMELLIA128_RFC_CIPHERTEXT,
+    });
+    roundtrip::<CbcEncryptor<Camellia192, NoPadding>, CbcDecryptor<Camellia192, NoPadding>>(&camellia192_key(), &[0u8; 16], &SymcGoldData {
+        plaintext: &CAMELLIA_RFC_PLAINTEXT,
+        ciphertext: &CAMELLIA192_RFC_CIPHERTEXT,
+    });
+    roundtrip::<CbcEncryptor<Camellia256, NoPadding>, CbcDecryptor<Camellia256, NoPadding>>(&camellia256_key(), &[0u8; 16], &SymcGoldData {
+        plaintext: &CAMELLIA_RFC_PLAINTEXT,
+        ciphertext: &CAMELLIA256_RFC_CIPHERTEXT,
+    });
+}
+
+#[test]
+fn camellia128_cbc_nopadding_roundtrip() {
+    for data in CAMELLIA128_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Camellia128, NoPadding>, CbcDecryptor<Camellia128, NoPadding>>(&camellia128_key(), &IV, data);
+    }
+}
+
+#[test]
+fn camellia128_cbc_pkcs7_roundtrip() {
+    for data in CAMELLIA128_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Camellia128, Pkcs7>, CbcDecryptor<Camellia128, Pkcs7>>(&camellia128_key(), &IV, data);
+    }
+}
+
+#[test]
+fn camellia128_ctr_roundtrip() {
+    for data in CAMELLIA128_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Camellia128>, CtrDecryptor<Camellia128>>(&camellia128_key(), &NONCE.into(), data);
+    }
+}
+
+#[test]
+fn camellia192_cbc_nopadding_roundtrip() {
+    for data in CAMELLIA192_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Camellia192, NoPadding>, CbcDecryptor<Camellia192, NoPadding>>(&camellia192_key(), &IV, data);
+    }
+}
+
+#[test]
+fn camellia192_cbc_pkcs7_roundtrip() {
+    for data in CAMELLIA192_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Camellia192, Pkcs7>, CbcDecryptor<Camellia192, Pkcs7>>(&camellia192_key(), &IV, data);
+    }
+}
+
+#[test]
+fn camellia192_ctr_roundtrip() {
+    for data in CAMELLIA192_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Camellia192>, CtrDecryptor<Camellia192>>(&camellia192_key(), &NONCE.into(), data);
+    }
+}
+
+#[test]
+fn camellia256_cbc_nopadding_roundtrip() {
+    for data in CAMELLIA256_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Camellia256, NoPadding>, CbcDecryptor<Camellia256, NoPadding>>(&camellia256_key(), &IV, data);
+    }
+}
+
+#[test]
+fn camellia256_cbc_pkcs7_roundtrip() {
+    for data in CAMELLIA256_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Camellia256, Pkcs7>, CbcDecryptor<Camellia256, Pkcs7>>(&camellia256_key(), &IV, data);
+    }
+}
+
+#[test]
+fn camellia256_ctr_roundtrip() {
+    for data in CAMELLIA256_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Camellia256>, CtrDecryptor<Camellia256>>(&camellia256_key(), &NONCE.into(), data);
+    }
+}
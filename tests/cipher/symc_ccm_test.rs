@@ -0,0 +1,297 @@
+use super::*;
+
+use wovocrypt::aead::Aead;
+use wovocrypt::cipher::mode::ccm::{Ccm, CcmStar};
+use wovocrypt::error::SymcError;
+
+const AES128_CCM_GOLD_DATA: &[AeadGoldData] = &[
+    // RFC 3610 Packet Vector #1
+    AeadGoldData {
+        key: &[
+            0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xcb, 0xcc, 0xcd, 0xce, 0xcf,
+        ],
+        iv: &[
+            0x00, 0x00, 0x00, 0x03, 0x02, 0x01, 0x00, 0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5,
+        ],
+        aad: &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        ],
+        plaintext: &[
+            0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+            0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e,
+        ],
+        ciphertext: &[
+            0x58, 0x8c, 0x97, 0x9a, 0x61, 0xc6, 0x63, 0xd2, 0xf0, 0x66, 0xd0, 0xc2, 0xc0, 0xf9, 0x89, 0x80,
+            0x6d, 0x5f, 0x6b, 0x61, 0xda, 0xc3, 0x84,
+        ],
+        tag: &[
+            0x17, 0xe8, 0xd1, 0x2c, 0xfd, 0xf9, 0x26, 0xe0,
+        ],
+    },
+    // RFC 3610 Packet Vector #2
+    AeadGoldData {
+        key: &[
+            0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xcb, 0xcc, 0xcd, 0xce, 0xcf,
+        ],
+        iv: &[
+            0x00, 0x00, 0x00, 0x04, 0x03, 0x02, 0x01, 0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5,
+        ],
+        aad: &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        ],
+        plaintext: &[
+            0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+            0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+        ],
+        ciphertext: &[
+            0x72, 0xc9, 0x1a, 0x36, 0xe1, 0x35, 0xf8, 0xcf, 0x29, 0x1c, 0xa8, 0x94, 0x08, 0x5c, 0x87, 0xe3,
+            0xcc, 0x15, 0xc4, 0x39, 0xc9, 0xe4, 0x3a, 0x3b,
+        ],
+        tag: &[
+            0xa0, 0x91, 0xd5, 0x6e, 0x10, 0x40, 0x09, 0x16,
+        ],
+    },
+    // RFC 3610 Packet Vector #3
+    AeadGoldData {
+        key: &[
+            0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xcb, 0xcc, 0xcd, 0xce, 0xcf,
+        ],
+        iv: &[
+            0x00, 0x00, 0x00, 0x05, 0x04, 0x03, 0x02, 0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5,
+        ],
+        aad: &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        ],
+        plaintext: &[
+            0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+            0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f, 0x20,
+        ],
+        ciphertext: &[
+            0x51, 0xb1, 0xe5, 0xf4, 0x4a, 0x19, 0x7d, 0x1d, 0xa4, 0x6b, 0x0f, 0x8e, 0x2d, 0x28, 0x2a, 0xe8,
+            0x71, 0xe8, 0x38, 0xbb, 0x64, 0xda, 0x85, 0x96, 0x57,
+        ],
+        tag: &[
+            0x4a, 0xda, 0xa7, 0x6f, 0xbd, 0x9f, 0xb0, 0xc5,
+        ],
+    },
+    // RFC 3610 Packet Vector #7
+    AeadGoldData {
+        key: &[
+            0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7, 0xc8, 0xc9, 0xca, 0xcb, 0xcc, 0xcd, 0xce, 0xcf,
+        ],
+        iv: &[
+            0x00, 0x00, 0x00, 0x09, 0x08, 0x07, 0x06, 0xa0, 0xa1, 0xa2, 0xa3, 0xa4, 0xa5,
+        ],
+        aad: &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        ],
+        plaintext: &[
+            0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+            0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e,
+        ],
+        ciphertext: &[
+            0x01, 0x35, 0xd1, 0xb2, 0xc9, 0x5f, 0x41, 0xd5, 0xd1, 0xd4, 0xfe, 0xc1, 0x85, 0xd1, 0x66, 0xb8,
+            0x09, 0x4e, 0x99, 0x9d, 0xfe, 0xd9, 0x6c,
+        ],
+        tag: &[
+            0x04, 0x8c, 0x56, 0x60, 0x2c, 0x97, 0xac, 0xbb, 0x74, 0x90,
+        ],
+    },
+    // SP 800-38C Example 1
+    AeadGoldData {
+        key: &[
+            0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+        ],
+        iv: &[
+            0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16,
+        ],
+        aad: &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07,
+        ],
+        plaintext: &[
+            0x20, 0x21, 0x22, 0x23,
+        ],
+        ciphertext: &[
+            0x71, 0x62, 0x01, 0x5b,
+        ],
+        tag: &[
+            0x4d, 0xac, 0x25, 0x5d,
+        ],
+    },
+    // SP 800-38C Example 2
+    AeadGoldData {
+        key: &[
+            0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+        ],
+        iv: &[
+            0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+        ],
+        aad: &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+        ],
+        plaintext: &[
+            0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f,
+        ],
+        ciphertext: &[
+            0xd2, 0xa1, 0xf0, 0xe0, 0x51, 0xea, 0x5f, 0x62, 0x08, 0x1a, 0x77, 0x92, 0x07, 0x3d, 0x59, 0x3d,
+        ],
+        tag: &[
+            0x1f, 0xc6, 0x4f, 0xbf, 0xac, 0xcd,
+        ],
+    },
+    // SP 800-38C Example 3
+    AeadGoldData {
+        key: &[
+            0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+        ],
+        iv: &[
+            0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b,
+        ],
+        aad: &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+            0x10, 0x11, 0x12, 0x13,
+        ],
+        plaintext: &[
+            0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28, 0x29, 0x2a, 0x2b, 0x2c, 0x2d, 0x2e, 0x2f,
+            0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37,
+        ],
+        ciphertext: &[
+            0xe3, 0xb2, 0x01, 0xa9, 0xf5, 0xb7, 0x1a, 0x7a, 0x9b, 0x1c, 0xea, 0xec, 0xcd, 0x97, 0xe7, 0x0b,
+            0x61, 0x76, 0xaa, 0xd9, 0xa4, 0x42, 0x8a, 0xa5,
+        ],
+        tag: &[
+            0x48, 0x43, 0x92, 0xfb, 0xc1, 0xb0, 0x99, 0x51,
+        ],
+    },
+];
+
+#[test]
+fn aes128_ccm_roundtrip() {
+    for data in AES128_CCM_GOLD_DATA {
+        let ccm = Ccm::<Aes128>::with_tag_size(&aes128_key(data.key), data.tag.len()).unwrap();
+
+        let mut out_ciphertext = [0u8; 64];
+        let mut tag = [0u8; 16];
+        let encrypt_written = ccm.encrypt_detached(data.iv, data.aad, data.plaintext, &mut out_ciphertext, &mut tag[..data.tag.len()]).expect("Encryption failed");
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+        assert_eq!(&tag[..data.tag.len()], data.tag);
+
+        let mut out_plaintext = [0u8; 64];
+        let decrypt_written = ccm.decrypt_detached(data.iv, data.aad, data.ciphertext, data.tag, &mut out_plaintext).expect("Decryption failed");
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+
+        let mut sealed = [0u8; 80];
+        let sealed_len = ccm.encrypt(data.iv, data.aad, data.plaintext, &mut sealed).expect("Encryption failed");
+        assert_eq!(&sealed[..data.ciphertext.len()], data.ciphertext);
+        assert_eq!(&sealed[data.ciphertext.len()..sealed_len], data.tag);
+
+        let mut opened = [0u8; 64];
+        let opened_len = ccm.decrypt(data.iv, data.aad, &sealed[..sealed_len], &mut opened).expect("Decryption failed");
+        assert_eq!(&opened[..opened_len], data.plaintext);
+    }
+}
+
+#[test]
+fn aes128_ccm_tampering_wipes_plaintext() {
+    let data = &AES128_CCM_GOLD_DATA[0];
+    let ccm = Ccm::<Aes128>::with_tag_size(&aes128_key(data.key), data.tag.len()).unwrap();
+
+    let mut bad_ciphertext = [0u8; 64];
+    bad_ciphertext[..data.ciphertext.len()].copy_from_slice(data.ciphertext);
+    bad_ciphertext[3] ^= 0x01;
+
+    let mut out_plaintext = [0u8; 64];
+    let result = ccm.decrypt_detached(data.iv, data.aad, &bad_ciphertext[..data.ciphertext.len()], data.tag, &mut out_plaintext);
+    assert_eq!(result, Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 64]);
+}
+
+#[test]
+fn aes128_ccm_invalid_parameters() {
+    let data = &AES128_CCM_GOLD_DATA[0];
+    let key = aes128_key(data.key);
+    assert_eq!(Ccm::<Aes128>::with_tag_size(&key, 0).err(), Some(SymcError::InvalidLength));
+    assert_eq!(Ccm::<Aes128>::with_tag_size(&key, 7).err(), Some(SymcError::InvalidLength));
+
+    let ccm = Ccm::<Aes128>::new(&key);
+    let mut output = [0u8; 64];
+    let mut tag = [0u8; 16];
+    assert_eq!(ccm.encrypt_detached(&[0u8; 6], data.aad, data.plaintext, &mut output, &mut tag), Err(SymcError::InvalidLength));
+    assert_eq!(ccm.encrypt_detached(&[0u8; 14], data.aad, data.plaintext, &mut output, &mut tag), Err(SymcError::InvalidLength));
+    assert_eq!(ccm.encrypt_detached(data.iv, data.aad, data.plaintext, &mut output[..4], &mut tag), Err(SymcError::BufferTooSmall));
+}
+
+#[test]
+fn aes128_ccm_rejects_tag_not_matching_instance() {
+    let data = &AES128_CCM_GOLD_DATA[0];
+    let key = aes128_key(data.key);
+    let mut out_plaintext = [0u8; 64];
+
+    // a 4 byte tag is valid CCM, but not on a 16 byte instance
+    let ccm = Ccm::<Aes128>::new(&key);
+    assert_eq!(ccm.encrypt_detached(data.iv, data.aad, data.plaintext, &mut [0u8; 64], &mut [0u8; 4]), Err(SymcError::InvalidLength));
+    assert_eq!(ccm.decrypt_detached(data.iv, data.aad, data.ciphertext, &[0u8; 4], &mut out_plaintext), Err(SymcError::InvalidLength));
+
+    // an empty tag must not skip authentication on a CCM* instance with a tag
+    let ccm_star = CcmStar::<Aes128>::with_tag_size(&key, 16).unwrap();
+    assert_eq!(ccm_star.encrypt_detached(data.iv, data.aad, data.plaintext, &mut [0u8; 64], &mut []), Err(SymcError::InvalidLength));
+    assert_eq!(ccm_star.decrypt_detached(data.iv, data.aad, &[0u8; 32], &[], &mut out_plaintext), Err(SymcError::InvalidLength));
+    assert_eq!(ccm_star.decrypt_detached(data.iv, data.aad, &[0u8; 32], &[0u8; 4], &mut out_plaintext), Err(SymcError::InvalidLength));
+    assert_eq!(out_plaintext, [0u8; 64]);
+}
+
+#[test]
+fn aes128_ccm_star_encryption_only() {
+    let data = &AES128_CCM_GOLD_DATA[0];
+    let ccm_star = CcmStar::<Aes128>::with_tag_size(&aes128_key(data.key), 0).unwrap();
+
+    // without a tag CCM* is the CCM counter mode encryption on its own
+    let mut out_ciphertext = [0u8; 64];
+    let encrypt_written = ccm_star.encrypt(data.iv, data.aad, data.plaintext, &mut out_ciphertext).unwrap();
+    assert_eq!(encrypt_written, data.ciphertext.len());
+    assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+    let mut out_plaintext = [0u8; 64];
+    let decrypt_written = ccm_star.decrypt(data.iv, &[], &out_ciphertext[..encrypt_written], &mut out_plaintext).unwrap();
+    assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+}
+
+#[test]
+fn aes128_ccm_star_matches_ccm() {
+    for data in AES128_CCM_GOLD_DATA {
+        let ccm_star = CcmStar::<Aes128>::with_tag_size(&aes128_key(data.key), data.tag.len()).unwrap();
+
+        let mut sealed = [0u8; 80];
+        let sealed_len = ccm_star.encrypt(data.iv, data.aad, data.plaintext, &mut sealed).expect("Encryption failed");
+        assert_eq!(&sealed[..data.ciphertext.len()], data.ciphertext);
+        assert_eq!(&sealed[data.ciphertext.len()..sealed_len], data.tag);
+    }
+}
+
+#[test]
+fn test_ccm_stress() {
+    let key_192 = aes192_key(&[0x42; 24]);
+    let key_256 = aes256_key(&[0x24; 32]);
+    for i in 0..1000 {
+        let len = i % 64;
+        let plaintext = [((i & 0xff) as u8); 64];
+        let nonce = [(i % 251) as u8; 13];
+        let nonce = &nonce[..(7 + i % 7)];
+        let tag_size = 4 + 2 * (i % 7);
+
+        let mut sealed = [0u8; 80];
+        let mut opened = [0u8; 64];
+        if i % 2 == 0 {
+            let ccm = Ccm::<Aes192>::with_tag_size(&key_192, tag_size).unwrap();
+            let sealed_len = ccm.encrypt(nonce, b"stress", &plaintext[..len], &mut sealed).expect("enc");
+            let opened_len = ccm.decrypt(nonce, b"stress", &sealed[..sealed_len], &mut opened).expect("dec");
+            assert_eq!(&opened[..opened_len], &plaintext[..len]);
+        } else {
+            let ccm = Ccm::<Aes256>::with_tag_size(&key_256, tag_size).unwrap();
+            let sealed_len = ccm.encrypt(nonce, b"stress", &plaintext[..len], &mut sealed).expect("enc");
+            let opened_len = ccm.decrypt(nonce, b"stress", &sealed[..sealed_len], &mut opened).expect("dec");
+            assert_eq!(&opened[..opened_len], &plaintext[..len]);
+        }
+    }
+}
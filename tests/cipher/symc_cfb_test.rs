@@ -0,0 +1,266 @@
+use super::*;
+
+use wovocrypt::cipher::mode::cfb::*;
+
+const KEY_128: [u8; 16] = [
+    0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+];
+const KEY_192: [u8; 24] = [
+    0x8e, 0x73, 0xb0, 0xf7, 0xda, 0x0e, 0x64, 0x52, 0xc8, 0x10, 0xf3, 0x2b,
+    0x80, 0x90, 0x79, 0xe5, 0x62, 0xf8, 0xea, 0xd2, 0x52, 0x2c, 0x6b, 0x7b,
+];
+const KEY_256: [u8; 32] = [
+    0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe, 0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d, 0x77, 0x81,
+    0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7, 0x2d, 0x98, 0x10, 0xa3, 0x09, 0x14, 0xdf, 0xf4,
+];
+const IV: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+
+// NIST SP 800-38A, Appendix F.3
+const AES128_CFB1_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1,
+        ],
+        ciphertext: &[
+            0x68, 0xb3,
+        ],
+    },
+];
+
+const AES128_CFB8_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d,
+        ],
+        ciphertext: &[
+            0x3b, 0x79, 0x42, 0x4c, 0x9c, 0x0d, 0xd4, 0x36, 0xba, 0xce, 0x9e, 0x0e, 0xd4, 0x58, 0x6a, 0x4f,
+            0x32, 0xb9,
+        ],
+    },
+];
+
+const AES128_CFB128_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+        ],
+        ciphertext: &[
+            0x3b, 0x3f, 0xd9, 0x2e, 0xb7, 0x2d, 0xad, 0x20, 0x33, 0x34, 0x49, 0xf8, 0xe8, 0x3c, 0xfb, 0x4a,
+            0xc8, 0xa6, 0x45, 0x37, 0xa0, 0xb3, 0xa9, 0x3f, 0xcd, 0xe3, 0xcd, 0xad, 0x9f, 0x1c, 0xe5, 0x8b,
+            0x26, 0x75, 0x1f, 0x67, 0xa3, 0xcb, 0xb1, 0x40, 0xb1, 0x80, 0x8c, 0xf1, 0x87, 0xa4, 0xf4, 0xdf,
+            0xc0, 0x4b, 0x05, 0x35, 0x7c, 0x5d, 0x1c, 0x0e, 0xea, 0xc4, 0xc6, 0x6f, 0x9f, 0xf7, 0xf2, 0xe6,
+        ],
+    },
+];
+
+const AES192_CFB1_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1,
+        ],
+        ciphertext: &[
+            0x93, 0x59,
+        ],
+    },
+];
+
+const AES192_CFB8_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d,
+        ],
+        ciphertext: &[
+            0xcd, 0xa2, 0x52, 0x1e, 0xf0, 0xa9, 0x05, 0xca, 0x44, 0xcd, 0x05, 0x7c, 0xbf, 0x0d, 0x47, 0xa0,
+            0x67, 0x8a,
+        ],
+    },
+];
+
+const AES192_CFB128_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+        ],
+        ciphertext: &[
+            0xcd, 0xc8, 0x0d, 0x6f, 0xdd, 0xf1, 0x8c, 0xab, 0x34, 0xc2, 0x59, 0x09, 0xc9, 0x9a, 0x41, 0x74,
+            0x67, 0xce, 0x7f, 0x7f, 0x81, 0x17, 0x36, 0x21, 0x96, 0x1a, 0x2b, 0x70, 0x17, 0x1d, 0x3d, 0x7a,
+            0x2e, 0x1e, 0x8a, 0x1d, 0xd5, 0x9b, 0x88, 0xb1, 0xc8, 0xe6, 0x0f, 0xed, 0x1e, 0xfa, 0xc4, 0xc9,
+            0xc0, 0x5f, 0x9f, 0x9c, 0xa9, 0x83, 0x4f, 0xa0, 0x42, 0xae, 0x8f, 0xba, 0x58, 0x4b, 0x09, 0xff,
+        ],
+    },
+];
+
+const AES256_CFB1_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1,
+        ],
+        ciphertext: &[
+            0x90, 0x29,
+        ],
+    },
+];
+
+const AES256_CFB8_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d,
+        ],
+        ciphertext: &[
+            0xdc, 0x1f, 0x1a, 0x85, 0x20, 0xa6, 0x4d, 0xb5, 0x5f, 0xcc, 0x8a, 0xc5, 0x54, 0x84, 0x4e, 0x88,
+            0x97, 0x00,
+        ],
+    },
+];
+
+const AES256_CFB128_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+        ],
+        ciphertext: &[
+            0xdc, 0x7e, 0x84, 0xbf, 0xda, 0x79, 0x16, 0x4b, 0x7e, 0xcd, 0x84, 0x86, 0x98, 0x5d, 0x38, 0x60,
+            0x39, 0xff, 0xed, 0x14, 0x3b, 0x28, 0xb1, 0xc8, 0x32, 0x11, 0x3c, 0x63, 0x31, 0xe5, 0x40, 0x7b,
+            0xdf, 0x10, 0x13, 0x24, 0x15, 0xe5, 0x4b, 0x92, 0xa1, 0x3e, 0xd0, 0xa8, 0x26, 0x7a, 0xe2, 0xf9,
+            0x75, 0xa3, 0x85, 0x74, 0x1a, 0xb9, 0xce, 0xf8, 0x20, 0x31, 0x62, 0x3d, 0x55, 0xb1, 0xe4, 0x71,
+        ],
+    },
+];
+
+// OpenPGP CFB with IV as the random prefix, checked against libgcrypt (GCRY_CIPHER_ENABLE_SYNC)
+const AES128_OPENPGP_CFB_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: b"The quick brown fox jumps over the lazy dog",
+        ciphertext: &[
+            0x7d, 0xf6, 0x69, 0x0f, 0x1e, 0xbd, 0x9f, 0xb4, 0x36, 0x4b, 0xfa, 0x4c, 0xb5, 0x16, 0x5a, 0x60,
+            0x31, 0xc3, 0x97, 0x2f, 0xb8, 0x9f, 0x8a, 0x9a, 0xeb, 0x7b, 0x68, 0x7f, 0xfc, 0x33, 0xa3, 0xd0,
+            0xd7, 0x50, 0xe4, 0x79, 0xb8, 0x80, 0xba, 0x1e, 0xd7, 0x04, 0xbe, 0xe1, 0x4c, 0x83, 0xd2, 0x0c,
+            0xc5, 0xcb, 0x92, 0x23, 0xd1, 0x04, 0xef, 0x2d, 0x3d, 0x8f, 0xa2, 0x33, 0x19,
+        ],
+    },
+];
+
+/// Runs every gold entry one shot and again byte by byte.
+fn cfb_roundtrip<E, D>(key: &E::Key, dkey: &D::Key, iv: &E::IV, div: &D::IV, gold: &[SymcGoldData])
+where E: SymcEncryptor, D: SymcDecryptor {
+    for data in gold {
+        let mut out_ciphertext = [0u8; 96];
+        let encrypt_written = E::encrypt(key, iv, data.plaintext, &mut out_ciphertext).expect("Encryption failed");
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 96];
+        let decrypt_written = D::decrypt(dkey, div, data.ciphertext, &mut out_plaintext).expect("Decryption failed");
+        assert_eq!(decrypt_written, data.plaintext.len());
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+
+        let mut encryptor = E::new(key, iv);
+        let mut written = 0;
+        for byte in data.plaintext.chunks(1) {
+            written += encryptor.update(byte, &mut out_ciphertext[written..]).expect("Encryption update failed");
+        }
+        written += encryptor.finalize(&mut out_ciphertext[written..]).expect("Encryption finalize failed");
+        assert_eq!(&out_ciphertext[..written], data.ciphertext);
+
+        let mut decryptor = D::new(dkey, div);
+        let mut written = 0;
+        for byte in data.ciphertext.chunks(1) {
+            written += decryptor.update(byte, &mut out_plaintext[written..]).expect("Decryption update failed");
+        }
+        written += decryptor.finalize(&mut out_plaintext[written..]).expect("Decryption finalize failed");
+        assert_eq!(&out_plaintext[..written], data.plaintext);
+    }
+}
+
+#[test]
+fn aes128_cfb1() {
+    let key = aes128_key(&KEY_128);
+    cfb_roundtrip::<Cfb1Encryptor<Aes128>, Cfb1Decryptor<Aes128>>(&key, &key, &IV, &IV, AES128_CFB1_GOLD_DATA);
+}
+
+#[test]
+fn aes192_cfb1() {
+    let key = aes192_key(&KEY_192);
+    cfb_roundtrip::<Cfb1Encryptor<Aes192>, Cfb1Decryptor<Aes192>>(&key, &key, &IV, &IV, AES192_CFB1_GOLD_DATA);
+}
+
+#[test]
+fn aes256_cfb1() {
+    let key = aes256_key(&KEY_256);
+    cfb_roundtrip::<Cfb1Encryptor<Aes256>, Cfb1Decryptor<Aes256>>(&key, &key, &IV, &IV, AES256_CFB1_GOLD_DATA);
+}
+
+#[test]
+fn aes128_cfb8() {
+    let key = aes128_key(&KEY_128);
+    cfb_roundtrip::<Cfb8Encryptor<Aes128>, Cfb8Decryptor<Aes128>>(&key, &key, &IV, &IV, AES128_CFB8_GOLD_DATA);
+}
+
+#[test]
+fn aes192_cfb8() {
+    let key = aes192_key(&KEY_192);
+    cfb_roundtrip::<Cfb8Encryptor<Aes192>, Cfb8Decryptor<Aes192>>(&key, &key, &IV, &IV, AES192_CFB8_GOLD_DATA);
+}
+
+#[test]
+fn aes256_cfb8() {
+    let key = aes256_key(&KEY_256);
+    cfb_roundtrip::<Cfb8Encryptor<Aes256>, Cfb8Decryptor<Aes256>>(&key, &key, &IV, &IV, AES256_CFB8_GOLD_DATA);
+}
+
+#[test]
+fn aes128_cfb128() {
+    let key = aes128_key(&KEY_128);
+    cfb_roundtrip::<CfbEncryptor<Aes128>, CfbDecryptor<Aes128>>(&key, &key, &IV, &IV, AES128_CFB128_GOLD_DATA);
+}
+
+#[test]
+fn aes192_cfb128() {
+    let key = aes192_key(&KEY_192);
+    cfb_roundtrip::<CfbEncryptor<Aes192>, CfbDecryptor<Aes192>>(&key, &key, &IV, &IV, AES192_CFB128_GOLD_DATA);
+}
+
+#[test]
+fn aes256_cfb128() {
+    let key = aes256_key(&KEY_256);
+    cfb_roundtrip::<CfbEncryptor<Aes256>, CfbDecryptor<Aes256>>(&key, &key, &IV, &IV, AES256_CFB128_GOLD_DATA);
+}
+
+#[test]
+fn aes128_cfb128_partial_final_segment() {
+    // a truncated F.3.13 message still matches the vector prefix
+    let key = aes128_key(&KEY_128);
+    let data = &AES128_CFB128_GOLD_DATA[0];
+    let mut out_ciphertext = [0u8; 64];
+    let written = CfbEncryptor::<Aes128>::encrypt(&key, &IV, &data.plaintext[..37], &mut out_ciphertext).expect("Encryption failed");
+    assert_eq!(written, 37);
+    assert_eq!(&out_ciphertext[..37], &data.ciphertext[..37]);
+}
+
+#[test]
+fn aes128_openpgp_cfb() {
+    let key = aes128_key(&KEY_128);
+    cfb_roundtrip::<OpenPgpCfbEncryptor<Aes128>, OpenPgpCfbDecryptor<Aes128>>(&key, &key, &IV, &[], AES128_OPENPGP_CFB_GOLD_DATA);
+}
+
+#[test]
+fn test_openpgp_cfb_truncated_prefix() {
+    let key = aes128_key(&KEY_128);
+    let mut out_plaintext = [0u8; 16];
+    let result = OpenPgpCfbDecryptor::<Aes128>::decrypt(&key, &[], &AES128_OPENPGP_CFB_GOLD_DATA[0].ciphertext[..17], &mut out_plaintext);
+    assert_eq!(result, Err(wovocrypt::error::SymcError::InvalidInputLength));
+}
@@ -0,0 +1,198 @@
+use wovocrypt::cipher::StreamCipher;
+use wovocrypt::cipher::chacha20::{ChaCha20, ChaCha20Key, ChaCha20Nonce};
+#[cfg(feature = "xchacha20")]
+use wovocrypt::cipher::chacha20::{XChaCha20, XChaCha20Nonce};
+use wovocrypt::error::SymcError;
+
+struct StreamGoldData {
+    key: &'static [u8],
+    nonce: &'static [u8],
+    counter: u32,
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+}
+
+const CHACHA20_GOLD_DATA: &[StreamGoldData] = &[
+    // RFC 8439, A.2 #1
+    StreamGoldData {
+        key: &[0u8; 32],
+        nonce: &[0u8; 12],
+        counter: 0,
+        plaintext: &[0u8; 64],
+        ciphertext: &[
+            0x76, 0xb8, 0xe0, 0xad, 0xa0, 0xf1, 0x3d, 0x90, 0x40, 0x5d, 0x6a, 0xe5, 0x53, 0x86, 0xbd, 0x28,
+            0xbd, 0xd2, 0x19, 0xb8, 0xa0, 0x8d, 0xed, 0x1a, 0xa8, 0x36, 0xef, 0xcc, 0x8b, 0x77, 0x0d, 0xc7,
+            0xda, 0x41, 0x59, 0x7c, 0x51, 0x57, 0x48, 0x8d, 0x77, 0x24, 0xe0, 0x3f, 0xb8, 0xd8, 0x4a, 0x37,
+            0x6a, 0x43, 0xb8, 0xf4, 0x15, 0x18, 0xa1, 0x1c, 0xc3, 0x87, 0xb6, 0x69, 0xb2, 0xee, 0x65, 0x86,
+        ],
+    },
+    // RFC 8439, 2.4.2
+    StreamGoldData {
+        key: &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+            0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+        ],
+        nonce: &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x4a, 0x00, 0x00, 0x00, 0x00,
+        ],
+        counter: 1,
+        plaintext: b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.",
+        ciphertext: &[
+            0x6e, 0x2e, 0x35, 0x9a, 0x25, 0x68, 0xf9, 0x80, 0x41, 0xba, 0x07, 0x28, 0xdd, 0x0d, 0x69, 0x81,
+            0xe9, 0x7e, 0x7a, 0xec, 0x1d, 0x43, 0x60, 0xc2, 0x0a, 0x27, 0xaf, 0xcc, 0xfd, 0x9f, 0xae, 0x0b,
+            0xf9, 0x1b, 0x65, 0xc5, 0x52, 0x47, 0x33, 0xab, 0x8f, 0x59, 0x3d, 0xab, 0xcd, 0x62, 0xb3, 0x57,
+            0x16, 0x39, 0xd6, 0x24, 0xe6, 0x51, 0x52, 0xab, 0x8f, 0x53, 0x0c, 0x35, 0x9f, 0x08, 0x61, 0xd8,
+            0x07, 0xca, 0x0d, 0xbf, 0x50, 0x0d, 0x6a, 0x61, 0x56, 0xa3, 0x8e, 0x08, 0x8a, 0x22, 0xb6, 0x5e,
+            0x52, 0xbc, 0x51, 0x4d, 0x16, 0xcc, 0xf8, 0x06, 0x81, 0x8c, 0xe9, 0x1a, 0xb7, 0x79, 0x37, 0x36,
+            0x5a, 0xf9, 0x0b, 0xbf, 0x74, 0xa3, 0x5b, 0xe6, 0xb4, 0x0b, 0x8e, 0xed, 0xf2, 0x78, 0x5e, 0x42,
+            0x87, 0x4d,
+        ],
+    },
+    // RFC 8439, A.2 #3
+    StreamGoldData {
+        key: &[
+            0x1c, 0x92, 0x40, 0xa5, 0xeb, 0x55, 0xd3, 0x8a, 0xf3, 0x33, 0x88, 0x86, 0x04, 0xf6, 0xb5, 0xf0,
+            0x47, 0x39, 0x17, 0xc1, 0x40, 0x2b, 0x80, 0x09, 0x9d, 0xca, 0x5c, 0xbc, 0x20, 0x70, 0x75, 0xc0,
+        ],
+        nonce: &[
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+        ],
+        counter: 42,
+        plaintext: b"'Twas brillig, and the slithy toves\nDid gyre and gimble in the wabe:\nAll mimsy were the borogoves,\nAnd the mome raths outgrabe.",
+        ciphertext: &[
+            0x62, 0xe6, 0x34, 0x7f, 0x95, 0xed, 0x87, 0xa4, 0x5f, 0xfa, 0xe7, 0x42, 0x6f, 0x27, 0xa1, 0xdf,
+            0x5f, 0xb6, 0x91, 0x10, 0x04, 0x4c, 0x0d, 0x73, 0x11, 0x8e, 0xff, 0xa9, 0x5b, 0x01, 0xe5, 0xcf,
+            0x16, 0x6d, 0x3d, 0xf2, 0xd7, 0x21, 0xca, 0xf9, 0xb2, 0x1e, 0x5f, 0xb1, 0x4c, 0x61, 0x68, 0x71,
+            0xfd, 0x84, 0xc5, 0x4f, 0x9d, 0x65, 0xb2, 0x83, 0x19, 0x6c, 0x7f, 0xe4, 0xf6, 0x05, 0x53, 0xeb,
+            0xf3, 0x9c, 0x64, 0x02, 0xc4, 0x22, 0x34, 0xe3, 0x2a, 0x35, 0x6b, 0x3e, 0x76, 0x43, 0x12, 0xa6,
+            0x1a, 0x55, 0x32, 0x05, 0x57, 0x16, 0xea, 0xd6, 0x96, 0x25, 0x68, 0xf8, 0x7d, 0x3f, 0x3f, 0x77,
+            0x04, 0xc6, 0xa8, 0xd1, 0xbc, 0xd1, 0xbf, 0x4d, 0x50, 0xd6, 0x15, 0x4b, 0x6d, 0xa7, 0x31, 0xb1,
+            0x87, 0xb5, 0x8d, 0xfd, 0x72, 0x8a, 0xfa, 0x36, 0x75, 0x7a, 0x79, 0x7a, 0xc1, 0x88, 0xd1,
+        ],
+    },
+];
+
+fn chacha20_key(key: &[u8]) -> ChaCha20Key {
+    ChaCha20Key::from(<[u8; 32]>::try_from(key).unwrap())
+}
+
+fn chacha20_nonce(nonce: &[u8]) -> ChaCha20Nonce {
+    ChaCha20Nonce::from(<[u8; 12]>::try_from(nonce).unwrap())
+}
+
+#[test]
+fn test_chacha20_gold_data() {
+    for data in CHACHA20_GOLD_DATA {
+        let key = chacha20_key(data.key);
+        let nonce = chacha20_nonce(data.nonce);
+
+        let mut out_ciphertext = [0u8; 128];
+        let mut cipher = ChaCha20::with_counter(&key, &nonce, data.counter);
+        let written = cipher.apply_keystream(data.plaintext, &mut out_ciphertext).expect("Encryption failed");
+        assert_eq!(&out_ciphertext[..written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 128];
+        let mut cipher = ChaCha20::with_counter(&key, &nonce, data.counter);
+        let written = cipher.apply_keystream(data.ciphertext, &mut out_plaintext).expect("Decryption failed");
+        assert_eq!(&out_plaintext[..written], data.plaintext);
+    }
+}
+
+#[test]
+fn test_chacha20_split_updates() {
+    let data = &CHACHA20_GOLD_DATA[1];
+    let key = chacha20_key(data.key);
+    let nonce = chacha20_nonce(data.nonce);
+
+    for chunk_size in [1, 7, 63, 64, 65] {
+        let mut cipher = ChaCha20::with_counter(&key, &nonce, data.counter);
+        let mut out_ciphertext = [0u8; 128];
+        let mut written = 0;
+        for chunk in data.plaintext.chunks(chunk_size) {
+            written += cipher.apply_keystream(chunk, &mut out_ciphertext[written..]).expect("Encryption failed");
+        }
+        assert_eq!(&out_ciphertext[..written], data.ciphertext);
+    }
+}
+
+#[test]
+fn test_chacha20_process() {
+    let data = &CHACHA20_GOLD_DATA[0];
+    let mut output = [0u8; 64];
+    let written = ChaCha20::process(&chacha20_key(data.key), &chacha20_nonce(data.nonce), data.plaintext, &mut output).expect("Encryption failed");
+    assert_eq!(&output[..written], data.ciphertext);
+}
+
+#[test]
+fn test_chacha20_errors() {
+    let key = ChaCha20Key::default();
+    let nonce = ChaCha20Nonce::default();
+
+    let mut cipher = ChaCha20::new(&key, &nonce);
+    let mut output = [0u8; 8];
+    assert_eq!(cipher.apply_keystream(&[0u8; 16], &mut output), Err(SymcError::BufferTooSmall));
+
+    // the last block of the counter space is usable, the one after it is not
+    let mut cipher = ChaCha20::with_counter(&key, &nonce, u32::MAX);
+    let mut output = [0u8; 128];
+    assert_eq!(cipher.apply_keystream(&[0u8; 65], &mut output), Err(SymcError::InvalidInputLength));
+    assert_eq!(cipher.apply_keystream(&[0u8; 64], &mut output), Ok(64));
+    assert_eq!(cipher.apply_keystream(&[0u8; 1], &mut output), Err(SymcError::InvalidInputLength));
+}
+
+/// XChaCha20 keystream, cross-checked against HChaCha20 + RFC 8439 ChaCha20
+#[cfg(feature = "xchacha20")]
+#[test]
+fn test_xchacha20() {
+    const KEY: &[u8] = &[
+        0x80, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f,
+        0x90, 0x91, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0x9b, 0x9c, 0x9d, 0x9e, 0x9f,
+    ];
+    const NONCE: &[u8] = &[
+        0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+        0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+    ];
+    const PLAINTEXT: &[u8] = b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.";
+    const CIPHERTEXT: &[u8] = &[
+        0x37, 0x78, 0x7b, 0xe9, 0x96, 0x12, 0xd0, 0xf8, 0x67, 0x2b, 0x4f, 0x0c, 0xea, 0xd7, 0x09, 0x94,
+        0x22, 0xa1, 0x0d, 0x1d, 0x88, 0x9d, 0xd7, 0xb0, 0xa9, 0x1b, 0xe5, 0x51, 0xe0, 0x95, 0x66, 0xa6,
+        0xd2, 0xeb, 0x48, 0x5e, 0x7b, 0x27, 0x0b, 0xa6, 0x47, 0xfc, 0x5b, 0x16, 0x79, 0x9f, 0xa8, 0x46,
+        0x3e, 0xd4, 0x4c, 0x83, 0x43, 0x7c, 0x34, 0x8f, 0xd5, 0x4a, 0x35, 0x0b, 0x86, 0x25, 0x35, 0x35,
+        0x9f, 0x60, 0x0a, 0xd4, 0x34, 0x9e, 0x91, 0x7a, 0x8f, 0x7b, 0x07, 0xf3, 0x90, 0xc1, 0xef, 0x75,
+        0x46, 0x2f, 0x17, 0x4e, 0x63, 0x31, 0xe8, 0x99, 0xb8, 0xdf, 0xd9, 0x2c, 0x31, 0x20, 0x63, 0xbb,
+        0x63, 0x4e, 0x75, 0x18, 0x45, 0x4d, 0xe8, 0x12, 0x44, 0xbf, 0x85, 0x69, 0x0c, 0xf6, 0x7e, 0x33,
+        0xb5, 0x3f,
+    ];
+
+    let key = chacha20_key(KEY);
+    let nonce = XChaCha20Nonce::from(<[u8; 24]>::try_from(NONCE).unwrap());
+
+    let mut out_ciphertext = [0u8; 128];
+    let written = XChaCha20::process(&key, &nonce, PLAINTEXT, &mut out_ciphertext).expect("Encryption failed");
+    assert_eq!(&out_ciphertext[..written], CIPHERTEXT);
+
+    let mut cipher = XChaCha20::new(&key, &nonce);
+    let mut out_plaintext = [0u8; 128];
+    let mut written = 0;
+    for chunk in CIPHERTEXT.chunks(13) {
+        written += cipher.apply_keystream(chunk, &mut out_plaintext[written..]).expect("Decryption failed");
+    }
+    assert_eq!(&out_plaintext[..written], PLAINTEXT);
+}
+
+#[test]
+fn test_chacha20_stress() {
+    let key = chacha20_key(CHACHA20_GOLD_DATA[2].key);
+    for i in 0..1000 {
+        let len = i % 200;
+        let plaintext = [((i & 0xff) as u8); 200];
+        let nonce = ChaCha20Nonce::from([(i % 251) as u8; 12]);
+
+        let mut ciphertext = [0u8; 200];
+        ChaCha20::process(&key, &nonce, &plaintext[..len], &mut ciphertext).expect("enc");
+
+        let mut decrypted = [0u8; 200];
+        ChaCha20::process(&key, &nonce, &ciphertext[..len], &mut decrypted).expect("dec");
+        assert_eq!(&decrypted[..len], &plaintext[..len]);
+    }
+}
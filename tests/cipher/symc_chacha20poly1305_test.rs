@@ -0,0 +1,219 @@
+use super::*;
+
+use wovocrypt::aead::Aead;
+use wovocrypt::cipher::chacha20::ChaCha20Key;
+#[cfg(feature = "chacha20poly1305")]
+use wovocrypt::aead::chacha20poly1305::ChaCha20Poly1305;
+#[cfg(feature = "xchacha20poly1305")]
+use wovocrypt::aead::chacha20poly1305::XChaCha20Poly1305;
+use wovocrypt::error::SymcError;
+
+#[cfg(feature = "chacha20poly1305")]
+const CHACHA20POLY1305_GOLD_DATA: &[AeadGoldData] = &[
+    // RFC 8439, 2.8.2
+    AeadGoldData {
+        key: &[
+            0x80, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f,
+            0x90, 0x91, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0x9b, 0x9c, 0x9d, 0x9e, 0x9f,
+        ],
+        iv: &[
+            0x07, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+        ],
+        aad: &[
+            0x50, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7,
+        ],
+        plaintext: b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.",
+        ciphertext: &[
+            0xd3, 0x1a, 0x8d, 0x34, 0x64, 0x8e, 0x60, 0xdb, 0x7b, 0x86, 0xaf, 0xbc, 0x53, 0xef, 0x7e, 0xc2,
+            0xa4, 0xad, 0xed, 0x51, 0x29, 0x6e, 0x08, 0xfe, 0xa9, 0xe2, 0xb5, 0xa7, 0x36, 0xee, 0x62, 0xd6,
+            0x3d, 0xbe, 0xa4, 0x5e, 0x8c, 0xa9, 0x67, 0x12, 0x82, 0xfa, 0xfb, 0x69, 0xda, 0x92, 0x72, 0x8b,
+            0x1a, 0x71, 0xde, 0x0a, 0x9e, 0x06, 0x0b, 0x29, 0x05, 0xd6, 0xa5, 0xb6, 0x7e, 0xcd, 0x3b, 0x36,
+            0x92, 0xdd, 0xbd, 0x7f, 0x2d, 0x77, 0x8b, 0x8c, 0x98, 0x03, 0xae, 0xe3, 0x28, 0x09, 0x1b, 0x58,
+            0xfa, 0xb3, 0x24, 0xe4, 0xfa, 0xd6, 0x75, 0x94, 0x55, 0x85, 0x80, 0x8b, 0x48, 0x31, 0xd7, 0xbc,
+            0x3f, 0xf4, 0xde, 0xf0, 0x8e, 0x4b, 0x7a, 0x9d, 0xe5, 0x76, 0xd2, 0x65, 0x86, 0xce, 0xc6, 0x4b,
+            0x61, 0x16,
+        ],
+        tag: &[
+            0x1a, 0xe1, 0x0b, 0x59, 0x4f, 0x09, 0xe2, 0x6a, 0x7e, 0x90, 0x2e, 0xcb, 0xd0, 0x60, 0x06, 0x91,
+        ],
+    },
+    // empty message
+    AeadGoldData {
+        key: &[
+            0x80, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f,
+            0x90, 0x91, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0x9b, 0x9c, 0x9d, 0x9e, 0x9f,
+        ],
+        iv: &[
+            0x07, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+        ],
+        aad: &[],
+        plaintext: &[],
+        ciphertext: &[],
+        tag: &[
+            0xa0, 0x78, 0x4d, 0x7a, 0x47, 0x16, 0xf3, 0xfe, 0xb4, 0xf6, 0x4e, 0x7f, 0x4b, 0x39, 0xbf, 0x04,
+        ],
+    },
+    // AAD only
+    AeadGoldData {
+        key: &[
+            0x80, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f,
+            0x90, 0x91, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0x9b, 0x9c, 0x9d, 0x9e, 0x9f,
+        ],
+        iv: &[
+            0x07, 0x00, 0x00, 0x00, 0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47,
+        ],
+        aad: &[
+            0x50, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7,
+        ],
+        plaintext: &[],
+        ciphertext: &[],
+        tag: &[
+            0xe6, 0x22, 0xe5, 0x64, 0x7a, 0x38, 0xd9, 0x67, 0xa7, 0xec, 0xbc, 0xb4, 0x6c, 0x7f, 0x67, 0x5c,
+        ],
+    },
+];
+
+#[cfg(feature = "xchacha20poly1305")]
+const XCHACHA20POLY1305_GOLD_DATA: &[AeadGoldData] = &[
+    // draft-irtf-cfrg-xchacha, A.3.1
+    AeadGoldData {
+        key: &[
+            0x80, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f,
+            0x90, 0x91, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0x9b, 0x9c, 0x9d, 0x9e, 0x9f,
+        ],
+        iv: &[
+            0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+            0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+        ],
+        aad: &[
+            0x50, 0x51, 0x52, 0x53, 0xc0, 0xc1, 0xc2, 0xc3, 0xc4, 0xc5, 0xc6, 0xc7,
+        ],
+        plaintext: b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it.",
+        ciphertext: &[
+            0xbd, 0x6d, 0x17, 0x9d, 0x3e, 0x83, 0xd4, 0x3b, 0x95, 0x76, 0x57, 0x94, 0x93, 0xc0, 0xe9, 0x39,
+            0x57, 0x2a, 0x17, 0x00, 0x25, 0x2b, 0xfa, 0xcc, 0xbe, 0xd2, 0x90, 0x2c, 0x21, 0x39, 0x6c, 0xbb,
+            0x73, 0x1c, 0x7f, 0x1b, 0x0b, 0x4a, 0xa6, 0x44, 0x0b, 0xf3, 0xa8, 0x2f, 0x4e, 0xda, 0x7e, 0x39,
+            0xae, 0x64, 0xc6, 0x70, 0x8c, 0x54, 0xc2, 0x16, 0xcb, 0x96, 0xb7, 0x2e, 0x12, 0x13, 0xb4, 0x52,
+            0x2f, 0x8c, 0x9b, 0xa4, 0x0d, 0xb5, 0xd9, 0x45, 0xb1, 0x1b, 0x69, 0xb9, 0x82, 0xc1, 0xbb, 0x9e,
+            0x3f, 0x3f, 0xac, 0x2b, 0xc3, 0x69, 0x48, 0x8f, 0x76, 0xb2, 0x38, 0x35, 0x65, 0xd3, 0xff, 0xf9,
+            0x21, 0xf9, 0x66, 0x4c, 0x97, 0x63, 0x7d, 0xa9, 0x76, 0x88, 0x12, 0xf6, 0x15, 0xc6, 0x8b, 0x13,
+            0xb5, 0x2e,
+        ],
+        tag: &[
+            0xc0, 0x87, 0x59, 0x24, 0xc1, 0xc7, 0x98, 0x79, 0x47, 0xde, 0xaf, 0xd8, 0x78, 0x0a, 0xcf, 0x49,
+        ],
+    },
+    // empty message
+    AeadGoldData {
+        key: &[
+            0x80, 0x81, 0x82, 0x83, 0x84, 0x85, 0x86, 0x87, 0x88, 0x89, 0x8a, 0x8b, 0x8c, 0x8d, 0x8e, 0x8f,
+            0x90, 0x91, 0x92, 0x93, 0x94, 0x95, 0x96, 0x97, 0x98, 0x99, 0x9a, 0x9b, 0x9c, 0x9d, 0x9e, 0x9f,
+        ],
+        iv: &[
+            0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+            0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57,
+        ],
+        aad: &[],
+        plaintext: &[],
+        ciphertext: &[],
+        tag: &[
+            0x1d, 0xac, 0x8f, 0x73, 0x14, 0x6d, 0x1e, 0x9d, 0xa7, 0x96, 0xcb, 0x7f, 0x72, 0x21, 0xa5, 0xdf,
+        ],
+    },
+];
+
+fn chacha20_key(key: &[u8]) -> ChaCha20Key {
+    ChaCha20Key::from(<[u8; 32]>::try_from(key).unwrap())
+}
+
+fn aead_gold_roundtrip<A: Aead<Key = ChaCha20Key>>(gold_data: &[AeadGoldData]) {
+    for data in gold_data {
+        let aead = A::new(&chacha20_key(data.key));
+
+        let mut out_ciphertext = [0u8; 128];
+        let mut tag = [0u8; 16];
+        let encrypt_written = aead.encrypt_detached(data.iv, data.aad, data.plaintext, &mut out_ciphertext, &mut tag).expect("Encryption failed");
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+        assert_eq!(&tag, data.tag);
+
+        let mut out_plaintext = [0u8; 128];
+        let decrypt_written = aead.decrypt_detached(data.iv, data.aad, data.ciphertext, data.tag, &mut out_plaintext).expect("Decryption failed");
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+
+        let mut sealed = [0u8; 144];
+        let sealed_len = aead.encrypt(data.iv, data.aad, data.plaintext, &mut sealed).expect("Encryption failed");
+        assert_eq!(&sealed[..data.ciphertext.len()], data.ciphertext);
+        assert_eq!(&sealed[data.ciphertext.len()..sealed_len], data.tag);
+
+        let mut opened = [0u8; 128];
+        let opened_len = aead.decrypt(data.iv, data.aad, &sealed[..sealed_len], &mut opened).expect("Decryption failed");
+        assert_eq!(&opened[..opened_len], data.plaintext);
+    }
+}
+
+fn aead_tampering_releases_no_plaintext<A: Aead<Key = ChaCha20Key>>(data: &AeadGoldData) {
+    let aead = A::new(&chacha20_key(data.key));
+    let mut out_plaintext = [0u8; 128];
+
+    let mut bad_tag = [0u8; 16];
+    bad_tag.copy_from_slice(data.tag);
+    bad_tag[0] ^= 1;
+    assert_eq!(aead.decrypt_detached(data.iv, data.aad, data.ciphertext, &bad_tag, &mut out_plaintext), Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 128]);
+
+    let mut bad_ciphertext = [0u8; 128];
+    bad_ciphertext[..data.ciphertext.len()].copy_from_slice(data.ciphertext);
+    bad_ciphertext[data.ciphertext.len() - 1] ^= 0x80;
+    assert_eq!(aead.decrypt_detached(data.iv, data.aad, &bad_ciphertext[..data.ciphertext.len()], data.tag, &mut out_plaintext), Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 128]);
+
+    assert_eq!(aead.decrypt_detached(data.iv, &data.aad[1..], data.ciphertext, data.tag, &mut out_plaintext), Err(SymcError::AuthenticationFailed));
+    assert_eq!(out_plaintext, [0u8; 128]);
+
+    // wrong nonce and tag sizes are rejected up front
+    assert_eq!(aead.decrypt_detached(&data.iv[1..], data.aad, data.ciphertext, data.tag, &mut out_plaintext), Err(SymcError::InvalidLength));
+    assert_eq!(aead.decrypt_detached(data.iv, data.aad, data.ciphertext, &data.tag[..12], &mut out_plaintext), Err(SymcError::InvalidLength));
+    assert_eq!(aead.decrypt(data.iv, data.aad, &data.tag[..15], &mut out_plaintext), Err(SymcError::InvalidInputLength));
+}
+
+#[cfg(feature = "chacha20poly1305")]
+#[test]
+fn chacha20poly1305_roundtrip() {
+    aead_gold_roundtrip::<ChaCha20Poly1305>(CHACHA20POLY1305_GOLD_DATA);
+}
+
+#[cfg(feature = "chacha20poly1305")]
+#[test]
+fn chacha20poly1305_tampering_releases_no_plaintext() {
+    aead_tampering_releases_no_plaintext::<ChaCha20Poly1305>(&CHACHA20POLY1305_GOLD_DATA[0]);
+}
+
+#[cfg(feature = "xchacha20poly1305")]
+#[test]
+fn xchacha20poly1305_roundtrip() {
+    aead_gold_roundtrip::<XChaCha20Poly1305>(XCHACHA20POLY1305_GOLD_DATA);
+}
+
+#[cfg(feature = "xchacha20poly1305")]
+#[test]
+fn xchacha20poly1305_tampering_releases_no_plaintext() {
+    aead_tampering_releases_no_plaintext::<XChaCha20Poly1305>(&XCHACHA20POLY1305_GOLD_DATA[0]);
+}
+
+#[cfg(feature = "xchacha20poly1305")]
+#[test]
+fn test_chacha20poly1305_stress() {
+    let aead = XChaCha20Poly1305::new(&chacha20_key(&[0x42; 32]));
+    for i in 0..1000 {
+        let len = i % 128;
+        let plaintext = [((i & 0xff) as u8); 128];
+        let nonce = [(i % 251) as u8; 24];
+
+        let mut sealed = [0u8; 144];
+        let sealed_len = aead.encrypt(&nonce, b"stress", &plaintext[..len], &mut sealed).expect("enc");
+
+        let mut opened = [0u8; 128];
+        let opened_len = aead.decrypt(&nonce, b"stress", &sealed[..sealed_len], &mut opened).expect("dec");
+        assert_eq!(&opened[..opened_len], &plaintext[..len]);
+    }
+}
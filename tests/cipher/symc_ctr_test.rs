@@ -1,6 +1,8 @@
 use super::*;
 
-use wovocrypt::cipher::mode::ctr::{CtrEncryptor, CtrDecryptor};
+use wovocrypt::cipher::BlockCipher;
+use wovocrypt::cipher::mode::ctr::{CtrEncryptor, CtrDecryptor, CtrEndian, CtrNonce};
+use wovocrypt::error::SymcError;
 
 const KEY_128: [u8; 16] = [
     0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
@@ -80,6 +82,88 @@ const AES256_CTR_GOLD_DATA: &[SymcGoldData] = &[
     },
 ];
 
+// NIST SP 800-38A, Appendix F.5, with the full initial counter block
+const ICB: [u8; 16] = [
+    0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb, 0xfc, 0xfd, 0xfe, 0xff,
+];
+
+const AES128_CTR_F5_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+        ],
+        ciphertext: &[
+            0x87, 0x4d, 0x61, 0x91, 0xb6, 0x20, 0xe3, 0x26, 0x1b, 0xef, 0x68, 0x64, 0x99, 0x0d, 0xb6, 0xce,
+            0x98, 0x06, 0xf6, 0x6b, 0x79, 0x70, 0xfd, 0xff, 0x86, 0x17, 0x18, 0x7b, 0xb9, 0xff, 0xfd, 0xff,
+            0x5a, 0xe4, 0xdf, 0x3e, 0xdb, 0xd5, 0xd3, 0x5e, 0x5b, 0x4f, 0x09, 0x02, 0x0d, 0xb0, 0x3e, 0xab,
+            0x1e, 0x03, 0x1d, 0xda, 0x2f, 0xbe, 0x03, 0xd1, 0x79, 0x21, 0x70, 0xa0, 0xf3, 0x00, 0x9c, 0xee,
+        ],
+    },
+];
+
+const AES192_CTR_F5_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+        ],
+        ciphertext: &[
+            0x1a, 0xbc, 0x93, 0x24, 0x17, 0x52, 0x1c, 0xa2, 0x4f, 0x2b, 0x04, 0x59, 0xfe, 0x7e, 0x6e, 0x0b,
+            0x09, 0x03, 0x39, 0xec, 0x0a, 0xa6, 0xfa, 0xef, 0xd5, 0xcc, 0xc2, 0xc6, 0xf4, 0xce, 0x8e, 0x94,
+            0x1e, 0x36, 0xb2, 0x6b, 0xd1, 0xeb, 0xc6, 0x70, 0xd1, 0xbd, 0x1d, 0x66, 0x56, 0x20, 0xab, 0xf7,
+            0x4f, 0x78, 0xa7, 0xf6, 0xd2, 0x98, 0x09, 0x58, 0x5a, 0x97, 0xda, 0xec, 0x58, 0xc6, 0xb0, 0x50,
+        ],
+    },
+];
+
+const AES256_CTR_F5_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+        ],
+        ciphertext: &[
+            0x60, 0x1e, 0xc3, 0x13, 0x77, 0x57, 0x89, 0xa5, 0xb7, 0xa7, 0xf5, 0x04, 0xbb, 0xf3, 0xd2, 0x28,
+            0xf4, 0x43, 0xe3, 0xca, 0x4d, 0x62, 0xb5, 0x9a, 0xca, 0x84, 0xe9, 0x90, 0xca, 0xca, 0xf5, 0xc5,
+            0x2b, 0x09, 0x30, 0xda, 0xa2, 0x3d, 0xe9, 0x4c, 0xe8, 0x70, 0x17, 0xba, 0x2d, 0x84, 0x98, 0x8d,
+            0xdf, 0xc9, 0xc5, 0x8d, 0xb6, 0x7a, 0xad, 0xa6, 0x13, 0xc2, 0xdd, 0x08, 0x45, 0x79, 0x41, 0xa6,
+        ],
+    },
+];
+
+// RFC 3686, test vector #2
+const AES128_CTR_RFC3686_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+            0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+        ],
+        ciphertext: &[
+            0x51, 0x04, 0xa1, 0x06, 0x16, 0x8a, 0x72, 0xd9, 0x79, 0x0d, 0x41, 0xee, 0x8e, 0xda, 0xd3, 0x88,
+            0xeb, 0x2e, 0x1e, 0xfc, 0x46, 0xda, 0x57, 0xc8, 0xfc, 0xe6, 0x30, 0xdf, 0x91, 0x41, 0xbe, 0x28,
+        ],
+    },
+];
+
+// WinZip AES layout: 64-bit little-endian counter starting at 1, zero nonce
+const AES256_CTR_WINZIP_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: b"The quick brown fox jumps over the lazy dog",
+        ciphertext: &[
+            0x37, 0xd7, 0x2a, 0xce, 0xb7, 0x6c, 0xa6, 0xfe, 0xba, 0x60, 0x13, 0x02, 0x38, 0x29, 0x4b, 0x6c,
+            0x6c, 0x00, 0x94, 0xbd, 0x7c, 0x99, 0x2c, 0x1b, 0xa6, 0x76, 0xfe, 0x06, 0x48, 0x70, 0xdb, 0xa9,
+            0x6e, 0xce, 0xea, 0x34, 0xf4, 0xac, 0x98, 0x31, 0x3a, 0x2a, 0x42,
+        ],
+    },
+];
+
 #[test]
 fn aes128_ctr_roundtrip() {
     for data in AES128_CTR_GOLD_DATA {
@@ -192,4 +276,62 @@ fn test_cbc_stress() {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+fn ctr_check<C: BlockCipher>(key: &C::Key, nonce: &CtrNonce, gold: &[SymcGoldData]) {
+    for data in gold {
+        let mut out_ciphertext = [0u8; 64];
+        let encrypt_written = CtrEncryptor::<C>::encrypt(key, nonce, data.plaintext, &mut out_ciphertext).expect("Encryption failed");
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 64];
+        let decrypt_written = CtrDecryptor::<C>::decrypt(key, nonce, data.ciphertext, &mut out_plaintext).expect("Decryption failed");
+        assert_eq!(decrypt_written, data.plaintext.len());
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+    }
+}
+
+#[test]
+fn aes_ctr_full_initial_counter_block() {
+    // the vectors never carry past the low two bytes, so any counter width from 2 up agrees
+    for counter_size in [2, 4, 8, 16] {
+        let nonce = CtrNonce::from_counter_block(&ICB, counter_size, CtrEndian::Big).unwrap();
+        ctr_check::<Aes128>(&aes128_key(&KEY_128), &nonce, AES128_CTR_F5_GOLD_DATA);
+        ctr_check::<Aes192>(&aes192_key(&KEY_192), &nonce, AES192_CTR_F5_GOLD_DATA);
+        ctr_check::<Aes256>(&aes256_key(&KEY_256), &nonce, AES256_CTR_F5_GOLD_DATA);
+    }
+}
+
+#[test]
+fn aes128_ctr_rfc3686() {
+    // nonce || IV with a 32-bit counter starting at 1
+    let key = aes128_key(&[
+        0x7e, 0x24, 0x06, 0x78, 0x17, 0xfa, 0xe0, 0xd7, 0x43, 0xd6, 0xce, 0x1f, 0x32, 0x53, 0x91, 0x63,
+    ]);
+    let nonce = CtrNonce::new(&[
+        0x00, 0x6c, 0xb6, 0xdb, 0xc0, 0x54, 0x3b, 0x59, 0xda, 0x48, 0xd9, 0x0b,
+    ], 1, CtrEndian::Big).unwrap();
+    ctr_check::<Aes128>(&key, &nonce, AES128_CTR_RFC3686_GOLD_DATA);
+}
+
+#[test]
+fn aes256_ctr_little_endian_counter() {
+    let nonce = CtrNonce::new(&[0u8; 8], 1, CtrEndian::Little).unwrap();
+    ctr_check::<Aes256>(&aes256_key(&KEY_256), &nonce, AES256_CTR_WINZIP_GOLD_DATA);
+}
+
+#[test]
+fn test_ctr_counter_overflow() {
+    // 32-bit counter one block from the end, the default layout no longer wraps
+    let nonce = CtrNonce::new(&IV, u32::MAX as u128, CtrEndian::Big).unwrap();
+    let mut out_ciphertext = [0u8; 32];
+
+    let mut encryptor = CtrEncryptor::<Aes128>::new(&KEY_128.into(), &nonce);
+    assert_eq!(encryptor.update(&[0u8; 17], &mut out_ciphertext), Ok(16));
+    assert_eq!(encryptor.finalize(&mut out_ciphertext[16..]), Err(SymcError::InvalidInputLength));
+
+    let mut decryptor = CtrDecryptor::<Aes128>::new(&KEY_128.into(), &nonce);
+    assert_eq!(decryptor.update(&[0u8; 32], &mut out_ciphertext), Err(SymcError::InvalidInputLength));
+    assert_eq!(decryptor.update(&[0u8; 16], &mut out_ciphertext), Ok(16));
+}
@@ -0,0 +1,198 @@
+use super::*;
+
+use wovocrypt::cipher::mode::ecb::{EcbEncryptor, EcbDecryptor};
+use wovocrypt::padding::*;
+
+const KEY_128: [u8; 16] = [
+    0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+];
+const KEY_192: [u8; 24] = [
+    0x8e, 0x73, 0xb0, 0xf7, 0xda, 0x0e, 0x64, 0x52, 0xc8, 0x10, 0xf3, 0x2b,
+    0x80, 0x90, 0x79, 0xe5, 0x62, 0xf8, 0xea, 0xd2, 0x52, 0x2c, 0x6b, 0x7b,
+];
+const KEY_256: [u8; 32] = [
+    0x60, 0x3d, 0xeb, 0x10, 0x15, 0xca, 0x71, 0xbe, 0x2b, 0x73, 0xae, 0xf0, 0x85, 0x7d, 0x77, 0x81,
+    0x1f, 0x35, 0x2c, 0x07, 0x3b, 0x61, 0x08, 0xd7, 0x2d, 0x98, 0x10, 0xa3, 0x09, 0x14, 0xdf, 0xf4,
+];
+
+// NIST SP 800-38A, Appendix F.1
+const AES128_ECB_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+        ],
+        ciphertext: &[
+            0x3a, 0xd7, 0x7b, 0xb4, 0x0d, 0x7a, 0x36, 0x60, 0xa8, 0x9e, 0xca, 0xf3, 0x24, 0x66, 0xef, 0x97,
+            0xf5, 0xd3, 0xd5, 0x85, 0x03, 0xb9, 0x69, 0x9d, 0xe7, 0x85, 0x89, 0x5a, 0x96, 0xfd, 0xba, 0xaf,
+            0x43, 0xb1, 0xcd, 0x7f, 0x59, 0x8e, 0xce, 0x23, 0x88, 0x1b, 0x00, 0xe3, 0xed, 0x03, 0x06, 0x88,
+            0x7b, 0x0c, 0x78, 0x5e, 0x27, 0xe8, 0xad, 0x3f, 0x82, 0x23, 0x20, 0x71, 0x04, 0x72, 0x5d, 0xd4,
+        ],
+    },
+];
+
+const AES128_ECB_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: b"The quick brown fox jumps over the lazy dog",
+        ciphertext: &[
+            0x16, 0xfa, 0x65, 0x87, 0x31, 0x00, 0x2a, 0xd6, 0xe3, 0x4a, 0x2f, 0xa0, 0x0f, 0x29, 0x0d, 0x9f,
+            0x97, 0x4f, 0x7b, 0xac, 0x10, 0x45, 0x57, 0x4b, 0x74, 0xc2, 0x04, 0x9e, 0x65, 0xd2, 0xa8, 0x89,
+            0x4a, 0x6b, 0x61, 0x17, 0x51, 0x2f, 0x17, 0xc8, 0x80, 0xde, 0x68, 0xa9, 0xe1, 0x00, 0x3a, 0x74,
+        ],
+    },
+];
+
+const AES192_ECB_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+        ],
+        ciphertext: &[
+            0xbd, 0x33, 0x4f, 0x1d, 0x6e, 0x45, 0xf2, 0x5f, 0xf7, 0x12, 0xa2, 0x14, 0x57, 0x1f, 0xa5, 0xcc,
+            0x97, 0x41, 0x04, 0x84, 0x6d, 0x0a, 0xd3, 0xad, 0x77, 0x34, 0xec, 0xb3, 0xec, 0xee, 0x4e, 0xef,
+            0xef, 0x7a, 0xfd, 0x22, 0x70, 0xe2, 0xe6, 0x0a, 0xdc, 0xe0, 0xba, 0x2f, 0xac, 0xe6, 0x44, 0x4e,
+            0x9a, 0x4b, 0x41, 0xba, 0x73, 0x8d, 0x6c, 0x72, 0xfb, 0x16, 0x69, 0x16, 0x03, 0xc1, 0x8e, 0x0e,
+        ],
+    },
+];
+
+const AES192_ECB_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: b"The quick brown fox jumps over the lazy dog",
+        ciphertext: &[
+            0x7e, 0xdc, 0x21, 0xb3, 0xdd, 0x38, 0xf5, 0x79, 0x18, 0x90, 0x57, 0x8e, 0xd7, 0x76, 0x25, 0x6a,
+            0xb4, 0xda, 0xe8, 0x51, 0x12, 0x97, 0xba, 0x20, 0x53, 0x72, 0x8b, 0x7f, 0xd5, 0xb7, 0x55, 0x08,
+            0xa8, 0x4c, 0x08, 0xbd, 0xae, 0x74, 0x35, 0xf5, 0x88, 0xbd, 0x58, 0x40, 0x38, 0x6f, 0x45, 0x8d,
+        ],
+    },
+];
+
+const AES256_ECB_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+            0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+            0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+            0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+        ],
+        ciphertext: &[
+            0xf3, 0xee, 0xd1, 0xbd, 0xb5, 0xd2, 0xa0, 0x3c, 0x06, 0x4b, 0x5a, 0x7e, 0x3d, 0xb1, 0x81, 0xf8,
+            0x59, 0x1c, 0xcb, 0x10, 0xd4, 0x10, 0xed, 0x26, 0xdc, 0x5b, 0xa7, 0x4a, 0x31, 0x36, 0x28, 0x70,
+            0xb6, 0xed, 0x21, 0xb9, 0x9c, 0xa6, 0xf4, 0xf9, 0xf1, 0x53, 0xe7, 0xb1, 0xbe, 0xaf, 0xed, 0x1d,
+            0x23, 0x30, 0x4b, 0x7a, 0x39, 0xf9, 0xf3, 0xff, 0x06, 0x7d, 0x8d, 0x8f, 0x9e, 0x24, 0xec, 0xc7,
+        ],
+    },
+];
+
+const AES256_ECB_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: b"The quick brown fox jumps over the lazy dog",
+        ciphertext: &[
+            0x98, 0xd9, 0xed, 0x09, 0xc5, 0x2c, 0xc5, 0x31, 0xd2, 0x7c, 0x67, 0x30, 0xfd, 0x9c, 0xea, 0xc0,
+            0xff, 0xa2, 0x2b, 0x5d, 0x57, 0xb2, 0xab, 0xbe, 0xa6, 0x11, 0xc8, 0x12, 0x11, 0xdf, 0x83, 0x0f,
+            0x64, 0xe9, 0xd2, 0xc1, 0x0a, 0x8d, 0x00, 0xbe, 0x70, 0x97, 0x20, 0x5e, 0xd6, 0x77, 0xef, 0x0d,
+        ],
+    },
+];
+
+fn ecb_roundtrip<E, D>(key: &E::Key, dkey: &D::Key, gold: &[SymcGoldData])
+where E: SymcEncryptor<IV = [u8; 0]>, D: SymcDecryptor<IV = [u8; 0]> {
+    for data in gold {
+        let mut out_ciphertext = [0u8; 64];
+        let mut encryptor = E::new(key, &[]);
+
+        let mut encrypt_written = encryptor.update(data.plaintext, &mut out_ciphertext).expect("Encryption update failed");
+        encrypt_written += encryptor.finalize(&mut out_ciphertext[encrypt_written..]).expect("Encryption finalize failed");
+
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 64];
+        let mut decryptor = D::new(dkey, &[]);
+
+        let mut decrypt_written = decryptor.update(&out_ciphertext[..encrypt_written], &mut out_plaintext).expect("Decryption update failed");
+        decrypt_written += decryptor.finalize(&mut out_plaintext[decrypt_written..]).expect("Decryption finalize failed");
+
+        assert_eq!(decrypt_written, data.plaintext.len());
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+    }
+}
+
+#[test]
+fn aes128_ecb_nopadding_roundtrip() {
+    let key = aes128_key(&KEY_128);
+    ecb_roundtrip::<EcbEncryptor<Aes128, NoPadding>, EcbDecryptor<Aes128, NoPadding>>(&key, &key, AES128_ECB_NOPADDING_GOLD_DATA);
+}
+
+#[test]
+fn aes128_ecb_pkcs7_roundtrip() {
+    let key = aes128_key(&KEY_128);
+    ecb_roundtrip::<EcbEncryptor<Aes128, Pkcs7>, EcbDecryptor<Aes128, Pkcs7>>(&key, &key, AES128_ECB_PKCS7_GOLD_DATA);
+}
+
+#[test]
+fn aes192_ecb_nopadding_roundtrip() {
+    let key = aes192_key(&KEY_192);
+    ecb_roundtrip::<EcbEncryptor<Aes192, NoPadding>, EcbDecryptor<Aes192, NoPadding>>(&key, &key, AES192_ECB_NOPADDING_GOLD_DATA);
+}
+
+#[test]
+fn aes192_ecb_pkcs7_roundtrip() {
+    let key = aes192_key(&KEY_192);
+    ecb_roundtrip::<EcbEncryptor<Aes192, Pkcs7>, EcbDecryptor<Aes192, Pkcs7>>(&key, &key, AES192_ECB_PKCS7_GOLD_DATA);
+}
+
+#[test]
+fn aes256_ecb_nopadding_roundtrip() {
+    let key = aes256_key(&KEY_256);
+    ecb_roundtrip::<EcbEncryptor<Aes256, NoPadding>, EcbDecryptor<Aes256, NoPadding>>(&key, &key, AES256_ECB_NOPADDING_GOLD_DATA);
+}
+
+#[test]
+fn aes256_ecb_pkcs7_roundtrip() {
+    let key = aes256_key(&KEY_256);
+    ecb_roundtrip::<EcbEncryptor<Aes256, Pkcs7>, EcbDecryptor<Aes256, Pkcs7>>(&key, &key, AES256_ECB_PKCS7_GOLD_DATA);
+}
+
+#[test]
+fn test_ecb_nopadding_rejects_partial_block() {
+    let key = aes128_key(&KEY_128);
+    let mut out_ciphertext = [0u8; 32];
+    let result = EcbEncryptor::<Aes128, NoPadding>::encrypt(&key, &[], b"not a block", &mut out_ciphertext);
+    assert_eq!(result, Err(wovocrypt::error::SymcError::InvalidInputLength));
+}
+
+#[test]
+fn test_ecb_stress_pkcs7() {
+    let key = aes128_key(&KEY_128);
+    let msg = b"The quick brown fox jumps over the lazy dog";
+
+    let mut expected = [0u8; 64];
+    let expected_len = EcbEncryptor::<Aes128, Pkcs7>::encrypt(&key, &[], msg, &mut expected).expect("one-shot encrypt");
+
+    // feed the message in every chunk size, the output must not depend on the split
+    for chunk_size in 1..=msg.len() {
+        let mut out_ciphertext = [0u8; 64];
+        let mut encryptor = EcbEncryptor::<Aes128, Pkcs7>::new(&key, &[]);
+        let mut written = 0;
+        for chunk in msg.chunks(chunk_size) {
+            written += encryptor.update(chunk, &mut out_ciphertext[written..]).expect("enc update");
+        }
+        written += encryptor.finalize(&mut out_ciphertext[written..]).expect("enc finalize");
+        assert_eq!(&out_ciphertext[..written], &expected[..expected_len]);
+
+        let mut out_plaintext = [0u8; 64];
+        let mut decryptor = EcbDecryptor::<Aes128, Pkcs7>::new(&key, &[]);
+        let mut dwritten = 0;
+        for chunk in out_ciphertext[..written].chunks(chunk_size) {
+            dwritten += decryptor.update(chunk, &mut out_plaintext[dwritten..]).expect("dec update");
+        }
+        dwritten += decryptor.finalize(&mut out_plaintext[dwritten..]).expect("dec finalize");
+        assert_eq!(&out_plaintext[..dwritten], msg);
+    }
+}
@@ -0,0 +1,174 @@
+use super::*;
+
+use wovocrypt::cipher::BlockCipher;
+use wovocrypt::cipher::mode::cbc::{CbcEncryptor, CbcDecryptor};
+use wovocrypt::cipher::mode::ctr::{CtrEncryptor, CtrDecryptor};
+use wovocrypt::cipher::kuznyechik::{Kuznyechik, KuznyechikKey};
+use wovocrypt::padding::*;
+
+const KEY: [u8; 32] = [
+    0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff, 0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77,
+    0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef,
+];
+const IV: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const NONCE: [u8; 12] = [
+    0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb,
+];
+
+// GOST R 34.13-2015, Appendix A.1
+const PLAINTEXT: &[u8] = &[
+    0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x00, 0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88,
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a,
+    0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00,
+    0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xee, 0xff, 0x0a, 0x00, 0x11,
+];
+
+const KUZNYECHIK_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x00, 0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88,
+        ],
+        ciphertext: &[
+            0x4d, 0xde, 0xea, 0xcd, 0xbe, 0x5f, 0x61, 0x38, 0x68, 0x0a, 0x77, 0x7c, 0x52, 0x77, 0x38, 0xb0,
+        ],
+    },
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x4d, 0xde, 0xea, 0xcd, 0xbe, 0x5f, 0x61, 0x38, 0x68, 0x0a, 0x77, 0x7c, 0x52, 0x77, 0x38, 0xb0,
+            0xaf, 0x5a, 0xa2, 0xb8, 0x1e, 0xd5, 0x2d, 0xdc, 0x42, 0x23, 0x8a, 0xc8, 0xf8, 0x9d, 0x8b, 0xc6,
+            0x97, 0xea, 0xf0, 0x65, 0xa1, 0x41, 0xe6, 0x67, 0xf5, 0x57, 0x06, 0x11, 0x42, 0xe9, 0x47, 0xe5,
+            0xc7, 0xfd, 0x6c, 0xa0, 0x93, 0x58, 0x53, 0x9b, 0xaa, 0x12, 0xaf, 0xd4, 0x5a, 0xc4, 0x39, 0xcf,
+        ],
+    },
+];
+
+const KUZNYECHIK_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: b"The quick brown fox jumps over the lazy dog",
+        ciphertext: &[
+            0xb0, 0xc6, 0xf5, 0x9e, 0xae, 0x31, 0x9e, 0x44, 0x55, 0x66, 0xba, 0xd2, 0x32, 0x23, 0x85, 0xf6,
+            0x63, 0x3e, 0x79, 0x10, 0xdd, 0x4b, 0x73, 0x30, 0x5d, 0xa7, 0x3d, 0x81, 0x55, 0xbd, 0xe5, 0x8f,
+            0x94, 0x90, 0xfb, 0x23, 0x47, 0xe6, 0x76, 0x58, 0x5e, 0x84, 0x62, 0xef, 0x9e, 0x7d, 0x7d, 0x19,
+        ],
+    },
+];
+
+const KUZNYECHIK_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x00, 0xff, 0xee, 0xdd, 0xcc, 0xbb, 0xaa, 0x99, 0x88,
+        ],
+        ciphertext: &[
+            0x7d, 0x9c, 0x41, 0x25, 0xb9, 0x82, 0x32, 0x5d, 0x7f, 0x05, 0x8e, 0x89, 0x81, 0x36, 0xd0, 0xde,
+        ],
+    },
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x7d, 0x9c, 0x41, 0x25, 0xb9, 0x82, 0x32, 0x5d, 0x7f, 0x05, 0x8e, 0x89, 0x81, 0x36, 0xd0, 0xde,
+            0xfb, 0x47, 0x76, 0x8e, 0xb4, 0x39, 0x8f, 0xa3, 0xa2, 0x88, 0x6f, 0x8d, 0x61, 0x1b, 0xb7, 0xec,
+            0xe9, 0xd3, 0x6d, 0xaf, 0x8f, 0x89, 0xc2, 0x21, 0x10, 0xa4, 0x62, 0xe0, 0x9c, 0x70, 0x30, 0x4b,
+            0x23, 0x73, 0xe6, 0xb0, 0xbf, 0x36, 0x2c, 0x2c, 0x79, 0x7d, 0xf5, 0xa9, 0x06, 0xec, 0x09, 0x6a,
+        ],
+    },
+    SymcGoldData {
+        plaintext: b"The quick brown fox jumps over the lazy dog",
+        ciphertext: &[
+            0x38, 0xd6, 0x17, 0x41, 0x9d, 0x91, 0x2c, 0x3e, 0xeb, 0xcb, 0x31, 0x37, 0x55, 0xeb, 0x27, 0x76,
+            0x9d, 0x39, 0x2c, 0x9d, 0x9a, 0x19, 0x84, 0xa4, 0x59, 0x31, 0xaa, 0x40, 0xc8, 0x87, 0x68, 0x92,
+            0x90, 0x94, 0x7e, 0x87, 0xbb, 0x95, 0xcc, 0x89, 0xed, 0x61, 0xbe,
+        ],
+    },
+];
+
+/// RFC 7801, Section 5.5 and GOST R 34.13-2015, Appendix A.1.1
+#[test]
+fn kuznyechik_ecb_blocks() {
+    let expected = [
+        "7f679d90bebc24305a468d42b9d4edcd",
+        "b429912c6e0032f9285452d76718d08b",
+        "f0ca33549d247ceef3f5a5313bd4b157",
+        "d0b09ccde830b9eb3a02c4c5aa8ada98",
+    ];
+
+    let cipher = Kuznyechik::new(&KuznyechikKey::from(KEY));
+    for (chunk, expected) in PLAINTEXT.chunks_exact(16).zip(expected) {
+        let mut block: [u8; 16] = chunk.try_into().unwrap();
+        cipher.encrypt_block(&mut block);
+        assert_eq!(hex::encode(block), expected);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, chunk);
+    }
+}
+
+#[test]
+fn kuznyechik_cbc_nopadding_roundtrip() {
+    for data in KUZNYECHIK_CBC_NOPADDING_GOLD_DATA {
+        let mut out_ciphertext = [0u8; 64];
+        let mut encryptor = CbcEncryptor::<Kuznyechik, NoPadding>::new(&KEY.into(), &IV);
+
+        let mut encrypt_written = encryptor.update(data.plaintext, &mut out_ciphertext).expect("Encryption update failed");
+        encrypt_written += encryptor.finalize(&mut out_ciphertext[encrypt_written..data.ciphertext.len()]).expect("Encryption finalize failed");
+
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 64];
+        let mut decryptor = CbcDecryptor::<Kuznyechik, NoPadding>::new(&KEY.into(), &IV);
+
+        let mut decrypt_written = decryptor.update(&out_ciphertext[..encrypt_written], &mut out_plaintext).expect("Decryption update failed");
+        decrypt_written += decryptor.finalize(&mut out_plaintext[decrypt_written..data.plaintext.len()]).expect("Decryption finalize failed");
+
+        assert_eq!(decrypt_written, data.plaintext.len());
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+    }
+}
+
+#[test]
+fn kuznyechik_cbc_pkcs7_roundtrip() {
+    for data in KUZNYECHIK_CBC_PKCS7_GOLD_DATA {
+        let mut out_ciphertext = [0u8; 64];
+        let mut encryptor = CbcEncryptor::<Kuznyechik, Pkcs7>::new(&KEY.into(), &IV);
+
+        let mut encrypt_written = encryptor.update(data.plaintext, &mut out_ciphertext).expect("Encryption update failed");
+        encrypt_written += encryptor.finalize(&mut out_ciphertext[encrypt_written..]).expect("Encryption finalize failed");
+
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 64];
+        let mut decryptor = CbcDecryptor::<Kuznyechik, Pkcs7>::new(&KEY.into(), &IV);
+
+        let mut decrypt_written = decryptor.update(&out_ciphertext[..encrypt_written], &mut out_plaintext).expect("Decryption update failed");
+        decrypt_written += decryptor.finalize(&mut out_plaintext[decrypt_written..data.plaintext.len()]).expect("Decryption finalize failed");
+
+        assert_eq!(decrypt_written, data.plaintext.len());
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+    }
+}
+
+#[test]
+fn kuznyechik_ctr_roundtrip() {
+    for data in KUZNYECHIK_CTR_GOLD_DATA {
+        let mut out_ciphertext = [0u8; 64];
+        let mut encryptor = CtrEncryptor::<Kuznyechik>::new(&KEY.into(), &NONCE.into());
+
+        let mut encrypt_written = encryptor.update(data.plaintext, &mut out_ciphertext).expect("Encryption update failed");
+        encrypt_written += encryptor.finalize(&mut out_ciphertext[encrypt_written..data.ciphertext.len()]).expect("Encryption finalize failed");
+
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 64];
+        let mut decryptor = CtrDecryptor::<Kuznyechik>::new(&KEY.into(), &NONCE.into());
+
+        let mut decrypt_written = decryptor.update(&out_ciphertext[..encrypt_written], &mut out_plaintext).expect("Decryption update failed");
+        decrypt_written += decryptor.finalize(&mut out_plaintext[decrypt_written..data.plaintext.len()]).expect("Decryption finalize failed");
+
+        assert_eq!(decrypt_written, data.plaintext.len());
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+    }
+}
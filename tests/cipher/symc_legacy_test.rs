@@ -0,0 +1,275 @@
+use super::*;
+
+use wovocrypt::cipher::BlockCipher;
+use wovocrypt::cipher::blowfish::{Blowfish, BlowfishKey};
+use wovocrypt::cipher::mode::cbc::{CbcEncryptor, CbcDecryptor};
+use wovocrypt::cipher::mode::ctr::{CtrEncryptor, CtrDecryptor, CtrNonce};
+use wovocrypt::cipher::tdea::{Tdea2, Tdea2Key, Tdea3, Tdea3Key};
+use wovocrypt::padding::*;
+
+// SP 800-67 Rev. 2 example keys, the two-key variant uses the first 16 bytes
+const TDEA_KEY: [u8; 24] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01,
+    0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01, 0x23,
+];
+const BLOWFISH_KEY: [u8; 16] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87,
+];
+const IV: [u8; 8] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+// 64-bit blocks leave 4 bytes of nonce ahead of the 32-bit counter
+const NONCE: [u8; 4] = [0xf0, 0xf1, 0xf2, 0xf3];
+
+// expected values from the Python cryptography package
+const PLAINTEXT: &[u8] = &[
+    0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+    0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+];
+const FOX: &[u8] = b"The quick brown fox jumps over the lazy dog";
+const ECB_PLAINTEXT: &[u8] = b"The qufck brown fox jump";
+
+const TDEA2_ECB_CIPHERTEXT: &[u8] = &[
+    0xc4, 0x48, 0x62, 0xf7, 0x0c, 0xf2, 0xfb, 0xdc, 0x90, 0x77, 0xd0, 0x90, 0x9f, 0xa9, 0x1b, 0x88,
+    0x4c, 0xab, 0xd6, 0x1f, 0xc5, 0x8e, 0x0c, 0xbb,
+];
+
+const TDEA2_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0xeb, 0xbb, 0xf7, 0x74, 0xad, 0xb4, 0x8e, 0xb5, 0x47, 0x10, 0x16, 0xd5, 0xff, 0x74, 0x52, 0x1d,
+            0x46, 0x21, 0x1a, 0xa1, 0x7a, 0xe0, 0x9c, 0xca, 0x0f, 0x97, 0xc8, 0xf2, 0x8c, 0x91, 0x3c, 0x1f,
+        ],
+    },
+];
+
+const TDEA2_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x00, 0x7b, 0x2d, 0x14, 0x01, 0x55, 0x7e, 0xc3, 0x01, 0xce, 0xe0, 0x32, 0x06, 0xba, 0x3d, 0xf3,
+            0x1b, 0xbc, 0xb2, 0x9e, 0xd9, 0x50, 0xf3, 0xe5, 0x5f, 0x98, 0x96, 0x02, 0x48, 0xbc, 0x5d, 0xfb,
+            0x01, 0x4d, 0xdb, 0xef, 0x41, 0xb9, 0x31, 0x43, 0x8b, 0x18, 0xa7, 0x04, 0x12, 0xed, 0x5f, 0x33,
+        ],
+    },
+];
+
+const TDEA2_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x1b, 0x1d, 0x3d, 0x2e, 0x5c, 0x9f, 0x5a, 0xf7, 0x60, 0xa9, 0x76, 0x72, 0x0d, 0x37, 0x1a, 0xdd,
+            0x4b, 0xbb, 0xce, 0x78, 0xe0, 0xba, 0xe5, 0x82, 0xc2, 0x95, 0xb5, 0xe6, 0x39, 0x65, 0x3f, 0x83,
+            0xc8, 0x0f, 0xdd, 0x28, 0x69, 0x5a, 0x7e, 0x16, 0x3f, 0x5a, 0xa3,
+        ],
+    },
+];
+
+// SP 800-67 Rev. 2, Appendix B
+const TDEA3_ECB_CIPHERTEXT: &[u8] = &[
+    0xa8, 0x26, 0xfd, 0x8c, 0xe5, 0x3b, 0x85, 0x5f, 0xcc, 0xe2, 0x1c, 0x81, 0x12, 0x25, 0x6f, 0xe6,
+    0x68, 0xd5, 0xc0, 0x5d, 0xd9, 0xb6, 0xb9, 0x00,
+];
+
+const TDEA3_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0xdf, 0x4f, 0xb4, 0x8a, 0x5c, 0x34, 0x14, 0xfa, 0x34, 0x0a, 0x15, 0x53, 0xef, 0xae, 0x84, 0x31,
+            0x7b, 0x4c, 0x6a, 0xab, 0x88, 0x45, 0xfb, 0x92, 0x47, 0xee, 0x5e, 0x08, 0x51, 0x4d, 0xd2, 0xbc,
+        ],
+    },
+];
+
+const TDEA3_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x29, 0xb0, 0x1b, 0x01, 0x1b, 0x9e, 0xbb, 0x6f, 0x10, 0x30, 0x8a, 0x42, 0x93, 0x82, 0x79, 0x06,
+            0x87, 0x82, 0xe8, 0xbe, 0xc9, 0x7f, 0xe0, 0x3f, 0x62, 0xf7, 0xa1, 0xf4, 0x80, 0x71, 0x00, 0x59,
+            0xcb, 0xe4, 0xc5, 0x50, 0x6d, 0x08, 0xf4, 0x8a, 0xdf, 0x7e, 0x3e, 0x55, 0x8a, 0x68, 0x60, 0xa3,
+        ],
+    },
+];
+
+const TDEA3_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x5b, 0xb3, 0x27, 0x18, 0xca, 0x72, 0x72, 0x5b, 0xb7, 0x01, 0x2c, 0xe4, 0xae, 0x67, 0x95, 0xba,
+            0xc8, 0x50, 0x1a, 0x52, 0xee, 0x85, 0xe2, 0x53, 0xc1, 0x3b, 0xe4, 0xcb, 0xe2, 0xb5, 0xfe, 0xac,
+            0x90, 0x0f, 0x38, 0x99, 0x82, 0xa4, 0x79, 0x97, 0xdf, 0xc9, 0xc9,
+        ],
+    },
+];
+
+const BLOWFISH_ECB_CIPHERTEXT: &[u8] = &[
+    0xd4, 0x65, 0x22, 0xf9, 0x09, 0x66, 0x34, 0xd4, 0xdf, 0x4f, 0xdc, 0x56, 0xcb, 0x50, 0x94, 0xcc,
+    0x7f, 0x3d, 0x17, 0xd8, 0x39, 0x50, 0x0b, 0x66,
+];
+
+const BLOWFISH_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0xd2, 0x87, 0x5f, 0xc0, 0x23, 0xe7, 0xf9, 0x15, 0x32, 0x56, 0x89, 0x34, 0x42, 0x9b, 0x80, 0xd2,
+            0x1a, 0xb6, 0xbf, 0x84, 0xde, 0x6a, 0xd5, 0xe0, 0x66, 0x8b, 0x88, 0x94, 0x1e, 0xd2, 0x43, 0xa8,
+        ],
+    },
+];
+
+const BLOWFISH_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x00, 0x63, 0x17, 0xc9, 0x24, 0xc1, 0x53, 0xbb, 0x1b, 0x2f, 0x0a, 0x68, 0x85, 0x2c, 0x67, 0xfc,
+            0x14, 0x30, 0xae, 0x2a, 0x89, 0x95, 0xe7, 0x2a, 0x66, 0x96, 0xd4, 0xb0, 0xa5, 0x19, 0x1d, 0xb7,
+            0x58, 0x12, 0xdf, 0x03, 0x11, 0x07, 0x89, 0x98, 0x98, 0xcd, 0x2d, 0x5d, 0x68, 0x24, 0x51, 0xcd,
+        ],
+    },
+];
+
+const BLOWFISH_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x36, 0x9f, 0x81, 0x3d, 0x10, 0xa9, 0x83, 0x9e, 0x7f, 0xd5, 0x6d, 0x66, 0xd9, 0x7d, 0xe0, 0x93,
+            0x0e, 0xe3, 0xf2, 0x2f, 0x05, 0x91, 0xb5, 0xf7, 0x31, 0x90, 0x7a, 0x20, 0xe9, 0xa5, 0x73, 0x2d,
+            0x4d, 0x77, 0x0c, 0x13, 0xc1, 0xf3, 0x4d, 0x0c, 0x00, 0xb8, 0x31,
+        ],
+    },
+];
+
+fn tdea2_key() -> Tdea2Key {
+    Tdea2Key::from(<[u8; 16]>::try_from(&TDEA_KEY[..16]).unwrap())
+}
+
+fn tdea3_key() -> Tdea3Key {
+    Tdea3Key::from(TDEA_KEY)
+}
+
+fn blowfish_key() -> BlowfishKey {
+    BlowfishKey::from(BLOWFISH_KEY)
+}
+
+fn ecb<C: BlockCipher<Block = [u8; 8]>>(key: &C::Key, plaintext: &[u8], ciphertext: &[u8]) {
+    let cipher = C::new(key);
+    for (p, c) in plaintext.chunks_exact(8).zip(ciphertext.chunks_exact(8)) {
+        let mut block: [u8; 8] = p.try_into().unwrap();
+        cipher.encrypt_block(&mut block);
+        assert_eq!(block, c);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, p);
+    }
+}
+
+/// Feeds the ciphertext in uneven pieces, so the decryptor keeps tails shorter and longer than a block.
+fn chunked_decrypt<D: SymcDecryptor>(key: &D::Key, iv: &D::IV, data: &SymcGoldData) {
+    for chunk_size in 1..=data.ciphertext.len() {
+        let mut out_plaintext = [0u8; 64];
+        let mut decryptor = D::new(key, iv);
+
+        let mut written = 0;
+        for chunk in data.ciphertext.chunks(chunk_size) {
+            written += decryptor.update(chunk, &mut out_plaintext[written..]).expect("Decryption update failed");
+        }
+        written += decryptor.finalize(&mut out_plaintext[written..]).expect("Decryption finalize failed");
+
+        assert_eq!(&out_plaintext[..written], data.plaintext, "chunk size {}", chunk_size);
+    }
+}
+
+#[test]
+fn tdea_ecb_blocks() {
+    ecb::<Tdea2>(&tdea2_key(), ECB_PLAINTEXT, TDEA2_ECB_CIPHERTEXT);
+    ecb::<Tdea3>(&tdea3_key(), ECB_PLAINTEXT, TDEA3_ECB_CIPHERTEXT);
+}
+
+#[test]
+fn blowfish_ecb_blocks() {
+    ecb::<Blowfish>(&blowfish_key(), ECB_PLAINTEXT, BLOWFISH_ECB_CIPHERTEXT);
+}
+
+#[test]
+fn tdea2_cbc_nopadding_roundtrip() {
+    for data in TDEA2_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Tdea2, NoPadding>, CbcDecryptor<Tdea2, NoPadding>>(&tdea2_key(), &IV, data);
+    }
+}
+
+#[test]
+fn tdea2_cbc_pkcs7_roundtrip() {
+    for data in TDEA2_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Tdea2, Pkcs7>, CbcDecryptor<Tdea2, Pkcs7>>(&tdea2_key(), &IV, data);
+        chunked_decrypt::<CbcDecryptor<Tdea2, Pkcs7>>(&tdea2_key(), &IV, data);
+    }
+}
+
+#[test]
+fn tdea2_ctr_roundtrip() {
+    for data in TDEA2_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Tdea2>, CtrDecryptor<Tdea2>>(&tdea2_key(), &NONCE.into(), data);
+        chunked_decrypt::<CtrDecryptor<Tdea2>>(&tdea2_key(), &NONCE.into(), data);
+    }
+}
+
+#[test]
+fn tdea3_cbc_nopadding_roundtrip() {
+    for data in TDEA3_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Tdea3, NoPadding>, CbcDecryptor<Tdea3, NoPadding>>(&tdea3_key(), &IV, data);
+    }
+}
+
+#[test]
+fn tdea3_cbc_pkcs7_roundtrip() {
+    for data in TDEA3_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Tdea3, Pkcs7>, CbcDecryptor<Tdea3, Pkcs7>>(&tdea3_key(), &IV, data);
+        chunked_decrypt::<CbcDecryptor<Tdea3, Pkcs7>>(&tdea3_key(), &IV, data);
+    }
+}
+
+#[test]
+fn tdea3_ctr_roundtrip() {
+    for data in TDEA3_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Tdea3>, CtrDecryptor<Tdea3>>(&tdea3_key(), &NONCE.into(), data);
+        chunked_decrypt::<CtrDecryptor<Tdea3>>(&tdea3_key(), &NONCE.into(), data);
+    }
+}
+
+/// Encrypts `FOX` in one call.
+fn ctr_encrypt<C: BlockCipher>(key: &C::Key, nonce: &CtrNonce) -> [u8; 43] {
+    let mut ciphertext = [0u8; 43];
+    let mut encryptor = CtrEncryptor::<C>::new(key, nonce);
+    let written = encryptor.update(FOX, &mut ciphertext).expect("Encryption update failed");
+    encryptor.finalize(&mut ciphertext[written..]).expect("Encryption finalize failed");
+    ciphertext
+}
+
+#[test]
+fn ctr_default_nonce_on_64_bit_blocks() {
+    // the default nonce shrinks to the block, the same as an explicit 4 byte zero nonce
+    assert_eq!(ctr_encrypt::<Tdea3>(&tdea3_key(), &CtrNonce::default()), ctr_encrypt::<Tdea3>(&tdea3_key(), &[0u8; 4].into()));
+    assert_eq!(ctr_encrypt::<Blowfish>(&blowfish_key(), &CtrNonce::default()), ctr_encrypt::<Blowfish>(&blowfish_key(), &[0u8; 4].into()));
+}
+
+#[test]
+fn blowfish_cbc_nopadding_roundtrip() {
+    for data in BLOWFISH_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Blowfish, NoPadding>, CbcDecryptor<Blowfish, NoPadding>>(&blowfish_key(), &IV, data);
+    }
+}
+
+#[test]
+fn blowfish_cbc_pkcs7_roundtrip() {
+    for data in BLOWFISH_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Blowfish, Pkcs7>, CbcDecryptor<Blowfish, Pkcs7>>(&blowfish_key(), &IV, data);
+        chunked_decrypt::<CbcDecryptor<Blowfish, Pkcs7>>(&blowfish_key(), &IV, data);
+    }
+}
+
+#[test]
+fn blowfish_ctr_roundtrip() {
+    for data in BLOWFISH_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Blowfish>, CtrDecryptor<Blowfish>>(&blowfish_key(), &NONCE.into(), data);
+        chunked_decrypt::<CtrDecryptor<Blowfish>>(&blowfish_key(), &NONCE.into(), data);
+    }
+}
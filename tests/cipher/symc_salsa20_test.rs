@@ -0,0 +1,194 @@
+use wovocrypt::cipher::StreamCipher;
+use wovocrypt::cipher::salsa20::{Salsa20, Salsa20_12, Salsa20Key, Salsa20Nonce};
+#[cfg(feature = "xsalsa20")]
+use wovocrypt::cipher::salsa20::{XSalsa20, XSalsa20Nonce};
+use wovocrypt::error::SymcError;
+
+struct StreamGoldData {
+    key: &'static [u8],
+    nonce: &'static [u8],
+    counter: u64,
+    plaintext: &'static [u8],
+    ciphertext: &'static [u8],
+}
+
+const KEY: &[u8] = &[
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+const NONCE: &[u8] = &[0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47];
+const FOX: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+// eSTREAM Set 1 vector 0, the rest cross-checked against libsodium
+const SALSA20_GOLD_DATA: &[StreamGoldData] = &[
+    StreamGoldData {
+        key: &[
+            0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        nonce: &[0u8; 8],
+        counter: 0,
+        plaintext: &[0u8; 64],
+        ciphertext: &[
+            0xe3, 0xbe, 0x8f, 0xdd, 0x8b, 0xec, 0xa2, 0xe3, 0xea, 0x8e, 0xf9, 0x47, 0x5b, 0x29, 0xa6, 0xe7,
+            0x00, 0x39, 0x51, 0xe1, 0x09, 0x7a, 0x5c, 0x38, 0xd2, 0x3b, 0x7a, 0x5f, 0xad, 0x9f, 0x68, 0x44,
+            0xb2, 0x2c, 0x97, 0x55, 0x9e, 0x27, 0x23, 0xc7, 0xcb, 0xbd, 0x3f, 0xe4, 0xfc, 0x8d, 0x9a, 0x07,
+            0x44, 0x65, 0x2a, 0x83, 0xe7, 0x2a, 0x9c, 0x46, 0x18, 0x76, 0xaf, 0x4d, 0x7e, 0xf1, 0xa1, 0x17,
+        ],
+    },
+    StreamGoldData {
+        key: KEY,
+        nonce: NONCE,
+        counter: 0,
+        plaintext: FOX,
+        ciphertext: &[
+            0x86, 0x39, 0xeb, 0xa9, 0xb4, 0x30, 0xa2, 0xc8, 0xb5, 0x9d, 0x40, 0x09, 0xb0, 0xbd, 0x08, 0x07,
+            0x3c, 0xfa, 0x86, 0xf2, 0x22, 0x25, 0x26, 0x11, 0x7b, 0xb8, 0x60, 0x06, 0xed, 0x97, 0x7b, 0x2e,
+            0xe3, 0x34, 0x3b, 0x3c, 0x35, 0x7a, 0xe4, 0x5f, 0xcc, 0xb2, 0xa7,
+        ],
+    },
+    // starting from block 1
+    StreamGoldData {
+        key: KEY,
+        nonce: NONCE,
+        counter: 1,
+        plaintext: &[0u8; 70],
+        ciphertext: &[
+            0x25, 0xff, 0xde, 0xee, 0x7c, 0x3a, 0x5e, 0x38, 0x86, 0xd9, 0x2c, 0x52, 0x09, 0xbf, 0x05, 0x9e,
+            0xaf, 0xa0, 0x10, 0x1b, 0xd2, 0x5a, 0x93, 0x37, 0x88, 0xe9, 0x87, 0xce, 0xab, 0xc2, 0xd7, 0xe9,
+            0xdf, 0x48, 0x09, 0xb8, 0xde, 0x08, 0x22, 0xc3, 0xf2, 0x86, 0xc3, 0xe0, 0x82, 0x34, 0x1e, 0xe9,
+            0xdf, 0xbc, 0x82, 0x34, 0xdb, 0x2d, 0xe1, 0x61, 0xb0, 0x9e, 0x43, 0x55, 0x75, 0xf8, 0x57, 0x2f,
+            0xee, 0xd6, 0x26, 0x62, 0xdc, 0x4f,
+        ],
+    },
+];
+
+const SALSA20_12_GOLD_DATA: &[StreamGoldData] = &[
+    StreamGoldData {
+        key: &[
+            0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ],
+        nonce: &[0u8; 8],
+        counter: 0,
+        plaintext: &[0u8; 64],
+        ciphertext: &[
+            0xaf, 0xe4, 0x11, 0xed, 0x1c, 0x4e, 0x07, 0xe4, 0xd0, 0xcd, 0xe3, 0xb3, 0x3e, 0x31, 0xec, 0x19,
+            0x0f, 0xa4, 0xcc, 0x79, 0x6a, 0x58, 0xba, 0xfb, 0x84, 0x8e, 0xad, 0x8d, 0x07, 0xd0, 0x2c, 0xd2,
+            0xd4, 0xb6, 0xf9, 0xf3, 0x0c, 0xb0, 0xb5, 0x70, 0x07, 0xe3, 0x73, 0x38, 0x95, 0xcc, 0x8d, 0x10,
+            0x60, 0x10, 0x79, 0x75, 0xac, 0xae, 0xeb, 0x68, 0x9b, 0x6c, 0xf6, 0x14, 0xab, 0x64, 0xa3, 0xd6,
+        ],
+    },
+    StreamGoldData {
+        key: KEY,
+        nonce: NONCE,
+        counter: 0,
+        plaintext: FOX,
+        ciphertext: &[
+            0xe5, 0xed, 0x56, 0xea, 0x28, 0xf6, 0x65, 0x9c, 0xdd, 0x49, 0x1c, 0xd9, 0x24, 0xaa, 0x59, 0x3a,
+            0xff, 0xcc, 0x58, 0xd7, 0x75, 0x5e, 0xc9, 0xe6, 0x77, 0x07, 0x28, 0xbb, 0x58, 0x78, 0xcf, 0xe0,
+            0x19, 0x03, 0x41, 0x2e, 0x0f, 0xb5, 0xaf, 0x8a, 0xbf, 0xa5, 0x39,
+        ],
+    },
+];
+
+fn salsa20_key(key: &[u8]) -> Salsa20Key {
+    Salsa20Key::from(<[u8; 32]>::try_from(key).unwrap())
+}
+
+fn salsa20_nonce(nonce: &[u8]) -> Salsa20Nonce {
+    Salsa20Nonce::from(<[u8; 8]>::try_from(nonce).unwrap())
+}
+
+fn check_gold_data<S: StreamCipher<Key = Salsa20Key, Nonce = Salsa20Nonce>>(gold_data: &[StreamGoldData], with_counter: fn(&Salsa20Key, &Salsa20Nonce, u64) -> S) {
+    for data in gold_data {
+        let key = salsa20_key(data.key);
+        let nonce = salsa20_nonce(data.nonce);
+
+        let mut out_ciphertext = [0u8; 128];
+        let mut cipher = with_counter(&key, &nonce, data.counter);
+        let written = cipher.apply_keystream(data.plaintext, &mut out_ciphertext).expect("Encryption failed");
+        assert_eq!(&out_ciphertext[..written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 128];
+        let mut cipher = with_counter(&key, &nonce, data.counter);
+        let written = cipher.apply_keystream(data.ciphertext, &mut out_plaintext).expect("Decryption failed");
+        assert_eq!(&out_plaintext[..written], data.plaintext);
+    }
+}
+
+#[test]
+fn test_salsa20_gold_data() {
+    check_gold_data(SALSA20_GOLD_DATA, Salsa20::with_counter);
+}
+
+#[test]
+fn test_salsa20_12_gold_data() {
+    check_gold_data(SALSA20_12_GOLD_DATA, Salsa20_12::with_counter);
+}
+
+#[test]
+fn test_salsa20_split_updates() {
+    let data = &SALSA20_GOLD_DATA[2];
+    let key = salsa20_key(data.key);
+    let nonce = salsa20_nonce(data.nonce);
+
+    for chunk_size in [1, 7, 63, 64, 65] {
+        let mut cipher = Salsa20::with_counter(&key, &nonce, data.counter);
+        let mut out_ciphertext = [0u8; 128];
+        let mut written = 0;
+        for chunk in data.plaintext.chunks(chunk_size) {
+            written += cipher.apply_keystream(chunk, &mut out_ciphertext[written..]).expect("Encryption failed");
+        }
+        assert_eq!(&out_ciphertext[..written], data.ciphertext);
+    }
+}
+
+#[test]
+fn test_salsa20_errors() {
+    let key = Salsa20Key::default();
+    let nonce = Salsa20Nonce::default();
+
+    let mut cipher = Salsa20::new(&key, &nonce);
+    let mut output = [0u8; 8];
+    assert_eq!(cipher.apply_keystream(&[0u8; 16], &mut output), Err(SymcError::BufferTooSmall));
+
+    // the last block of the counter space is usable, the one after it is not
+    let mut cipher = Salsa20_12::with_counter(&key, &nonce, u64::MAX);
+    let mut output = [0u8; 128];
+    assert_eq!(cipher.apply_keystream(&[0u8; 65], &mut output), Err(SymcError::InvalidInputLength));
+    assert_eq!(cipher.apply_keystream(&[0u8; 64], &mut output), Ok(64));
+    assert_eq!(cipher.apply_keystream(&[0u8; 1], &mut output), Err(SymcError::InvalidInputLength));
+}
+
+/// The key and nonce of NaCl tests/stream3.c, keystream from libsodium
+#[cfg(feature = "xsalsa20")]
+#[test]
+fn test_xsalsa20() {
+    const XSALSA20_KEY: [u8; 32] = [
+        0x1b, 0x27, 0x55, 0x64, 0x73, 0xe9, 0x85, 0xd4, 0x62, 0xcd, 0x51, 0x19, 0x7a, 0x9a, 0x46, 0xc7,
+        0x60, 0x09, 0x54, 0x9e, 0xac, 0x64, 0x74, 0xf2, 0x06, 0xc4, 0xee, 0x08, 0x44, 0xf6, 0x83, 0x89,
+    ];
+    const XSALSA20_NONCE: [u8; 24] = [
+        0x69, 0x69, 0x6e, 0xe9, 0x55, 0xb6, 0x2b, 0x73, 0xcd, 0x62, 0xbd, 0xa8, 0x75, 0xfc, 0x73, 0xd6,
+        0x82, 0x19, 0xe0, 0x03, 0x6b, 0x7a, 0x0b, 0x37,
+    ];
+    const KEYSTREAM: &[u8] = &[
+        0xee, 0xa6, 0xa7, 0x25, 0x1c, 0x1e, 0x72, 0x91, 0x6d, 0x11, 0xc2, 0xcb, 0x21, 0x4d, 0x3c, 0x25,
+        0x25, 0x39, 0x12, 0x1d, 0x8e, 0x23, 0x4e, 0x65, 0x2d, 0x65, 0x1f, 0xa4, 0xc8, 0xcf, 0xf8, 0x80,
+        0x30, 0x9e, 0x64, 0x5a, 0x74, 0xe9, 0xe0, 0xa6, 0x0d, 0x82, 0x43, 0xac, 0xd9, 0x17, 0x7a, 0xb5,
+        0x1a, 0x1b, 0xeb, 0x8d, 0x5a, 0x2f, 0x5d, 0x70, 0x0c, 0x09, 0x3c, 0x5e, 0x55, 0x85, 0x57, 0x96,
+        0x25, 0x33, 0x7b, 0xd3, 0xab, 0x61, 0x9d, 0x61, 0x57, 0x60, 0xd8, 0xc5, 0xb2, 0x24, 0xa8, 0x5b,
+    ];
+
+    let key = Salsa20Key::from(XSALSA20_KEY);
+    let nonce = XSalsa20Nonce::from(XSALSA20_NONCE);
+    let mut output = [0u8; 80];
+    let written = XSalsa20::process(&key, &nonce, &[0u8; 80], &mut output).expect("Encryption failed");
+    assert_eq!(&output[..written], KEYSTREAM);
+
+    let mut cipher = XSalsa20::with_counter(&key, &nonce, 1);
+    let mut output = [0u8; 16];
+    cipher.apply_keystream(&[0u8; 16], &mut output).expect("Encryption failed");
+    assert_eq!(&output, &KEYSTREAM[64..]);
+}
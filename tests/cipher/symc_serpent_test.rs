@@ -0,0 +1,241 @@
+use super::*;
+
+use wovocrypt::cipher::mode::cbc::{CbcEncryptor, CbcDecryptor};
+use wovocrypt::cipher::mode::ctr::{CtrEncryptor, CtrDecryptor};
+use wovocrypt::cipher::serpent::*;
+use wovocrypt::padding::*;
+
+// Mode test key, truncated for Serpent-128 and Serpent-192
+const KEY: [u8; 32] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10,
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+];
+const IV: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const NONCE: [u8; 12] = [
+    0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb,
+];
+
+// NIST SP 800-38A plaintext, expected values from libgcrypt and Nettle
+const PLAINTEXT: &[u8] = &[
+    0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+    0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+    0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+    0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+];
+const FOX: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+/// NESSIE Set 1, vector 0, checked as a single CBC block with a zero IV since there is no ECB outside `hazmat`
+const SERPENT_KAT_PLAINTEXT: [u8; 16] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+const SERPENT128_KAT_CIPHERTEXT: [u8; 16] = [
+    0x26, 0x4e, 0x54, 0x81, 0xef, 0xf4, 0x2a, 0x46, 0x06, 0xab, 0xda, 0x06, 0xc0, 0xbf, 0xda, 0x3d,
+];
+const SERPENT192_KAT_CIPHERTEXT: [u8; 16] = [
+    0x9e, 0x27, 0x4e, 0xad, 0x9b, 0x73, 0x7b, 0xb2, 0x1e, 0xfc, 0xfc, 0xa5, 0x48, 0x60, 0x26, 0x89,
+];
+const SERPENT256_KAT_CIPHERTEXT: [u8; 16] = [
+    0xa2, 0x23, 0xaa, 0x12, 0x88, 0x46, 0x3c, 0x0e, 0x2b, 0xe3, 0x8e, 0xbd, 0x82, 0x56, 0x16, 0xc0,
+];
+
+const SERPENT128_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x68, 0xcf, 0x0e, 0x82, 0x63, 0x46, 0x67, 0x70, 0xc3, 0xeb, 0x3e, 0xd4, 0x84, 0x23, 0x64, 0xc8,
+            0x73, 0x1a, 0xb9, 0x7f, 0x9f, 0x93, 0x01, 0xc4, 0xd1, 0x41, 0x0b, 0x25, 0x09, 0xb5, 0x26, 0x51,
+            0xc7, 0x53, 0xac, 0x6d, 0xaa, 0xa8, 0x98, 0x19, 0xcc, 0xb1, 0x6d, 0x6b, 0x74, 0x40, 0xee, 0x61,
+            0xcf, 0x85, 0xa4, 0x35, 0x27, 0xc2, 0xbe, 0xeb, 0x9b, 0x62, 0xad, 0xb3, 0x30, 0x2b, 0xec, 0x9e,
+        ],
+    },
+];
+
+const SERPENT128_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xfc, 0xb3, 0xc9, 0x3c, 0x87, 0xb6, 0x53, 0x79, 0xdb, 0x0c, 0x6e, 0xa3, 0x78, 0xe9, 0xf1, 0x94,
+            0x30, 0xa6, 0xa7, 0x5c, 0xae, 0x3d, 0xa9, 0x08, 0x62, 0x6b, 0x94, 0xbf, 0xe8, 0x10, 0xc4, 0x72,
+            0x39, 0xff, 0x23, 0xdc, 0x4d, 0x31, 0x36, 0x6e, 0x66, 0xa1, 0xcc, 0x97, 0xe5, 0x82, 0x25, 0x00,
+        ],
+    },
+];
+
+const SERPENT128_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xf7, 0x75, 0x3f, 0xe1, 0x6c, 0x89, 0xe2, 0x4c, 0xa8, 0x1d, 0x3b, 0x37, 0xad, 0xbe, 0x1f, 0x8d,
+            0x7f, 0xdc, 0x93, 0xc6, 0xd5, 0x02, 0x94, 0x2b, 0xc1, 0xb9, 0x96, 0xae, 0x7d, 0xf4, 0xaa, 0x11,
+            0xd9, 0x2b, 0xae, 0x50, 0x03, 0x54, 0x3c, 0x7a, 0x48, 0x48, 0x30,
+        ],
+    },
+];
+
+const SERPENT192_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x5b, 0xe2, 0x4c, 0x64, 0xa2, 0xe2, 0x5d, 0x31, 0x71, 0xd7, 0xaa, 0x3a, 0x60, 0x28, 0x4b, 0xd2,
+            0x01, 0x53, 0x24, 0x00, 0x0c, 0xd4, 0x65, 0x94, 0x3b, 0xd0, 0xba, 0x47, 0x59, 0xe4, 0xcd, 0xcf,
+            0xc7, 0xaa, 0xac, 0x76, 0x9d, 0x83, 0xfe, 0xf3, 0xd9, 0xf4, 0x90, 0x89, 0x57, 0x4a, 0x74, 0x92,
+            0x34, 0x40, 0x8d, 0x26, 0x86, 0xae, 0x4e, 0x22, 0x39, 0x64, 0x69, 0xc3, 0x44, 0xdc, 0xff, 0xcc,
+        ],
+    },
+];
+
+const SERPENT192_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x3f, 0x17, 0xca, 0x6f, 0xf3, 0x6a, 0x01, 0x78, 0xfa, 0x9e, 0x98, 0xa4, 0x2c, 0x9f, 0xb0, 0xe4,
+            0x2e, 0x91, 0xcc, 0x7d, 0x42, 0xc1, 0x4b, 0x6e, 0xa9, 0x6c, 0x6d, 0x28, 0x2f, 0x30, 0x00, 0xf5,
+            0xd8, 0x13, 0x68, 0xb1, 0x44, 0xd0, 0x89, 0x64, 0x00, 0x9f, 0xbd, 0x8c, 0x49, 0x16, 0xd0, 0x9c,
+        ],
+    },
+];
+
+const SERPENT192_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x59, 0x66, 0xcd, 0xf9, 0xee, 0x35, 0x53, 0x0a, 0x12, 0x8d, 0x58, 0xdc, 0xc8, 0x32, 0x74, 0x39,
+            0x0c, 0xcb, 0x3a, 0x6e, 0x3d, 0x13, 0x44, 0x13, 0x93, 0x99, 0x95, 0xf5, 0xf4, 0x35, 0xef, 0xf5,
+            0x3d, 0x36, 0x25, 0xa0, 0x78, 0xb5, 0x94, 0x79, 0x88, 0x63, 0x77,
+        ],
+    },
+];
+
+const SERPENT256_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x73, 0x3d, 0x5b, 0x88, 0xb4, 0x40, 0x6a, 0x9d, 0xcb, 0xd6, 0x95, 0xd6, 0x3c, 0xb5, 0x41, 0x94,
+            0xe0, 0x56, 0x86, 0xf0, 0xd4, 0xd3, 0x17, 0x94, 0x51, 0xa2, 0x4e, 0x8d, 0x3e, 0xef, 0x4c, 0x62,
+            0xa9, 0x47, 0xa9, 0x0b, 0xc1, 0x1e, 0xd8, 0x9a, 0x7a, 0x1c, 0x35, 0x67, 0xd2, 0x66, 0x64, 0xa8,
+            0xe7, 0xc4, 0xe7, 0xde, 0x05, 0x24, 0x87, 0x6a, 0xaf, 0x06, 0x07, 0x32, 0x33, 0xcf, 0x26, 0x68,
+        ],
+    },
+];
+
+const SERPENT256_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xa7, 0x52, 0xcf, 0x0c, 0x29, 0xc9, 0x97, 0xfa, 0xce, 0x3b, 0x63, 0x43, 0xef, 0x22, 0x90, 0x35,
+            0x1a, 0x48, 0xc5, 0x27, 0x49, 0x3d, 0x0e, 0xac, 0xe0, 0x35, 0x33, 0x73, 0x8f, 0x29, 0x45, 0xa6,
+            0x96, 0x8a, 0xa5, 0xcb, 0x87, 0xad, 0xa2, 0xa9, 0x7d, 0x43, 0x60, 0xd7, 0xef, 0xd6, 0xec, 0xc9,
+        ],
+    },
+];
+
+const SERPENT256_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x2a, 0x6b, 0xaf, 0x33, 0xfe, 0xfd, 0x47, 0x50, 0xcc, 0x09, 0x96, 0x2d, 0x48, 0xf1, 0x85, 0xf3,
+            0x23, 0xb8, 0xcb, 0x61, 0xf7, 0x34, 0x00, 0x2c, 0x90, 0xac, 0xe8, 0xc7, 0x06, 0x72, 0xd4, 0x65,
+            0xa5, 0xae, 0x16, 0xd6, 0x5e, 0x4b, 0x0d, 0xe3, 0x80, 0xe1, 0x6a,
+        ],
+    },
+];
+
+fn serpent128_key() -> Serpent128Key {
+    Serpent128Key::from(<[u8; 16]>::try_from(&KEY[..16]).unwrap())
+}
+
+fn serpent192_key() -> Serpent192Key {
+    Serpent192Key::from(<[u8; 24]>::try_from(&KEY[..24]).unwrap())
+}
+
+fn serpent256_key() -> Serpent256Key {
+    Serpent256Key::from(KEY)
+}
+
+/// NESSIE Set 1 keys have only the top bit set
+fn nessie_key<const N: usize>() -> [u8; N] {
+    let mut key = [0u8; N];
+    key[0] = 0x80;
+    key
+}
+
+#[test]
+fn serpent_kat_through_cbc() {
+    roundtrip::<CbcEncryptor<Serpent128, NoPadding>, CbcDecryptor<Serpent128, NoPadding>>(&Serpent128Key::from(nessie_key::<16>()), &[0u8; 16], &SymcGoldData {
+        plaintext: &SERPENT_KAT_PLAINTEXT,
+        ciphertext: &SERPENT128_KAT_CIPHERTEXT,
+    });
+    roundtrip::<CbcEncryptor<Serpent192, NoPadding>, CbcDecryptor<Serpent192, NoPadding>>(&Serpent192Key::from(nessie_key::<24>()), &[0u8; 16], &SymcGoldData {
+        plaintext: &SERPENT_KAT_PLAINTEXT,
+        ciphertext: &SERPENT192_KAT_CIPHERTEXT,
+    });
+    roundtrip::<CbcEncryptor<Serpent256, NoPadding>, CbcDecryptor<Serpent256, NoPadding>>(&Serpent256Key::from(nessie_key::<32>()), &[0u8; 16], &SymcGoldData {
+        plaintext: &SERPENT_KAT_PLAINTEXT,
+        ciphertext: &SERPENT256_KAT_CIPHERTEXT,
+    });
+}
+
+#[test]
+fn serpent128_cbc_nopadding_roundtrip() {
+    for data in SERPENT128_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Serpent128, NoPadding>, CbcDecryptor<Serpent128, NoPadding>>(&serpent128_key(), &IV, data);
+    }
+}
+
+#[test]
+fn serpent128_cbc_pkcs7_roundtrip() {
+    for data in SERPENT128_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Serpent128, Pkcs7>, CbcDecryptor<Serpent128, Pkcs7>>(&serpent128_key(), &IV, data);
+    }
+}
+
+#[test]
+fn serpent128_ctr_roundtrip() {
+    for data in SERPENT128_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Serpent128>, CtrDecryptor<Serpent128>>(&serpent128_key(), &NONCE.into(), data);
+    }
+}
+
+#[test]
+fn serpent192_cbc_nopadding_roundtrip() {
+    for data in SERPENT192_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Serpent192, NoPadding>, CbcDecryptor<Serpent192, NoPadding>>(&serpent192_key(), &IV, data);
+    }
+}
+
+#[test]
+fn serpent192_cbc_pkcs7_roundtrip() {
+    for data in SERPENT192_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Serpent192, Pkcs7>, CbcDecryptor<Serpent192, Pkcs7>>(&serpent192_key(), &IV, data);
+    }
+}
+
+#[test]
+fn serpent192_ctr_roundtrip() {
+    for data in SERPENT192_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Serpent192>, CtrDecryptor<Serpent192>>(&serpent192_key(), &NONCE.into(), data);
+    }
+}
+
+#[test]
+fn serpent256_cbc_nopadding_roundtrip() {
+    for data in SERPENT256_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Serpent256, NoPadding>, CbcDecryptor<Serpent256, NoPadding>>(&serpent256_key(), &IV, data);
+    }
+}
+
+#[test]
+fn serpent256_cbc_pkcs7_roundtrip() {
+    for data in SERPENT256_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Serpent256, Pkcs7>, CbcDecryptor<Serpent256, Pkcs7>>(&serpent256_key(), &IV, data);
+    }
+}
+
+#[test]
+fn serpent256_ctr_roundtrip() {
+    for data in SERPENT256_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Serpent256>, CtrDecryptor<Serpent256>>(&serpent256_key(), &NONCE.into(), data);
+    }
+}
@@ -0,0 +1,184 @@
+use super::*;
+
+use wovocrypt::cipher::BlockCipher;
+use wovocrypt::cipher::mode::cbc::{CbcEncryptor, CbcDecryptor};
+use wovocrypt::cipher::mode::ctr::{CtrEncryptor, CtrDecryptor};
+use wovocrypt::cipher::sm4::{Sm4, Sm4Key};
+use wovocrypt::padding::*;
+
+const KEY: [u8; 16] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10,
+];
+const IV: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const NONCE: [u8; 12] = [
+    0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb,
+];
+
+const SM4_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb,
+        ],
+        ciphertext: &[
+            0x95, 0x54, 0xbc, 0xdd, 0xf2, 0xd3, 0x71, 0x45, 0x2b, 0xff, 0xd9, 0x3d, 0xf8, 0xd4, 0x61, 0x87,
+        ],
+    },
+    SymcGoldData {
+        plaintext: &[
+            0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb,
+            0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd,
+        ],
+        ciphertext: &[
+            0x95, 0x54, 0xbc, 0xdd, 0xf2, 0xd3, 0x71, 0x45, 0x2b, 0xff, 0xd9, 0x3d, 0xf8, 0xd4, 0x61, 0x87,
+            0x23, 0x60, 0x66, 0x40, 0x50, 0xb1, 0xae, 0x28, 0xe3, 0xe2, 0x5a, 0xb2, 0x53, 0x9e, 0xde, 0xdb,
+        ],
+    },
+    SymcGoldData {
+        plaintext: &[
+            0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb,
+            0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd,
+            0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb,
+        ],
+        ciphertext: &[
+            0x95, 0x54, 0xbc, 0xdd, 0xf2, 0xd3, 0x71, 0x45, 0x2b, 0xff, 0xd9, 0x3d, 0xf8, 0xd4, 0x61, 0x87,
+            0x23, 0x60, 0x66, 0x40, 0x50, 0xb1, 0xae, 0x28, 0xe3, 0xe2, 0x5a, 0xb2, 0x53, 0x9e, 0xde, 0xdb,
+            0xec, 0x17, 0x43, 0x5c, 0xee, 0x4d, 0x9e, 0x7c, 0x41, 0x3b, 0x77, 0x4a, 0xcf, 0x6a, 0xd1, 0x21,
+            0x94, 0xdd, 0x59, 0x77, 0x66, 0x04, 0x23, 0xca, 0x22, 0x8a, 0x14, 0x0b, 0x32, 0xdf, 0x68, 0xce,
+        ],
+    },
+];
+
+const SM4_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: b"The quick brown fox jumps over the lazy dog",
+        ciphertext: &[
+            0xb6, 0x55, 0x66, 0x13, 0x48, 0x0f, 0x80, 0xc2, 0xa4, 0xc4, 0xbe, 0xad, 0xbd, 0xc7, 0x95, 0xce,
+            0xd2, 0x03, 0xd6, 0x94, 0x54, 0x66, 0x92, 0x4b, 0x4f, 0xaa, 0x7b, 0xf4, 0x7b, 0xfb, 0x40, 0x34,
+            0xf7, 0x69, 0x7a, 0xf0, 0xaa, 0xe4, 0x98, 0xa4, 0x08, 0xe8, 0x56, 0xab, 0x10, 0xfe, 0x99, 0xce,
+        ],
+    },
+];
+
+const SM4_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: &[
+            0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb,
+        ],
+        ciphertext: &[
+            0xb2, 0x27, 0x4a, 0xc2, 0x9b, 0xb7, 0x80, 0xdf, 0xa8, 0x0e, 0x37, 0x29, 0xe3, 0x38, 0xc0, 0x2c,
+        ],
+    },
+    SymcGoldData {
+        plaintext: &[
+            0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb,
+            0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xcc, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd, 0xdd,
+            0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb, 0xbb,
+        ],
+        ciphertext: &[
+            0xb2, 0x27, 0x4a, 0xc2, 0x9b, 0xb7, 0x80, 0xdf, 0xa8, 0x0e, 0x37, 0x29, 0xe3, 0x38, 0xc0, 0x2c,
+            0xd5, 0x67, 0xae, 0xcc, 0x06, 0xf9, 0xe4, 0xda, 0xd8, 0x33, 0x65, 0x98, 0xa8, 0x29, 0xd2, 0x12,
+            0xd5, 0x75, 0x34, 0x70, 0x30, 0x0f, 0x9d, 0xde, 0x31, 0x7e, 0xc9, 0xa3, 0x77, 0x36, 0x97, 0xa7,
+            0xaa, 0xd1, 0x08, 0xd1, 0x4c, 0x5b, 0x26, 0x61, 0xbd, 0xe5, 0x11, 0xc6, 0x0c, 0x61, 0x18, 0x6c,
+        ],
+    },
+    SymcGoldData {
+        plaintext: &[
+            0x54, 0x68, 0x65, 0x20, 0x71, 0x75, 0x69, 0x63, 0x6b, 0x20, 0x62, 0x72, 0x6f, 0x77, 0x6e, 0x20,
+            0x66, 0x6f, 0x78, 0x20, 0x6a, 0x75, 0x6d, 0x70, 0x73, 0x20, 0x6f, 0x76, 0x65, 0x72, 0x20, 0x74,
+            0x68, 0x65, 0x20, 0x6c, 0x61, 0x7a, 0x79, 0x20, 0x64, 0x6f, 0x67,
+        ],
+        ciphertext: &[
+            0x4c, 0xe5, 0x85, 0x48, 0x40, 0x68, 0x43, 0x16, 0x78, 0x95, 0xee, 0xe0, 0x37, 0xf4, 0x15, 0xb7,
+            0x7f, 0xc4, 0x1a, 0x20, 0xa0, 0x40, 0x45, 0x66, 0x76, 0xce, 0xd7, 0x33, 0x10, 0x86, 0x2f, 0xbb,
+            0x53, 0xfe, 0xfa, 0xf2, 0xbf, 0x9b, 0x0a, 0x10, 0xaa, 0xee, 0x51,
+        ],
+    },
+];
+
+/// GB/T 32907-2016, Appendix A.1 and A.2
+#[test]
+fn sm4_gbt_32907_examples() {
+    let cipher = Sm4::new(&Sm4Key::from(KEY));
+    let mut block = KEY;
+    cipher.encrypt_block(&mut block);
+    assert_eq!(hex::encode(block), "681edf34d206965e86b3e94f536e4246");
+
+    let mut block = KEY;
+    for _ in 0..1000000 {
+        cipher.encrypt_block(&mut block);
+    }
+    assert_eq!(hex::encode(block), "595298c7c6fd271f0402f804c33d3f66");
+}
+
+#[test]
+fn sm4_cbc_nopadding_roundtrip() {
+    for data in SM4_CBC_NOPADDING_GOLD_DATA {
+        let mut out_ciphertext = [0u8; 64];
+        let mut encryptor = CbcEncryptor::<Sm4, NoPadding>::new(&KEY.into(), &IV);
+
+        let mut encrypt_written = encryptor.update(data.plaintext, &mut out_ciphertext).expect("Encryption update failed");
+        encrypt_written += encryptor.finalize(&mut out_ciphertext[encrypt_written..data.ciphertext.len()]).expect("Encryption finalize failed");
+
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 64];
+        let mut decryptor = CbcDecryptor::<Sm4, NoPadding>::new(&KEY.into(), &IV);
+
+        let mut decrypt_written = decryptor.update(&out_ciphertext[..encrypt_written], &mut out_plaintext).expect("Decryption update failed");
+        decrypt_written += decryptor.finalize(&mut out_plaintext[decrypt_written..data.plaintext.len()]).expect("Decryption finalize failed");
+
+        assert_eq!(decrypt_written, data.plaintext.len());
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+    }
+}
+
+#[test]
+fn sm4_cbc_pkcs7_roundtrip() {
+    for data in SM4_CBC_PKCS7_GOLD_DATA {
+        let mut out_ciphertext = [0u8; 64];
+        let mut encryptor = CbcEncryptor::<Sm4, Pkcs7>::new(&KEY.into(), &IV);
+
+        let mut encrypt_written = encryptor.update(data.plaintext, &mut out_ciphertext).expect("Encryption update failed");
+        encrypt_written += encryptor.finalize(&mut out_ciphertext[encrypt_written..]).expect("Encryption finalize failed");
+
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 64];
+        let mut decryptor = CbcDecryptor::<Sm4, Pkcs7>::new(&KEY.into(), &IV);
+
+        let mut decrypt_written = decryptor.update(&out_ciphertext[..encrypt_written], &mut out_plaintext).expect("Decryption update failed");
+        decrypt_written += decryptor.finalize(&mut out_plaintext[decrypt_written..data.plaintext.len()]).expect("Decryption finalize failed");
+
+        assert_eq!(decrypt_written, data.plaintext.len());
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+    }
+}
+
+#[test]
+fn sm4_ctr_roundtrip() {
+    for data in SM4_CTR_GOLD_DATA {
+        let mut out_ciphertext = [0u8; 64];
+        let mut encryptor = CtrEncryptor::<Sm4>::new(&KEY.into(), &NONCE.into());
+
+        let mut encrypt_written = encryptor.update(data.plaintext, &mut out_ciphertext).expect("Encryption update failed");
+        encrypt_written += encryptor.finalize(&mut out_ciphertext[encrypt_written..data.ciphertext.len()]).expect("Encryption finalize failed");
+
+        assert_eq!(encrypt_written, data.ciphertext.len());
+        assert_eq!(&out_ciphertext[..encrypt_written], data.ciphertext);
+
+        let mut out_plaintext = [0u8; 64];
+        let mut decryptor = CtrDecryptor::<Sm4>::new(&KEY.into(), &NONCE.into());
+
+        let mut decrypt_written = decryptor.update(&out_ciphertext[..encrypt_written], &mut out_plaintext).expect("Decryption update failed");
+        decrypt_written += decryptor.finalize(&mut out_plaintext[decrypt_written..data.plaintext.len()]).expect("Decryption finalize failed");
+
+        assert_eq!(decrypt_written, data.plaintext.len());
+        assert_eq!(&out_plaintext[..decrypt_written], data.plaintext);
+    }
+}
@@ -0,0 +1,234 @@
+use super::*;
+
+use wovocrypt::cipher::mode::cbc::{CbcEncryptor, CbcDecryptor};
+use wovocrypt::cipher::mode::ctr::{CtrEncryptor, CtrDecryptor};
+use wovocrypt::cipher::twofish::*;
+use wovocrypt::padding::*;
+
+// Key for the mode vectors, the 128 and 192-bit variants take its leading bytes
+const KEY: [u8; 32] = [
+    0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xfe, 0xdc, 0xba, 0x98, 0x76, 0x54, 0x32, 0x10,
+    0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff,
+];
+const IV: [u8; 16] = [
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+];
+const NONCE: [u8; 12] = [
+    0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9, 0xfa, 0xfb,
+];
+
+// NIST SP 800-38A plaintext, expected values from libgcrypt and Nettle
+const PLAINTEXT: &[u8] = &[
+    0x6b, 0xc1, 0xbe, 0xe2, 0x2e, 0x40, 0x9f, 0x96, 0xe9, 0x3d, 0x7e, 0x11, 0x73, 0x93, 0x17, 0x2a,
+    0xae, 0x2d, 0x8a, 0x57, 0x1e, 0x03, 0xac, 0x9c, 0x9e, 0xb7, 0x6f, 0xac, 0x45, 0xaf, 0x8e, 0x51,
+    0x30, 0xc8, 0x1c, 0x46, 0xa3, 0x5c, 0xe4, 0x11, 0xe5, 0xfb, 0xc1, 0x19, 0x1a, 0x0a, 0x52, 0xef,
+    0xf6, 0x9f, 0x24, 0x45, 0xdf, 0x4f, 0x9b, 0x17, 0xad, 0x2b, 0x41, 0x7b, 0xe6, 0x6c, 0x37, 0x10,
+];
+const FOX: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+/// Twofish ecb_tbl.txt, I=1, all-zero key and plaintext, run as one CBC block under a zero IV
+const TWOFISH_KAT_PLAINTEXT: [u8; 16] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+];
+const TWOFISH128_KAT_CIPHERTEXT: [u8; 16] = [
+    0x9f, 0x58, 0x9f, 0x5c, 0xf6, 0x12, 0x2c, 0x32, 0xb6, 0xbf, 0xec, 0x2f, 0x2a, 0xe8, 0xc3, 0x5a,
+];
+const TWOFISH192_KAT_CIPHERTEXT: [u8; 16] = [
+    0xef, 0xa7, 0x1f, 0x78, 0x89, 0x65, 0xbd, 0x44, 0x53, 0xf8, 0x60, 0x17, 0x8f, 0xc1, 0x91, 0x01,
+];
+const TWOFISH256_KAT_CIPHERTEXT: [u8; 16] = [
+    0x57, 0xff, 0x73, 0x9d, 0x4d, 0xc9, 0x2c, 0x1b, 0xd7, 0xfc, 0x01, 0x70, 0x0c, 0xc8, 0x21, 0x6f,
+];
+
+const TWOFISH128_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0xa9, 0x6a, 0x3c, 0xc9, 0x5f, 0xa5, 0x47, 0xc3, 0x22, 0x43, 0x80, 0x76, 0x4b, 0x8a, 0xe8, 0xe8,
+            0x41, 0xd0, 0xa9, 0xa0, 0xaf, 0x50, 0x3c, 0x5f, 0x02, 0x27, 0x51, 0x62, 0x60, 0xb6, 0xb6, 0xc3,
+            0xc0, 0x8e, 0x53, 0x30, 0xb3, 0xd3, 0x31, 0x37, 0x02, 0x37, 0x24, 0x5f, 0x4b, 0x5e, 0x9f, 0x9d,
+            0xba, 0xf4, 0xb5, 0x2b, 0x52, 0x59, 0xf3, 0xd3, 0xae, 0x52, 0xf6, 0x8c, 0x35, 0x21, 0xfa, 0x48,
+        ],
+    },
+];
+
+const TWOFISH128_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x06, 0x2c, 0xdd, 0x24, 0xc9, 0x37, 0xa7, 0x14, 0x01, 0x05, 0x55, 0x06, 0x15, 0x77, 0x35, 0xe7,
+            0xe3, 0x84, 0x0d, 0xcc, 0xd0, 0xf9, 0xc2, 0x9a, 0xfa, 0xe8, 0x7e, 0x8d, 0x98, 0x16, 0x1a, 0x6a,
+            0x48, 0x45, 0x17, 0x6f, 0x89, 0xc7, 0x60, 0x13, 0x82, 0x6b, 0xd1, 0x7c, 0xdf, 0x6c, 0xce, 0x36,
+        ],
+    },
+];
+
+const TWOFISH128_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xdf, 0x78, 0x8f, 0x5e, 0x96, 0x05, 0xad, 0x2b, 0xb2, 0x7e, 0x94, 0x37, 0x62, 0xd4, 0xc3, 0x0d,
+            0xaa, 0x27, 0x65, 0x2c, 0x4f, 0xcd, 0x38, 0x3e, 0x37, 0x94, 0x44, 0x09, 0x70, 0xc8, 0xdc, 0xd0,
+            0x87, 0x7a, 0x3e, 0x00, 0x01, 0x1a, 0x6f, 0x2d, 0x6b, 0x24, 0x3d,
+        ],
+    },
+];
+
+const TWOFISH192_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x6b, 0xe9, 0x1c, 0x39, 0x1a, 0xac, 0x06, 0x5c, 0xbc, 0xd4, 0xe1, 0xaf, 0x6c, 0xa6, 0x82, 0xab,
+            0x53, 0x80, 0x07, 0x4e, 0x1c, 0x26, 0x28, 0xec, 0x65, 0x90, 0xc8, 0x10, 0xd2, 0x91, 0xf4, 0x30,
+            0xfd, 0xc9, 0x25, 0x73, 0x40, 0xaa, 0x88, 0x07, 0x37, 0x5c, 0xcf, 0xce, 0xd3, 0x0d, 0x3e, 0x29,
+            0xc5, 0xc1, 0x91, 0xf7, 0x04, 0xf2, 0x6e, 0x60, 0x87, 0x0b, 0xea, 0xec, 0xc1, 0xab, 0xc7, 0xe3,
+        ],
+    },
+];
+
+const TWOFISH192_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x4f, 0x38, 0x06, 0xa4, 0xff, 0x4d, 0x63, 0x80, 0x6d, 0xc3, 0xbc, 0x07, 0xe5, 0x8a, 0xb1, 0x22,
+            0x11, 0x53, 0x22, 0xef, 0x7b, 0x99, 0x6f, 0x89, 0xbb, 0x9e, 0xa3, 0xd7, 0xad, 0x53, 0xe9, 0xd1,
+            0x4e, 0x9d, 0x2f, 0x37, 0x5a, 0xb8, 0x6e, 0x2e, 0xdd, 0xdf, 0x19, 0x7b, 0x42, 0x73, 0x8f, 0xaf,
+        ],
+    },
+];
+
+const TWOFISH192_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0xf1, 0xd9, 0x1e, 0x8c, 0xff, 0x58, 0x03, 0xb5, 0xa5, 0x2d, 0x1e, 0x87, 0x9d, 0x42, 0x9b, 0x8a,
+            0xc2, 0x58, 0x63, 0x6e, 0x70, 0xe1, 0xd5, 0xfb, 0x7e, 0x88, 0xbf, 0x61, 0xad, 0x9d, 0x4c, 0x3b,
+            0xfd, 0xe6, 0xb9, 0xf5, 0xb6, 0x18, 0x08, 0x84, 0xdd, 0x55, 0xf7,
+        ],
+    },
+];
+
+const TWOFISH256_CBC_NOPADDING_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: PLAINTEXT,
+        ciphertext: &[
+            0x3f, 0xb0, 0xb0, 0x6a, 0xd8, 0x07, 0xe5, 0x7e, 0x7d, 0x98, 0x77, 0xcb, 0xb1, 0xdc, 0xb6, 0xa8,
+            0xcf, 0xd4, 0xb2, 0xb4, 0xbb, 0x28, 0x82, 0x1e, 0x74, 0x65, 0x2a, 0xf8, 0xd5, 0xe7, 0xcd, 0xed,
+            0xa6, 0xbd, 0x08, 0xec, 0xc7, 0x32, 0xdc, 0x23, 0xce, 0x63, 0x7d, 0xd6, 0x28, 0x08, 0xc4, 0x86,
+            0x98, 0x5d, 0xad, 0x11, 0xca, 0x20, 0xce, 0x92, 0x1a, 0x2f, 0x42, 0x49, 0x7b, 0x38, 0x86, 0x8d,
+        ],
+    },
+];
+
+const TWOFISH256_CBC_PKCS7_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x25, 0x6f, 0x30, 0xc1, 0xe0, 0x8e, 0xe4, 0xeb, 0x6c, 0x48, 0x36, 0x1a, 0xe8, 0xf6, 0x92, 0xd0,
+            0x17, 0x2b, 0xaf, 0xf5, 0x06, 0xd0, 0x56, 0x0c, 0x68, 0x1d, 0x89, 0x17, 0x0b, 0xe7, 0x9e, 0x3c,
+            0xd4, 0x13, 0xfe, 0xbe, 0xab, 0xce, 0xa3, 0xf3, 0xad, 0x2d, 0x86, 0x1c, 0x89, 0x2c, 0x60, 0xb5,
+        ],
+    },
+];
+
+const TWOFISH256_CTR_GOLD_DATA: &[SymcGoldData] = &[
+    SymcGoldData {
+        plaintext: FOX,
+        ciphertext: &[
+            0x7d, 0x96, 0x02, 0x4f, 0x5d, 0xcb, 0xb5, 0x9e, 0x5f, 0x63, 0x24, 0xfe, 0x74, 0x72, 0xd0, 0x73,
+            0x9a, 0xf6, 0x7c, 0xa5, 0x4d, 0x40, 0x3b, 0x30, 0x3e, 0x77, 0x9e, 0x32, 0xe4, 0xc3, 0x2f, 0x4d,
+            0x1c, 0x13, 0xc3, 0x59, 0x54, 0x06, 0x85, 0x0e, 0x0b, 0x5b, 0xa6,
+        ],
+    },
+];
+
+fn twofish128_key() -> Twofish128Key {
+    Twofish128Key::from(<[u8; 16]>::try_from(&KEY[..16]).unwrap())
+}
+
+fn twofish192_key() -> Twofish192Key {
+    Twofish192Key::from(<[u8; 24]>::try_from(&KEY[..24]).unwrap())
+}
+
+fn twofish256_key() -> Twofish256Key {
+    Twofish256Key::from(KEY)
+}
+
+#[test]
+fn twofish_kat_through_cbc() {
+    roundtrip::<CbcEncryptor<Twofish128, NoPadding>, CbcDecryptor<Twofish128, NoPadding>>(&Twofish128Key::default(), &[0u8; 16], &SymcGoldData {
+        plaintext: &TWOFISH_KAT_PLAINTEXT,
+        ciphertext: &TWOFISH128_KAT_CIPHERTEXT,
+    });
+    roundtrip::<CbcEncryptor<Twofish192, NoPadding>, CbcDecryptor<Twofish192, NoPadding>>(&Twofish192Key::default(), &[0u8; 16], &SymcGoldData {
+        plaintext: &TWOFISH_KAT_PLAINTEXT,
+        ciphertext: &TWOFISH192_KAT_CIPHERTEXT,
+    });
+    roundtrip::<CbcEncryptor<Twofish256, NoPadding>, CbcDecryptor<Twofish256, NoPadding>>(&Twofish256Key::default(), &[0u8; 16], &SymcGoldData {
+        plaintext: &TWOFISH_KAT_PLAINTEXT,
+        ciphertext: &TWOFISH256_KAT_CIPHERTEXT,
+    });
+}
+
+#[test]
+fn twofish128_cbc_nopadding_roundtrip() {
+    for data in TWOFISH128_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Twofish128, NoPadding>, CbcDecryptor<Twofish128, NoPadding>>(&twofish128_key(), &IV, data);
+    }
+}
+
+#[test]
+fn twofish128_cbc_pkcs7_roundtrip() {
+    for data in TWOFISH128_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Twofish128, Pkcs7>, CbcDecryptor<Twofish128, Pkcs7>>(&twofish128_key(), &IV, data);
+    }
+}
+
+#[test]
+fn twofish128_ctr_roundtrip() {
+    for data in TWOFISH128_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Twofish128>, CtrDecryptor<Twofish128>>(&twofish128_key(), &NONCE.into(), data);
+    }
+}
+
+#[test]
+fn twofish192_cbc_nopadding_roundtrip() {
+    for data in TWOFISH192_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Twofish192, NoPadding>, CbcDecryptor<Twofish192, NoPadding>>(&twofish192_key(), &IV, data);
+    }
+}
+
+#[test]
+fn twofish192_cbc_pkcs7_roundtrip() {
+    for data in TWOFISH192_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Twofish192, Pkcs7>, CbcDecryptor<Twofish192, Pkcs7>>(&twofish192_key(), &IV, data);
+    }
+}
+
+#[test]
+fn twofish192_ctr_roundtrip() {
+    for data in TWOFISH192_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Twofish192>, CtrDecryptor<Twofish192>>(&twofish192_key(), &NONCE.into(), data);
+    }
+}
+
+#[test]
+fn twofish256_cbc_nopadding_roundtrip() {
+    for data in TWOFISH256_CBC_NOPADDING_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Twofish256, NoPadding>, CbcDecryptor<Twofish256, NoPadding>>(&twofish256_key(), &IV, data);
+    }
+}
+
+#[test]
+fn twofish256_cbc_pkcs7_roundtrip() {
+    for data in TWOFISH256_CBC_PKCS7_GOLD_DATA {
+        roundtrip::<CbcEncryptor<Twofish256, Pkcs7>, CbcDecryptor<Twofish256, Pkcs7>>(&twofish256_key(), &IV, data);
+    }
+}
+
+#[test]
+fn twofish256_ctr_roundtrip() {
+    for data in TWOFISH256_CTR_GOLD_DATA {
+        roundtrip::<CtrEncryptor<Twofish256>, CtrDecryptor<Twofish256>>(&twofish256_key(), &NONCE.into(), data);
+    }
+}
@@ -0,0 +1,142 @@
+#[cfg(feature = "ascon-hash256")]
+use wovocrypt::hash::Hasher;
+#[cfg(feature = "ascon-xof128")]
+use wovocrypt::hash::{ExtendableOutput, XofReader};
+use wovocrypt::hash::prelude::*;
+
+#[cfg(feature = "ascon-hash256")]
+use crate::common::{HashGoldData, assert_hash_eq};
+#[cfg(feature = "ascon-hash256")]
+use crate::common::utils::stress_test_hasher;
+
+// NIST LWC reference KATs, messages count up from 00
+#[cfg(feature = "ascon-hash256")]
+const ASCON_HASH256_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: b"",
+        expected: "0b3be5850f2f6b98caf29f8fdea89b64a1fa70aa249b8f839bd53baa304d92b2",
+    },
+    HashGoldData {
+        message: &[0x00],
+        expected: "0728621035af3ed2bca03bf6fde900f9456f5330e4b5ee23e7f6a1e70291bc80",
+    },
+    HashGoldData {
+        message: &[0x00, 0x01],
+        expected: "6115e7c9c4081c2797fc8fe1bc57a836afa1c5381e556dd583860ca2dfb48dd2",
+    },
+];
+
+#[cfg(feature = "ascon-xof128")]
+struct CxofGoldData {
+    customization: &'static [u8],
+    message: &'static [u8],
+    expected: &'static str,
+}
+
+#[cfg(feature = "ascon-xof128")]
+const ASCON_XOF128_GOLD_DATA: &[CxofGoldData] = &[
+    CxofGoldData {
+        customization: b"",
+        message: b"",
+        expected: "473d5e6164f58b39dfd84aacdb8ae42ec2d91fed33388ee0d960d9b3993295c6ad77855a5d3b13fe6ad9e6098988373af7d0956d05a8f1665d2c67d1a3ad10ff",
+    },
+    CxofGoldData {
+        customization: b"",
+        message: &[0x00],
+        expected: "51430e0438ecdf642b393630d977625f5f337656ba58ab1e960784ac32a16e0d446405551f5469384f8ea283cf12e64fa72c426bfebaea3aa1529e2c4ab23a2f",
+    },
+];
+
+#[cfg(feature = "ascon-xof128")]
+const ASCON_CXOF128_GOLD_DATA: &[CxofGoldData] = &[
+    CxofGoldData {
+        customization: b"",
+        message: b"",
+        expected: "4f50159ef70bb3dad8807e034eaebd44c4fa2cbbc8cf1f05511ab66cdcc529905ca12083fc186ad899b270b1473dc5f7ec88d1052082dcdfe69fb75d269e7b74",
+    },
+    CxofGoldData {
+        customization: &[0x10],
+        message: b"",
+        expected: "0c93a483e7d574d49fe52cce03ee646117977d57a8aa57704ab4daf44b501430ff6ac11a5d1fd6f2154b5c65728268270c8bb578508487b8965718ada6272fd6",
+    },
+];
+
+#[cfg(feature = "ascon-hash256")]
+#[test]
+fn test_ascon_hash256_gold_data() {
+    for data in ASCON_HASH256_GOLD_DATA {
+        assert_hash_eq!(AsconHash256, data.message, data.expected);
+    }
+}
+
+#[cfg(feature = "ascon-hash256")]
+#[test]
+fn test_ascon_hash256_multiple_blocks() {
+    let message = vec![b'x'; 200];
+    let result = AsconHash256::compute(&message);
+
+    let mut hasher = AsconHash256::default();
+    hasher.update(&message[..5]);
+    hasher.update(&message[5..100]);
+    hasher.update(&message[100..]);
+    let result2 = hasher.finalize();
+
+    assert_eq!(result.as_ref(), result2.as_ref());
+}
+
+#[cfg(feature = "ascon-hash256")]
+#[test]
+fn test_ascon_hash256_stress() {
+    stress_test_hasher::<AsconHash256>(1000);
+}
+
+#[cfg(feature = "ascon-xof128")]
+#[test]
+fn test_ascon_xof128_gold_data() {
+    for data in ASCON_XOF128_GOLD_DATA {
+        let expected = hex::decode(data.expected).unwrap();
+        let mut output = vec![0u8; expected.len()];
+        AsconXof128::compute_into(data.message, &mut output);
+        assert_eq!(output, expected);
+    }
+}
+
+#[cfg(feature = "ascon-xof128")]
+#[test]
+fn test_ascon_cxof128_gold_data() {
+    for data in ASCON_CXOF128_GOLD_DATA {
+        let expected = hex::decode(data.expected).unwrap();
+
+        let mut xof = AsconCxof128::new(data.customization).unwrap();
+        for chunk in data.message.chunks(3) {
+            xof.update(chunk);
+        }
+        let mut reader = xof.finalize_xof();
+        let mut output = vec![0u8; expected.len()];
+        for chunk in output.chunks_mut(5) {
+            reader.squeeze(chunk);
+        }
+        assert_eq!(output, expected);
+    }
+}
+
+#[cfg(feature = "ascon-xof128")]
+#[test]
+fn test_ascon_xof128_incremental_squeeze() {
+    let message: Vec<u8> = (0..100).collect();
+    let mut expected = [0u8; 100];
+    AsconXof128::compute_into(&message, &mut expected);
+
+    let mut xof = AsconXof128::default();
+    xof.update(b"discarded");
+    xof.reset();
+    for chunk in message.chunks(7) {
+        xof.update(chunk);
+    }
+    let mut reader = xof.finalize_xof();
+    let mut output = [0u8; 100];
+    for chunk in output.chunks_mut(9) {
+        reader.squeeze(chunk);
+    }
+    assert_eq!(output, expected);
+}
@@ -0,0 +1,142 @@
+use wovocrypt::hash::Hasher;
+
+#[cfg(feature = "sha256d")]
+mod sha256d {
+    use super::*;
+    use wovocrypt::hash::prelude::Sha256d;
+    use crate::common::utils::stress_test_hasher;
+
+    // header of the Bitcoin genesis block
+    const GENESIS_HEADER: &str = concat!(
+        "01000000",
+        "0000000000000000000000000000000000000000000000000000000000000000",
+        "3ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a",
+        "29ab5f49", "ffff001d", "1dac2b7c",
+    );
+
+    #[test]
+    fn test_sha256d_genesis_block() {
+        let header = hex::decode(GENESIS_HEADER).unwrap();
+        let mut id = Sha256d::compute(&header).as_ref().to_vec();
+        // block ids are displayed byte-reversed
+        id.reverse();
+        assert_eq!(hex::encode(id), "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f");
+    }
+
+    #[test]
+    fn test_sha256d_streaming() {
+        let mut hasher = Sha256d::default();
+        hasher.update(b"hel");
+        hasher.update(b"lo");
+        let expected = "9595c9df90075148eb06860365df33584b75bff782a510c6cd4883a419833d50";
+        assert_eq!(hex::encode(hasher.finalize_and_reset().as_ref()), expected);
+
+        hasher.update(b"hello");
+        assert_eq!(hex::encode(hasher.finalize().as_ref()), expected);
+    }
+
+    #[test]
+    fn test_sha256d_stress() {
+        stress_test_hasher::<Sha256d>(1000);
+    }
+}
+
+#[cfg(feature = "hash160")]
+mod hash160 {
+    use super::*;
+    use wovocrypt::hash::prelude::Hash160;
+
+    #[test]
+    fn test_hash160_pubkey() {
+        // compressed public key of the secret key 1
+        let pubkey = hex::decode("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").unwrap();
+        let result = Hash160::compute(&pubkey);
+        assert_eq!(hex::encode(result.as_ref()), "751e76e8199196d454941c45d1b3a323f1433bd6");
+        assert_eq!(Hash160::OUTPUT_SIZE, 20);
+    }
+}
+
+#[cfg(feature = "tagged-hash")]
+mod tagged {
+    use super::*;
+    use wovocrypt::hash::prelude::{Bip340AuxHash, Bip340ChallengeHash, Bip340NonceHash, HashTag, Tagged, TaggedHash};
+    use crate::common::utils::stress_test_hasher;
+
+    struct TaggedGoldData {
+        tag: &'static [u8],
+        message: &'static [u8],
+        expected: &'static str,
+    }
+
+    const TAGGED_GOLD_DATA: &[TaggedGoldData] = &[
+        TaggedGoldData {
+            tag: b"BIP0340/challenge",
+            message: b"abc",
+            expected: "770a5b7e7c304bbcc3ea107343ff951dd404312ef418db0c3b94e2ebfbb50087",
+        },
+        TaggedGoldData {
+            tag: b"BIP0340/aux",
+            message: &[0u8; 32],
+            expected: "54f169cfc9e2e5727480441f90ba25c488f461c70b5ea5dcaaf7af69270aa514",
+        },
+        TaggedGoldData {
+            tag: b"TapLeaf",
+            message: b"",
+            expected: "5212c288a377d1f8164962a5a13429f9ba6a7b84e59776a52c6637df2106facb",
+        },
+        TaggedGoldData {
+            tag: b"TapLeaf",
+            message: &[b'x'; 200],
+            expected: "c78c9c3f87a7f6b184f8c149db4e0a0f0c1bfff2c397dfd897c7808cda89d5f8",
+        },
+    ];
+
+    #[test]
+    fn test_tagged_hash_gold_data() {
+        for data in TAGGED_GOLD_DATA {
+            let result = TaggedHash::compute(data.tag, data.message);
+            assert_eq!(hex::encode(result.as_ref()), data.expected);
+
+            let mut hasher = TaggedHash::new(data.tag);
+            hasher.update(b"garbage");
+            hasher.reset();
+            for chunk in data.message.chunks(13) {
+                hasher.update(chunk);
+            }
+            assert_eq!(hex::encode(hasher.finalize().as_ref()), data.expected);
+        }
+    }
+
+    #[test]
+    fn test_bip340_tags() {
+        let result = Bip340ChallengeHash::compute(b"abc");
+        assert_eq!(hex::encode(result.as_ref()), TAGGED_GOLD_DATA[0].expected);
+
+        let result = Bip340AuxHash::compute(&[0u8; 32]);
+        assert_eq!(hex::encode(result.as_ref()), TAGGED_GOLD_DATA[1].expected);
+
+        let message: Vec<u8> = (0u8..96).collect();
+        let result = Bip340NonceHash::compute(&message);
+        assert_eq!(hex::encode(result.as_ref()), "15fa0d0722a5353238c24648ec3da2a7d4435020123d24497b384371b8164854");
+    }
+
+    struct TapLeaf;
+    impl HashTag for TapLeaf {
+        const MIDSTATE: [u32; 8] = [
+            0x9ce0e4e6, 0x7c116c39, 0x38b3caf2, 0xc30f5089,
+            0xd3f3936c, 0x47636e60, 0x7db33eea, 0xddc6f0c9
+        ];
+    }
+
+    #[test]
+    fn test_custom_tag() {
+        assert_eq!(TaggedHash::new(b"TapLeaf").midstate(), TapLeaf::MIDSTATE);
+        let result = Tagged::<TapLeaf>::compute(&[b'x'; 200]);
+        assert_eq!(hex::encode(result.as_ref()), TAGGED_GOLD_DATA[3].expected);
+    }
+
+    #[test]
+    fn test_tagged_hash_stress() {
+        stress_test_hasher::<Bip340ChallengeHash>(1000);
+    }
+}
@@ -0,0 +1,243 @@
+use wovocrypt::hash::Hasher;
+use wovocrypt::hash::prelude::*;
+
+use crate::common::{HashGoldData, assert_hash_eq};
+use crate::common::utils::{stress_test_hasher};
+
+#[cfg(feature = "blake2b")]
+const BLAKE2B_512_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: b"",
+        expected: "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d17d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923",
+    },
+    HashGoldData {
+        message: b"The quick brown fox jumps over the lazy dog",
+        expected: "a8add4bdddfd93e4877d2746e62817b116364a1fa7bc148d95090bc7333b3673f82401cf7aa2e4cb1ecd90296e3f14cb5413f8ed77be73045b13914cdcd6a918",
+    },
+];
+
+#[cfg(feature = "blake2b")]
+const BLAKE2B_256_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: b"",
+        expected: "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "bddd813c634239723171ef3fee98579b94964e3bb1cb3e427262c8c068d52319",
+    },
+    HashGoldData {
+        message: b"The quick brown fox jumps over the lazy dog",
+        expected: "01718cec35cd3d796dd00020e0bfecb473ad23457d063b75eff29c0ffa2e58a9",
+    },
+];
+
+#[cfg(feature = "blake2s")]
+const BLAKE2S_256_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: b"",
+        expected: "69217a3079908094e11121d042354a7c1f55b6482ca1a51e1b250dfd1ed0eef9",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982",
+    },
+    HashGoldData {
+        message: b"The quick brown fox jumps over the lazy dog",
+        expected: "606beeec743ccbeff6cbcdf5d5302aa855c256c29b88c8ed331ea1a6bf3c8812",
+    },
+];
+
+#[cfg(feature = "blake2s")]
+const BLAKE2S_128_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: b"",
+        expected: "64550d6ffe2c0a01a14aba1eade0200c",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "aa4938119b1dc7b87cbad0ffd200d0ae",
+    },
+    HashGoldData {
+        message: b"The quick brown fox jumps over the lazy dog",
+        expected: "96fd07258925748a0d2fb1c8a1167a73",
+    },
+];
+
+// bytes i % 251, long enough to cross several blocks
+fn sample_data(length: usize) -> Vec<u8> {
+    (0..length).map(|i| (i % 251) as u8).collect()
+}
+
+#[cfg(feature = "blake2b")]
+#[test]
+fn test_blake2b_gold_data() {
+    for data in BLAKE2B_512_GOLD_DATA {
+        assert_hash_eq!(Blake2b512, data.message, data.expected);
+    }
+    for data in BLAKE2B_256_GOLD_DATA {
+        assert_hash_eq!(Blake2b256, data.message, data.expected);
+    }
+}
+
+#[cfg(feature = "blake2s")]
+#[test]
+fn test_blake2s_gold_data() {
+    for data in BLAKE2S_256_GOLD_DATA {
+        assert_hash_eq!(Blake2s256, data.message, data.expected);
+    }
+    for data in BLAKE2S_128_GOLD_DATA {
+        assert_hash_eq!(Blake2s128, data.message, data.expected);
+    }
+}
+
+#[cfg(feature = "blake2b")]
+#[test]
+fn test_blake2b_multi_update() {
+    let message = sample_data(1000);
+    for chunk_size in [1, 7, Blake2b512::BLOCK_SIZE - 1, Blake2b512::BLOCK_SIZE, Blake2b512::BLOCK_SIZE + 1] {
+        let mut hasher = Blake2b512::default();
+        for chunk in message.chunks(chunk_size) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hex::encode(hasher.finalize().as_ref()), "c11e1c0340bd7e5a1b275f1230c962fad215ecb1391486e74e31b960a2f2996381a5fad092da06841d5f26e38f6ecfeaf441acbcd1c2de61aef121e7927175f5");
+    }
+}
+
+#[cfg(feature = "blake2s")]
+#[test]
+fn test_blake2s_multi_update() {
+    let message = sample_data(1000);
+    for chunk_size in [1, 7, Blake2s256::BLOCK_SIZE - 1, Blake2s256::BLOCK_SIZE, Blake2s256::BLOCK_SIZE + 1] {
+        let mut hasher = Blake2s256::default();
+        for chunk in message.chunks(chunk_size) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hex::encode(hasher.finalize().as_ref()), "1c067a5e746fb0f6734efac9a8cdb0e11061f0077f255184365c690115392501");
+    }
+}
+
+struct Blake2VarGoldData {
+    output_size: usize,
+    message_len: usize,
+    expected: &'static str,
+}
+
+#[cfg(feature = "blake2b")]
+const BLAKE2B_VAR_GOLD_DATA: &[Blake2VarGoldData] = &[
+    Blake2VarGoldData { output_size: 1, message_len: 0, expected: "2e" },
+    Blake2VarGoldData { output_size: 20, message_len: 128, expected: "e6992372ab022447b34f6d6032fbab707a11adef" },
+    Blake2VarGoldData { output_size: 33, message_len: 129, expected: "3586bda6d7d2c9420f3c618bb67d33e8d352923d219c9cf020ec9e70e51cb58afc" },
+    Blake2VarGoldData { output_size: 64, message_len: 256, expected: "93463ac058b6163eb43be3f5bb32b28541498f4e3366f1effe253ad44e1e076e41c3616046027c82a7124f8f4746668ad10b12e8e25a95ac8f3151df01cd5a93" },
+];
+
+#[cfg(feature = "blake2s")]
+const BLAKE2S_VAR_GOLD_DATA: &[Blake2VarGoldData] = &[
+    Blake2VarGoldData { output_size: 1, message_len: 0, expected: "a1" },
+    Blake2VarGoldData { output_size: 20, message_len: 64, expected: "68c18b80dd398444aa9fd4272ecfa8e71b31ee8a" },
+    Blake2VarGoldData { output_size: 31, message_len: 65, expected: "7219f7f1b5dfe930bb2918f0f8578ebff6de97416a275df00975c08258f936" },
+    Blake2VarGoldData { output_size: 32, message_len: 128, expected: "1fa877de67259d19863a2a34bcc6962a2b25fcbf5cbecd7ede8f1fa36688a796" },
+];
+
+#[cfg(feature = "blake2b")]
+#[test]
+fn test_blake2b_variable_output() {
+    for data in BLAKE2B_VAR_GOLD_DATA {
+        let mut hasher = Blake2bVar::new(data.output_size).unwrap();
+        hasher.update(&sample_data(data.message_len));
+        let mut output = vec![0u8; data.output_size];
+        hasher.finalize_into(&mut output).unwrap();
+        assert_eq!(hex::encode(output), data.expected);
+    }
+    assert!(Blake2bVar::new(0).is_err());
+    assert!(Blake2bVar::new(65).is_err());
+}
+
+#[cfg(feature = "blake2s")]
+#[test]
+fn test_blake2s_variable_output() {
+    for data in BLAKE2S_VAR_GOLD_DATA {
+        let mut hasher = Blake2sVar::new(data.output_size).unwrap();
+        hasher.update(&sample_data(data.message_len));
+        let mut output = vec![0u8; data.output_size];
+        hasher.finalize_into(&mut output).unwrap();
+        assert_eq!(hex::encode(output), data.expected);
+    }
+    assert!(Blake2sVar::new(33).is_err());
+}
+
+#[cfg(all(feature = "blake2b", feature = "alloc"))]
+#[test]
+fn test_blake2b_params() {
+    let message = sample_data(200);
+
+    let mut hasher = Blake2bParams::new()
+        .key(b"secret key")
+        .salt(b"salt-of-16-bytes")
+        .personal(b"personalization!")
+        .build()
+        .unwrap();
+    hasher.update(&message);
+    assert_eq!(hex::encode(hasher.finalize_vec()), "583bb9bb7cb6a6c4671da0c756a0e065de7038b15f8488fc02ef9f363f5ec13506eddcdf32aefbf72a729b14b33f7d0330026d5a1df6d8a2a8afddde250851e5");
+
+    // short salt and personalization are zero padded
+    let mut hasher = Blake2b256::with_params(&Blake2bParams::new().salt(b"NaCl").personal(b"app")).unwrap();
+    hasher.update(&message);
+    assert_eq!(hex::encode(hasher.finalize().as_ref()), "dd234405db3af472fdf99a03bfe9b04af08b6b0601ad40baaaf1a8c4d42479aa");
+
+    let params = Blake2bParams::new()
+        .fanout(2)
+        .max_depth(3)
+        .leaf_length(4096)
+        .node_offset(0x0102030405)
+        .node_depth(1)
+        .inner_length(64)
+        .last_node(true);
+    let mut hasher = Blake2b512::with_params(&params).unwrap();
+    hasher.update(&message);
+    assert_eq!(hex::encode(hasher.finalize().as_ref()), "de4c6f4b74e8ae0f8fd68b203924912ae0fa7c153a0d3f88f66cfe5c56ef78b8240d884550fb591f48ff0102f38ecb2382a12ad65a0f8fa9e4be37597be8d8db");
+}
+
+#[cfg(all(feature = "blake2s", feature = "alloc"))]
+#[test]
+fn test_blake2s_params() {
+    let message = sample_data(200);
+
+    let mut hasher = Blake2sParams::new()
+        .output_size(24)
+        .key(b"secret key")
+        .salt(b"8 bytes!")
+        .personal(b"app v1.0")
+        .build()
+        .unwrap();
+    hasher.update(&message);
+    assert_eq!(hex::encode(hasher.finalize_vec()), "73fe45541abe74205b88cb9aea732a2a24a217c51c745458");
+
+    let params = Blake2sParams::new()
+        .fanout(2)
+        .max_depth(3)
+        .leaf_length(4096)
+        .node_offset(0x010203040506)
+        .node_depth(1)
+        .inner_length(32)
+        .last_node(true);
+    let mut hasher = Blake2s256::with_params(&params).unwrap();
+    hasher.update(&message);
+    assert_eq!(hex::encode(hasher.finalize().as_ref()), "8c3690463d58aa8ea8ab46ec78087bc1b734cd1ef20adb183223057cd3512780");
+}
+
+#[cfg(feature = "blake2b")]
+#[test]
+fn test_blake2b_stress() {
+    stress_test_hasher::<Blake2b512>(1000);
+}
+
+#[cfg(feature = "blake2s")]
+#[test]
+fn test_blake2s_stress() {
+    stress_test_hasher::<Blake2s256>(1000);
+}
@@ -0,0 +1,287 @@
+use wovocrypt::hash::{Hasher, XofReader};
+use wovocrypt::hash::prelude::*;
+
+use crate::common::utils::{stress_test_hasher};
+
+// inputs follow the official BLAKE3 test vectors, bytes i % 251
+fn sample_data(length: usize) -> Vec<u8> {
+    (0..length).map(|i| (i % 251) as u8).collect()
+}
+
+const KEY: &[u8; 32] = b"a 32 byte key for BLAKE3 testing";
+const CONTEXT: &str = "BLAKE3 2019-12-27 16:29:52 test vectors context";
+
+struct Blake3GoldData {
+    input_len: usize,
+    hash: &'static str,
+    keyed_hash: &'static str,
+    derive_key: &'static str,
+}
+
+// first 32 bytes of each output are the default hash, the rest is XOF output
+const BLAKE3_GOLD_DATA: &[Blake3GoldData] = &[
+    Blake3GoldData {
+        input_len: 0,
+        hash: "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262e00f03e7b69af26b7faaf09fcd333050338ddfe085b8cc869ca98b206c08243a26f5487789e8f660afe6c99ef9e0c52b92e7393024a80459cf91f476f9ffdbda7001c22e159b402631f277ca96f2defdf1078282314e763699a31c5363165421cce14d",
+        keyed_hash: "085678efae25e17de07bdd67aafd26715d06a03343b8950eae694fed4c394320ea9767042471b53e0cd0c5858f458cf1588e135a920c554ddc3e13a49287ed4658d5cbd9506519c4051ce82ac82611c9c9de16fbf4aebd344d282cdcd61344321444188b48873cc2d96f94df651d28be46e00449fface700b6ac1e2bb573fb0e6237bf",
+        derive_key: "2cc39783c223154fea8dfb7c1b1660f2ac2dcbd1c1de8277b0b0dd39b7e50d7d905630c8be290dfcf3e6842f13bddd573c098c3f17361f1f206b8cad9d088aa4a3f746752c6b0ce6a83b0da81d59649257cdf8eb3e9f7d4998e41021fac119deefb896224ac99f860011f73609e6e0e4540f93b273e56547dfd3aa1a035ba6689d89a0",
+    },
+    Blake3GoldData {
+        input_len: 1,
+        hash: "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213c3a6cb8bf623e20cdb535f8d1a5ffb86342d9c0b64aca3bce1d31f60adfa137b358ad4d79f97b47c3d5e79f179df87a3b9776ef8325f8329886ba42f07fb138bb502f4081cbcec3195c5871e6c23e2cc97d3c69a613eba131e5f1351f3f1da786545e5",
+        keyed_hash: "7f11bee9a5f1673e555699361c51c5a778f308e9c2958eec50e55bd76b53a701ecc18ad41c28d95d9364269cb048036264ae37b77ee1b9dacc5d6768cada6215c25fa451e0b00b8972747d9f386bda89af3c2455c03e6fa1929d985c189240b45aea0823c6cc900f0d7e410e3dbf9e6c03a8a8a49b664f3f5d2524781ba75ed0a985b6",
+        derive_key: "b3e2e340a117a499c6cf2398a19ee0d29cca2bb7404c73063382693bf66cb06c5827b91bf889b6b97c5477f535361caefca0b5d8c4746441c57617111933158950670f9aa8a05d791daae10ac683cbef8faf897c84e6114a59d2173c3f417023a35d6983f2c7dfa57e7fc559ad751dbfb9ffab39c2ef8c4aafebc9ae973a64f0c76551",
+    },
+    Blake3GoldData {
+        input_len: 63,
+        hash: "e9bc37a594daad83be9470df7f7b3798297c3d834ce80ba85d6e207627b7db7b1197012b1e7d9af4d7cb7bdd1f3bb49a90a9b5dec3ea2bbc6eaebce77f4e470cbf4687093b5352f04e4a4570fba233164e6acc36900e35d185886a827f7ea9bdc1e5c3ce88b095a200e62c10c043b3e9bc6cb9b6ac4dfa51794b02ace9f98779040755",
+        keyed_hash: "30fd438b84ed17684c1f53e9d96c83ec46d3f5a47ba0b78d09b8cefc84b50f357f8746f5c10236edf0769b3aa98aca2ceefce783c07d770e6e49528dea3ee83a303534e37656c31e7e024af0d7c62cbd0c901ae06dd1adfd993a697551708e412b24795717b59153ba6b485f47f45b54bc2451b3c7230c645b04f44dabc17fe9014511",
+        derive_key: "b6451e30b953c206e34644c6803724e9d2725e0893039cfc49584f991f451af3b89e8ff572d3da4f4022199b9563b9d70ebb616efff0763e9abec71b550f1371e233319c4c4e74da936ba8e5bbb29a598e007a0bbfa929c99738ca2cc098d59134d11ff300c39f82e2fce9f7f0fa266459503f64ab9913befc65fddc474f6dc1c67669",
+    },
+    Blake3GoldData {
+        input_len: 64,
+        hash: "4eed7141ea4a5cd4b788606bd23f46e212af9cacebacdc7d1f4c6dc7f2511b98fc9cc56cb831ffe33ea8e7e1d1df09b26efd2767670066aa82d023b1dfe8ab1b2b7fbb5b97592d46ffe3e05a6a9b592e2949c74160e4674301bc3f97e04903f8c6cf95b863174c33228924cdef7ae47559b10b294acd660666c4538833582b43f82d74",
+        keyed_hash: "f5869743c4decfaee8278d39c4c751571e5e45e34e4a0a01c0d7ae9c994d726f1c8b6787aa4cdfcc3b06f83466a52432c35f8a151083ee38b9c9191081b1685a51a3e59e99fa5a2c5c5faf896c481a2b6f12c5104a9253a342c6166e06bd243581ee777eb22036e4bcc779195332538236807247880f863046c984a07f36f8e6dcd1ac",
+        derive_key: "a5c4a7053fa86b64746d4bb688d06ad1f02a18fce9afd3e818fefaa7126bf73e9b9493a9befebe0bf0c9509fb3105cfa0e262cde141aa8e3f2c2f77890bb64a4cca96922a21ead111f6338ad5244f2c15c44cb595443ac2ac294231e31be4a4307d0a91e874d36fc9852aeb1265c09b6e0cda7c37ef686fbbcab97e8ff66718be048bb",
+    },
+    Blake3GoldData {
+        input_len: 65,
+        hash: "de1e5fa0be70df6d2be8fffd0e99ceaa8eb6e8c93a63f2d8d1c30ecb6b263dee0e16e0a4749d6811dd1d6d1265c29729b1b75a9ac346cf93f0e1d7296dfcfd4313b3a227faaaaf7757cc95b4e87a49be3b8a270a12020233509b1c3632b3485eef309d0abc4a4a696c9decc6e90454b53b000f456a3f10079072baaf7a981653221f2c",
+        keyed_hash: "384933c3265888f9014b827dddec0dd1bf1645c4479b1a5dbceeef5fd3e8de8c80ca059578906d73ba7d52624b07cb5c1057b23ae90de693745a4bfca4849505c1d045f2844813cd5dec7e5eb2cf751ce6b0ef9d845b84c3869879c6d7b463724dcfcc832e7d9a4c8435d2da199960ee138e3fec9c2421dd98383cda15f0108a9f883d",
+        derive_key: "51fd05c3c1cfbc8ed67d139ad76f5cf8236cd2acd26627a30c104dfd9d3ff8a82b02e8bd36d8498a75ad8c8e9b15eb386970283d6dd42c8ae7911cc592887fdbe26a0a5f0bf821cd92986c60b2502c9be3f98a9c133a7e8045ea867e0828c7252e739321f7c2d65daee4468eb4429efae469a42763f1f94977435d10dccae3e3dce88d",
+    },
+    Blake3GoldData {
+        input_len: 1023,
+        hash: "10108970eeda3eb932baac1428c7a2163b0e924c9a9e25b35bba72b28f70bd11a182d27a591b05592b15607500e1e8dd56bc6c7fc063715b7a1d737df5bad3339c56778957d870eb9717b57ea3d9fb68d1b55127bba6a906a4a24bbd5acb2d123a37b28f9e9a81bbaae360d58f85e5fc9d75f7c370a0cc09b6522d9c8d822f2f28f485",
+        keyed_hash: "e8af172a210427b96bc4dcf391dd02cae326bd230d633631c2203d9c93ac7d955c1cd2db42cfd5ab4d8ff44e2b7f76e73e770edc471cfaa3929264fff1e995b88f16d6c3db7fe89962e17ee5dd41bb049c603530f8f8daa5f0c6ce87ca53f7a076f679db7b8da8ab54f06d5f1c0d4e07e27d9d64b2aeafc075357a063d834be9129866",
+        derive_key: "74a16c1c3d44368a86e1ca6df64be6a2f64cce8f09220787450722d85725dea59c413264404661e9e4d955409dfe4ad3aa487871bcd454ed12abfe2c2b1eb7757588cf6cb18d2eccad49e018c0d0fec323bec82bf1644c6325717d13ea712e6840d3e6e730d35553f59eff5377a9c350bcc1556694b924b858f329c44ee64b884ef00d",
+    },
+    Blake3GoldData {
+        input_len: 1024,
+        hash: "42214739f095a406f3fc83deb889744ac00df831c10daa55189b5d121c855af71cf8107265ecdaf8505b95d8fcec83a98a6a96ea5109d2c179c47a387ffbb404756f6eeae7883b446b70ebb144527c2075ab8ab204c0086bb22b7c93d465efc57f8d917f0b385c6df265e77003b85102967486ed57db5c5ca170ba441427ed9afa684e",
+        keyed_hash: "96eaf049bc2375a89d89cc14331cd3d1a5d23fdd0a4c70cfbf5b7900a8045f0e921e7d20a68485596c4d4168514a55f30d91e79737e7989bdd8a1eaf8059ac2531b66b4fa90aaa27bac4891dee06456b95a5b82a6000205c52c92941cf8668a8ed60090711989c0ebefc87d1673fb57db113d3d41a3509791f8f107ecda2884ed03d18",
+        derive_key: "7356cd7720d5b66b6d0697eb3177d9f8d73a4a5c5e968896eb6a6896843027066c23b601d3ddfb391e90d5c8eccdef4ae2a264bce9e612ba15e2bc9d654af1481b2e75dbabe615974f1070bba84d56853265a34330b4766f8e75edd1f4a1650476c10802f22b64bd3919d246ba20a17558bc51c199efdec67e80a227251808d8ce5bad",
+    },
+    Blake3GoldData {
+        input_len: 1025,
+        hash: "d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444f4c4a22b4b399155358a994e52bf255de60035742ec71bd08ac275a1b51cc6bfe332b0ef84b409108cda080e6269ed4b3e2c3f7d722aa4cdc98d16deb554e5627be8f955c98e1d5f9565a9194cad0c4285f93700062d9595adb992ae68ff12800ab67a",
+        keyed_hash: "102c6566dbe5455b7fc61ed0d537015cf4c45d4c8ffc4b08a327496e8827fa8a828b2954cdb3257d58b8b325c8ad0f6aacde1ef9a7b6e24acdb6c1876b8bedd35c21a30846a20fde35db2619f3b37e813b81035a702ba02ae5d7918cf032b2b53f48a53afebc615437e5dce3bd746cc55d10e1ab183feaeae6d6bfc3824a059deff548",
+        derive_key: "effaa245f065fbf82ac186839a249707c3bddf6d3fdda22d1b95a3c970379bcb5d31013a167509e9066273ab6e2123bc835b408b067d88f96addb550d96b6852dad38e320b9d940f86db74d398c770f462118b35d2724efa13da97194491d96dd37c3c09cbef665953f2ee85ec83d88b88d11547a6f911c8217cca46defa2751e7f3ad",
+    },
+    Blake3GoldData {
+        input_len: 2048,
+        hash: "e776b6028c7cd22a4d0ba182a8bf62205d2ef576467e838ed6f2529b85fba24a9a60bf80001410ec9eea6698cd537939fad4749edd484cb541aced55cd9bf54764d063f23f6f1e32e12958ba5cfeb1bf618ad094266d4fc3c968c2088f677454c288c67ba0dba337b9d91c7e1ba586dc9a5bc2d5e90c14f53a8863ac75655461cea8f9",
+        keyed_hash: "b718c05d8f7512454dab8fc3001f34734fc786ef620e10c3a5688a41097435c7d889a4992c47ade95e7fa2b82d1200e2cfac950e79efe73f6339c47425a1ee5c497c017daa34394be308c1cc39bededd9683b494b496223b89934ccf3a061e2bf6f031ef5464ed017bfd401df3c189e3da6366c14aa10d7b67e08bf18f73272401ba1f",
+        derive_key: "7b2945cb4fef70885cc5d78a87bf6f6207dd901ff239201351ffac04e1088a23e2c11a1ebffcea4d80447867b61badb1383d842d4e79645d48dd82ccba290769caa7af8eaa1bd78a2a5e6e94fbdab78d9c7b74e894879f6a515257ccf6f95056f4e25390f24f6b35ffbb74b766202569b1d797f2d4bd9d17524c720107f985f4ddc583",
+    },
+    Blake3GoldData {
+        input_len: 2049,
+        hash: "5f4d72f40d7a5f82b15ca2b2e44b1de3c2ef86c426c95c1af0b687952256303096de31d71d74103403822a2e0bc1eb193e7aecc9643a76b7bbc0c9f9c52e8783aae98764ca468962b5c2ec92f0c74eb5448d519713e09413719431c802f948dd5d90425a4ecdadece9eb178d80f26efccae630734dff63340285adec2aed3b51073ad3",
+        keyed_hash: "23a13322cb589c6630e6d98bb06de55c8d689bffb4e3d6bf10f04410ad6a9dc408e9726188dd192300785f4df68b4d8b20c02ecbc33fcd67e1c7914b0a800c918f9fca208e8c6949edf344fd4b530330896a7089d57ac01d26e11fb81a6e8243db2cce645d6e5da86b0b6eab731a3a816566b0ca7f41a5bd212b6d6b407bc4abffce93",
+        derive_key: "2ea477c5515cc3dd606512ee72bb3e0e758cfae7232826f35fb98ca1bcbdf27316d8e9e79081a80b046b60f6a263616f33ca464bd78d79fa18200d06c7fc9bffd808cc4755277a7d5e09da0f29ed150f6537ea9bed946227ff184cc66a72a5f8c1e4bd8b04e81cf40fe6dc4427ad5678311a61f4ffc39d195589bdbc670f63ae70f4b6",
+    },
+    Blake3GoldData {
+        input_len: 3072,
+        hash: "b98cb0ff3623be03326b373de6b9095218513e64f1ee2edd2525c7ad1e5cffd29a3f6b0b978d6608335c09dc94ccf682f9951cdfc501bfe47b9c9189a6fc7b404d120258506341a6d802857322fbd20d3e5dae05b95c88793fa83db1cb08e7d8008d1599b6209d78336e24839724c191b2a52a80448306e0daa84a3fdb566661a37e11",
+        keyed_hash: "ab92ad3750b387a3bed589be67958d48a7f7ff068769e74d9353dff20ab26fe486d3c40339e7f5d6d967d6fa9b10cdcf76f6baaa188559c385a3b573d53439b0cd1447efe934a6e588426ec401c84c75a183c550663d50f40a2ea338f712fce4c20e85c09e4ceb07a05008f042fe5b10fee5b43f64c149dcb52bdc3666ab81e84b7778",
+        derive_key: "050df97f8c2ead654d9bb3ab8c9178edcd902a32f8495949feadcc1e0480c46b3604131bbd6e3ba573b6dd682fa0a63e5b165d39fc43a625d00207607a2bfeb65ff1d29292152e26b298868e3b87be95d6458f6f2ce6118437b632415abe6ad522874bcd79e4030a5e7bad2efa90a7a7c67e93f0a18fb28369d0a9329ab5c24134ccb0",
+    },
+    Blake3GoldData {
+        input_len: 3073,
+        hash: "7124b49501012f81cc7f11ca069ec9226cecb8a2c850cfe644e327d22d3e1cd39a27ae3b79d68d89da9bf25bc27139ae65a324918a5f9b7828181e52cf373c84f35b639b7fccbb985b6f2fa56aea0c18f531203497b8bbd3a07ceb5926f1cab74d14bd66486d9a91eba99059a98bd1cd25876b2af5a76c3e9eed554ed72ea952b603bf",
+        keyed_hash: "2e9aa90b99a4e9fd9cb1595829d1d5a5ec8785d2b05032edcb2c2fd6fca4d35aefe184b04c0538506942bfecc3aec4e8924d1da8abdec576e476afd0ec48c34ae54df9b77194daf54596803619eace093ee390c5e3d650e35e34acae13f9e9089160afbec9296ca292ffc90a754a86e61bdaf8e21a896fb79c342ac43a170e261f319e",
+        derive_key: "72613c9ec9ff7e40f8f5c173784c532ad852e827dba2bf85b2ab4b76f7079081576288e552647a9d86481c2cae75c2dd4e7c5195fb9ada1ef50e9c5098c249d743929191441301c69e1f48505a4305ec1778450ee48b8e69dc23a25960fe33070ea549119599760a8a2d28aeca06b8c5e9ba58bc19e11fe57b6ee98aa44b2a8e6b14a5",
+    },
+    Blake3GoldData {
+        input_len: 4096,
+        hash: "015094013f57a5277b59d8475c0501042c0b642e531b0a1c8f58d2163229e9690289e9409ddb1b99768eafe1623da896faf7e1114bebeadc1be30829b6f8af707d85c298f4f0ff4d9438aef948335612ae921e76d411c3a9111df62d27eaf871959ae0062b5492a0feb98ef3ed4af277f5395172dbe5c311918ea0074ce0036454f620",
+        keyed_hash: "d98d1439e4e9c443a2e9bd3368f8224036d4b42b47f89f3fd6e721b818706b39d992a6d64c7b0db710038592e5b668328e6af9e282d42068849be72384997146d614ee3c0cf61be52a544241cfd084d2e796643c26153d4993bb1cf223dfe58be3d5db1be457eef06f43f9efca16c201bdb2c0ff1cf76993d7356a997361712f707ee8",
+        derive_key: "1e0d7f3db8c414c97c6307cbda6cd27ac3b030949da8e23be1a1a924ad2f25b9d78038f7b198596c6cc4a9ccf93223c08722d684f240ff6569075ed81591fd93f9fff1110b3a75bc67e426012e5588959cc5a4c192173a03c00731cf84544f65a2fb9378989f72e9694a6a394a8a30997c2e67f95a504e631cd2c5f55246024761b245",
+    },
+    Blake3GoldData {
+        input_len: 4097,
+        hash: "9b4052b38f1c5fc8b1f9ff7ac7b27cd242487b3d890d15c96a1c25b8aa0fb99505f91b0b5600a11251652eacfa9497b31cd3c409ce2e45cfe6c0a016967316c426bd26f619eab5d70af9a418b845c608840390f361630bd497b1ab44019316357c61dbe091ce72fc16dc340ac3d6e009e050b3adac4b5b2c92e722cffdc46501531956",
+        keyed_hash: "58f603e86a3299fe5beb6dc1f202bf5cd79d04abd63ad45132a021563fa4eaf33c60153bb94e2a82b07ea8eb42aeea11b8a02f6b1e788d70b07653d43dbdab3fd070ffa8bf560afda6bbbe33f5eff6ca5e6f985990cc56b32599b2c71d647d18ba6ca36740a0c070e24457d5dab12502e9f8470ff96895f4dafc4a6cf5261a924923a5",
+        derive_key: "aca51029626b55fda7117b42a7c211f8c6e9ba4fe5b7a8ca922f34299500ead8a897f66a400fed9198fd61dd2d58d382458e64e100128075fc54b860934e8de2e84170734b06e1d212a117100820dbc48292d148afa50567b8b84b1ec336ae10d40c8c975a624996e12de31abbe135d9d159375739c333798a80c64ae895e51e22f3ad",
+    },
+    Blake3GoldData {
+        input_len: 8192,
+        hash: "aae792484c8efe4f19e2ca7d371d8c467ffb10748d8a5a1ae579948f718a2a635fe51a27db045a567c1ad51be5aa34c01c6651c4d9b5b5ac5d0fd58cf18dd61a47778566b797a8c67df7b1d60b97b19288d2d877bb2df417ace009dcb0241ca1257d62712b6a4043b4ff33f690d849da91ea3bf711ed583cb7b7a7da2839ba71309bbf",
+        keyed_hash: "3e5a375c019c51f9ebb60f416392722c5cfb9acbe92b4780af32e57afaf3afda4e526245548b5cc746894538bc1df0c48ecdf7943803ac7b601828f882886a4a29a9ea8c3b8f561b46a633218960b638add32df2941a7f79d88853781e2eb28a07c5209ea3a351864875892f55f096d4ec830e9f100015b7a2ac81b728be42eaee2900",
+        derive_key: "ad01d7ae4ad059b0d33baa3c01319dcf8088094d0359e5fd45d6aeaa8b2d0c3d4c9e58958553513b67f84f8eac653aeeb02ae1d5672dcecf91cd9985a0e67f4501910ecba25555395427ccc7241d70dc21c190e2aadee875e5aae6bf1912837e53411dabf7a56cbf8e4fb780432b0d7fe6cec45024a0788cf5874616407757e9e6bef7",
+    },
+    Blake3GoldData {
+        input_len: 8193,
+        hash: "bab6c09cb8ce8cf459261398d2e7aef35700bf488116ceb94a36d0f5f1b7bc3bb2282aa69be089359ea1154b9a9286c4a56af4de975a9aa4a5c497654914d279bea60bb6d2cf7225a2fa0ff5ef56bbe4b149f3ed15860f78b4e2ad04e158e375c1e0c0b551cd7dfc82f1b155c11b6b3ed51ec9edb30d133653bb5709d1dbd55f4e1ff6",
+        keyed_hash: "eff807d5f5096bb358eba7ad5ffe588c042a9f1215d7d294de8226fa679eeaa6cb53ee1c69892bfbb04c1ed63a727c3a59820cd418833796c23ffd92e0504d2fb116851a97c233007ad3a8db41a30112c2541e34a0b69a1d6d60627f36365fbc25892bb62c6a8e280c20791ae63416c3e03a2c2ba9567dd3ee10cea4a78c73b5af5bd9",
+        derive_key: "af1e0346e389b17c23200270a64aa4e1ead98c61695d917de7d5b00491c9b0f12f20a01d6d622edf3de026a4db4e4526225debb93c1237934d71c7340bb5916158cbdafe9ac3225476b6ab57a12357db3abbad7a26c6e66290e44034fb08a20a8d0ec264f309994d2810c49cfba6989d7abb095897459f5425adb48aba07c5fb3c83c0",
+    },
+    Blake3GoldData {
+        input_len: 16384,
+        hash: "f875d6646de28985646f34ee13be9a576fd515f76b5b0a26bb324735041ddde49d764c270176e53e97bdffa58d549073f2c660be0e81293767ed4e4929f9ad34bbb39a529334c57c4a381ffd2a6d4bfdbf1482651b172aa883cc13408fa67758a3e47503f93f87720a3177325f7823251b85275f64636a8f1d599c2e49722f42e93893",
+        keyed_hash: "8f3f226c4896660447526b0eae8b22ccee4b980a1a3f81053701bccc8cd37d774fbc1ae6b5db87e3d568a98b99cc05fa11554fe973054ea3077b36c4152dffd600f77c8f8a9a3ec3dd6e318078bda35baeda6b00cb4ee9ff41dc80de2407d4df36b7acd425853a0fbe34d03b01145b5d52a54eed4719518ca3327c9ab0e91ee9570e92",
+        derive_key: "160e18b5878cd0df1c3af85eb25a0db5344d43a6fbd7a8ef4ed98d0714c3f7e160dc0b1f09caa35f2f417b9ef309dfe5ebd67f4c9507995a531374d099cf8ae317542e885ec6f589378864d3ea98716b3bbb65ef4ab5e0ab5bb298a501f19a41ec19af84a5e6b428ecd813b1a47ed91c9657c3fba11c406bc316768b58f6802c9e9b57",
+    },
+    Blake3GoldData {
+        input_len: 31744,
+        hash: "62b6960e1a44bcc1eb1a611a8d6235b6b4b78f32e7abc4fb4c6cdcce94895c47860cc51f2b0c28a7b77304bd55fe73af663c02d3f52ea053ba43431ca5bab7bfea2f5e9d7121770d88f70ae9649ea713087d1914f7f312147e247f87eb2d4ffef0ac978bf7b6579d57d533355aa20b8b77b13fd09748728a5cc327a8ec470f4013226f",
+        keyed_hash: "5ac0eb53d873a8efc730c8c50188387e88b90ade2a032cd96c741cbc64db7e43c546fa53e9c9eff2b708442dc56d3314444e1cc082d100ca627f3cb1ca9c78d546664620ca635c7d85c8bb11d07b7eb833928233d7c988a0dece184f49cbd56a3ffe3e7138ec8392a364dc61ff269880a39607112c2b633f512ead2fe115bb070bd2f3",
+        derive_key: "39772aef80e0ebe60596361e45b061e8f417429d529171b6764468c22928e28e9759adeb797a3fbf771b1bcea30150a020e317982bf0d6e7d14dd9f064bc11025c25f31e81bd78a921db0174f03dd481d30e93fd8e90f8b2fee209f849f2d2a52f31719a490fb0ba7aea1e09814ee912eba111a9fde9d5c274185f7bae8ba85d300a2b",
+    },
+    Blake3GoldData {
+        input_len: 102400,
+        hash: "bc3e3d41a1146b069abffad3c0d44860cf664390afce4d9661f7902e7943e085e01c59dab908c04c3342b816941a26d69c2605ebee5ec5291cc55e15b76146e6745f0601156c3596cb75065a9c57f35585a52e1ac70f69131c23d611ce11ee4ab1ec2c009012d236648e77be9295dd0426f29b764d65de58eb7d01dd42248204f45f8e",
+        keyed_hash: "05b622fb874e8d7f67d2c5ddf4b09948aaa891e3f382c05b058839b29f1fd4ee6ae812c60a317ef389417657c5ee09ea83bf2c92fe20229c91052548340255c8423d0714e946cbb4c47e975312eec70d25856b7d2014fa7fcc7c3849702f13ca08bcdca661fd5c5b7120733aa74fa55bfcc98d820db9997811f7321d4000e0997c8203",
+        derive_key: "4652cff7a3f385a6103b5c260fc1593e13c778dbe608efb092fe7ee69df6e9c6d83a3e041bc3a48df2879f4a0a3ed40e7c961c73eff740f3117a0504c2dff4786d44fb17f1549eb0ba585e40ec29bf7732f0b7e286ff8acddc4cb1e23b87ff5d824a986458dcc6a04ac83969b80637562953df51ed1a7e90a7926924d2763778be8560",
+    },
+];
+
+#[test]
+fn test_blake3_gold_data() {
+    for data in BLAKE3_GOLD_DATA {
+        let input = sample_data(data.input_len);
+        assert_eq!(hex::encode(Blake3::compute(&input).as_ref()), data.hash[..64]);
+
+        let mut hasher = Blake3::new();
+        hasher.update(&input);
+        let mut output = vec![0u8; data.hash.len() / 2];
+        hasher.finalize_xof().squeeze(&mut output);
+        assert_eq!(hex::encode(output), data.hash);
+    }
+}
+
+#[test]
+fn test_blake3_keyed_gold_data() {
+    let key = Blake3Key::from(*KEY);
+    for data in BLAKE3_GOLD_DATA {
+        let input = sample_data(data.input_len);
+        assert_eq!(hex::encode(Blake3::keyed_hash(&key, &input).as_ref()), data.keyed_hash[..64]);
+
+        let mut hasher = Blake3::new_keyed(&key);
+        hasher.update(&input);
+        let mut reader = hasher.finalize_xof();
+        let mut output = vec![0u8; data.keyed_hash.len() / 2];
+        for chunk in output.chunks_mut(10) {
+            reader.squeeze(chunk);
+        }
+        assert_eq!(hex::encode(output), data.keyed_hash);
+    }
+}
+
+#[test]
+fn test_blake3_derive_key_gold_data() {
+    for data in BLAKE3_GOLD_DATA {
+        let input = sample_data(data.input_len);
+        assert_eq!(hex::encode(Blake3::derive_key(CONTEXT, &input).as_ref()), data.derive_key[..64]);
+
+        let mut hasher = Blake3::new_derive_key(CONTEXT);
+        hasher.update(&input);
+        let mut output = vec![0u8; data.derive_key.len() / 2];
+        hasher.finalize_xof().squeeze(&mut output);
+        assert_eq!(hex::encode(output), data.derive_key);
+    }
+}
+
+#[test]
+fn test_blake3_multi_update() {
+    let data = &BLAKE3_GOLD_DATA[BLAKE3_GOLD_DATA.len() - 2];
+    let input = sample_data(data.input_len);
+    for chunk_size in [1, 63, 64, 1000, 1024, 1025] {
+        let mut hasher = Blake3::new();
+        for chunk in input.chunks(chunk_size) {
+            hasher.update(chunk);
+        }
+        assert_eq!(hex::encode(hasher.finalize().as_ref()), data.hash[..64]);
+    }
+}
+
+#[test]
+fn test_blake3_xof_seek() {
+    let data = &BLAKE3_GOLD_DATA[6];
+    let mut hasher = Blake3::new();
+    hasher.update(&sample_data(data.input_len));
+    let mut reader = hasher.finalize_xof();
+
+    reader.seek(100);
+    let mut tail = [0u8; 31];
+    reader.squeeze(&mut tail);
+    assert_eq!(hex::encode(tail), data.hash[200..]);
+    assert_eq!(reader.position(), 131);
+
+    reader.seek(0);
+    let mut head = [0u8; 32];
+    reader.squeeze(&mut head);
+    assert_eq!(hex::encode(head), data.hash[..64]);
+}
+
+#[test]
+fn test_blake3_subtree_verification() {
+    // 8 full chunks and a partial one: the root's children cover 8192 and 100 bytes
+    let input = sample_data(8292);
+    let expected = Blake3::compute(&input);
+    let verifier = Blake3::new();
+
+    let left_len = left_subtree_len(input.len() as u64).unwrap() as usize;
+    assert_eq!(left_len, 8192);
+
+    let subtree_cv = |offset: usize, len: usize| {
+        let mut hasher = Blake3::new();
+        hasher.set_input_offset(offset as u64).unwrap();
+        hasher.update(&input[offset..offset + len]);
+        hasher.finalize_chaining_value()
+    };
+
+    let left = subtree_cv(0, left_len);
+    let right = subtree_cv(left_len, input.len() - left_len);
+    assert_eq!(verifier.merge_subtrees_root(&left, &right).as_ref(), expected.as_ref());
+
+    // the left child is itself the parent of two 4096 byte subtrees
+    let left_left = subtree_cv(0, 4096);
+    let left_right = subtree_cv(4096, 4096);
+    assert_eq!(verifier.merge_subtrees(&left_left, &left_right).as_ref(), left.as_ref());
+    assert_eq!(max_subtree_len(4096), Some(4096));
+
+    // stream the second subtree and check it against the trusted chaining value
+    let mut hasher = Blake3::new();
+    hasher.set_input_offset(4096).unwrap();
+    for chunk in input[4096..8192].chunks(1000) {
+        hasher.update(chunk);
+    }
+    assert!(hasher.verify_chaining_value(&left_right));
+    hasher.update(b"x");
+    assert!(!hasher.verify_chaining_value(&left_right));
+}
+
+#[test]
+fn test_blake3_keyed_subtree_verification() {
+    let key = Blake3Key::from(*KEY);
+    let input = sample_data(3000);
+    let verifier = Blake3::new_keyed(&key);
+
+    let mut left = Blake3::new_keyed(&key);
+    left.update(&input[..2048]);
+    let mut right = Blake3::new_keyed(&key);
+    right.set_input_offset(2048).unwrap();
+    right.update(&input[2048..]);
+
+    let root = verifier.merge_subtrees_root(&left.finalize_chaining_value(), &right.finalize_chaining_value());
+    assert_eq!(root.as_ref(), Blake3::keyed_hash(&key, &input).as_ref());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_blake3_update_parallel() {
+    for data in BLAKE3_GOLD_DATA {
+        let input = sample_data(data.input_len);
+        for threads in [1, 4] {
+            let mut hasher = Blake3::new();
+            hasher.update_parallel(&input, threads);
+            assert_eq!(hex::encode(hasher.finalize().as_ref()), data.hash[..64]);
+        }
+    }
+}
+
+#[test]
+fn test_blake3_stress() {
+    stress_test_hasher::<Blake3>(1000);
+}
@@ -0,0 +1,269 @@
+use wovocrypt::hash::{ExtendableOutput, XofReader};
+use wovocrypt::hash::prelude::*;
+
+// SP 800-185 samples hash the byte sequences 00 01 02 ..
+fn sample_data(length: usize) -> Vec<u8> {
+    (0..length).map(|i| i as u8).collect()
+}
+
+struct CShakeGoldData {
+    data_len: usize,
+    customization: &'static [u8],
+    expected: &'static str,
+}
+
+// cSHAKE samples #1 and #2
+const CSHAKE128_GOLD_DATA: &[CShakeGoldData] = &[
+    CShakeGoldData {
+        data_len: 4,
+        customization: b"Email Signature",
+        expected: "c1c36925b6409a04f1b504fcbca9d82b4017277cb5ed2b2065fc1d3814d5aaf5",
+    },
+    CShakeGoldData {
+        data_len: 200,
+        customization: b"Email Signature",
+        expected: "c5221d50e4f822d96a2e8881a961420f294b7b24fe3d2094baed2c6524cc166b",
+    },
+];
+
+// cSHAKE samples #3 and #4
+const CSHAKE256_GOLD_DATA: &[CShakeGoldData] = &[
+    CShakeGoldData {
+        data_len: 4,
+        customization: b"Email Signature",
+        expected: "d008828e2b80ac9d2218ffee1d070c48b8e4c87bff32c9699d5b6896eee0edd164020e2be0560858d9c00c037e34a96937c561a74c412bb4c746469527281c8c",
+    },
+    CShakeGoldData {
+        data_len: 200,
+        customization: b"Email Signature",
+        expected: "07dc27b11e51fbac75bc7b3c1d983e8b4b85fb1defaf218912ac86430273091727f42b17ed1df63e8ec118f04b23633c1dfb1574c8fb55cb45da8e25afb092bb",
+    },
+];
+
+#[test]
+fn test_cshake128_gold_data() {
+    for data in CSHAKE128_GOLD_DATA {
+        let mut xof = CShake128::new(b"", data.customization);
+        xof.update(&sample_data(data.data_len));
+        let expected = hex::decode(data.expected).unwrap();
+        let mut output = vec![0u8; expected.len()];
+        xof.finalize_into(&mut output);
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_cshake256_gold_data() {
+    for data in CSHAKE256_GOLD_DATA {
+        let mut xof = CShake256::new(b"", data.customization);
+        for chunk in sample_data(data.data_len).chunks(9) {
+            xof.update(chunk);
+        }
+        let expected = hex::decode(data.expected).unwrap();
+
+        let mut reader = xof.finalize_xof();
+        let mut output = vec![0u8; expected.len()];
+        for chunk in output.chunks_mut(5) {
+            reader.squeeze(chunk);
+        }
+        assert_eq!(output, expected);
+    }
+}
+
+#[cfg(all(feature = "shake128", feature = "shake256"))]
+#[test]
+fn test_cshake_without_strings_is_shake() {
+    let mut cshake = [0u8; 100];
+    let mut shake = [0u8; 100];
+
+    CShake128::compute_into(b"abc", &mut cshake);
+    Shake128::compute_into(b"abc", &mut shake);
+    assert_eq!(cshake, shake);
+
+    CShake256::compute_into(b"abc", &mut cshake);
+    Shake256::compute_into(b"abc", &mut shake);
+    assert_eq!(cshake, shake);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_cshake_reset_keeps_customization() {
+    let mut xof = CShake128::new(b"", b"Email Signature");
+    xof.update(b"discarded");
+    xof.reset();
+    xof.update(&sample_data(4));
+    assert_eq!(hex::encode(xof.finalize_xof_vec(32)), CSHAKE128_GOLD_DATA[0].expected);
+}
+
+#[cfg(feature = "tuplehash")]
+struct TupleHashGoldData {
+    tuple: &'static [&'static [u8]],
+    customization: &'static [u8],
+    expected: &'static str,
+}
+
+// TupleHash samples #1 - #3
+#[cfg(feature = "tuplehash")]
+const TUPLEHASH128_GOLD_DATA: &[TupleHashGoldData] = &[
+    TupleHashGoldData {
+        tuple: &[&[0x00, 0x01, 0x02], &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15]],
+        customization: b"",
+        expected: "c5d8786c1afb9b82111ab34b65b2c0048fa64e6d48e263264ce1707d3ffc8ed1",
+    },
+    TupleHashGoldData {
+        tuple: &[&[0x00, 0x01, 0x02], &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15]],
+        customization: b"My Tuple App",
+        expected: "75cdb20ff4db1154e841d758e24160c54bae86eb8c13e7f5f40eb35588e96dfb",
+    },
+    TupleHashGoldData {
+        tuple: &[&[0x00, 0x01, 0x02], &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15], &[0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28]],
+        customization: b"My Tuple App",
+        expected: "e60f202c89a2631eda8d4c588ca5fd07f39e5151998deccf973adb3804bb6e84",
+    },
+];
+
+// TupleHash samples #4 - #6
+#[cfg(feature = "tuplehash")]
+const TUPLEHASH256_GOLD_DATA: &[TupleHashGoldData] = &[
+    TupleHashGoldData {
+        tuple: &[&[0x00, 0x01, 0x02], &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15]],
+        customization: b"",
+        expected: "cfb7058caca5e668f81a12a20a2195ce97a925f1dba3e7449a56f82201ec607311ac2696b1ab5ea2352df1423bde7bd4bb78c9aed1a853c78672f9eb23bbe194",
+    },
+    TupleHashGoldData {
+        tuple: &[&[0x00, 0x01, 0x02], &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15]],
+        customization: b"My Tuple App",
+        expected: "147c2191d5ed7efd98dbd96d7ab5a11692576f5fe2a5065f3e33de6bba9f3aa1c4e9a068a289c61c95aab30aee1e410b0b607de3620e24a4e3bf9852a1d4367e",
+    },
+    TupleHashGoldData {
+        tuple: &[&[0x00, 0x01, 0x02], &[0x10, 0x11, 0x12, 0x13, 0x14, 0x15], &[0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27, 0x28]],
+        customization: b"My Tuple App",
+        expected: "45000be63f9b6bfd89f54717670f69a9bc763591a4f05c50d68891a744bcc6e7d6d5b5e82c018da999ed35b0bb49c9678e526abd8e85c13ed254021db9e790ce",
+    },
+];
+
+#[cfg(feature = "tuplehash")]
+#[test]
+fn test_tuplehash128_gold_data() {
+    for data in TUPLEHASH128_GOLD_DATA {
+        let mut output = [0u8; 32];
+        TupleHash128::compute_into(data.customization, data.tuple, &mut output);
+        assert_eq!(hex::encode(output), data.expected);
+    }
+}
+
+#[cfg(feature = "tuplehash")]
+#[test]
+fn test_tuplehash256_gold_data() {
+    for data in TUPLEHASH256_GOLD_DATA {
+        let mut hasher = TupleHash256::new(data.customization);
+        for element in data.tuple {
+            hasher.update(element);
+        }
+        let mut output = [0u8; 64];
+        hasher.finalize_into(&mut output);
+        assert_eq!(hex::encode(output), data.expected);
+    }
+}
+
+#[cfg(feature = "tuplehash")]
+#[test]
+fn test_tuplehash_is_unambiguous() {
+    let mut split1 = [0u8; 32];
+    let mut split2 = [0u8; 32];
+    TupleHash128::compute_into(b"", &[b"ab", b"c"], &mut split1);
+    TupleHash128::compute_into(b"", &[b"a", b"bc"], &mut split2);
+    assert_ne!(split1, split2);
+
+    // the XOF variant does not bind the output length
+    let mut hasher = TupleHash128::default();
+    hasher.update(b"abc");
+    let mut reader = hasher.clone().finalize_xof();
+    let mut xof_output = [0u8; 64];
+    reader.squeeze(&mut xof_output);
+    let mut fixed_output = [0u8; 64];
+    hasher.finalize_into(&mut fixed_output);
+    assert_ne!(xof_output, fixed_output);
+}
+
+#[cfg(feature = "parallelhash")]
+struct ParallelHashGoldData {
+    block_size: usize,
+    customization: &'static [u8],
+    expected: &'static str,
+}
+
+// ParallelHash samples hash 00..07 10..17 20..27
+#[cfg(feature = "parallelhash")]
+const PARALLELHASH_DATA: &[u8] = &[
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17,
+    0x20, 0x21, 0x22, 0x23, 0x24, 0x25, 0x26, 0x27,
+];
+
+#[cfg(feature = "parallelhash")]
+const PARALLELHASH128_GOLD_DATA: &[ParallelHashGoldData] = &[
+    ParallelHashGoldData {
+        block_size: 8,
+        customization: b"",
+        expected: "ba8dc1d1d979331d3f813603c67f72609ab5e44b94a0b8f9af46514454a2b4f5",
+    },
+    ParallelHashGoldData {
+        block_size: 8,
+        customization: b"Parallel Data",
+        expected: "fc484dcb3f84dceedc353438151bee58157d6efed0445a81f165e495795b7206",
+    },
+];
+
+#[cfg(feature = "parallelhash")]
+const PARALLELHASH256_GOLD_DATA: &[ParallelHashGoldData] = &[
+    ParallelHashGoldData {
+        block_size: 8,
+        customization: b"",
+        expected: "bc1ef124da34495e948ead207dd9842235da432d2bbc54b4c110e64c451105531b7f2a3e0ce055c02805e7c2de1fb746af97a1dd01f43b824e31b87612410429",
+    },
+    ParallelHashGoldData {
+        block_size: 8,
+        customization: b"Parallel Data",
+        expected: "cdf15289b54f6212b4bc270528b49526006dd9b54e2b6add1ef6900dda3963bb33a72491f236969ca8afaea29c682d47a393c065b38e29fae651a2091c833110",
+    },
+];
+
+#[cfg(feature = "parallelhash")]
+#[test]
+fn test_parallelhash128_gold_data() {
+    for data in PARALLELHASH128_GOLD_DATA {
+        for chunk_size in [1, 5, 8, 24] {
+            let mut hasher = ParallelHash128::new(data.block_size, data.customization).unwrap();
+            for chunk in PARALLELHASH_DATA.chunks(chunk_size) {
+                hasher.update(chunk);
+            }
+            let mut output = [0u8; 32];
+            hasher.finalize_into(&mut output);
+            assert_eq!(hex::encode(output), data.expected);
+        }
+    }
+}
+
+#[cfg(feature = "parallelhash")]
+#[test]
+fn test_parallelhash256_gold_data() {
+    for data in PARALLELHASH256_GOLD_DATA {
+        let mut hasher = ParallelHash256::new(data.block_size, data.customization).unwrap();
+        hasher.update(PARALLELHASH_DATA);
+        let mut output = [0u8; 64];
+        hasher.finalize_into(&mut output);
+        assert_eq!(hex::encode(output), data.expected);
+    }
+}
+
+#[cfg(all(feature = "parallelhash", feature = "alloc"))]
+#[test]
+fn test_parallelhash_reset_and_errors() {
+    assert!(ParallelHash128::new(0, b"").is_err());
+
+    let mut hasher = ParallelHash128::new(8, b"").unwrap();
+    hasher.update(&[0xaa; 13]);
+    hasher.reset();
+    hasher.update(PARALLELHASH_DATA);
+    assert_eq!(hex::encode(hasher.finalize_vec(32)), PARALLELHASH128_GOLD_DATA[0].expected);
+}
@@ -0,0 +1,81 @@
+use wovocrypt::hash::Hasher;
+use wovocrypt::hash::prelude::Md5;
+
+use crate::common::{HashGoldData, assert_hash_eq};
+use crate::common::utils::{stress_test_hasher};
+
+// RFC 1321, A.5
+const MD5_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: b"",
+        expected: "d41d8cd98f00b204e9800998ecf8427e",
+    },
+    HashGoldData {
+        message: b"a",
+        expected: "0cc175b9c0f1b6a831c399e269772661",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "900150983cd24fb0d6963f7d28e17f72",
+    },
+    HashGoldData {
+        message: b"message digest",
+        expected: "f96b697d7cb7938d525a2f31aaf161d0",
+    },
+    HashGoldData {
+        message: b"abcdefghijklmnopqrstuvwxyz",
+        expected: "c3fcd3d76192e4007dfb496cca67e13b",
+    },
+    HashGoldData {
+        message: b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+        expected: "d174ab98d277d9f5a5611c2c9f419d9f",
+    },
+    HashGoldData {
+        message: b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+        expected: "57edf4a22be3c955ac49da2e2107b67a",
+    },
+];
+
+#[test]
+fn test_md5_gold_data() {
+    for data in MD5_GOLD_DATA {
+        assert_hash_eq!(Md5, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_md5_million_a() {
+    let message = vec![b'a'; 1000000];
+    let result = Md5::compute(&message);
+    let expected = "7707d6ae4e027c70eea2a935c2296f21";
+    assert_eq!(hex::encode(result.as_ref()), expected);
+}
+
+#[test]
+fn test_md5_multi_update() {
+    let mut hasher = Md5::default();
+    hasher.update(b"message ");
+    hasher.update(b"digest");
+    let result = hasher.finalize();
+    let expected = "f96b697d7cb7938d525a2f31aaf161d0";
+    assert_eq!(hex::encode(result.as_ref()), expected);
+}
+
+#[test]
+fn test_md5_multiple_blocks() {
+    let message = vec![b'x'; 200];
+    let result = Md5::compute(&message);
+
+    let mut hasher = Md5::default();
+    hasher.update(&message[..64]);
+    hasher.update(&message[64..128]);
+    hasher.update(&message[128..]);
+    let result2 = hasher.finalize();
+
+    assert_eq!(result.as_ref(), result2.as_ref());
+}
+
+#[test]
+fn test_md5_stress() {
+    stress_test_hasher::<Md5>(1000);
+}
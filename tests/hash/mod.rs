@@ -9,4 +9,43 @@ pub mod sha256_test;
 pub mod sha384_test;
 
 #[cfg(feature = "sha512")]
-pub mod sha512_test;
\ No newline at end of file
+pub mod sha512_test;
+
+#[cfg(any(feature = "sha512-224", feature = "sha512-256", feature = "sha512t"))]
+pub mod sha512t_test;
+
+#[cfg(any(feature = "sha3-224", feature = "sha3-256", feature = "sha3-384", feature = "sha3-512"))]
+pub mod sha3_test;
+
+#[cfg(any(feature = "shake128", feature = "shake256"))]
+pub mod shake_test;
+
+#[cfg(feature = "cshake")]
+pub mod cshake_test;
+
+#[cfg(any(feature = "blake2b", feature = "blake2s"))]
+pub mod blake2_test;
+
+#[cfg(feature = "blake3")]
+pub mod blake3_test;
+
+#[cfg(feature = "ripemd160")]
+pub mod ripemd160_test;
+
+#[cfg(any(feature = "ascon-hash256", feature = "ascon-xof128"))]
+pub mod ascon_test;
+
+#[cfg(feature = "sm3")]
+pub mod sm3_test;
+
+#[cfg(feature = "streebog")]
+pub mod streebog_test;
+
+#[cfg(any(feature = "sha256d", feature = "hash160", feature = "tagged-hash"))]
+pub mod bitcoin_test;
+
+#[cfg(feature = "legacy")]
+pub mod sha1_test;
+
+#[cfg(feature = "legacy")]
+pub mod md5_test;
\ No newline at end of file
@@ -0,0 +1,88 @@
+use wovocrypt::hash::Hasher;
+use wovocrypt::hash::prelude::Ripemd160;
+
+use crate::common::{HashGoldData, assert_hash_eq};
+use crate::common::utils::{stress_test_hasher};
+
+// test vectors from the RIPEMD-160 home page
+const RIPEMD160_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: b"",
+        expected: "9c1185a5c5e9fc54612808977ee8f548b2258d31",
+    },
+    HashGoldData {
+        message: b"a",
+        expected: "0bdc9d2d256b3ee9daae347be6f4dc835a467ffe",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc",
+    },
+    HashGoldData {
+        message: b"message digest",
+        expected: "5d0689ef49d2fae572b881b123a85ffa21595f36",
+    },
+    HashGoldData {
+        message: b"abcdefghijklmnopqrstuvwxyz",
+        expected: "f71c27109c692c1b56bbdceb5b9d2865b3708dbc",
+    },
+    HashGoldData {
+        message: b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        expected: "12a053384a9c0c88e405a06c27dcf49ada62eb2b",
+    },
+    HashGoldData {
+        message: b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+        expected: "b0e20b6e3116640286ed3a87a5713079b21f5189",
+    },
+    HashGoldData {
+        message: b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+        expected: "9b752e45573d4b39f4dbd3323cab82bf63326bfb",
+    },
+];
+
+#[test]
+fn test_ripemd160_gold_data() {
+    for data in RIPEMD160_GOLD_DATA {
+        assert_hash_eq!(Ripemd160, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_ripemd160_million_a() {
+    let message = vec![b'a'; 1000000];
+    let result = Ripemd160::compute(&message);
+    let expected = "52783243c1697bdbe16d37f97f68f08325dc1528";
+    assert_eq!(hex::encode(result.as_ref()), expected);
+}
+
+#[test]
+fn test_ripemd160_multiple_blocks() {
+    let message = vec![b'x'; 200];
+    let result = Ripemd160::compute(&message);
+
+    let mut hasher = Ripemd160::default();
+    hasher.update(&message[..64]);
+    hasher.update(&message[64..100]);
+    hasher.update(&message[100..]);
+    let result2 = hasher.finalize();
+
+    assert_eq!(result.as_ref(), result2.as_ref());
+}
+
+#[cfg(feature = "hmac")]
+#[test]
+fn test_hmac_ripemd160() {
+    use wovocrypt::mac::Mac;
+    use wovocrypt::mac::prelude::HmacRipemd160;
+
+    // RFC 2286, test cases 1 and 2
+    let result = HmacRipemd160::compute(&[0x0b; 20], b"Hi There");
+    assert_eq!(hex::encode(result.as_ref()), "24cb4bd67d20fc1a5d2ed7732dcc39377f0a5668");
+    let result = HmacRipemd160::compute(b"Jefe", b"what do ya want for nothing?");
+    assert_eq!(hex::encode(result.as_ref()), "dda6c0213a485a9e24f4742064a7f033b43c4069");
+}
+
+#[test]
+fn test_ripemd160_stress() {
+    stress_test_hasher::<Ripemd160>(1000);
+}
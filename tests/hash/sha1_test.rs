@@ -0,0 +1,221 @@
+use wovocrypt::error::SymcError;
+use wovocrypt::hash::Hasher;
+use wovocrypt::hash::prelude::{Sha1, Sha1Checked};
+
+use crate::common::{HashGoldData, assert_hash_eq};
+use crate::common::utils::{stress_test_hasher};
+
+const SHA1_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: b"",
+        expected: "da39a3ee5e6b4b0d3255bfef95601890afd80709",
+    },
+    HashGoldData {
+        message: b"a",
+        expected: "86f7e437faa5a7fce15d1ddcb9eaeaea377667b8",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "a9993e364706816aba3e25717850c26c9cd0d89d",
+    },
+    HashGoldData {
+        message: b"message digest",
+        expected: "c12252ceda8be8994d5fa0290a47231c1d16aae3",
+    },
+    HashGoldData {
+        message: b"abcdefghijklmnopqrstuvwxyz",
+        expected: "32d10c7b8cf96570ca04ce37f2a19d84240d3a89",
+    },
+    HashGoldData {
+        message: b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+        expected: "761c457bf73b14d27e9e9265c46f4b4dda11f940",
+    },
+    HashGoldData {
+        message: b"12345678901234567890123456789012345678901234567890123456789012345678901234567890",
+        expected: "50abf5706a150990a08b2c5ea40fa0e585554732",
+    },
+];
+
+// SHA-1 is a Shambles chosen-prefix collision (https://sha-mbles.github.io)
+const SHAMBLES_1: &str = concat!(
+    "99040d047fe81780012000ff4b65792069732070617274206f66206120636f6c",
+    "6c6973696f6e212049742773206120747261702179c61af0afcc054515d9274e",
+    "7307624b1dc7fb23988bb8de8b575dba7b9eab31c1674b6d974378a827732ff5",
+    "851c76a2e60772b5a47ce1eac40bb993c12d8c70e24a4f8d5fcdedc1b32c9cf1",
+    "9e31af2429759d42e4dfdb31719f587623ee552939b6dcdc459fca53553b70f8",
+    "7ede30a247ea3af6c759a2f20b320d760db64ff479084fd3ccb3cdd48362d96a",
+    "9c430617caff6c36c637e53fde28417f626fec54ed7943a46e5f5730f2bb38fb",
+    "1df6e0090010d00e24ad78bf92641993608e8d158a789f34c46fe1e6027f35a4",
+    "cbfb827076c50eca0e8b7cca69bb2c2b790259f9bf9570dd8d4437a3115faff7",
+    "c3cac09ad25266055c27104755178eaeff825a2caa2acfb5de64ce7641dc59a5",
+    "41a9fc9c756756e2e23dc713c8c24c9790aa6b0e38a7f55f14452a1ca2850ddd",
+    "9562fd9a18ad42496aa97008f74672f68ef461eb88b09933d626b4f918749cc0",
+    "27fddd6c425fc4216835d0134d15285bab2cb784a4f7cbb4fb514d4bf0f6237c",
+    "f00a9e9f132b9a066e6fd17f6c42987478586ff651af96747fb426b9872b9a88",
+    "e4063f59bb334cc00650f83a80c42751b71974d300fc2819a2e8f1e32c1b51cb",
+    "18e6bfc4db9baef675d4aaf5b1574a047f8f6dd2ec153a93412293974d928f88",
+    "ced9363cfef97ce2e742bf34c96b8ef3875676fea5cca8e5f7dea0bab2413d4d",
+    "e00ee71ee01f162bdb6d1eafd925e6aebaae6a354ef17cf205a404fbdb12fc45",
+    "4d41fdd95cf2459664a2ad032d1da60a73264075d7f1e0d6c1403ae7a0d861df",
+    "3fe5707188dd5e07d1589b9f8b6630553f8fc352b3e0c27da80bddba4c64020d",
+);
+
+const SHAMBLES_2: &str = concat!(
+    "99030d047fe81780011800ff50726163746963616c205348412d312063686f73",
+    "656e2d70726566697820636f6c6c6973696f6e211d276c6ba661e1040e1f7d76",
+    "7f076249ddc7fb332c8bb8c2b7575dbec79eab2be1674b7db34378b4cb732fe1",
+    "891c76a0260772a5107ce1f6e80bb9977d2d8c68524a4f9d5fcdedcd0b2c9ce1",
+    "9231af26e9759d5250dfdb2d4d9f58729fee553319b6dccc619fca4fb93b70ec",
+    "72de30a087ea3ae67359a2ee27320d72b1b64fecc9084fc3ccb3cdd83b62d97a",
+    "904306150aff6c267237e523e228417bde6fec4ecd7943b44a5f572c1ebb38ef",
+    "11f6e00bc010d01e90ad78a3be641997dc8e8d0d3a789f24c46fe1eaba7f35b4",
+    "c7fb8272b6c50edaba8b7cd655bb2c2fc50259e39f9570cda94437bffd5fafe3",
+    "cfcac09812526615e827105b79178eaa43825a341a2acfa5de64ce7af9dc59b5",
+    "4da9fc9eb56756f2563dc70ff4c24c932caa6b1418a7f54f30452a004e850dc9",
+    "9962fd98d8ad4259dea97014db4672f232f461f338b09923d626b4f5a0749cd0",
+    "2bfddd6e825fc431dc35d00f7115285f172cb79e84f7cba4df514d571cf62368",
+    "fc0a9e9dd32b9a16da6fd16340429870c4586feee1af96647fb426b53f2b9a98",
+    "e8063f5b7b334cd0b250f826bcc427550b1974c920fc280986e8f1ffc01b51df",
+    "14e6bfc61b9baee6c1d4aae99d574a00c38f6dca5c153a834122939bf5928f98",
+    "c2d9363e3ef97cf25342bf28f56b8ef73b5676e485cca8f5d3dea0a65e413d59",
+    "ec0ee71c201f163b6f6d1eb3f525e6aa06ae6a2dfef17ce205a404f76312fc55",
+    "4141fddb9cf24586d0a2ad1f111da60ecf26406ff7f1e0c6e5403afb4cd861cb",
+    "33e5707348dd5e1765589b83a7663051838fc34a03e0c26da80bddb6f464021d",
+);
+
+// first 320 bytes of the SHAttered PDFs (https://shattered.io), an identical-prefix collision
+const SHATTERED_1: &str = concat!(
+    "255044462d312e330a25e2e3cfd30a0a0a312030206f626a0a3c3c2f57696474",
+    "682032203020522f4865696768742033203020522f547970652034203020522f",
+    "537562747970652035203020522f46696c7465722036203020522f436f6c6f72",
+    "53706163652037203020522f4c656e6774682038203020522f42697473506572",
+    "436f6d706f6e656e7420383e3e0a73747265616d0affd8fffe00245348412d31",
+    "20697320646561642121212121852fec092339759c39b1a1c63c4c97e1fffe01",
+    "7346dc9166b67e118f029ab621b2560ff9ca67cca8c7f85ba84c79030c2b3de2",
+    "18f86db3a90901d5df45c14f26fedfb3dc38e96ac22fe7bd728f0e45bce046d2",
+    "3c570feb141398bb552ef5a0a82be331fea48037b8b5d71f0e332edf93ac3500",
+    "eb4ddc0decc1a864790c782c76215660dd309791d06bd0af3f98cda4bc4629b1",
+);
+
+const SHATTERED_2: &str = concat!(
+    "255044462d312e330a25e2e3cfd30a0a0a312030206f626a0a3c3c2f57696474",
+    "682032203020522f4865696768742033203020522f547970652034203020522f",
+    "537562747970652035203020522f46696c7465722036203020522f436f6c6f72",
+    "53706163652037203020522f4c656e6774682038203020522f42697473506572",
+    "436f6d706f6e656e7420383e3e0a73747265616d0affd8fffe00245348412d31",
+    "20697320646561642121212121852fec092339759c39b1a1c63c4c97e1fffe01",
+    "7f46dc93a6b67e013b029aaa1db2560b45ca67d688c7f84b8c4c791fe02b3df6",
+    "14f86db1690901c56b45c1530afedfb76038e972722fe7ad728f0e4904e046c2",
+    "30570fe9d41398abe12ef5bc942be33542a4802d98b5d70f2a332ec37fac3514",
+    "e74ddc0f2cc1a874cd0c78305a21566461309789606bd0bf3f98cda8044629a1",
+);
+
+#[test]
+fn test_sha1_gold_data() {
+    for data in SHA1_GOLD_DATA {
+        assert_hash_eq!(Sha1, data.message, data.expected);
+        assert_hash_eq!(Sha1Checked, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_sha1_million_a() {
+    let message = vec![b'a'; 1000000];
+    let expected = "34aa973cd4c4daa4f61eeb2bdbad27316534016f";
+    assert_eq!(hex::encode(Sha1::compute(&message).as_ref()), expected);
+    assert_eq!(hex::encode(Sha1Checked::compute(&message).as_ref()), expected);
+}
+
+#[test]
+fn test_sha1_multi_update() {
+    let mut hasher = Sha1::default();
+    hasher.update(b"a");
+    hasher.update(b"b");
+    hasher.update(b"c");
+    let result = hasher.finalize();
+    let expected = "a9993e364706816aba3e25717850c26c9cd0d89d";
+    assert_eq!(hex::encode(result.as_ref()), expected);
+}
+
+#[test]
+fn test_sha1_multiple_blocks() {
+    let message = vec![b'x'; 200];
+    let result = Sha1::compute(&message);
+
+    let mut hasher = Sha1::default();
+    hasher.update(&message[..64]);
+    hasher.update(&message[64..128]);
+    hasher.update(&message[128..]);
+    let result2 = hasher.finalize();
+
+    assert_eq!(result.as_ref(), result2.as_ref());
+}
+
+#[test]
+fn test_sha1_shambles_collision() {
+    let m1 = hex::decode(SHAMBLES_1).unwrap();
+    let m2 = hex::decode(SHAMBLES_2).unwrap();
+    assert_ne!(m1, m2);
+
+    let expected = "8ac60ba76f1999a1ab70223f225aefdc78d4ddc0";
+    assert_eq!(hex::encode(Sha1::compute(&m1).as_ref()), expected);
+    assert_eq!(hex::encode(Sha1::compute(&m2).as_ref()), expected);
+
+    let mut hasher = Sha1Checked::default();
+    hasher.update(&m1);
+    assert!(hasher.collision_detected());
+    assert!(matches!(hasher.clone().finalize_checked(), Err(SymcError::CollisionDetected)));
+    assert_eq!(hex::encode(hasher.finalize().as_ref()), "4f3d9be4a472c4dae83c6314aa6c36a064c1fd14");
+
+    let mut hasher = Sha1Checked::default();
+    hasher.update(&m2);
+    assert!(hasher.collision_detected());
+    assert_eq!(hex::encode(hasher.finalize().as_ref()), "9ed5d77a4f48be1dbf3e9e15650733eb850897f2");
+}
+
+#[test]
+fn test_sha1_shattered_collision() {
+    let m1 = hex::decode(SHATTERED_1).unwrap();
+    let m2 = hex::decode(SHATTERED_2).unwrap();
+    assert_eq!(Sha1::compute(&m1).as_ref(), Sha1::compute(&m2).as_ref());
+
+    for message in [&m1, &m2] {
+        let mut hasher = Sha1Checked::default();
+        for chunk in message.chunks(7) {
+            hasher.update(chunk);
+        }
+        assert!(hasher.collision_detected());
+        assert!(matches!(hasher.finalize_checked(), Err(SymcError::CollisionDetected)));
+    }
+    assert_ne!(Sha1Checked::compute(&m1).as_ref(), Sha1Checked::compute(&m2).as_ref());
+}
+
+#[test]
+fn test_sha1_checked_reset() {
+    let mut hasher = Sha1Checked::default();
+    hasher.update(&hex::decode(SHAMBLES_1).unwrap());
+    assert!(hasher.collision_detected());
+    hasher.reset();
+    assert!(!hasher.collision_detected());
+    hasher.update(b"abc");
+    assert_eq!(hex::encode(hasher.finalize_checked().ok().unwrap().as_ref()), "a9993e364706816aba3e25717850c26c9cd0d89d");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_sha1_vec_methods() {
+    let result_vec = Sha1::compute(b"abc").as_ref().to_vec();
+    let mut hasher = Sha1::default();
+    hasher.update(b"abc");
+    let finalize_vec = hasher.clone().finalize_vec();
+    let reset_vec = hasher.finalize_and_reset_vec();
+
+    assert_eq!(result_vec, finalize_vec);
+    assert_eq!(result_vec, reset_vec);
+}
+
+#[test]
+fn test_sha1_stress() {
+    stress_test_hasher::<Sha1>(1000);
+    stress_test_hasher::<Sha1Checked>(100);
+}
@@ -84,6 +84,12 @@ fn test_sha512_vec_methods() {
     assert_eq!(result_vec, reset_vec);
 }
 
+#[test]
+fn test_sha384_output_size() {
+    assert_eq!(Sha384::OUTPUT_SIZE, 48);
+    assert_eq!(Sha384::compute(b"abc").as_ref().len(), Sha384::OUTPUT_SIZE);
+}
+
 #[test]
 fn test_sha512_stress() {
     stress_test_hasher::<Sha384>(1000);
@@ -0,0 +1,216 @@
+use wovocrypt::hash::Hasher;
+
+use crate::common::{HashGoldData, assert_hash_eq};
+use crate::common::utils::{stress_test_hasher};
+
+#[cfg(feature = "sha512-224")]
+mod sha512_224 {
+    use super::*;
+    use wovocrypt::hash::prelude::Sha512_224;
+
+    const SHA512_224_GOLD_DATA: &[HashGoldData] = &[
+        HashGoldData {
+            message: b"",
+            expected: "6ed0dd02806fa89e25de060c19d3ac86cabb87d6a0ddd05c333b84f4",
+        },
+        HashGoldData {
+            message: b"a",
+            expected: "d5cdb9ccc769a5121d4175f2bfdd13d6310e0d3d361ea75d82108327",
+        },
+        HashGoldData {
+            message: b"abc",
+            expected: "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa",
+        },
+        HashGoldData {
+            message: b"message digest",
+            expected: "ad1a4db188fe57064f4f24609d2a83cd0afb9b398eb2fcaeaae2c564",
+        },
+        HashGoldData {
+            message: b"abcdefghijklmnopqrstuvwxyz",
+            expected: "ff83148aa07ec30655c1b40aff86141c0215fe2a54f767d3f38743d8",
+        },
+        HashGoldData {
+            message: b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+            expected: "a8b4b9174b99ffc67d6f49be9981587b96441051e16e6dd036b140d3",
+        },
+        HashGoldData {
+            message: b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+            expected: "23fec5bb94d60b23308192640b0c453335d664734fe40e7268674af9",
+        },
+    ];
+
+    #[test]
+    fn test_sha512_224_gold_data() {
+        for data in SHA512_224_GOLD_DATA {
+            assert_hash_eq!(Sha512_224, data.message, data.expected);
+        }
+    }
+
+    #[test]
+    fn test_sha512_224_million_a() {
+        let message = vec![b'a'; 1000000];
+        let result = Sha512_224::compute(&message);
+        let expected = "37ab331d76f0d36de422bd0edeb22a28accd487b7a8453ae965dd287";
+        assert_eq!(hex::encode(result.as_ref()), expected);
+    }
+
+    #[test]
+    fn test_sha512_224_multiple_blocks() {
+        let message = vec![b'x'; 300];
+        let result = Sha512_224::compute(&message);
+
+        let mut hasher = Sha512_224::default();
+        hasher.update(&message[..128]);
+        hasher.update(&message[128..200]);
+        hasher.update(&message[200..]);
+        let result2 = hasher.finalize();
+
+        assert_eq!(result.as_ref(), result2.as_ref());
+    }
+
+    #[test]
+    fn test_sha512_224_stress() {
+        stress_test_hasher::<Sha512_224>(1000);
+    }
+}
+
+#[cfg(feature = "sha512-256")]
+mod sha512_256 {
+    use super::*;
+    use wovocrypt::hash::prelude::Sha512_256;
+
+    const SHA512_256_GOLD_DATA: &[HashGoldData] = &[
+        HashGoldData {
+            message: b"",
+            expected: "c672b8d1ef56ed28ab87c3622c5114069bdd3ad7b8f9737498d0c01ecef0967a",
+        },
+        HashGoldData {
+            message: b"a",
+            expected: "455e518824bc0601f9fb858ff5c37d417d67c2f8e0df2babe4808858aea830f8",
+        },
+        HashGoldData {
+            message: b"abc",
+            expected: "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23",
+        },
+        HashGoldData {
+            message: b"message digest",
+            expected: "0cf471fd17ed69d990daf3433c89b16d63dec1bb9cb42a6094604ee5d7b4e9fb",
+        },
+        HashGoldData {
+            message: b"abcdefghijklmnopqrstuvwxyz",
+            expected: "fc3189443f9c268f626aea08a756abe7b726b05f701cb08222312ccfd6710a26",
+        },
+        HashGoldData {
+            message: b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+            expected: "cdf1cc0effe26ecc0c13758f7b4a48e000615df241284185c39eb05d355bb9c8",
+        },
+        HashGoldData {
+            message: b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+            expected: "3928e184fb8690f840da3988121d31be65cb9d3ef83ee6146feac861e19b563a",
+        },
+    ];
+
+    #[test]
+    fn test_sha512_256_gold_data() {
+        for data in SHA512_256_GOLD_DATA {
+            assert_hash_eq!(Sha512_256, data.message, data.expected);
+        }
+    }
+
+    #[test]
+    fn test_sha512_256_million_a() {
+        let message = vec![b'a'; 1000000];
+        let result = Sha512_256::compute(&message);
+        let expected = "9a59a052930187a97038cae692f30708aa6491923ef5194394dc68d56c74fb21";
+        assert_eq!(hex::encode(result.as_ref()), expected);
+    }
+
+    #[test]
+    fn test_sha512_256_reset() {
+        let mut hasher = Sha512_256::default();
+        hasher.update(b"garbage");
+        hasher.reset();
+        hasher.update(b"abc");
+        let expected = "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23";
+        assert_eq!(hex::encode(hasher.finalize().as_ref()), expected);
+    }
+
+    #[test]
+    fn test_sha512_256_stress() {
+        stress_test_hasher::<Sha512_256>(1000);
+    }
+}
+
+#[cfg(feature = "sha512t")]
+mod sha512_t {
+    use wovocrypt::error::SymcError;
+    use wovocrypt::hash::prelude::{Sha512T, sha512_t_iv};
+
+    struct Sha512TGoldData {
+        t: usize,
+        message: &'static [u8],
+        expected: &'static str,
+    }
+
+    const SHA512_T_GOLD_DATA: &[Sha512TGoldData] = &[
+        Sha512TGoldData { t: 8, message: b"abc", expected: "c5" },
+        Sha512TGoldData { t: 160, message: b"abc", expected: "0a74fe1b43eecbea62182658da8a68b8acef25bf" },
+        Sha512TGoldData { t: 160, message: &[b'a'; 200], expected: "a7d15c94cda93503e37137e5f60bf1e86ba80e1e" },
+        Sha512TGoldData { t: 224, message: b"abc", expected: "4634270f707b6a54daae7530460842e20e37ed265ceee9a43e8924aa" },
+        Sha512TGoldData { t: 248, message: b"abc", expected: "c693a3c7e71d396fe002f157b5019260ed3535fe8e49917f2a1875d3c0bbeb" },
+        Sha512TGoldData { t: 256, message: b"abc", expected: "53048e2681941ef99b2e29b76b4c7dabe4c2d0c634fc6d46e0e2f13107e7af23" },
+        Sha512TGoldData {
+            t: 504,
+            message: &[b'a'; 200],
+            expected: "d72c39885da25635802200496eae835dfe3c81fdee122ab4dfe04b68dbe62949ae0857b2ac2ae1d0cd4c3671807f2981d06862f1d1eb173205523968609184",
+        },
+    ];
+
+    #[test]
+    fn test_sha512_t_gold_data() {
+        for data in SHA512_T_GOLD_DATA {
+            let mut hasher = Sha512T::new(data.t).unwrap();
+            assert_eq!(hasher.output_size(), data.t / 8);
+            hasher.update(data.message);
+            let mut output = vec![0u8; data.t / 8];
+            hasher.finalize_into(&mut output).unwrap();
+            assert_eq!(hex::encode(&output), data.expected, "t = {}", data.t);
+        }
+    }
+
+    #[test]
+    fn test_sha512_t_iv() {
+        let iv = sha512_t_iv(256).unwrap();
+        assert_eq!(iv[0], 0x22312194fc2bf72c);
+        assert_eq!(iv[7], 0x0eb72ddc81c52ca2);
+
+        let iv = sha512_t_iv(224).unwrap();
+        assert_eq!(iv[0], 0x8c3d37c819544da2);
+        assert_eq!(iv[7], 0x1112e6ad91d692a1);
+
+        let iv = sha512_t_iv(1).unwrap();
+        assert_eq!(iv[0], 0xc39b53900ddd8237);
+    }
+
+    #[test]
+    fn test_sha512_t_errors() {
+        assert_eq!(Sha512T::new(384).err(), Some(SymcError::InvalidLength));
+        assert_eq!(Sha512T::new(512).err(), Some(SymcError::InvalidLength));
+        assert_eq!(Sha512T::new(0).err(), Some(SymcError::InvalidLength));
+        assert_eq!(Sha512T::new(255).err(), Some(SymcError::InvalidLength));
+        assert_eq!(sha512_t_iv(384), Err(SymcError::InvalidLength));
+        assert!(sha512_t_iv(255).is_ok());
+
+        let hasher = Sha512T::new(256).unwrap();
+        let mut output = [0u8; 31];
+        assert_eq!(hasher.finalize_into(&mut output), Err(SymcError::InvalidLength));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_sha512_t_vec_methods() {
+        let mut hasher = Sha512T::new(160).unwrap();
+        hasher.update(b"abc");
+        assert_eq!(hex::encode(hasher.finalize_vec()), "0a74fe1b43eecbea62182658da8a68b8acef25bf");
+    }
+}
@@ -0,0 +1,157 @@
+use wovocrypt::hash::{ExtendableOutput, XofReader};
+use wovocrypt::hash::prelude::*;
+
+struct XofGoldData {
+    message: &'static [u8],
+    expected: &'static str,
+}
+
+#[cfg(feature = "shake128")]
+const SHAKE128_GOLD_DATA: &[XofGoldData] = &[
+    XofGoldData {
+        message: b"",
+        expected: "7f9c2ba4e88f827d616045507605853ed73b8093f6efbc88eb1a6eacfa66ef26",
+    },
+    XofGoldData {
+        message: b"abc",
+        expected: "5881092dd818bf5cf8a3ddb793fbcba74097d5c526a6d35f97b83351940f2cc844c50af32acd3f2cdd066568706f509bc1bdde58295dae3f891a9a0fca578378",
+    },
+    XofGoldData {
+        message: b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        expected: "1a96182b50fb8c7e74e0a707788f55e98209b8d91fade8f32f8dd5cff7bf21f54ee5f19550825a6e070030519e944263ac1c6765287065621f9fcb3201723e3223b63a46c2938aa953ba8401d0ea77b8d26490775566407b95673c0f4cc1ce9fd966148d7efdff26bbf9f48a21c6da35bfaa545654f70ae586ff10131420771483ec92edab408c767bf4c5b4fffaa80c8ca214d84c4dc700d0c50630b2ffc3793ea4d87258b4c9548c5485a5ca666ef73fbd816d418aea6395b503addd9b150f9e0663325f01e551",
+    },
+    XofGoldData {
+        message: b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        expected: "7b6df6ff181173b6d7898d7ff63fb07b7c237daf471a5ae5602adbccef9ccf4b37e06b4a3543164ffbe0d0557c02f9b25ad434005526d88ca04a6094b93ee57a55d5ea66e744bd391f8f52baf4e031d9e60e5ca32a0ed162bb89fc908097984548796652952dd4737d2a234a401f4857f3d1866efa736fd6a8f7c0b5d02ab06e5f821b2cc8cb8b4606fb15b9527cce5c3ec02c65cd1cdb5c81bd67686ebdd3b5b3fcffb123ca8ca63df53537042f64637ab595f06e865ebaa322b253bfa533a056b46c7a63e21e569ff3cb26a976ccb749104adfce5f8db6751bbdbf0d898c22dad8e85523376744b889a7e68fab7e68699cd63bcc3d40e6b8cbb39e8e8a0931a654566e47b8cc1b43e5dfcf09e3d50433454c908a33897537485d54aa7377ec70b4a2912940316bdce41a054f73a9001eed6dcaf91764e4236b10dff5134b0e68908b99bf0fd1d65f5c798e430ba1473d5826cf5811d39d443c7031c4c81991712f2f648416940dc32450295dbad221f9cbbbca4b0fca40deb9f37ce03eeec584ad7ba5f546633f79c92d2ed0adc5f1273f18bca35b8a2aa74fdb88ba87f36ea56acafa03f416864632a3f4f4f259334945a0a5f4d848696966fce6abc54a4b4d3795c89cdce5297f9df391794a8d992407be93dbe182703a83560ca2ed8d1ba03c63a3d8c6342504c11f8d40b7c65b8e4777850f2ce126716df79725bd1c83",
+    },
+];
+
+#[cfg(feature = "shake128")]
+#[test]
+fn test_shake128_gold_data() {
+    for data in SHAKE128_GOLD_DATA {
+        let expected = hex::decode(data.expected).unwrap();
+        let mut output = vec![0u8; expected.len()];
+        Shake128::compute_into(data.message, &mut output);
+        assert_eq!(output, expected);
+    }
+}
+
+#[cfg(feature = "shake128")]
+#[test]
+fn test_shake128_incremental_squeeze() {
+    for data in SHAKE128_GOLD_DATA {
+        let expected = hex::decode(data.expected).unwrap();
+
+        let mut xof = Shake128::default();
+        for chunk in data.message.chunks(3) {
+            xof.update(chunk);
+        }
+        let mut reader = xof.finalize_xof();
+        let mut output = vec![0u8; expected.len()];
+        for chunk in output.chunks_mut(17) {
+            reader.squeeze(chunk);
+        }
+        assert_eq!(output, expected);
+    }
+}
+
+#[cfg(all(feature = "shake128", feature = "alloc"))]
+#[test]
+fn test_shake128_vec_and_reset() {
+    let data = &SHAKE128_GOLD_DATA[1];
+    let expected = hex::decode(data.expected).unwrap();
+
+    let mut xof = Shake128::default();
+    xof.update(b"discarded");
+    xof.reset();
+    xof.update(data.message);
+    let mut reader = xof.finalize_xof_and_reset();
+    assert_eq!(reader.squeeze_vec(expected.len()), expected);
+
+    xof.update(data.message);
+    assert_eq!(xof.finalize_xof_vec(expected.len()), expected);
+}
+
+#[cfg(all(feature = "shake128", feature = "alloc"))]
+#[test]
+fn test_shake128_million_a() {
+    let mut xof = Shake128::default();
+    xof.update(&vec![b'a'; 1000000]);
+    assert_eq!(hex::encode(xof.finalize_xof_vec(32)), "9d222c79c4ff9d092cf6ca86143aa411e369973808ef97093255826c5572ef58");
+}
+
+#[cfg(feature = "shake256")]
+const SHAKE256_GOLD_DATA: &[XofGoldData] = &[
+    XofGoldData {
+        message: b"",
+        expected: "46b9dd2b0ba88d13233b3feb743eeb243fcd52ea62b81b82b50c27646ed5762f",
+    },
+    XofGoldData {
+        message: b"abc",
+        expected: "483366601360a8771c6863080cc4114d8db44530f8f1e1ee4f94ea37e78b5739d5a15bef186a5386c75744c0527e1faa9f8726e462a12a4feb06bd8801e751e4",
+    },
+    XofGoldData {
+        message: b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq",
+        expected: "4d8c2dd2435a0128eefbb8c36f6f87133a7911e18d979ee1ae6be5d4fd2e332940d8688a4e6a59aa8060f1f9bc996c05aca3c696a8b66279dc672c740bb224ec37a92b65db0539c0203455f51d97cce4cfc49127d7260afc673af208baf19be21233f3debe78d06760cfa551ee1e079141d49dd3ef7e182b1524df82ea1cefe1c6c3966175f0228d35887cd9f09b05457f6d952f9b3b32464e0b3c54dcc13efdb4c54e29cdb4088faf482cddd0a5e6b822f5a80d0cc78d4cc90131906fd5159eb5142e155024b624",
+    },
+    XofGoldData {
+        message: b"abcdefghbcdefghicdefghijdefghijkefghijklfghijklmghijklmnhijklmnoijklmnopjklmnopqklmnopqrlmnopqrsmnopqrstnopqrstu",
+        expected: "98be04516c04cc73593fef3ed0352ea9f6443942d6950e29a372a681c3deaf4535423709b02843948684e029010badcc0acd8303fc85fdad3eabf4f78cae165635f57afd28810fc22abf63df55c5ead450fdfb64209010e982102aa0b5f0a4b4753b53eb4b5319c06986f5aac5cc247256d06b05a273d7ef8d31864777d488d541451ed82a38926582deb65d40ddb959b79dbe933635f9f3e2ae57f7c6aefc4d5bd7f230070fc2e9e2357d4eb39cee4bd064c4a33f35d5f652774fe941300cce4e800b127d54ba3548986db411d08dee19a295c1e9219e8c76a292bae5cfecf54785b37044bac9deef0f129c666b99719164d5f62ccef52b2ae53e4e8e971646f3e8cdcb2887abceba41829d88cf60cb35bbcaa329eb854b13636d14cc5d03688eb5deb122cbf98b3514d02967fe8f404c7f53dda530e7199260a090e61777f52fdc0327ea450a49ad667c7670a59c39a25743c30768b7b50f4b0aead4cef9c29b8a56d8fea34b0e2ed26ff944be0d9937fa6871c97c6501f2878e21f54fe7d3443dee89112f68b10c7c8d8516c2ed087acaab0ddd64a1926265603cb65a022f006b701988d590e21a8d6c91839b2fb8902cf4357c4f01433f2683c3cfb1d522a377b3688e071e6c2c3f899fc83d929f8a1d69c818aa715d0d381565e0708d68142038ea56bb183f5fd9395319afe76b057020d2123895097154ca7338a84912",
+    },
+];
+
+#[cfg(feature = "shake256")]
+#[test]
+fn test_shake256_gold_data() {
+    for data in SHAKE256_GOLD_DATA {
+        let expected = hex::decode(data.expected).unwrap();
+        let mut output = vec![0u8; expected.len()];
+        Shake256::compute_into(data.message, &mut output);
+        assert_eq!(output, expected);
+    }
+}
+
+#[cfg(feature = "shake256")]
+#[test]
+fn test_shake256_incremental_squeeze() {
+    for data in SHAKE256_GOLD_DATA {
+        let expected = hex::decode(data.expected).unwrap();
+
+        let mut xof = Shake256::default();
+        for chunk in data.message.chunks(3) {
+            xof.update(chunk);
+        }
+        let mut reader = xof.finalize_xof();
+        let mut output = vec![0u8; expected.len()];
+        for chunk in output.chunks_mut(17) {
+            reader.squeeze(chunk);
+        }
+        assert_eq!(output, expected);
+    }
+}
+
+#[cfg(all(feature = "shake256", feature = "alloc"))]
+#[test]
+fn test_shake256_vec_and_reset() {
+    let data = &SHAKE256_GOLD_DATA[1];
+    let expected = hex::decode(data.expected).unwrap();
+
+    let mut xof = Shake256::default();
+    xof.update(b"discarded");
+    xof.reset();
+    xof.update(data.message);
+    let mut reader = xof.finalize_xof_and_reset();
+    assert_eq!(reader.squeeze_vec(expected.len()), expected);
+
+    xof.update(data.message);
+    assert_eq!(xof.finalize_xof_vec(expected.len()), expected);
+}
+
+#[cfg(all(feature = "shake256", feature = "alloc"))]
+#[test]
+fn test_shake256_million_a() {
+    let mut xof = Shake256::default();
+    xof.update(&vec![b'a'; 1000000]);
+    assert_eq!(hex::encode(xof.finalize_xof_vec(32)), "3578a7a4ca9137569cdf76ed617d31bb994fca9c1bbf8b184013de8234dfd13a");
+}
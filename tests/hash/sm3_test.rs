@@ -0,0 +1,71 @@
+use wovocrypt::hash::Hasher;
+use wovocrypt::hash::prelude::Sm3;
+
+use crate::common::{HashGoldData, assert_hash_eq};
+use crate::common::utils::{stress_test_hasher};
+
+// "abc" and "abcd" * 16 are the examples of GB/T 32905-2016, the rest are from OpenSSL
+const SM3_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: b"",
+        expected: "1ab21d8355cfa17f8e61194831e81a8f22bec8c728fefb747ed035eb5082aa2b",
+    },
+    HashGoldData {
+        message: b"a",
+        expected: "623476ac18f65a2909e43c7fec61b49c7e764a91a18ccb82f1917a29c86c5e88",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "66c7f0f462eeedd9d1f2d46bdc10e4e24167c4875cf2f7a2297da02b8f4ba8e0",
+    },
+    HashGoldData {
+        message: b"message digest",
+        expected: "c522a942e89bd80d97dd666e7a5531b36188c9817149e9b258dfe51ece98ed77",
+    },
+    HashGoldData {
+        message: b"abcdefghijklmnopqrstuvwxyz",
+        expected: "b80fe97a4da24afc277564f66a359ef440462ad28dcc6d63adb24d5c20a61595",
+    },
+    HashGoldData {
+        message: b"abcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcdabcd",
+        expected: "debe9ff92275b8a138604889c18e5a4d6fdb70e5387e5765293dcba39c0c5732",
+    },
+    HashGoldData {
+        message: b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+        expected: "2971d10c8842b70c979e55063480c50bacffd90e98e2e60d2512ab8abfdfcec5",
+    },
+];
+
+#[test]
+fn test_sm3_gold_data() {
+    for data in SM3_GOLD_DATA {
+        assert_hash_eq!(Sm3, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_sm3_million_a() {
+    let message = vec![b'a'; 1000000];
+    let result = Sm3::compute(&message);
+    let expected = "c8aaf89429554029e231941a2acc0ad61ff2a5acd8fadd25847a3a732b3b02c3";
+    assert_eq!(hex::encode(result.as_ref()), expected);
+}
+
+#[test]
+fn test_sm3_multiple_blocks() {
+    let message = vec![b'x'; 200];
+    let result = Sm3::compute(&message);
+
+    let mut hasher = Sm3::default();
+    hasher.update(&message[..64]);
+    hasher.update(&message[64..100]);
+    hasher.update(&message[100..]);
+    let result2 = hasher.finalize();
+
+    assert_eq!(result.as_ref(), result2.as_ref());
+}
+
+#[test]
+fn test_sm3_stress() {
+    stress_test_hasher::<Sm3>(1000);
+}
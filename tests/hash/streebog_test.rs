@@ -0,0 +1,127 @@
+use wovocrypt::hash::Hasher;
+use wovocrypt::hash::prelude::{Streebog256, Streebog512};
+
+use crate::common::{HashGoldData, assert_hash_eq};
+use crate::common::utils::{stress_test_hasher};
+
+// RFC 6986, Section 10, M1
+const M1: &[u8] = b"012345678901234567890123456789012345678901234567890123456789012";
+
+// RFC 6986, Section 10, M2, in the byte order it is hashed
+const M2: &[u8] = &[
+    0xd1, 0xe5, 0x20, 0xe2, 0xe5, 0xf2, 0xf0, 0xe8, 0x2c, 0x20, 0xd1, 0xf2, 0xf0, 0xe8, 0xe1, 0xee,
+    0xe6, 0xe8, 0x20, 0xe2, 0xed, 0xf3, 0xf6, 0xe8, 0x2c, 0x20, 0xe2, 0xe5, 0xfe, 0xf2, 0xfa, 0x20,
+    0xf1, 0x20, 0xec, 0xee, 0xf0, 0xff, 0x20, 0xf1, 0xf2, 0xf0, 0xe5, 0xeb, 0xe0, 0xec, 0xe8, 0x20,
+    0xed, 0xe0, 0x20, 0xf5, 0xf0, 0xe0, 0xe1, 0xf0, 0xfb, 0xff, 0x20, 0xef, 0xeb, 0xfa, 0xea, 0xfb,
+    0x20, 0xc8, 0xe3, 0xee, 0xf0, 0xe5, 0xe2, 0xfb,
+];
+
+// M1 and M2 are from RFC 6986, the rest are from GnuTLS
+const STREEBOG256_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: M1,
+        expected: "9d151eefd8590b89daa6ba6cb74af9275dd051026bb149a452fd84e5e57b5500",
+    },
+    HashGoldData {
+        message: M2,
+        expected: "9dd2fe4e90409e5da87f53976d7405b0c0cac628fc669a741d50063c557e8f50",
+    },
+    HashGoldData {
+        message: b"",
+        expected: "3f539a213e97c802cc229d474c6aa32a825a360b2a933a949fd925208d9ce1bb",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "4e2919cf137ed41ec4fb6270c61826cc4fffb660341e0af3688cd0626d23b481",
+    },
+    HashGoldData {
+        message: b"message digest",
+        expected: "0d45451b2004234de7fbd289b89c665a494ffefe93c2ff6d6f99677c99086bff",
+    },
+    HashGoldData {
+        message: b"The quick brown fox jumps over the lazy dog",
+        expected: "3e7dea7f2384b6c5a3d0e24aaa29c05e89ddd762145030ec22c71a6db8b2c1f4",
+    },
+    HashGoldData {
+        message: &[0xff; 64],
+        expected: "964a5ab60286f106288743e2fe1a422d160898ca1bd535e831aa500cfe34d7e8",
+    },
+];
+
+const STREEBOG512_GOLD_DATA: &[HashGoldData] = &[
+    HashGoldData {
+        message: M1,
+        expected: "1b54d01a4af5b9d5cc3d86d68d285462b19abc2475222f35c085122be4ba1ffa00ad30f8767b3a82384c6574f024c311e2a481332b08ef7f41797891c1646f48",
+    },
+    HashGoldData {
+        message: M2,
+        expected: "1e88e62226bfca6f9994f1f2d51569e0daf8475a3b0fe61a5300eee46d961376035fe83549ada2b8620fcd7c496ce5b33f0cb9dddc2b6460143b03dabac9fb28",
+    },
+    HashGoldData {
+        message: b"",
+        expected: "8e945da209aa869f0455928529bcae4679e9873ab707b55315f56ceb98bef0a7362f715528356ee83cda5f2aac4c6ad2ba3a715c1bcd81cb8e9f90bf4c1c1a8a",
+    },
+    HashGoldData {
+        message: b"abc",
+        expected: "28156e28317da7c98f4fe2bed6b542d0dab85bb224445fcedaf75d46e26d7eb8d5997f3e0915dd6b7f0aab08d9c8beb0d8c64bae2ab8b3c8c6bc53b3bf0db728",
+    },
+    HashGoldData {
+        message: b"message digest",
+        expected: "96b52f322e3ecf6348d177608e2ddb084309c1642a94923c0bc50e41e4cc50e851d1dd94e4b7a35c30503caf87e3e2ac334e2c805adb99b5adb5443dd4ac23c8",
+    },
+    HashGoldData {
+        message: b"The quick brown fox jumps over the lazy dog",
+        expected: "d2b793a0bb6cb5904828b5b6dcfb443bb8f33efc06ad09368878ae4cdc8245b97e60802469bed1e7c21a64ff0b179a6a1e0bb74d92965450a0adab69162c00fe",
+    },
+    HashGoldData {
+        message: &[0xff; 64],
+        expected: "41629de677d7e8090c3cd70affe3300d1e1cfba2db97945ec37feb4e1375bc02a53f00370b7d715b07f37f93cac844efadbfd1b85f9ddae3de9656c0e95affc7",
+    },
+];
+
+#[test]
+fn test_streebog256_gold_data() {
+    for data in STREEBOG256_GOLD_DATA {
+        assert_hash_eq!(Streebog256, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_streebog512_gold_data() {
+    for data in STREEBOG512_GOLD_DATA {
+        assert_hash_eq!(Streebog512, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_streebog_million_a() {
+    let message = vec![b'a'; 1000000];
+    assert_eq!(
+        hex::encode(Streebog256::compute(&message).as_ref()),
+        "841af1a0b2f92a800fb1b7e4aabc8e48763153c448a0fc57c90ba830e130f152"
+    );
+    assert_eq!(
+        hex::encode(Streebog512::compute(&message).as_ref()),
+        "d396a40b126b1f324465bfa7aa159859ab33fac02dcdd4515ad231206396a266d0102367e4c544ef47d2294064e1a25342d0cd25ae3d904b45abb1425ae41095"
+    );
+}
+
+#[test]
+fn test_streebog_multiple_blocks() {
+    let message = vec![b'x'; 200];
+    let result = Streebog512::compute(&message);
+
+    let mut hasher = Streebog512::default();
+    hasher.update(&message[..64]);
+    hasher.update(&message[64..100]);
+    hasher.update(&message[100..]);
+    let result2 = hasher.finalize();
+
+    assert_eq!(result.as_ref(), result2.as_ref());
+}
+
+#[test]
+fn test_streebog_stress() {
+    stress_test_hasher::<Streebog256>(1000);
+    stress_test_hasher::<Streebog512>(1000);
+}
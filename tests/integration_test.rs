@@ -1,4 +1,5 @@
 mod common;
 mod hash;
 mod cipher;
-mod mac;
\ No newline at end of file
+mod mac;
+mod kdf;
\ No newline at end of file
@@ -0,0 +1,276 @@
+use wovocrypt::kdf::Hkdf;
+use wovocrypt::hash::Hasher;
+use wovocrypt::hash::prelude::*;
+use wovocrypt::error::SymcError;
+
+struct HkdfGoldData {
+    ikm: &'static str,
+    salt: &'static str,
+    info: &'static str,
+    okm: &'static str,
+}
+
+#[cfg(feature = "sha256")]
+const HKDF_SHA256_GOLD_DATA: &[HkdfGoldData] = &[
+    // RFC 5869, A.1
+    HkdfGoldData {
+        ikm: "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+        salt: "000102030405060708090a0b0c",
+        info: "f0f1f2f3f4f5f6f7f8f9",
+        okm: "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865",
+    },
+    // RFC 5869, A.2
+    HkdfGoldData {
+        ikm: "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f",
+        salt: "606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9aaabacadaeaf",
+        info: "b0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+        okm: "b11e398dc80327a1c8e7f78c596a49344f012eda2d4efad8a050cc4c19afa97c59045a99cac7827271cb41c65e590e09da3275600c2f09b8367793a9aca3db71cc30c58179ec3e87c14c01d5c1f3434f1d87",
+    },
+    // RFC 5869, A.3
+    HkdfGoldData {
+        ikm: "0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b",
+        salt: "",
+        info: "",
+        okm: "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8",
+    },
+];
+
+// Selection from Wycheproof hkdf_sha384_test.json
+#[cfg(feature = "sha384")]
+const HKDF_SHA384_GOLD_DATA: &[HkdfGoldData] = &[
+    HkdfGoldData {
+        ikm: "60ab7f45b0ad534683b3a6c020d4f775",
+        salt: "",
+        info: "",
+        okm: "3f8b0e4a7b2bff01a26a18f1e07c0218897a324e",
+    },
+    HkdfGoldData {
+        ikm: "5b01b2da3166f217cdd68de8af60078f",
+        salt: "",
+        info: "6884cfa7ffe8f27bf4ebc6e46a7e01488c79243a",
+        okm: "7bf6c7c72fa9bf184f9a2e13077a0e1afb9d976a5574fb7ec819d8bafb9b10f962e6fa8bc6a844ee0b609eee34aaaa025065a7e3a7fe4678a005640f7dc286c2",
+    },
+    HkdfGoldData {
+        ikm: "8677dc79233ef3480777c4c601ef4f0b",
+        salt: "ad88db718244e2cb60e35f874d7ad81f",
+        info: "a38f634d947819a9bfa792174b42baa20c9fce15",
+        okm: "758546362a070c0f13cbfbf1756e8f29b7819fb903c7ed4f97a56be3c8f81e8c37aef5c0f8e5d2b17eb1aa02ec04c33f546cb2f3d193e930a9f89ec9ce3a82b5",
+    },
+    HkdfGoldData {
+        ikm: "6cf725e939e8824d4392233eeac75d30",
+        salt: "1e72f24b05a91a0093f34306ffced79e7003055b0833c6d0f27a4f33a1bd",
+        info: "495425d9727fee2e2b7e78899868c1c3e7735e1d",
+        okm: "e13a7490f842b6e5be206e6d5ce69b2a8e2cba5525715283f22b021d2fbc2aec59d0144088581058f0fb2f551c6d62bfbd8a15d2706e23e10f3bf7277fff337f",
+    },
+    HkdfGoldData {
+        ikm: "34bae5a158c1678aa76a744417a70d7a",
+        salt: "1532075f363e061133780ac959bf653c7687d181b9431215d6f62dd2f1ec3019d61c50fa82c70ae25e624c849a276b0c57d7c02a4d753fe84a1a6621e9a5ef01",
+        info: "87ec30aa53acfc3d09ccc1d57d654fdbce403cd4",
+        okm: "9413c6e1b27f829fb82252b5ac5e14a54503e5f433fc8182a6b556bd7b8e04ac34b0d6006950d5917132",
+    },
+    HkdfGoldData {
+        ikm: "5943c65bc33bf05a205b04be8ae0ab2e",
+        salt: "",
+        info: "be082f301a03f87787a80fbea88941214d50c42b",
+        okm: "6f93965b7399bdcaef06c151056ba14b7392a1521af1145e0c1d05e34b6f19f3",
+    },
+    HkdfGoldData {
+        ikm: "5943c65bc33bf05a205b04be8ae0ab2e",
+        salt: "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        info: "be082f301a03f87787a80fbea88941214d50c42b",
+        okm: "6f93965b7399bdcaef06c151056ba14b7392a1521af1145e0c1d05e34b6f19f3",
+    },
+    HkdfGoldData {
+        ikm: "5943c65bc33bf05a205b04be8ae0ab2e",
+        salt: "329f445e7de8a156cf26a0208dbb028d9de6ef76b8de67ca634f4a5a732138a1bd436a7b345d7a0314c7ed0a00b0d34ecad2cb8bd141e2ecc1c77e237094d55154",
+        info: "be082f301a03f87787a80fbea88941214d50c42b",
+        okm: "94190f4f21f412c1d9358264a9dbb5035f94f39f5a503c0f54189b1dff1df637",
+    },
+    HkdfGoldData {
+        ikm: "e8d20934b9d320458f4854e2442e2f0fa092f461",
+        salt: "",
+        info: "4425999958aa3cc629300c25ab15be8cea7a4277",
+        okm: "d1e7a09a0f77ae7dd5cdb8e568aa53fd4ba63688623079fc8df2a53c9a44275cc61b09091f5997d0c819f89803fb6c990dd6a599f00ff68ab379e7afb29a5b4a",
+    },
+    HkdfGoldData {
+        ikm: "624a5b59c2be55cbe29ea90c0020a7e8c60f2501",
+        salt: "1a08959149f4b073bcd902c9bc4ed0324c21c95590773afc77037d610b9584806aeeeda8b5d588d0cd79e7c12211b8e394067516ce12946d61111a52042b539353",
+        info: "5447e595250d02165aae3e61fa90313e25509a7b",
+        okm: "7e060421904a880f28703cea5defae446809d4cd0b971272ba2cb78d8fd7eadf",
+    },
+    HkdfGoldData {
+        ikm: "baa311295125e326efd92676775b9aa20a0acd68fdd9b05795cf82e157c7dac61394fdc26cd7f8a9015e9587c5d0855d",
+        salt: "79f4669058de474f47efb74371ca5b6e3788a729abc31d47113ca0c2f972217ac9deb56b317f1e80fe42f5504c8690fa",
+        info: "ac9954349e500c55",
+        okm: "5f027dcc4e32bc2f1c23de92b8b5fad67312fdeca2c09daa97bf0c81015bfe02ff2c17de1851336833666db3b29ceb16",
+    },
+    HkdfGoldData {
+        ikm: "a91adac5ab8bdd60fb350eb81d7243cf97740787877d41b40eee1c4c9a96f077e8bda335cb0e3b106454e85629bc5e63",
+        salt: "07e28c9f6efd74908c06435c95f3ab25f4d9a9e023f287e7298f9cde0cba29717baa1158e86fb70d5bd76d2549291923",
+        info: "3eb47169931585a5",
+        okm: "fca326c96af6690eb9b61b4b2a23d78a05c90152667c87cf813c2c16f56047a63cc6103986d3c2bce48c5e4e031dde077fc153876bab3f57e12e871a506278f220d6180321ce84eb1ea45494d6b1c5bf44f60a397cf01d5a",
+    },
+    HkdfGoldData {
+        ikm: "e80e0fdb818f228c505ea15887a42abfd7b6479b589a76c33b0f63c00e7d188a20ef8e98534aa85df6e482750f85ad7f",
+        salt: "d1dd17d92c45854e1c617830ec2bd6ea613d8debe261ac30f0fdf9358a2be2dbb25f7ffaa9eee85f06df367b370804c8",
+        info: "31580276db515d6b",
+        okm: "941c9c841ecfd3b0d2c0488e0b327d151081d6f4d6b927c319df7ba4e3c9dd92ebe1c5b420af2f3b50b6991cc57a4f5a6aded05d5be9d699b4c70555e3dae218eb520158fd63e7be11bff5c5601ed9c6e616147aeb9878d01314ff519c4fe23bf29abe768df09bc485c175d9320e93aebce8336bd83c400b69d07fc19ff692bf05d299b25679cd038bffa43405057d22f014b9db5e5d94f09d3f6cea5d479d7e70f31dce39e2acd93f47f789ff094c0ae4c68b231d818548a81cd1373120b0f5",
+    },
+];
+
+// Selection from Wycheproof hkdf_sha512_test.json
+#[cfg(feature = "sha512")]
+const HKDF_SHA512_GOLD_DATA: &[HkdfGoldData] = &[
+    HkdfGoldData {
+        ikm: "60ab7f45b0ad534683b3a6c020d4f775",
+        salt: "",
+        info: "",
+        okm: "2109bd244744acae2b8caa9e70f57596ad680212",
+    },
+    HkdfGoldData {
+        ikm: "5b01b2da3166f217cdd68de8af60078f",
+        salt: "",
+        info: "6884cfa7ffe8f27bf4ebc6e46a7e01488c79243a",
+        okm: "01e10d4c477c906d4f67105e4a8054bd2e9479d726166893fcf77b5df431ad007c0ae42847d3706a770a5e468783c9519804be63a404112dcd4ecea952952b73",
+    },
+    HkdfGoldData {
+        ikm: "8677dc79233ef3480777c4c601ef4f0b",
+        salt: "ad88db718244e2cb60e35f874d7ad81f",
+        info: "a38f634d947819a9bfa792174b42baa20c9fce15",
+        okm: "918e9cda37bf7f52506111048a878e64a503f9869d0c2615047b995f1efedc4f713b4dbcc940838e68f6a2bf772ebefae9154e9075da80ea1fd68b9df580ad76",
+    },
+    HkdfGoldData {
+        ikm: "6cf725e939e8824d4392233eeac75d30",
+        salt: "1e72f24b05a91a0093f34306ffced79e7003055b0833c6d0f27a4f33a1bd",
+        info: "495425d9727fee2e2b7e78899868c1c3e7735e1d",
+        okm: "379e6d4fc3c9b344754a1094eac60b71e47e281695515987abbc3b22c1e267d95b101592896e08c869557ea82ba075d9c9524d3cb79d7d8cabb33364f5252968",
+    },
+    HkdfGoldData {
+        ikm: "34bae5a158c1678aa76a744417a70d7a",
+        salt: "1532075f363e061133780ac959bf653c7687d181b9431215d6f62dd2f1ec3019d61c50fa82c70ae25e624c849a276b0c57d7c02a4d753fe84a1a6621e9a5ef01",
+        info: "87ec30aa53acfc3d09ccc1d57d654fdbce403cd4",
+        okm: "65f5385dab06d375033a6a25926ef4bf5dc660737ab8ccef370af10cc9dcd7743cf273fd048f64b7301e",
+    },
+    HkdfGoldData {
+        ikm: "5943c65bc33bf05a205b04be8ae0ab2e",
+        salt: "",
+        info: "be082f301a03f87787a80fbea88941214d50c42b",
+        okm: "e91cabd2038706c02f8ba3102a545f40f340bf65de30d3c37c2408f14df06e1a",
+    },
+    HkdfGoldData {
+        ikm: "5943c65bc33bf05a205b04be8ae0ab2e",
+        salt: "00000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+        info: "be082f301a03f87787a80fbea88941214d50c42b",
+        okm: "e91cabd2038706c02f8ba3102a545f40f340bf65de30d3c37c2408f14df06e1a",
+    },
+    HkdfGoldData {
+        ikm: "5943c65bc33bf05a205b04be8ae0ab2e",
+        salt: "329f445e7de8a156cf26a0208dbb028d9de6ef76b8de67ca634f4a5a732138a1bd436a7b345d7a0314c7ed0a00b0d34ecad2cb8bd141e2ecc1c77e237094d55154",
+        info: "be082f301a03f87787a80fbea88941214d50c42b",
+        okm: "23117b38f94d22c8180ed72976a1f784fe591caebb6a07c1f0cbf8e29c6938ee",
+    },
+    HkdfGoldData {
+        ikm: "e8d20934b9d320458f4854e2442e2f0fa092f461",
+        salt: "",
+        info: "4425999958aa3cc629300c25ab15be8cea7a4277",
+        okm: "636c9341fc005909bbbfe18d9b4db595b5007178c13d295375d0738204306b522f0e33f37c67465b56d4a09450375a50e8e02199f3c44b7a89618aa940040d1d",
+    },
+    HkdfGoldData {
+        ikm: "624a5b59c2be55cbe29ea90c0020a7e8c60f2501",
+        salt: "1a08959149f4b073bcd902c9bc4ed0324c21c95590773afc77037d610b9584806aeeeda8b5d588d0cd79e7c12211b8e394067516ce12946d61111a52042b539353",
+        info: "5447e595250d02165aae3e61fa90313e25509a7b",
+        okm: "8b4fe5702f56eeb71bf7d90e87c95c3ad054e448d50dca0f65b78101f657dfe3",
+    },
+    HkdfGoldData {
+        ikm: "a75ce5b072311acdf290ceb4c6fd25eb6c52ecabc8ed1ffc698d7556d1132180e2888bbe6a798d891e0c8c5e0f79cabf3d27df56d472be152aae155b52d9a9f9",
+        salt: "8df18f4f797c4be88ca6b2935441a1100db080759c042a6d2c37d2e6fea9fd6fb066805c467b7557c78d078ae44dcb886e5e3d5f74a96bf6394aad36847ed8b7",
+        info: "69d2aa66efec2cca",
+        okm: "8b025c9925f105898fe8f75da1839b683beb73e349dd08b79a3d80a7b29e7d9c1eebb5cf4c902371b31376e44d49ea39725ac912d5055c8638ce0771a8edc999",
+    },
+    HkdfGoldData {
+        ikm: "5be4b9756eff71ebe87fdb5933e5d88d51bcaf384cc289c16ec642d67f2e9236c04106e01ebe7956bac010e4107b6b788ed8b3916a39b59c7c01161cbc2671a7",
+        salt: "336a871d315b7d2ae0a0d24febebf0702d9f039ca97146cc0aa2341728824e83553e6eb166e954dac33e4d6a2437309d0980e26d1c7665ddc79b2e9ba3354262",
+        info: "e496bea60a731eb7",
+        okm: "02487460b110121d3df3746d7860332b6d67d746f96ff8e7bcb2d62481b653dcf67903b25aabaf9031b370959105c9136536b52dab810cf041862e73d3352f77747814aee2d74cfa29840dbfbca242f38b95ea26d4a540edbdab3fbeced1c767d35a73b4c2ff180eee75b4ada9739b7bd8c75c3bb03589ab",
+    },
+    HkdfGoldData {
+        ikm: "45102dd5f609c2f9352b91d8b492d83b5ab34976372b4ac814bf82cf0dc4f3875cd31dfd5897022458fc7bb8e5d2930a620909b7385ba4e48c8395b50d7d07fb",
+        salt: "76d1494d3631034558ac7108a69b79f7e38a45aa50783af41bf8c19531fdb30782e7689a50a4eb1391415ab2e6085b1e246ce0e6c35a5e02910c072a241cd8a0",
+        info: "cf6db9210ef18e3d",
+        okm: "146e59745a9d65fa6c98308b6f162566045d2f459a09eeb7ef7cf15e076fe8093fa202d15b12063e55d4b5ab4e80d58140b2664d944e33d8b2a72a3ac307ca51c23976adb1ff9ff04fd0fcbd21edea49890c12698b0600b3b70a61dd228542d47e69797122f0734c08d53f51afeccea785af1028b35e6ee911fa0d98f76e2aedc517f35d719c199b6a73aa867ad31253fc1c1d612ecc913b70c2f6b983fd50eccd8a20172052c8726ac6ecad97b8bc8770ba5fd59eec423160828fd8b1936a5ac5bbccfb6398cf46dc3e19df40d315091f2ae3df875334030418143e7b791cd1f732935482fdd585fba2f495973436138c7fc106058f9538b92f055ea3bbfec1",
+    },
+];
+
+fn hkdf_gold_data<H: Hasher>(gold_data: &[HkdfGoldData]) {
+    for data in gold_data {
+        let ikm = hex::decode(data.ikm).unwrap();
+        let salt = hex::decode(data.salt).unwrap();
+        let info = hex::decode(data.info).unwrap();
+        let expected = hex::decode(data.okm).unwrap();
+
+        let mut okm = vec![0u8; expected.len()];
+        Hkdf::<H>::derive(&salt, &ikm, &info, &mut okm).expect("HKDF failed");
+        assert_eq!(okm, expected);
+
+        // separate extract and expand, info split in pieces
+        let prk = Hkdf::<H>::extract(&salt, &ikm);
+        let hkdf = Hkdf::<H>::from_prk(prk.as_ref()).expect("Invalid PRK");
+        let mid = info.len() / 2;
+        let mut okm = vec![0u8; expected.len()];
+        hkdf.expand_multi_info(&[&info[..mid], &[], &info[mid..]], &mut okm).expect("HKDF expand failed");
+        assert_eq!(okm, expected);
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(hkdf.expand_vec(&info, expected.len()).expect("HKDF expand failed"), expected);
+    }
+}
+
+#[cfg(feature = "sha256")]
+#[test]
+fn test_hkdf_sha256_gold_data() {
+    hkdf_gold_data::<Sha256>(HKDF_SHA256_GOLD_DATA);
+}
+
+#[cfg(feature = "sha256")]
+#[test]
+fn test_hkdf_sha256_prk() {
+    // RFC 5869, A.1
+    let prk = Hkdf::<Sha256>::extract(&hex::decode("000102030405060708090a0b0c").unwrap(), &[0x0b; 22]);
+    assert_eq!(hex::encode(prk.as_ref()), "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5");
+}
+
+#[cfg(feature = "sha384")]
+#[test]
+fn test_hkdf_sha384_gold_data() {
+    hkdf_gold_data::<Sha384>(HKDF_SHA384_GOLD_DATA);
+}
+
+#[cfg(feature = "sha512")]
+#[test]
+fn test_hkdf_sha512_gold_data() {
+    hkdf_gold_data::<Sha512>(HKDF_SHA512_GOLD_DATA);
+}
+
+fn hkdf_output_limit<H: Hasher>() {
+    let hkdf = Hkdf::<H>::new(b"salt", b"ikm");
+    let mut okm = vec![0u8; 255 * H::OUTPUT_SIZE + 1];
+
+    assert_eq!(Hkdf::<H>::MAX_OUTPUT_SIZE, 255 * H::OUTPUT_SIZE);
+    assert_eq!(hkdf.expand(b"info", &mut okm[..Hkdf::<H>::MAX_OUTPUT_SIZE]), Ok(()));
+    assert_eq!(hkdf.expand(b"info", &mut okm), Err(SymcError::InvalidLength));
+    #[cfg(feature = "alloc")]
+    assert_eq!(hkdf.expand_vec(b"info", Hkdf::<H>::MAX_OUTPUT_SIZE + 1), Err(SymcError::InvalidLength));
+    assert_eq!(hkdf.expand(b"info", &mut []), Ok(()));
+}
+
+#[test]
+fn test_hkdf_output_limit() {
+    #[cfg(feature = "sha224")]
+    hkdf_output_limit::<Sha224>();
+    #[cfg(feature = "sha256")]
+    hkdf_output_limit::<Sha256>();
+    #[cfg(feature = "sha384")]
+    hkdf_output_limit::<Sha384>();
+    #[cfg(feature = "sha512")]
+    hkdf_output_limit::<Sha512>();
+}
@@ -0,0 +1,5 @@
+#[cfg(feature = "hkdf")]
+pub mod hkdf_test;
+
+#[cfg(all(feature = "pbkdf2", feature = "std"))]
+pub mod pbkdf2_test;
\ No newline at end of file
@@ -0,0 +1,155 @@
+use wovocrypt::cipher::aes::{Aes128Key, Aes256Key};
+use wovocrypt::error::SymcError;
+use wovocrypt::hash::Hasher;
+use wovocrypt::kdf::Pbkdf2;
+use wovocrypt::hash::prelude::*;
+
+struct Pbkdf2GoldData {
+    password: &'static [u8],
+    salt: &'static [u8],
+    iterations: u32,
+    expected: &'static str,
+}
+
+#[cfg(feature = "sha256")]
+const PBKDF2_SHA256_GOLD_DATA: &[Pbkdf2GoldData] = &[
+    Pbkdf2GoldData {
+        password: b"password",
+        salt: b"salt",
+        iterations: 1,
+        expected: "120fb6cffcf8b32c43e7225256c4f837a86548c92ccc35480805987cb70be17b",
+    },
+    Pbkdf2GoldData {
+        password: b"password",
+        salt: b"salt",
+        iterations: 2,
+        expected: "ae4d0c95af6b46d32d0adff928f06dd02a303f8ef3c251dfd6e2d85a95474c43",
+    },
+    Pbkdf2GoldData {
+        password: b"password",
+        salt: b"salt",
+        iterations: 4096,
+        expected: "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a",
+    },
+    Pbkdf2GoldData {
+        password: b"passwordPASSWORDpassword",
+        salt: b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+        iterations: 4096,
+        expected: "348c89dbcbd32b2f32d814b8116e84cf2b17347ebc1800181c4e2a1fb8dd53e1c635518c7dac47e9",
+    },
+    Pbkdf2GoldData {
+        password: b"pass\0word",
+        salt: b"sa\0lt",
+        iterations: 4096,
+        expected: "89b69d0516f829893c696226650a8687",
+    },
+    // RFC 7914, 11
+    Pbkdf2GoldData {
+        password: b"passwd",
+        salt: b"salt",
+        iterations: 1,
+        expected: "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783",
+    },
+    // RFC 7914, 11
+    Pbkdf2GoldData {
+        password: b"Password",
+        salt: b"NaCl",
+        iterations: 80000,
+        expected: "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d",
+    },
+];
+
+#[cfg(feature = "sha512")]
+const PBKDF2_SHA512_GOLD_DATA: &[Pbkdf2GoldData] = &[
+    Pbkdf2GoldData {
+        password: b"password",
+        salt: b"salt",
+        iterations: 1,
+        expected: "867f70cf1ade02cff3752599a3a53dc4af34c7a669815ae5d513554e1c8cf252c02d470a285a0501bad999bfe943c08f050235d7d68b1da55e63f73b60a57fce",
+    },
+    Pbkdf2GoldData {
+        password: b"password",
+        salt: b"salt",
+        iterations: 2,
+        expected: "e1d9c16aa681708a45f5c7c4e215ceb66e011a2e9f0040713f18aefdb866d53cf76cab2868a39b9f7840edce4fef5a82be67335c77a6068e04112754f27ccf4e",
+    },
+    Pbkdf2GoldData {
+        password: b"password",
+        salt: b"salt",
+        iterations: 4096,
+        expected: "d197b1b33db0143e018b12f3d1d1479e6cdebdcc97c5c0f87f6902e072f457b5143f30602641b3d55cd335988cb36b84376060ecd532e039b742a239434af2d5",
+    },
+    Pbkdf2GoldData {
+        password: b"passwordPASSWORDpassword",
+        salt: b"saltSALTsaltSALTsaltSALTsaltSALTsalt",
+        iterations: 4096,
+        expected: "8c0511f4c6e597c6ac6315d8f0362e225f3c501495ba23b868c005174dc4ee71115b59f9e60cd9532fa33e0f75aefe30225c583a186cd82bd4daea9724a3d3b8",
+    },
+    Pbkdf2GoldData {
+        password: b"pass\0word",
+        salt: b"sa\0lt",
+        iterations: 4096,
+        expected: "9d9e9c4cd21fe4be24d5b8244c759665f39d98fc12a9ca759bb021db3cfadf345844aebe70dd8b2f6966f25f3613e1187bbd24ed2ca43ed13b246e4675be7ab9",
+    },
+    Pbkdf2GoldData {
+        password: b"passwd",
+        salt: b"salt",
+        iterations: 1,
+        expected: "c74319d99499fc3e9013acff597c23c5baf0a0bec5634c46b8352b793e324723d55caa76b2b25c43402dcfdc06cdcf66f95b7d0429420b39520006749c51a04e",
+    },
+    Pbkdf2GoldData {
+        password: b"Password",
+        salt: b"NaCl",
+        iterations: 80000,
+        expected: "e6337d6fbeb645c794d4a9b5b75b7b30dac9ac50376a91df1f4460f6060d5addb2c1fd1f84409abacc67de7eb4056e6bb06c2d82c3ef4ccd1bded0f675ed97c6",
+    },
+];
+
+fn pbkdf2_gold_data<H: Hasher>(gold_data: &[Pbkdf2GoldData]) where Pbkdf2<H>: Sync {
+    for data in gold_data {
+        let expected = hex::decode(data.expected).unwrap();
+
+        let mut output = vec![0u8; expected.len()];
+        Pbkdf2::<H>::compute(data.password, data.salt, data.iterations, &mut output).expect("PBKDF2 failed");
+        assert_eq!(output, expected);
+
+        let mut output = vec![0u8; expected.len()];
+        Pbkdf2::<H>::new(data.password).derive_parallel(data.salt, data.iterations, &mut output, 4).expect("PBKDF2 failed");
+        assert_eq!(output, expected);
+    }
+}
+
+#[cfg(feature = "sha256")]
+#[test]
+fn test_pbkdf2_sha256_gold_data() {
+    pbkdf2_gold_data::<Sha256>(PBKDF2_SHA256_GOLD_DATA);
+}
+
+#[cfg(feature = "sha512")]
+#[test]
+fn test_pbkdf2_sha512_gold_data() {
+    pbkdf2_gold_data::<Sha512>(PBKDF2_SHA512_GOLD_DATA);
+}
+
+#[cfg(feature = "sha256")]
+#[test]
+fn test_pbkdf2_derive_key() {
+    let pbkdf2 = Pbkdf2::<Sha256>::new(b"password");
+
+    let key = pbkdf2.derive_key::<Aes256Key>(b"salt", 4096).expect("PBKDF2 failed");
+    assert_eq!(hex::encode(key.as_ref()), PBKDF2_SHA256_GOLD_DATA[2].expected);
+
+    let key = pbkdf2.derive_key::<Aes128Key>(b"salt", 4096).expect("PBKDF2 failed");
+    assert_eq!(hex::encode(key.as_ref()), &PBKDF2_SHA256_GOLD_DATA[2].expected[..32]);
+}
+
+#[cfg(feature = "sha256")]
+#[test]
+fn test_pbkdf2_invalid_params() {
+    let pbkdf2 = Pbkdf2::<Sha256>::new(b"password");
+    let mut output = [0u8; 32];
+    assert_eq!(pbkdf2.derive(b"salt", 0, &mut output), Err(SymcError::InvalidLength));
+    assert_eq!(pbkdf2.derive_parallel(b"salt", 0, &mut output, 2), Err(SymcError::InvalidLength));
+    assert_eq!(pbkdf2.derive_key::<Aes256Key>(b"salt", 0).err(), Some(SymcError::InvalidLength));
+    assert_eq!(pbkdf2.derive(b"salt", 1, &mut []), Ok(()));
+}
@@ -0,0 +1,101 @@
+use wovocrypt::mac::Mac;
+use wovocrypt::mac::prelude::*;
+
+use crate::common::utils::{stress_test_mac};
+
+// BLAKE2 reference KAT layout: the key is 00 01 02 .., each message is 00 01 .. (len - 1)
+fn sample_data(length: usize) -> Vec<u8> {
+    (0..length).map(|i| i as u8).collect()
+}
+
+struct Blake2MacGoldData {
+    message_len: usize,
+    expected: &'static str,
+}
+
+#[cfg(feature = "blake2b")]
+const BLAKE2B_MAC_GOLD_DATA: &[Blake2MacGoldData] = &[
+    Blake2MacGoldData { message_len: 0, expected: "10ebb67700b1868efb4417987acf4690ae9d972fb7a590c2f02871799aaa4786b5e996e8f0f4eb981fc214b005f42d2ff4233499391653df7aefcbc13fc51568" },
+    Blake2MacGoldData { message_len: 1, expected: "961f6dd1e4dd30f63901690c512e78e4b45e4742ed197c3c5e45c549fd25f2e4187b0bc9fe30492b16b0d0bc4ef9b0f34c7003fac09a5ef1532e69430234cebd" },
+    Blake2MacGoldData { message_len: 63, expected: "bd965bf31e87d70327536f2a341cebc4768eca275fa05ef98f7f1b71a0351298de006fba73fe6733ed01d75801b4a928e54231b38e38c562b2e33ea1284992fa" },
+    Blake2MacGoldData { message_len: 64, expected: "65676d800617972fbd87e4b9514e1c67402b7a331096d3bfac22f1abb95374abc942f16e9ab0ead33b87c91968a6e509e119ff07787b3ef483e1dcdccf6e3022" },
+    Blake2MacGoldData { message_len: 65, expected: "939fa189699c5d2c81ddd1ffc1fa207c970b6a3685bb29ce1d3e99d42f2f7442da53e95a72907314f4588399a3ff5b0a92beb3f6be2694f9f86ecf2952d5b41c" },
+    Blake2MacGoldData { message_len: 127, expected: "76d2d819c92bce55fa8e092ab1bf9b9eab237a25267986cacf2b8ee14d214d730dc9a5aa2d7b596e86a1fd8fa0804c77402d2fcd45083688b218b1cdfa0dcbcb" },
+    Blake2MacGoldData { message_len: 128, expected: "72065ee4dd91c2d8509fa1fc28a37c7fc9fa7d5b3f8ad3d0d7a25626b57b1b44788d4caf806290425f9890a3a2a35a905ab4b37acfd0da6e4517b2525c9651e4" },
+    Blake2MacGoldData { message_len: 129, expected: "64475dfe7600d7171bea0b394e27c9b00d8e74dd1e416a79473682ad3dfdbb706631558055cfc8a40e07bd015a4540dcdea15883cbbf31412df1de1cd4152b91" },
+    Blake2MacGoldData { message_len: 255, expected: "142709d62e28fcccd0af97fad0f8465b971e82201dc51070faa0372aa43e92484be1c1e73ba10906d5d1853db6a4106e0a7bf9800d373d6dee2d46d62ef2a461" },
+];
+
+#[cfg(feature = "blake2s")]
+const BLAKE2S_MAC_GOLD_DATA: &[Blake2MacGoldData] = &[
+    Blake2MacGoldData { message_len: 0, expected: "48a8997da407876b3d79c0d92325ad3b89cbb754d86ab71aee047ad345fd2c49" },
+    Blake2MacGoldData { message_len: 1, expected: "40d15fee7c328830166ac3f918650f807e7e01e177258cdc0a39b11f598066f1" },
+    Blake2MacGoldData { message_len: 63, expected: "c65382513f07460da39833cb666c5ed82e61b9e998f4b0c4287cee56c3cc9bcd" },
+    Blake2MacGoldData { message_len: 64, expected: "8975b0577fd35566d750b362b0897a26c399136df07bababbde6203ff2954ed4" },
+    Blake2MacGoldData { message_len: 65, expected: "21fe0ceb0052be7fb0f004187cacd7de67fa6eb0938d927677f2398c132317a8" },
+    Blake2MacGoldData { message_len: 127, expected: "ddbfea75cc467882eb3483ce5e2e756a4f4701b76b445519e89f22d60fa86e06" },
+    Blake2MacGoldData { message_len: 128, expected: "0c311f38c35a4fb90d651c289d486856cd1413df9b0677f53ece2cd9e477c60a" },
+    Blake2MacGoldData { message_len: 129, expected: "46a73a8dd3e70f59d3942c01df599def783c9da82fd83222cd662b53dce7dbdf" },
+    Blake2MacGoldData { message_len: 255, expected: "3fb735061abc519dfe979e54c1ee5bfad0a9d858b3315bad34bde999efd724dd" },
+];
+
+#[cfg(feature = "blake2b")]
+#[test]
+fn test_blake2b_mac_gold_data() {
+    let key = sample_data(64);
+    for data in BLAKE2B_MAC_GOLD_DATA {
+        let message = sample_data(data.message_len);
+        let tag = Blake2bMac512::compute(&key, &message);
+        assert_eq!(hex::encode(tag.as_ref()), data.expected);
+        assert!(Blake2bMac512::verify(&key, &message, tag.as_ref()));
+    }
+}
+
+#[cfg(feature = "blake2s")]
+#[test]
+fn test_blake2s_mac_gold_data() {
+    let key = sample_data(32);
+    for data in BLAKE2S_MAC_GOLD_DATA {
+        let message = sample_data(data.message_len);
+        let mut mac = Blake2sMac256::new(&key);
+        for chunk in message.chunks(17) {
+            mac.update(chunk);
+        }
+        assert_eq!(hex::encode(mac.finalize().as_ref()), data.expected);
+    }
+}
+
+#[cfg(feature = "blake2b")]
+#[test]
+fn test_blake2b_mac_truncated_and_personalized() {
+    assert_eq!(hex::encode(Blake2bMac256::compute(b"key", b"message").as_ref()), "a1b1483fd337f6b23318a53b756ad49d2541f3052ffb67515203dd7951b6e94e");
+
+    let params = wovocrypt::hash::prelude::Blake2bParams::new().key(b"key").salt(b"NaCl").personal(b"app");
+    let mut mac = Blake2bMac::<16>::with_params(&params).unwrap();
+    mac.update(b"message");
+    assert_eq!(hex::encode(mac.finalize().as_ref()), "65e68cf1823317a5162258ad9d442bcb");
+}
+
+#[cfg(feature = "blake2b")]
+#[test]
+fn test_blake2b_mac_stress() {
+    stress_test_mac::<Blake2bMac512>(1000);
+}
+
+#[cfg(feature = "blake2s")]
+#[test]
+fn test_blake2s_mac_stress() {
+    stress_test_mac::<Blake2sMac256>(1000);
+}
+
+#[cfg(feature = "blake2s")]
+#[test]
+fn test_blake2s_mac_long_key() {
+    use wovocrypt::hash::Hasher;
+
+    // keys over 32 bytes are hashed down to a full size BLAKE2s digest first
+    let long_key = b"a-constant-key-for-stress-testing";
+    let hashed_key = wovocrypt::hash::prelude::Blake2s256::compute(long_key);
+    assert_eq!(Blake2sMac256::compute(long_key, b"message").as_ref(), Blake2sMac256::compute(hashed_key.as_ref(), b"message").as_ref());
+    assert!(Blake2sMac256::new_from_slice(long_key).is_err());
+}
@@ -0,0 +1,121 @@
+use wovocrypt::mac::Mac;
+use wovocrypt::mac::prelude::{HmacMd5, HmacSha1};
+
+use crate::common::{HmacGoldData, assert_mac_eq};
+use crate::common::utils::{stress_test_mac};
+
+// RFC 2202, section 3
+const HMAC_SHA1_GOLD_DATA: &[HmacGoldData] = &[
+    HmacGoldData {
+        key: &[0x0b; 20],
+        message: b"Hi There",
+        expected: "b617318655057264e28bc0b6fb378c8ef146be00",
+    },
+    HmacGoldData {
+        key: b"Jefe",
+        message: b"what do ya want for nothing?",
+        expected: "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79",
+    },
+    HmacGoldData {
+        key: &[0xaa; 20],
+        message: &[0xdd; 50],
+        expected: "125d7342b9ac11cd91a39af48aa17b4f63f175d3",
+    },
+    HmacGoldData {
+        key: &[
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+            0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+        ],
+        message: &[0xcd; 50],
+        expected: "4c9007f4026250c6bc8414f9bf50c86c2d7235da",
+    },
+    HmacGoldData {
+        key: &[0x0c; 20],
+        message: b"Test With Truncation",
+        expected: "4c1a03424b55e07fe7f27be1d58bb9324a9a5a04",
+    },
+    HmacGoldData {
+        key: &[0xaa; 80],
+        message: b"Test Using Larger Than Block-Size Key - Hash Key First",
+        expected: "aa4ae5e15272d00e95705637ce8a3b55ed402112",
+    },
+    HmacGoldData {
+        key: &[0xaa; 80],
+        message: b"Test Using Larger Than Block-Size Key and Larger Than One Block-Size Data",
+        expected: "e8e99d0f45237d786d6bbaa7965c7808bbff1a91",
+    },
+];
+
+// RFC 2202, section 2
+const HMAC_MD5_GOLD_DATA: &[HmacGoldData] = &[
+    HmacGoldData {
+        key: &[0x0b; 16],
+        message: b"Hi There",
+        expected: "9294727a3638bb1c13f48ef8158bfc9d",
+    },
+    HmacGoldData {
+        key: b"Jefe",
+        message: b"what do ya want for nothing?",
+        expected: "750c783e6ab0b503eaa86e310a5db738",
+    },
+    HmacGoldData {
+        key: &[0xaa; 16],
+        message: &[0xdd; 50],
+        expected: "56be34521d144c88dbb8c733f0e8b3f6",
+    },
+    HmacGoldData {
+        key: &[
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d,
+            0x0e, 0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19,
+        ],
+        message: &[0xcd; 50],
+        expected: "697eaf0aca3a3aea3a75164746ffaa79",
+    },
+    HmacGoldData {
+        key: &[0x0c; 16],
+        message: b"Test With Truncation",
+        expected: "56461ef2342edc00f9bab995690efd4c",
+    },
+    HmacGoldData {
+        key: &[0xaa; 80],
+        message: b"Test Using Larger Than Block-Size Key - Hash Key First",
+        expected: "6b1ab7fe4bd7bf8f0b62e6ce61b9d0cd",
+    },
+    HmacGoldData {
+        key: &[0xaa; 80],
+        message: b"Test Using Larger Than Block-Size Key and Larger Than One Block-Size Data",
+        expected: "6f630fad67cda0ee1fb1f562db3aa53e",
+    },
+];
+
+#[test]
+fn test_hmac_sha1_gold_data() {
+    for data in HMAC_SHA1_GOLD_DATA {
+        assert_mac_eq!(HmacSha1, data.key, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_hmac_md5_gold_data() {
+    for data in HMAC_MD5_GOLD_DATA {
+        assert_mac_eq!(HmacMd5, data.key, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_hmac_sha1_multi_update() {
+    let key = b"Jefe";
+    let mut mac = HmacSha1::new(key);
+    mac.update(b"what do ya");
+    mac.update(b" want for ");
+    mac.update(b"nothing?");
+    let result = mac.finalize();
+    let expected = "effcdf6ae5eb2fa2d27416d5f184df9c259a7c79";
+    assert_eq!(hex::encode(result.as_ref()), expected);
+}
+
+#[test]
+fn test_hmac_sha1_stress() {
+    stress_test_mac::<HmacSha1>(1000);
+    stress_test_mac::<HmacMd5>(1000);
+}
@@ -0,0 +1,85 @@
+use wovocrypt::mac::Mac;
+
+use crate::common::{HmacGoldData, assert_mac_eq};
+use crate::common::utils::{stress_test_mac};
+
+// RFC 4231 inputs, expected values from OpenSSL
+#[cfg(feature = "sha512-224")]
+const HMAC_SHA512_224_GOLD_DATA: &[HmacGoldData] = &[
+    HmacGoldData {
+        key: &[0x0b; 20],
+        message: b"Hi There",
+        expected: "b244ba01307c0e7a8ccaad13b1067a4cf6b961fe0c6a20bda3d92039",
+    },
+    HmacGoldData {
+        key: b"Jefe",
+        message: b"what do ya want for nothing?",
+        expected: "4a530b31a79ebcce36916546317c45f247d83241dfb818fd37254bde",
+    },
+    HmacGoldData {
+        key: &[0xaa; 20],
+        message: &[0xdd; 50],
+        expected: "db34ea525c2c216ee5a6ccb6608bea870bbef12fd9b96a5109e2b6fc",
+    },
+    HmacGoldData {
+        key: &[0xaa; 131],
+        message: b"Test Using Larger Than Block-Size Key - Hash Key First",
+        expected: "29bef8ce88b54d4226c3c7718ea9e32ace2429026f089e38cea9aeda",
+    },
+];
+
+#[cfg(feature = "sha512-256")]
+const HMAC_SHA512_256_GOLD_DATA: &[HmacGoldData] = &[
+    HmacGoldData {
+        key: &[0x0b; 20],
+        message: b"Hi There",
+        expected: "9f9126c3d9c3c330d760425ca8a217e31feae31bfe70196ff81642b868402eab",
+    },
+    HmacGoldData {
+        key: b"Jefe",
+        message: b"what do ya want for nothing?",
+        expected: "6df7b24630d5ccb2ee335407081a87188c221489768fa2020513b2d593359456",
+    },
+    HmacGoldData {
+        key: &[0xaa; 20],
+        message: &[0xdd; 50],
+        expected: "229006391d66c8ecddf43ba5cf8f83530ef221a4e9401840d1bead5137c8a2ea",
+    },
+    HmacGoldData {
+        key: &[0xaa; 131],
+        message: b"Test Using Larger Than Block-Size Key - Hash Key First",
+        expected: "87123c45f7c537a404f8f47cdbedda1fc9bec60eeb971982ce7ef10e774e6539",
+    },
+];
+
+#[cfg(feature = "sha512-224")]
+#[test]
+fn test_hmac_sha512_224_gold_data() {
+    use wovocrypt::mac::prelude::HmacSha512_224;
+    for data in HMAC_SHA512_224_GOLD_DATA {
+        assert_mac_eq!(HmacSha512_224, data.key, data.message, data.expected);
+    }
+}
+
+#[cfg(feature = "sha512-224")]
+#[test]
+fn test_hmac_sha512_224_stress() {
+    use wovocrypt::mac::prelude::HmacSha512_224;
+    stress_test_mac::<HmacSha512_224>(1000);
+}
+
+#[cfg(feature = "sha512-256")]
+#[test]
+fn test_hmac_sha512_256_gold_data() {
+    use wovocrypt::mac::prelude::HmacSha512_256;
+    for data in HMAC_SHA512_256_GOLD_DATA {
+        assert_mac_eq!(HmacSha512_256, data.key, data.message, data.expected);
+    }
+}
+
+#[cfg(feature = "sha512-256")]
+#[test]
+fn test_hmac_sha512_256_stress() {
+    use wovocrypt::mac::prelude::HmacSha512_256;
+    stress_test_mac::<HmacSha512_256>(1000);
+}
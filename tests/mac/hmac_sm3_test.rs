@@ -0,0 +1,41 @@
+use wovocrypt::mac::Mac;
+use wovocrypt::mac::prelude::HmacSm3;
+
+use crate::common::{HmacGoldData, assert_mac_eq};
+use crate::common::utils::{stress_test_mac};
+
+// RFC 4231 inputs, expected values from OpenSSL
+const HMAC_SM3_GOLD_DATA: &[HmacGoldData] = &[
+    HmacGoldData {
+        key: &[0x0b; 20],
+        message: b"Hi There",
+        expected: "51b00d1fb49832bfb01c3ce27848e59f871d9ba938dc563b338ca964755cce70",
+    },
+    HmacGoldData {
+        key: b"Jefe",
+        message: b"what do ya want for nothing?",
+        expected: "2e87f1d16862e6d964b50a5200bf2b10b764faa9680a296a2405f24bec39f882",
+    },
+    HmacGoldData {
+        key: &[0xaa; 20],
+        message: &[0xdd; 50],
+        expected: "dd9421e1c725bdf52ec1aa34edadb3c97f5951a83a2fa93f73a7902bc1dcc777",
+    },
+    HmacGoldData {
+        key: &[0xaa; 131],
+        message: b"Test Using Larger Than Block-Size Key - Hash Key First",
+        expected: "b4fd844e13342002f0b2e0690ea7741f1497d993a70494cea601e657bedf67a0",
+    },
+];
+
+#[test]
+fn test_hmac_sm3_gold_data() {
+    for data in HMAC_SM3_GOLD_DATA {
+        assert_mac_eq!(HmacSm3, data.key, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_hmac_sm3_stress() {
+    stress_test_mac::<HmacSm3>(1000);
+}
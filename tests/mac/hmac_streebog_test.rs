@@ -0,0 +1,70 @@
+use wovocrypt::mac::Mac;
+use wovocrypt::mac::prelude::{HmacStreebog256, HmacStreebog512};
+
+use crate::common::{HmacGoldData, assert_mac_eq};
+use crate::common::utils::{stress_test_mac};
+
+const RFC7836_KEY: &[u8] = &[
+    0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e, 0x0f,
+    0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d, 0x1e, 0x1f,
+];
+const RFC7836_MESSAGE: &[u8] = &[
+    0x01, 0x26, 0xbd, 0xb8, 0x78, 0x00, 0xaf, 0x21, 0x43, 0x41, 0x45, 0x65, 0x63, 0x78, 0x01, 0x00,
+];
+
+// the first entries are RFC 7836, Section 4.1, the rest are from GnuTLS
+const HMAC_STREEBOG256_GOLD_DATA: &[HmacGoldData] = &[
+    HmacGoldData {
+        key: RFC7836_KEY,
+        message: RFC7836_MESSAGE,
+        expected: "a1aa5f7de402d7b3d323f2991c8d4534013137010a83754fd0af6d7cd4922ed9",
+    },
+    HmacGoldData {
+        key: b"Jefe",
+        message: b"what do ya want for nothing?",
+        expected: "a2615d78499c4e79b9fd8347aa795dc80d7053a278fe2b30b614eee1aec9cf4f",
+    },
+    HmacGoldData {
+        key: &[0xaa; 131],
+        message: b"Test Using Larger Than Block-Size Key - Hash Key First",
+        expected: "78c6bb5c334d4a142a54cd82f97fd36831f19b258ede86df4b8cc948f2124b20",
+    },
+];
+
+const HMAC_STREEBOG512_GOLD_DATA: &[HmacGoldData] = &[
+    HmacGoldData {
+        key: RFC7836_KEY,
+        message: RFC7836_MESSAGE,
+        expected: "a59bab22ecae19c65fbde6e5f4e9f5d8549d31f037f9df9b905500e171923a773d5f1530f2ed7e964cb2eedc29e9ad2f3afe93b2814f79f5000ffc0366c251e6",
+    },
+    HmacGoldData {
+        key: b"Jefe",
+        message: b"what do ya want for nothing?",
+        expected: "f384729546493f501a2180e69e9265eef6102b25bd0017b0e4e410efd89b862df24ab76b3421cbe9caecb086f9d81c3625ee16e98aeeb2cf37977e458754a7f7",
+    },
+    HmacGoldData {
+        key: &[0xaa; 131],
+        message: b"Test Using Larger Than Block-Size Key - Hash Key First",
+        expected: "cfe06d983928e54cd33215f9aedbc32d8845533d52eb40c465c5e6257c558240ad6f6e086f1494f2e5d55f3daa5a884ba567e81817eef4d79ac6101845aafe39",
+    },
+];
+
+#[test]
+fn test_hmac_streebog256_gold_data() {
+    for data in HMAC_STREEBOG256_GOLD_DATA {
+        assert_mac_eq!(HmacStreebog256, data.key, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_hmac_streebog512_gold_data() {
+    for data in HMAC_STREEBOG512_GOLD_DATA {
+        assert_mac_eq!(HmacStreebog512, data.key, data.message, data.expected);
+    }
+}
+
+#[test]
+fn test_hmac_streebog_stress() {
+    stress_test_mac::<HmacStreebog256>(1000);
+    stress_test_mac::<HmacStreebog512>(1000);
+}
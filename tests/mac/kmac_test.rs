@@ -0,0 +1,151 @@
+use wovocrypt::hash::XofReader;
+use wovocrypt::mac::Mac;
+use wovocrypt::mac::prelude::*;
+
+use crate::common::utils::{stress_test_mac};
+
+// SP 800-185 KMAC samples, all keyed with 40 41 .. 5f over data 00 01 02 ..
+const KEY: &[u8] = &[
+    0x40, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0x4a, 0x4b, 0x4c, 0x4d, 0x4e, 0x4f,
+    0x50, 0x51, 0x52, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5a, 0x5b, 0x5c, 0x5d, 0x5e, 0x5f,
+];
+
+fn sample_data(length: usize) -> Vec<u8> {
+    (0..length).map(|i| i as u8).collect()
+}
+
+struct KmacGoldData {
+    data_len: usize,
+    customization: &'static [u8],
+    expected: &'static str,
+}
+
+const KMAC128_GOLD_DATA: &[KmacGoldData] = &[
+    KmacGoldData {
+        data_len: 4,
+        customization: b"",
+        expected: "e5780b0d3ea6f7d3a429c5706aa43a00fadbd7d49628839e3187243f456ee14e",
+    },
+    KmacGoldData {
+        data_len: 4,
+        customization: b"My Tagged Application",
+        expected: "3b1fba963cd8b0b59e8c1a6d71888b7143651af8ba0a7070c0979e2811324aa5",
+    },
+    KmacGoldData {
+        data_len: 200,
+        customization: b"My Tagged Application",
+        expected: "1f5b4e6cca02209e0dcb5ca635b89a15e271ecc760071dfd805faa38f9729230",
+    },
+];
+
+const KMAC256_GOLD_DATA: &[KmacGoldData] = &[
+    KmacGoldData {
+        data_len: 4,
+        customization: b"My Tagged Application",
+        expected: "20c570c31346f703c9ac36c61c03cb64c3970d0cfc787e9b79599d273a68d2f7f69d4cc3de9d104a351689f27cf6f5951f0103f33f4f24871024d9c27773a8dd",
+    },
+    KmacGoldData {
+        data_len: 200,
+        customization: b"",
+        expected: "75358cf39e41494e949707927cee0af20a3ff553904c86b08f21cc414bcfd691589d27cf5e15369cbbff8b9a4c2eb17800855d0235ff635da82533ec6b759b69",
+    },
+    KmacGoldData {
+        data_len: 200,
+        customization: b"My Tagged Application",
+        expected: "b58618f71f92e1d56c1b8c55ddd7cd188b97b4ca4d99831eb2699a837da2e4d970fbacfde50033aea585f1a2708510c32d07880801bd182898fe476876fc8965",
+    },
+];
+
+const KMACXOF128_GOLD_DATA: &[KmacGoldData] = &[
+    KmacGoldData {
+        data_len: 4,
+        customization: b"",
+        expected: "cd83740bbd92ccc8cf032b1481a0f4460e7ca9dd12b08a0c4031178bacd6ec35",
+    },
+    KmacGoldData {
+        data_len: 4,
+        customization: b"My Tagged Application",
+        expected: "31a44527b4ed9f5c6101d11de6d26f0620aa5c341def41299657fe9df1a3b16c",
+    },
+    KmacGoldData {
+        data_len: 200,
+        customization: b"My Tagged Application",
+        expected: "47026c7cd793084aa0283c253ef658490c0db61438b8326fe9bddf281b83ae0f",
+    },
+];
+
+const KMACXOF256_GOLD_DATA: &[KmacGoldData] = &[
+    KmacGoldData {
+        data_len: 4,
+        customization: b"My Tagged Application",
+        expected: "1755133f1534752aad0748f2c706fb5c784512cab835cd15676b16c0c6647fa96faa7af634a0bf8ff6df39374fa00fad9a39e322a7c92065a64eb1fb0801eb2b",
+    },
+    KmacGoldData {
+        data_len: 200,
+        customization: b"",
+        expected: "ff7b171f1e8a2b24683eed37830ee797538ba8dc563f6da1e667391a75edc02ca633079f81ce12a25f45615ec89972031d18337331d24ceb8f8ca8e6a19fd98b",
+    },
+    KmacGoldData {
+        data_len: 200,
+        customization: b"My Tagged Application",
+        expected: "d5be731c954ed7732846bb59dbe3a8e30f83e77a4bff4459f2f1c2b4ecebb8ce67ba01c62e8ab8578d2d499bd1bb276768781190020a306a97de281dcc30305d",
+    },
+];
+
+#[test]
+fn test_kmac128_gold_data() {
+    for data in KMAC128_GOLD_DATA {
+        let mut kmac = Kmac128::new_customized(KEY, data.customization);
+        kmac.update(&sample_data(data.data_len));
+        assert_eq!(hex::encode(kmac.finalize().as_ref()), data.expected);
+    }
+
+    // the Mac trait uses an empty customization string
+    let tag = Kmac128::compute(KEY, &sample_data(4));
+    assert_eq!(hex::encode(tag.as_ref()), KMAC128_GOLD_DATA[0].expected);
+    assert!(Kmac128::verify(KEY, &sample_data(4), tag.as_ref()));
+}
+
+#[test]
+fn test_kmac256_gold_data() {
+    for data in KMAC256_GOLD_DATA {
+        let mut kmac = Kmac256::new_customized(KEY, data.customization);
+        for chunk in sample_data(data.data_len).chunks(11) {
+            kmac.update(chunk);
+        }
+        let mut output = [0u8; 64];
+        kmac.finalize_into(&mut output);
+        assert_eq!(hex::encode(output), data.expected);
+    }
+}
+
+#[test]
+fn test_kmacxof128_gold_data() {
+    for data in KMACXOF128_GOLD_DATA {
+        let mut kmac = Kmac128::new_customized(KEY, data.customization);
+        kmac.update(&sample_data(data.data_len));
+        let mut output = [0u8; 32];
+        kmac.finalize_xof().squeeze(&mut output);
+        assert_eq!(hex::encode(output), data.expected);
+    }
+}
+
+#[test]
+fn test_kmacxof256_gold_data() {
+    for data in KMACXOF256_GOLD_DATA {
+        let mut kmac = Kmac256::new_customized(KEY, data.customization);
+        kmac.update(&sample_data(data.data_len));
+        let mut reader = kmac.finalize_xof();
+        let mut output = [0u8; 64];
+        for chunk in output.chunks_mut(7) {
+            reader.squeeze(chunk);
+        }
+        assert_eq!(hex::encode(output), data.expected);
+    }
+}
+
+#[test]
+fn test_kmac_stress() {
+    stress_test_mac::<Kmac128>(1000);
+    stress_test_mac::<Kmac256>(1000);
+}
@@ -8,4 +8,28 @@ pub mod hmac_sha256_test;
 pub mod hmac_sha384_test;
 
 #[cfg(all(feature = "hmac", feature = "sha512"))]
-pub mod hmac_sha512_test;
\ No newline at end of file
+pub mod hmac_sha512_test;
+
+#[cfg(all(feature = "hmac", any(feature = "sha512-224", feature = "sha512-256")))]
+pub mod hmac_sha512t_test;
+
+#[cfg(all(feature = "hmac", any(feature = "sha3-224", feature = "sha3-256", feature = "sha3-384", feature = "sha3-512")))]
+pub mod hmac_sha3_test;
+
+#[cfg(feature = "poly1305")]
+pub mod poly1305_test;
+
+#[cfg(feature = "kmac")]
+pub mod kmac_test;
+
+#[cfg(any(feature = "blake2b", feature = "blake2s"))]
+pub mod blake2_mac_test;
+
+#[cfg(all(feature = "hmac", feature = "sm3"))]
+pub mod hmac_sm3_test;
+
+#[cfg(all(feature = "hmac", feature = "streebog"))]
+pub mod hmac_streebog_test;
+
+#[cfg(all(feature = "hmac", feature = "legacy"))]
+pub mod hmac_sha1_test;
\ No newline at end of file